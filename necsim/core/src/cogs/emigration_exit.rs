@@ -1,4 +1,4 @@
-use necsim_core_bond::{NonNegativeF64, PositiveF64};
+use necsim_core_bond::{ClosedUnitF64, NonNegativeF64, PositiveF64};
 
 use crate::{
     cogs::{Habitat, LineageStore, MathsCore, RngCore},
@@ -41,6 +41,7 @@ pub trait EmigrationExit<M: MathsCore, H: Habitat<M>, G: RngCore<M>, S: LineageS
         dispersal_target: Location,
         prior_time: NonNegativeF64,
         event_time: PositiveF64,
+        speciation_sample: ClosedUnitF64,
         simulation: &mut PartialSimulation<M, H, G, S>,
         rng: &mut G,
     ) -> Option<(
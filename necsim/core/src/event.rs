@@ -1,4 +1,4 @@
-use necsim_core_bond::{NonNegativeF64, PositiveF64};
+use necsim_core_bond::{ClosedUnitF64, NonNegativeF64, PositiveF64};
 use serde::{Deserialize, Serialize};
 
 use core::{
@@ -25,6 +25,7 @@ pub struct PackedEvent {
     origin: IndexedLocation,
     target: IndexedLocation,
     coalescence: GlobalLineageReference,
+    speciation_sample: ClosedUnitF64, // see `SpeciationEvent::speciation_sample`
 }
 
 impl PackedEvent {
@@ -37,7 +38,7 @@ impl PackedEvent {
 
 #[allow(dead_code)]
 const EXCESSIVE_PACKED_EVENT_ERROR: [(); 1 - {
-    const ASSERT: bool = core::mem::size_of::<PackedEvent>() == 56;
+    const ASSERT: bool = core::mem::size_of::<PackedEvent>() == 64;
     ASSERT
 } as usize] = [];
 
@@ -62,11 +63,13 @@ pub struct SpeciationEvent {
     pub prior_time: NonNegativeF64, // time of the previous event
     pub event_time: PositiveF64,    // time of this event
     pub origin: IndexedLocation,
+    // uniform sample in [0.0, 1.0) which decided the speciation, or 1.0
+    pub speciation_sample: ClosedUnitF64,
 }
 
 #[allow(dead_code)]
 const EXCESSIVE_SPECIATION_EVENT_ERROR: [(); 1 - {
-    const ASSERT: bool = core::mem::size_of::<SpeciationEvent>() == 40;
+    const ASSERT: bool = core::mem::size_of::<SpeciationEvent>() == 48;
     ASSERT
 } as usize] = [];
 
@@ -79,11 +82,13 @@ pub struct DispersalEvent {
     pub origin: IndexedLocation,
     pub target: IndexedLocation,
     pub interaction: LineageInteraction,
+    // uniform sample in [0.0, 1.0) which decided against speciation, or 1.0
+    pub speciation_sample: ClosedUnitF64,
 }
 
 #[allow(dead_code)]
 const EXCESSIVE_DISPERSAL_EVENT_ERROR: [(); 1 - {
-    const ASSERT: bool = core::mem::size_of::<DispersalEvent>() == 64;
+    const ASSERT: bool = core::mem::size_of::<DispersalEvent>() == 72;
     ASSERT
 } as usize] = [];
 
@@ -110,6 +115,7 @@ impl From<SpeciationEvent> for PackedEvent {
             origin: event.origin.clone(),
             target: event.origin,
             coalescence: event.global_lineage_reference,
+            speciation_sample: event.speciation_sample,
         }
     }
 }
@@ -123,6 +129,7 @@ impl From<DispersalEvent> for PackedEvent {
             origin,
             target,
             interaction,
+            speciation_sample,
         }: DispersalEvent,
     ) -> Self {
         let prior_time = prior_time.get();
@@ -158,6 +165,7 @@ impl From<DispersalEvent> for PackedEvent {
             origin,
             target,
             coalescence,
+            speciation_sample,
         }
     }
 }
@@ -192,6 +200,7 @@ impl From<PackedEvent> for TypedEvent {
             origin,
             target,
             coalescence,
+            speciation_sample,
         }: PackedEvent,
     ) -> Self {
         let prior_pos = prior_time.is_sign_positive();
@@ -209,6 +218,7 @@ impl From<PackedEvent> for TypedEvent {
                 origin,
                 target,
                 interaction: LineageInteraction::None,
+                speciation_sample,
             }),
             // speciation is encoded as p(-), e(+)
             (false, true) => Self::Speciation(SpeciationEvent {
@@ -216,6 +226,7 @@ impl From<PackedEvent> for TypedEvent {
                 prior_time,
                 event_time,
                 origin,
+                speciation_sample,
             }),
             // dispersal, maybe coalescence is encoded as p(+), e(-)
             (true, false) => Self::Dispersal(DispersalEvent {
@@ -225,6 +236,7 @@ impl From<PackedEvent> for TypedEvent {
                 origin,
                 target,
                 interaction: LineageInteraction::Maybe,
+                speciation_sample,
             }),
             // dispersal, with coalescence is encoded as p(+), e(+)
             (true, true) => Self::Dispersal(DispersalEvent {
@@ -234,6 +246,7 @@ impl From<PackedEvent> for TypedEvent {
                 origin,
                 target,
                 interaction: LineageInteraction::Coalescence(coalescence),
+                speciation_sample,
             }),
         }
     }
@@ -244,7 +257,8 @@ impl Eq for PackedEvent {}
 impl PartialEq for PackedEvent {
     // `Event`s are equal when they have the same `origin`, `event_time`,
     //  and `target`
-    // (`global_lineage_reference`, `prior_time`, and `coalescence` are ignored)
+    // (`global_lineage_reference`, `prior_time`, `coalescence`, and
+    //  `speciation_sample` are ignored)
     fn eq(&self, other: &Self) -> bool {
         self.origin == other.origin
             && unsafe { PositiveF64::new_unchecked(self.event_time) }
@@ -261,7 +275,7 @@ impl Ord for PackedEvent {
         //  (3) target                           \=/
         //  (4) prior_time              parent + offspring
         //  (5) global_lineage_reference
-        // (coalescence is ignored)
+        // (coalescence and speciation_sample are ignored)
         (
             &unsafe { PositiveF64::new_unchecked(self.event_time.make_positive()) },
             &self.origin,
@@ -288,7 +302,8 @@ impl PartialOrd for PackedEvent {
 impl Hash for PackedEvent {
     // `Event`s are equal when they have the same `origin`, `event_time`,
     //  and `target`.
-    // (`global_lineage_reference`, `prior_time`, and `coalescence` are ignored)
+    // (`global_lineage_reference`, `prior_time`, `coalescence`, and
+    //  `speciation_sample` are ignored)
     fn hash<S: Hasher>(&self, state: &mut S) {
         self.origin.hash(state);
         unsafe { PositiveF64::new_unchecked(self.event_time.make_positive()) }.hash(state);
@@ -300,7 +315,8 @@ impl Eq for SpeciationEvent {}
 
 impl PartialEq for SpeciationEvent {
     // `SpeciationEvent`s are equal when they have the same `origin` and
-    //  `event_time` (`global_lineage_reference` and `prior_time` are ignored)
+    //  `event_time` (`global_lineage_reference`, `prior_time`, and
+    //  `speciation_sample` are ignored)
     fn eq(&self, other: &Self) -> bool {
         self.origin == other.origin && self.event_time == other.event_time
     }
@@ -311,7 +327,8 @@ impl Eq for DispersalEvent {}
 impl PartialEq for DispersalEvent {
     // `SpeciationEvent`s are equal when they have the same `origin`,
     //  `event_time`, and `target`
-    // (`global_lineage_reference`, `prior_time`, and `interaction` are ignored)
+    // (`global_lineage_reference`, `prior_time`, `interaction`, and
+    //  `speciation_sample` are ignored)
     fn eq(&self, other: &Self) -> bool {
         self.origin == other.origin
             && self.event_time == other.event_time
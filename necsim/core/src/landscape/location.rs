@@ -28,6 +28,22 @@ impl Location {
     pub const fn y(&self) -> u32 {
         self.y
     }
+
+    /// Squared Euclidean distance to the `other` location on the wrapping
+    ///  `u32 x u32` torus
+    #[must_use]
+    pub fn wrapping_distance_squared(&self, other: &Self) -> u64 {
+        let dx = u64::from(u32::min(
+            self.x.wrapping_sub(other.x),
+            other.x.wrapping_sub(self.x),
+        ));
+        let dy = u64::from(u32::min(
+            self.y.wrapping_sub(other.y),
+            other.y.wrapping_sub(self.y),
+        ));
+
+        (dx * dx) + (dy * dy)
+    }
 }
 
 impl From<IndexedLocation> for Location {
@@ -5,7 +5,7 @@ use core::{
 
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-use necsim_core_bond::{NonNegativeF64, PositiveF64};
+use necsim_core_bond::{ClosedUnitF64, NonNegativeF64, PositiveF64};
 
 use crate::{
     cogs::{
@@ -169,6 +169,7 @@ pub struct MigratingLineage {
     pub global_reference: GlobalLineageReference,
     pub prior_time: NonNegativeF64,
     pub event_time: PositiveF64,
+    pub speciation_sample: ClosedUnitF64,
     pub coalescence_rng_sample: CoalescenceRngSample,
     pub dispersal_target: Location,
     pub dispersal_origin: IndexedLocation,
@@ -184,6 +185,7 @@ impl Backup for MigratingLineage {
             dispersal_target: self.dispersal_target.clone(),
             prior_time: self.prior_time,
             event_time: self.event_time,
+            speciation_sample: self.speciation_sample,
             coalescence_rng_sample: self.coalescence_rng_sample.backup_unchecked(),
             tie_breaker: self.tie_breaker,
         }
@@ -199,7 +201,8 @@ impl Ord for MigratingLineage {
         //  (4) prior_time              parent + offspring
         //  (5) global_lineage_reference
         //  (6) coalescence_rng_sample
-        // (tie_breaker is ignored as it cannot compare MigratingLineages)
+        // (speciation_sample is ignored as it is a function of the event, and
+        //  tie_breaker is ignored as it cannot compare MigratingLineages)
         match self.event_time.cmp(&other.event_time) {
             Ordering::Equal => match (&self.dispersal_origin, &self.dispersal_target)
                 .cmp(&(&other.dispersal_origin, &other.dispersal_target))
@@ -85,6 +85,7 @@ impl<
                         global_lineage_reference: migrating_lineage.global_reference,
                         target: dispersal_target,
                         interaction,
+                        speciation_sample: migrating_lineage.speciation_sample,
                     }
                     .into(),
                 );
@@ -129,8 +129,10 @@ impl<E: Copy + PartialEq> AliasMethodSamplerAtom<E> {
     #[allow(clippy::no_effect_underscore_binding)]
     #[debug_requires(!alias_samplers.is_empty(), "alias_samplers is non-empty")]
     #[debug_ensures(
-        old(alias_samplers).iter().map(|s| s.e).any(|e| e == ret),
-        "returns one of the weighted events"
+        old(alias_samplers).iter().map(|s| s.e).any(|e| e == ret)
+            || old(alias_samplers).last().map_or(false, |s| s.k == ret),
+        "returns one of the weighted events, or the alias of the last atom, \
+         which may have been flipped"
     )]
     pub fn sample_event_with_cdf_limit<M: MathsCore, G: RngCore<M>>(
         alias_samplers: &[AliasMethodSamplerAtom<E>],
@@ -180,3 +182,76 @@ fn pop_overfull_underfull_pair_atomic(
         (None, None) => None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use necsim_core::cogs::SeedableRng;
+    use necsim_core_bond::{ClosedUnitF64, NonNegativeF64};
+
+    use crate::cogs::{maths::intrinsics::IntrinsicsMathsCore, rng::wyhash::WyHash};
+
+    use super::AliasMethodSamplerAtom;
+
+    fn event_weights(weights: &[f64]) -> Vec<(usize, NonNegativeF64)> {
+        weights
+            .iter()
+            .enumerate()
+            .map(|(event, weight)| (event, NonNegativeF64::new(*weight).unwrap()))
+            .collect()
+    }
+
+    #[test]
+    fn test_aliases_are_weighted_events() {
+        for weights in [
+            &[1.0, 2.0, 3.0, 4.0][..],
+            &[0.1, 0.0, 5.0, 0.2, 0.7],
+            &[1.0, 1.0, 1.0],
+            &[3.0],
+        ] {
+            let atoms = AliasMethodSamplerAtom::create(&event_weights(weights));
+
+            // Accepting the alias of any unflipped atom does not weaken the
+            //  post-condition, since every alias is also some atom's event
+            assert!(atoms
+                .iter()
+                .all(|alias| atoms.iter().any(|atom| atom.e() == alias.k())));
+        }
+    }
+
+    #[test]
+    fn test_flipped_last_atom_samples_its_alias() {
+        // The underfull self-dispersal event 0 is only stored in its own atom
+        let mut atoms = AliasMethodSamplerAtom::create(&event_weights(&[1.0, 3.0]));
+        assert_eq!(atoms[0].e(), 0);
+        assert_eq!(atoms[0].k(), 1);
+        assert_eq!(atoms[1].e(), 1);
+        assert_eq!(atoms[1].k(), 1);
+
+        // The separable alias dispersal sampler flips the self-dispersal atom
+        //  and moves it to the end, s.t. a CDF limit can exclude the event
+        atoms[0].flip();
+        atoms.swap(0, 1);
+
+        // The flipped event is no longer any atom's event, only the alias of
+        //  the last atom, but it must still be sampled
+        assert!(atoms.iter().all(|atom| atom.e() == 1));
+        assert_eq!(atoms[1].k(), 0);
+
+        let mut rng = WyHash::<IntrinsicsMathsCore>::seed_from_u64(42);
+
+        let samples = (0..1000)
+            .map(|_| AliasMethodSamplerAtom::sample_event(&atoms, &mut rng))
+            .collect::<Vec<_>>();
+        assert!(samples.contains(&0));
+        assert!(samples.contains(&1));
+
+        let limit = ClosedUnitF64::new(0.75).unwrap();
+        assert!(
+            (0..1000).all(|_| AliasMethodSamplerAtom::sample_event_with_cdf_limit(
+                &atoms, &mut rng, limit
+            ) == 1)
+        );
+    }
+}
@@ -149,7 +149,7 @@ impl<M: MathsCore, G: RngCore<M>> SeparableDispersalSampler<M, AlmostInfiniteHab
 /// r = cdf_inv(u01) = sqrt(u * (((1 / (1 - u01)) ** (1/p)) - 1))
 ///
 /// See <https://gist.github.com/juntyr/c04f231ba8063a336744f1e1359f40d8>
-mod clark2dt {
+pub(super) mod clark2dt {
     use necsim_core::cogs::MathsCore;
     use necsim_core_bond::{ClosedOpenUnitF64, ClosedUnitF64, NonNegativeF64, PositiveF64};
 
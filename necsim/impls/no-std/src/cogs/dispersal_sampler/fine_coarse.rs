@@ -0,0 +1,342 @@
+use core::marker::PhantomData;
+
+use necsim_core::{
+    cogs::{DispersalSampler, Habitat, MathsCore, RngCore, RngSampler, SeparableDispersalSampler},
+    landscape::Location,
+};
+use necsim_core_bond::{ClosedUnitF64, NonNegativeF64};
+
+use crate::cogs::habitat::fine_coarse::FineCoarseHabitat;
+
+/// The normal dispersal kernel is truncated to jumps of at most
+///  `KERNEL_RADIUS_SIGMAS * sigma` cells along each axis
+const KERNEL_RADIUS_SIGMAS: f64 = 4.0;
+
+/// Number of rejection sampling attempts before falling back to inverse
+///  transform sampling over all habitable locations inside the kernel
+const REJECTION_SAMPLING_ATTEMPTS: usize = 16;
+
+/// Approximate normal dispersal, which is truncated to a square window
+///  around the origin and then restricted to the habitable locations inside
+///  this window.
+///
+/// The self-dispersal probability of each location is derived from the
+///  kernel mass on the habitable locations in its own neighbourhood, which
+///  costs `O(sigma^2)` per query.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug)]
+#[cfg_attr(feature = "cuda", derive(rust_cuda::lend::LendRustToCuda))]
+#[cfg_attr(feature = "cuda", cuda(free = "M", free = "G"))]
+pub struct FineCoarseApproximateNormalDispersalSampler<M: MathsCore, G: RngCore<M>> {
+    sigma: NonNegativeF64,
+    radius: u32,
+    marker: PhantomData<(M, G)>,
+}
+
+impl<M: MathsCore, G: RngCore<M>> FineCoarseApproximateNormalDispersalSampler<M, G> {
+    #[must_use]
+    pub fn new(sigma: NonNegativeF64) -> Self {
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let radius = M::ceil(KERNEL_RADIUS_SIGMAS * sigma.get()).min(f64::from(u32::MAX)) as u32;
+
+        Self {
+            sigma,
+            radius,
+            marker: PhantomData::<(M, G)>,
+        }
+    }
+
+    /// Probability that the discretised normal kernel jumps by `offset`
+    ///  cells along one axis
+    fn axis_mass(&self, offset: i64) -> f64 {
+        if self.sigma <= 0.0_f64 {
+            return if offset == 0 { 1.0_f64 } else { 0.0_f64 };
+        }
+
+        let scale = 1.0_f64 / (self.sigma.get() * core::f64::consts::SQRT_2);
+
+        #[allow(clippy::cast_precision_loss)]
+        let offset = offset.unsigned_abs() as f64;
+
+        0.5_f64 * (libm::erf((offset + 0.5_f64) * scale) - libm::erf((offset - 0.5_f64) * scale))
+    }
+
+    fn offset_location(location: &Location, dx: i64, dy: i64) -> Option<Location> {
+        Some(Location::new(
+            u32::try_from(i64::from(location.x()) + dx).ok()?,
+            u32::try_from(i64::from(location.y()) + dy).ok()?,
+        ))
+    }
+
+    /// Iterates over all habitable locations, except for the `location`
+    ///  itself, inside the kernel window, together with their kernel mass
+    fn non_self_targets<'a>(
+        &'a self,
+        location: &'a Location,
+        habitat: &'a FineCoarseHabitat<M>,
+    ) -> impl Iterator<Item = (Location, f64)> + 'a {
+        let radius = i64::from(self.radius);
+
+        (-radius..=radius).flat_map(move |dy| {
+            let mass_y = self.axis_mass(dy);
+
+            (-radius..=radius).filter_map(move |dx| {
+                if dx == 0 && dy == 0 {
+                    return None;
+                }
+
+                let target = Self::offset_location(location, dx, dy)?;
+
+                if !habitat.is_location_habitable(&target) {
+                    return None;
+                }
+
+                Some((target, self.axis_mass(dx) * mass_y))
+            })
+        })
+    }
+
+    fn sample_window_dispersal_from_location(
+        &self,
+        location: &Location,
+        rng: &mut G,
+    ) -> Option<Location> {
+        let (dx, dy): (f64, f64) = rng.sample_2d_normal(0.0_f64, self.sigma);
+
+        // Discrete dispersal assumes lineage positions are centred on (0.5, 0.5),
+        //  i.e. |dispersal| >= 0.5 changes the cell
+        #[allow(clippy::cast_possible_truncation)]
+        let (dx, dy): (i64, i64) = (M::round(dx) as i64, M::round(dy) as i64);
+
+        let radius = i64::from(self.radius);
+
+        if dx.abs() > radius || dy.abs() > radius {
+            return None;
+        }
+
+        Self::offset_location(location, dx, dy)
+    }
+}
+
+impl<M: MathsCore, G: RngCore<M>> Clone for FineCoarseApproximateNormalDispersalSampler<M, G> {
+    fn clone(&self) -> Self {
+        Self {
+            sigma: self.sigma,
+            radius: self.radius,
+            marker: PhantomData::<(M, G)>,
+        }
+    }
+}
+
+#[contract_trait]
+impl<M: MathsCore, G: RngCore<M>> DispersalSampler<M, FineCoarseHabitat<M>, G>
+    for FineCoarseApproximateNormalDispersalSampler<M, G>
+{
+    #[must_use]
+    #[inline]
+    fn sample_dispersal_from_location(
+        &self,
+        location: &Location,
+        habitat: &FineCoarseHabitat<M>,
+        rng: &mut G,
+    ) -> Location {
+        if rng.sample_event(self.get_self_dispersal_probability_at_location(location, habitat)) {
+            location.clone()
+        } else {
+            self.sample_non_self_dispersal_from_location(location, habitat, rng)
+        }
+    }
+}
+
+#[contract_trait]
+impl<M: MathsCore, G: RngCore<M>> SeparableDispersalSampler<M, FineCoarseHabitat<M>, G>
+    for FineCoarseApproximateNormalDispersalSampler<M, G>
+{
+    #[must_use]
+    fn sample_non_self_dispersal_from_location(
+        &self,
+        location: &Location,
+        habitat: &FineCoarseHabitat<M>,
+        rng: &mut G,
+    ) -> Location {
+        // Rejection sample the truncated normal dispersal kernel, which is fast
+        //  unless most of the neighbourhood is inhabitable
+        for _ in 0..REJECTION_SAMPLING_ATTEMPTS {
+            if let Some(target) = self.sample_window_dispersal_from_location(location, rng) {
+                if &target != location && habitat.is_location_habitable(&target) {
+                    return target;
+                }
+            }
+        }
+
+        // Inverse transform sample the same distribution, which terminates
+        //  since non-self-dispersal requires a habitable target in the window
+        let total_mass: f64 = self
+            .non_self_targets(location, habitat)
+            .map(|(_, mass)| mass)
+            .sum();
+        let mut remaining_mass = rng.sample_uniform_closed_open().get() * total_mass;
+
+        let mut last_target = None;
+
+        for (target, mass) in self.non_self_targets(location, habitat) {
+            if remaining_mass < mass {
+                return target;
+            }
+
+            remaining_mass -= mass;
+            last_target = Some(target);
+        }
+
+        // Note: the last target is only reached through rounding errors, and
+        //       only the location itself remains by PRE if there is none
+        last_target.unwrap_or_else(|| location.clone())
+    }
+
+    #[must_use]
+    fn get_self_dispersal_probability_at_location(
+        &self,
+        location: &Location,
+        habitat: &FineCoarseHabitat<M>,
+    ) -> ClosedUnitF64 {
+        // By PRE, the location is habitable, i.e. self-dispersal is possible
+        let self_mass = self.axis_mass(0) * self.axis_mass(0);
+        let non_self_mass: f64 = self
+            .non_self_targets(location, habitat)
+            .map(|(_, mass)| mass)
+            .sum();
+
+        // Safety:
+        // - self_mass and non_self_mass are both non-negative
+        // - a / (a + [0, inf)) = [0, 1] for a > 0
+        // - self_mass > 0 since the window always covers the origin cell
+        // Note: we still clamp to account for rounding errors
+        unsafe {
+            ClosedUnitF64::new_unchecked((self_mass / (self_mass + non_self_mass)).clamp(0.0, 1.0))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::num::NonZeroU32;
+
+    use necsim_core::{
+        cogs::{DispersalSampler, Habitat, SeedableRng, SeparableDispersalSampler},
+        landscape::Location,
+    };
+    use necsim_core_bond::NonNegativeF64;
+
+    use crate::{
+        array2d::Array2D,
+        cogs::{
+            habitat::fine_coarse::FineCoarseHabitat, maths::intrinsics::IntrinsicsMathsCore,
+            rng::wyhash::WyHash,
+        },
+    };
+
+    use super::FineCoarseApproximateNormalDispersalSampler;
+
+    fn fragmented_habitat() -> FineCoarseHabitat<IntrinsicsMathsCore> {
+        // The fine map contains an isolated cell at (1, 1) and a connected
+        //  patch in its bottom right corner, while the coarse map is empty
+        let fine = Array2D::from_row_major(
+            &[
+                0, 0, 0, 0, 0, 0, 0, 0, //
+                0, 2, 0, 0, 0, 0, 0, 0, //
+                0, 0, 0, 0, 0, 0, 0, 0, //
+                0, 0, 0, 0, 0, 0, 0, 0, //
+                0, 0, 0, 0, 0, 0, 0, 0, //
+                0, 0, 0, 0, 0, 0, 3, 1, //
+                0, 0, 0, 0, 0, 0, 1, 4, //
+            ],
+            7,
+            8,
+        )
+        .unwrap();
+        let coarse = Array2D::from_row_major(&[0, 0, 0, 0], 2, 2).unwrap();
+
+        FineCoarseHabitat::try_new(
+            fine,
+            coarse,
+            NonZeroU32::new(4).unwrap(),
+            Location::new(0, 0),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_isolated_location_only_self_disperses() {
+        let habitat = fragmented_habitat();
+        let dispersal = FineCoarseApproximateNormalDispersalSampler::<_, WyHash<_>>::new(
+            NonNegativeF64::new(0.5).unwrap(),
+        );
+        let mut rng = WyHash::<IntrinsicsMathsCore>::seed_from_u64(42);
+
+        let isolated = Location::new(1, 1);
+
+        assert_eq!(
+            dispersal
+                .get_self_dispersal_probability_at_location(&isolated, &habitat)
+                .get(),
+            1.0_f64
+        );
+
+        for _ in 0..1000 {
+            assert_eq!(
+                dispersal.sample_dispersal_from_location(&isolated, &habitat, &mut rng),
+                isolated
+            );
+        }
+    }
+
+    #[test]
+    fn test_self_dispersal_depends_on_local_habitat() {
+        let habitat = fragmented_habitat();
+        let dispersal = FineCoarseApproximateNormalDispersalSampler::<_, WyHash<_>>::new(
+            NonNegativeF64::new(1.0).unwrap(),
+        );
+
+        // The corner of the patch has fewer habitable neighbours
+        let corner = dispersal
+            .get_self_dispersal_probability_at_location(&Location::new(6, 5), &habitat)
+            .get();
+        let isolated = dispersal
+            .get_self_dispersal_probability_at_location(&Location::new(1, 1), &habitat)
+            .get();
+
+        assert!(corner < 1.0_f64);
+        assert!(corner < isolated);
+    }
+
+    #[test]
+    fn test_non_self_dispersal_targets_are_habitable() {
+        let habitat = fragmented_habitat();
+        let mut rng = WyHash::<IntrinsicsMathsCore>::seed_from_u64(42);
+
+        for sigma in [0.1, 1.0, 3.0] {
+            let dispersal = FineCoarseApproximateNormalDispersalSampler::new(
+                NonNegativeF64::new(sigma).unwrap(),
+            );
+
+            for origin in habitat.iter_habitable_locations() {
+                if dispersal
+                    .get_self_dispersal_probability_at_location(&origin, &habitat)
+                    .get()
+                    >= 1.0_f64
+                {
+                    continue;
+                }
+
+                for _ in 0..1000 {
+                    let target = dispersal
+                        .sample_non_self_dispersal_from_location(&origin, &habitat, &mut rng);
+
+                    assert!(habitat.is_location_habitable(&target));
+                    assert_ne!(target, origin);
+                }
+            }
+        }
+    }
+}
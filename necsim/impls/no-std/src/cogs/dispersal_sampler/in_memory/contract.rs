@@ -7,7 +7,7 @@ use necsim_core_bond::NonNegativeF64;
 
 use crate::array2d::Array2D;
 
-use super::InMemoryDispersalSamplerError;
+use super::{sparse::SparseDispersalMap, InMemoryDispersalSamplerError};
 
 #[allow(clippy::module_name_repetitions)]
 pub fn check_in_memory_dispersal_contract<M: MathsCore, H: Habitat<M>>(
@@ -67,3 +67,54 @@ pub fn check_in_memory_dispersal_contract<M: MathsCore, H: Habitat<M>>(
 
     Ok(())
 }
+
+#[allow(clippy::module_name_repetitions)]
+pub fn check_sparse_in_memory_dispersal_contract<M: MathsCore, H: Habitat<M>>(
+    dispersal: &SparseDispersalMap,
+    habitat: &H,
+) -> Result<(), InMemoryDispersalSamplerError> {
+    let habitat_extent = habitat.get_extent();
+
+    let habitat_area = usize::from(habitat_extent.width()) * usize::from(habitat_extent.height());
+
+    if dispersal.num_locations() != habitat_area {
+        return Err(InMemoryDispersalSamplerError::DispersalMapSizeMismatch);
+    }
+
+    let habitat_width = habitat_extent.width();
+
+    // The sparse map only stores non-zero (and thus positive) dispersal
+    for (row_index, row) in dispersal.rows_iter().enumerate() {
+        #[allow(clippy::cast_possible_truncation)]
+        let dispersal_origin = Location::new(
+            (row_index % usize::from(habitat_width)) as u32,
+            (row_index / usize::from(habitat_width)) as u32,
+        );
+
+        if habitat.get_habitat_at_location(&dispersal_origin) > 0 {
+            let mut any_dispersal = false;
+
+            for (col_index, _) in row {
+                #[allow(clippy::cast_possible_truncation)]
+                let dispersal_target = Location::new(
+                    (col_index % usize::from(habitat_width)) as u32,
+                    (col_index / usize::from(habitat_width)) as u32,
+                );
+
+                if habitat.get_habitat_at_location(&dispersal_target) == 0 {
+                    return Err(InMemoryDispersalSamplerError::DispersalToNonHabitat);
+                }
+
+                any_dispersal = true;
+            }
+
+            if !any_dispersal {
+                return Err(InMemoryDispersalSamplerError::NoDispersalFromHabitat);
+            }
+        } else if row.count() > 0 {
+            return Err(InMemoryDispersalSamplerError::DispersalFromNonHabitat);
+        }
+    }
+
+    Ok(())
+}
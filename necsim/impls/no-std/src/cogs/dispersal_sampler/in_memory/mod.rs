@@ -5,6 +5,8 @@ use necsim_core_bond::NonNegativeF64;
 
 use crate::array2d::Array2D;
 
+use self::sparse::SparseDispersalMap;
+
 mod contract;
 
 pub mod alias;
@@ -12,6 +14,7 @@ pub mod cumulative;
 pub mod packed_alias;
 pub mod packed_separable_alias;
 pub mod separable_alias;
+pub mod sparse;
 
 #[allow(clippy::module_name_repetitions)]
 pub trait InMemoryDispersalSampler<M: MathsCore, H: Habitat<M>, G: RngCore<M>>:
@@ -40,6 +43,32 @@ pub trait InMemoryDispersalSampler<M: MathsCore, H: Habitat<M>, G: RngCore<M>>:
     ) -> Result<Self, InMemoryDispersalSamplerError>;
 }
 
+#[allow(clippy::module_name_repetitions)]
+pub trait InMemorySparseDispersalSampler<M: MathsCore, H: Habitat<M>, G: RngCore<M>>:
+    InMemoryDispersalSampler<M, H, G>
+{
+    /// Creates a new in-memory dispersal sampler from the sparse `dispersal`
+    /// map and the habitat, without materialising the dense dispersal map.
+    ///
+    /// # Errors
+    ///
+    /// `Err(DispersalMapSizeMismatch)` is returned iff `dispersal` does not
+    /// have `E=WxH` locations where habitat has width `W` and height `H`.
+    ///
+    /// `Err(DispersalToNonHabitat)` is returned iff any dispersal targets a
+    /// non-habitat cell.
+    ///
+    /// `Err(DispersalFromNonHabitat)` is returned iff any non-habitat cell has
+    /// any dispersal.
+    ///
+    /// `Err(NoDispersalFromHabitat)` is returned iff any habitat cell does not
+    /// have any dispersal.
+    fn new_sparse(
+        dispersal: &SparseDispersalMap,
+        habitat: &H,
+    ) -> Result<Self, InMemoryDispersalSamplerError>;
+}
+
 #[allow(clippy::module_name_repetitions)]
 #[derive(Debug, displaydoc::Display)]
 pub enum InMemoryDispersalSamplerError {
@@ -15,8 +15,9 @@ use crate::{
 mod dispersal;
 
 use super::{
-    contract::check_in_memory_dispersal_contract, InMemoryDispersalSampler,
-    InMemoryDispersalSamplerError,
+    contract::{check_in_memory_dispersal_contract, check_sparse_in_memory_dispersal_contract},
+    sparse::SparseDispersalMap,
+    InMemoryDispersalSampler, InMemoryDispersalSamplerError, InMemorySparseDispersalSampler,
 };
 
 #[derive(Clone, Debug, TypeLayout)]
@@ -63,18 +64,47 @@ impl<M: MathsCore, H: Habitat<M>, G: RngCore<M>> InMemoryDispersalSampler<M, H,
     ) -> Result<Self, InMemoryDispersalSamplerError> {
         check_in_memory_dispersal_contract(dispersal, habitat)?;
 
+        Ok(Self::from_rows(
+            dispersal.rows_iter().map(|row| {
+                row.enumerate()
+                    .map(|(col_index, dispersal_probability)| (col_index, *dispersal_probability))
+            }),
+            habitat,
+        ))
+    }
+}
+
+impl<M: MathsCore, H: Habitat<M>, G: RngCore<M>> InMemorySparseDispersalSampler<M, H, G>
+    for InMemoryPackedAliasDispersalSampler<M, H, G>
+{
+    fn new_sparse(
+        dispersal: &SparseDispersalMap,
+        habitat: &H,
+    ) -> Result<Self, InMemoryDispersalSamplerError> {
+        check_sparse_in_memory_dispersal_contract(dispersal, habitat)?;
+
+        Ok(Self::from_rows(dispersal.rows_iter(), habitat))
+    }
+}
+
+impl<M: MathsCore, H: Habitat<M>, G: RngCore<M>> InMemoryPackedAliasDispersalSampler<M, H, G> {
+    /// Builds the sampler from the `(target, weight)` dispersal of each row,
+    ///  which must fulfil the in-memory dispersal contract
+    fn from_rows<R: Iterator<Item = (usize, NonNegativeF64)>>(
+        rows: impl Iterator<Item = R>,
+        habitat: &H,
+    ) -> Self {
         let habitat_extent = habitat.get_extent();
 
-        let mut event_weights: Vec<(usize, NonNegativeF64)> =
-            Vec::with_capacity(dispersal.row_len());
+        let mut event_weights: Vec<(usize, NonNegativeF64)> = Vec::new();
 
         let mut alias_dispersal_buffer = Vec::new();
 
         let alias_dispersal_ranges = Array2D::from_iter_row_major(
-            dispersal.rows_iter().map(|row| {
+            rows.map(|row| {
                 event_weights.clear();
 
-                for (col_index, dispersal_probability) in row.enumerate() {
+                for (col_index, dispersal_probability) in row {
                     #[allow(clippy::cast_possible_truncation)]
                     let location =
                         Location::new(
@@ -87,7 +117,7 @@ impl<M: MathsCore, H: Habitat<M>, G: RngCore<M>> InMemoryDispersalSampler<M, H,
                         );
 
                     // Multiply all dispersal probabilities by the habitat of their target
-                    let weight = *dispersal_probability
+                    let weight = dispersal_probability
                         * NonNegativeF64::from(habitat.get_habitat_at_location(&location));
 
                     if weight > 0.0_f64 {
@@ -111,11 +141,11 @@ impl<M: MathsCore, H: Habitat<M>, G: RngCore<M>> InMemoryDispersalSampler<M, H,
         )
         .unwrap(); // infallible by PRE;
 
-        Ok(Self {
+        Self {
             alias_dispersal_ranges,
             alias_dispersal_buffer: Arc::from(alias_dispersal_buffer.into_boxed_slice()),
             marker: PhantomData::<(M, H, G)>,
-        })
+        }
     }
 }
 
@@ -15,8 +15,9 @@ use crate::{
 mod dispersal;
 
 use super::{
-    contract::check_in_memory_dispersal_contract, InMemoryDispersalSampler,
-    InMemoryDispersalSamplerError,
+    contract::{check_in_memory_dispersal_contract, check_sparse_in_memory_dispersal_contract},
+    sparse::SparseDispersalMap,
+    InMemoryDispersalSampler, InMemoryDispersalSamplerError, InMemorySparseDispersalSampler,
 };
 
 #[derive(Clone, Debug, TypeLayout)]
@@ -60,17 +61,48 @@ pub struct InMemoryPackedSeparableAliasDispersalSampler<M: MathsCore, H: Habitat
 impl<M: MathsCore, H: Habitat<M>, G: RngCore<M>> InMemoryDispersalSampler<M, H, G>
     for InMemoryPackedSeparableAliasDispersalSampler<M, H, G>
 {
-    #[allow(clippy::too_many_lines)]
     fn new(
         dispersal: &Array2D<NonNegativeF64>,
         habitat: &H,
     ) -> Result<Self, InMemoryDispersalSamplerError> {
         check_in_memory_dispersal_contract(dispersal, habitat)?;
 
+        Ok(Self::from_rows(
+            dispersal.rows_iter().map(|row| {
+                row.enumerate()
+                    .map(|(col_index, dispersal_probability)| (col_index, *dispersal_probability))
+            }),
+            habitat,
+        ))
+    }
+}
+
+impl<M: MathsCore, H: Habitat<M>, G: RngCore<M>> InMemorySparseDispersalSampler<M, H, G>
+    for InMemoryPackedSeparableAliasDispersalSampler<M, H, G>
+{
+    fn new_sparse(
+        dispersal: &SparseDispersalMap,
+        habitat: &H,
+    ) -> Result<Self, InMemoryDispersalSamplerError> {
+        check_sparse_in_memory_dispersal_contract(dispersal, habitat)?;
+
+        Ok(Self::from_rows(dispersal.rows_iter(), habitat))
+    }
+}
+
+impl<M: MathsCore, H: Habitat<M>, G: RngCore<M>>
+    InMemoryPackedSeparableAliasDispersalSampler<M, H, G>
+{
+    /// Builds the sampler from the `(target, weight)` dispersal of each row,
+    ///  which must fulfil the in-memory dispersal contract
+    #[allow(clippy::too_many_lines)]
+    fn from_rows<R: Iterator<Item = (usize, NonNegativeF64)>>(
+        rows: impl Iterator<Item = R>,
+        habitat: &H,
+    ) -> Self {
         let habitat_extent = habitat.get_extent();
 
-        let mut event_weights: Vec<(usize, NonNegativeF64)> =
-            Vec::with_capacity(dispersal.row_len());
+        let mut event_weights: Vec<(usize, NonNegativeF64)> = Vec::new();
 
         let mut alias_dispersal_buffer = Vec::new();
 
@@ -86,12 +118,12 @@ impl<M: MathsCore, H: Habitat<M>, G: RngCore<M>> InMemoryDispersalSampler<M, H,
         .unwrap();
 
         let alias_dispersal_ranges = Array2D::from_iter_row_major(
-            dispersal.rows_iter().enumerate().map(|(row_index, row)| {
+            rows.enumerate().map(|(row_index, row)| {
                 event_weights.clear();
 
                 let mut self_dispersal_at_location = NonNegativeF64::zero();
 
-                for (col_index, dispersal_probability) in row.enumerate() {
+                for (col_index, dispersal_probability) in row {
                     #[allow(clippy::cast_possible_truncation)]
                     let location =
                         Location::new(
@@ -104,7 +136,7 @@ impl<M: MathsCore, H: Habitat<M>, G: RngCore<M>> InMemoryDispersalSampler<M, H,
                         );
 
                     // Multiply all dispersal probabilities by the habitat of their target
-                    let weight = *dispersal_probability
+                    let weight = dispersal_probability
                         * NonNegativeF64::from(habitat.get_habitat_at_location(&location));
 
                     if weight > 0.0_f64 {
@@ -170,7 +202,14 @@ impl<M: MathsCore, H: Habitat<M>, G: RngCore<M>> InMemoryDispersalSampler<M, H,
                         // remove self-dispersal from the alias sampler as it is cheaper to handle
                         //  it separately
                         event_weights.retain(|(event, _)| *event != row_index);
-                        atoms = AliasMethodSamplerAtom::create(&event_weights);
+
+                        // pure self-dispersal needs no alias sampler, since its
+                        //  guaranteed self-dispersal never samples the atoms
+                        atoms = if event_weights.is_empty() {
+                            Vec::new()
+                        } else {
+                            AliasMethodSamplerAtom::create(&event_weights)
+                        };
                     };
 
                     self_dispersal[(
@@ -194,12 +233,12 @@ impl<M: MathsCore, H: Habitat<M>, G: RngCore<M>> InMemoryDispersalSampler<M, H,
         )
         .unwrap(); // infallible by PRE;
 
-        Ok(Self {
+        Self {
             alias_dispersal_ranges,
             self_dispersal: self_dispersal.switch_backend(),
             alias_dispersal_buffer: Arc::from(alias_dispersal_buffer.into_boxed_slice()),
             marker: PhantomData::<(M, H, G)>,
-        })
+        }
     }
 }
 
@@ -0,0 +1,313 @@
+#![allow(non_local_definitions)] // FIXME: displaydoc
+
+use alloc::vec::Vec;
+
+use necsim_core_bond::NonNegativeF64;
+
+/// Sparse dispersal map in compressed sparse row (CSR) format, which only
+///  stores the non-zero dispersal weights between pairs of locations.
+///
+/// Locations are indexed in row-major order, i.e. the location `(x, y)` in a
+///  habitat of width `W` has the index `y * W + x`. The sparse map thus
+///  corresponds to a dense `ExE` dispersal map with `E=WxH`, where the ith
+///  row stores dispersal from the ith location.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SparseDispersalMap {
+    num_locations: usize,
+    row_offsets: Vec<usize>,
+    targets: Vec<usize>,
+    weights: Vec<NonNegativeF64>,
+}
+
+#[allow(clippy::module_name_repetitions, clippy::doc_markdown)]
+#[derive(Debug, displaydoc::Display, PartialEq, Eq)]
+pub enum SparseDispersalMapError {
+    /** The dispersal from {source} to {target} is outside the {num_locations}
+    locations of the dispersal map. */
+    OutOfBounds {
+        source: usize,
+        target: usize,
+        num_locations: usize,
+    },
+    /// The dispersal from {source} to {target} is given more than once.
+    DuplicateDispersal { source: usize, target: usize },
+}
+
+impl SparseDispersalMap {
+    /// Creates a new sparse dispersal map between `num_locations` locations
+    ///  from its `(source, target, weight)` entries, which may be given in any
+    ///  order. Entries with a zero weight are ignored.
+    ///
+    /// # Errors
+    ///
+    /// `Err(OutOfBounds)` is returned iff any entry's source or target is not
+    ///  less than `num_locations`.
+    ///
+    /// `Err(DuplicateDispersal)` is returned iff any pair of source and target
+    ///  locations is given more than once.
+    pub fn try_from_entries<I: IntoIterator<Item = (usize, usize, NonNegativeF64)>>(
+        num_locations: usize,
+        entries: I,
+    ) -> Result<Self, SparseDispersalMapError> {
+        let mut entries = entries.into_iter().collect::<Vec<_>>();
+
+        if let Some((source, target, _)) = entries
+            .iter()
+            .find(|(source, target, _)| *source >= num_locations || *target >= num_locations)
+        {
+            return Err(SparseDispersalMapError::OutOfBounds {
+                source: *source,
+                target: *target,
+                num_locations,
+            });
+        }
+
+        entries.sort_unstable_by_key(|(source, target, _)| (*source, *target));
+
+        if let Some(duplicate) = entries
+            .windows(2)
+            .find(|pair| (pair[0].0, pair[0].1) == (pair[1].0, pair[1].1))
+        {
+            return Err(SparseDispersalMapError::DuplicateDispersal {
+                source: duplicate[0].0,
+                target: duplicate[0].1,
+            });
+        }
+
+        entries.retain(|(_, _, weight)| *weight > 0.0_f64);
+
+        let mut row_offsets = Vec::with_capacity(num_locations + 1);
+        let mut targets = Vec::with_capacity(entries.len());
+        let mut weights = Vec::with_capacity(entries.len());
+
+        row_offsets.push(0);
+
+        for (source, target, weight) in entries {
+            while row_offsets.len() <= source {
+                row_offsets.push(targets.len());
+            }
+
+            targets.push(target);
+            weights.push(weight);
+        }
+
+        while row_offsets.len() <= num_locations {
+            row_offsets.push(targets.len());
+        }
+
+        Ok(Self {
+            num_locations,
+            row_offsets,
+            targets,
+            weights,
+        })
+    }
+
+    #[must_use]
+    pub fn num_locations(&self) -> usize {
+        self.num_locations
+    }
+
+    /// Returns the number of non-zero dispersal entries
+    #[must_use]
+    pub fn num_entries(&self) -> usize {
+        self.targets.len()
+    }
+
+    /// Iterates over the `(target, weight)` pairs of all non-zero dispersal
+    ///  from the `source` location, in ascending order of the target.
+    pub fn row_iter(&self, source: usize) -> impl Iterator<Item = (usize, NonNegativeF64)> + '_ {
+        let (start, end) = match (
+            self.row_offsets.get(source),
+            self.row_offsets.get(source + 1),
+        ) {
+            (Some(start), Some(end)) => (*start, *end),
+            _ => (0, 0),
+        };
+
+        self.targets[start..end]
+            .iter()
+            .copied()
+            .zip(self.weights[start..end].iter().copied())
+    }
+
+    /// Iterates over the rows of all source locations in ascending order, see
+    ///  [`Self::row_iter`].
+    pub fn rows_iter(
+        &self,
+    ) -> impl Iterator<Item = impl Iterator<Item = (usize, NonNegativeF64)> + '_> + '_ {
+        (0..self.num_locations).map(move |source| self.row_iter(source))
+    }
+
+    /// Iterates over the `(source, target, weight)` entries of all non-zero
+    ///  dispersal in ascending order.
+    pub fn entries_iter(&self) -> impl Iterator<Item = (usize, usize, NonNegativeF64)> + '_ {
+        self.rows_iter()
+            .enumerate()
+            .flat_map(|(source, row)| row.map(move |(target, weight)| (source, target, weight)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use necsim_core::{
+        cogs::{DispersalSampler, Habitat, SeedableRng},
+        landscape::Location,
+    };
+    use necsim_core_bond::NonNegativeF64;
+
+    use crate::{
+        array2d::Array2D,
+        cogs::{
+            dispersal_sampler::in_memory::{
+                packed_alias::InMemoryPackedAliasDispersalSampler,
+                packed_separable_alias::InMemoryPackedSeparableAliasDispersalSampler,
+                InMemoryDispersalSampler, InMemorySparseDispersalSampler,
+            },
+            habitat::in_memory::InMemoryHabitat,
+            maths::intrinsics::IntrinsicsMathsCore,
+            rng::wyhash::WyHash,
+        },
+    };
+
+    use super::{SparseDispersalMap, SparseDispersalMapError};
+
+    fn weight(weight: f64) -> NonNegativeF64 {
+        NonNegativeF64::new(weight).unwrap()
+    }
+
+    #[test]
+    fn test_csr_from_unordered_entries() {
+        let sparse = SparseDispersalMap::try_from_entries(
+            4,
+            [
+                (2, 1, weight(0.5)),
+                (0, 3, weight(1.0)),
+                (2, 0, weight(2.0)),
+                (1, 1, weight(0.0)),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(sparse.num_locations(), 4);
+        assert_eq!(sparse.num_entries(), 3);
+        assert_eq!(
+            sparse.entries_iter().collect::<Vec<_>>(),
+            [
+                (0, 3, weight(1.0)),
+                (2, 0, weight(2.0)),
+                (2, 1, weight(0.5))
+            ]
+        );
+        assert_eq!(sparse.row_iter(1).count(), 0);
+        assert_eq!(sparse.row_iter(3).count(), 0);
+        assert_eq!(sparse.row_iter(4).count(), 0);
+
+        assert_eq!(
+            SparseDispersalMap::try_from_entries(4, [(0, 4, weight(1.0))]),
+            Err(SparseDispersalMapError::OutOfBounds {
+                source: 0,
+                target: 4,
+                num_locations: 4
+            })
+        );
+        assert_eq!(
+            SparseDispersalMap::try_from_entries(4, [(1, 2, weight(1.0)), (1, 2, weight(0.0))]),
+            Err(SparseDispersalMapError::DuplicateDispersal {
+                source: 1,
+                target: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_sparse_samplers_match_dense_samplers() {
+        let habitat = InMemoryHabitat::<IntrinsicsMathsCore>::try_new(
+            Array2D::from_row_major(&[1, 0, 2, 3, 1, 0], 2, 3).unwrap(),
+        )
+        .unwrap();
+
+        let dense = Array2D::from_row_major(
+            [
+                [0.2, 0.0, 0.3, 0.5, 0.1, 0.0],
+                [0.0; 6],
+                [0.0, 0.0, 1.0, 0.0, 0.0, 0.0],
+                [0.1, 0.0, 0.1, 0.1, 0.7, 0.0],
+                [0.4, 0.0, 0.0, 0.6, 0.0, 0.0],
+                [0.0; 6],
+            ]
+            .concat()
+            .into_iter()
+            .map(weight)
+            .collect::<Vec<_>>()
+            .as_slice(),
+            6,
+            6,
+        )
+        .unwrap();
+        let sparse = SparseDispersalMap::try_from_entries(
+            dense.num_rows(),
+            dense.rows_iter().enumerate().flat_map(|(source, row)| {
+                row.enumerate()
+                    .map(move |(target, weight)| (source, target, *weight))
+            }),
+        )
+        .unwrap();
+
+        let dense_packed: InMemoryPackedAliasDispersalSampler<_, _, WyHash<_>> =
+            InMemoryDispersalSampler::new(&dense, &habitat).unwrap();
+        let sparse_packed: InMemoryPackedAliasDispersalSampler<_, _, WyHash<_>> =
+            InMemorySparseDispersalSampler::new_sparse(&sparse, &habitat).unwrap();
+
+        let dense_separable: InMemoryPackedSeparableAliasDispersalSampler<_, _, WyHash<_>> =
+            InMemoryDispersalSampler::new(&dense, &habitat).unwrap();
+        let sparse_separable: InMemoryPackedSeparableAliasDispersalSampler<_, _, WyHash<_>> =
+            InMemorySparseDispersalSampler::new_sparse(&sparse, &habitat).unwrap();
+
+        let mut dense_rng = WyHash::seed_from_u64(42);
+        let mut sparse_rng = WyHash::seed_from_u64(42);
+
+        for origin in habitat.iter_habitable_locations() {
+            for _ in 0..1000 {
+                assert_eq!(
+                    dense_packed.sample_dispersal_from_location(&origin, &habitat, &mut dense_rng),
+                    sparse_packed.sample_dispersal_from_location(
+                        &origin,
+                        &habitat,
+                        &mut sparse_rng
+                    ),
+                );
+                assert_eq!(
+                    dense_separable.sample_dispersal_from_location(
+                        &origin,
+                        &habitat,
+                        &mut dense_rng
+                    ),
+                    sparse_separable.sample_dispersal_from_location(
+                        &origin,
+                        &habitat,
+                        &mut sparse_rng
+                    ),
+                );
+            }
+        }
+
+        // The pure self-dispersal row has no alias atoms but always samples
+        //  self-dispersal
+        let pure_self_dispersal = Location::new(2, 0);
+
+        for _ in 0..1000 {
+            assert_eq!(
+                sparse_separable.sample_dispersal_from_location(
+                    &pure_self_dispersal,
+                    &habitat,
+                    &mut sparse_rng
+                ),
+                pure_self_dispersal,
+            );
+        }
+    }
+}
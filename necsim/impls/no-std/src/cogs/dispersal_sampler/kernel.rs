@@ -0,0 +1,556 @@
+use core::marker::PhantomData;
+
+use necsim_core::{
+    cogs::{DispersalSampler, Habitat, MathsCore, RngCore, RngSampler, SeparableDispersalSampler},
+    landscape::Location,
+};
+use necsim_core_bond::{
+    ClosedOpenUnitF64, ClosedUnitF64, NonNegativeF64, OffByOneU32, PositiveF64,
+};
+
+use crate::{
+    array2d::{ArcArray2D, VecArray2D},
+    cogs::habitat::in_memory::InMemoryHabitat,
+};
+
+use super::almost_infinite_clark2dt::clark2dt;
+
+/// Parametric dispersal kernel, which is centred on the origin cell
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Copy, Debug, PartialEq, TypeLayout)]
+#[repr(C, u8)]
+pub enum DispersalKernel {
+    /// Normal (Gaussian) dispersal with standard deviation `sigma`
+    Normal { sigma: NonNegativeF64 },
+    /// Clark 2Dt dispersal with shape `shape_u` and tail width `tail_p`
+    Clark2Dt {
+        shape_u: PositiveF64,
+        tail_p: PositiveF64,
+    },
+    /// Fat-tailed dispersal whose jump distances follow a Lomax (Pareto
+    ///  type II) distribution with `scale` and `shape`, such that the tail
+    ///  decays with a power law of `-(shape + 1)`
+    FatTailed {
+        scale: PositiveF64,
+        shape: PositiveF64,
+    },
+}
+
+/// Rule for dispersal jumps which leave the extent of the habitat
+#[derive(Clone, Copy, Debug, PartialEq, Eq, TypeLayout)]
+#[repr(u8)]
+pub enum DispersalBoundary {
+    /// Jumps are reflected back into the habitat at its edges
+    Reflect,
+    /// Jumps which leave the habitat are rejected and resampled
+    AbsorbResample,
+    /// Jumps wrap around the edges, i.e. the habitat is a torus
+    Wrap,
+}
+
+/// Number of jump distance quantiles, and of jump directions, which are used
+///  to integrate the kernel over the habitable neighbourhood of each location
+const QUADRATURE_POINTS: u32 = 64;
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug)]
+#[cfg_attr(feature = "cuda", derive(rust_cuda::lend::LendRustToCuda))]
+#[cfg_attr(feature = "cuda", cuda(free = "M", free = "G"))]
+pub struct InMemoryKernelDispersalSampler<M: MathsCore, G: RngCore<M>> {
+    kernel: DispersalKernel,
+    boundary: DispersalBoundary,
+    #[cfg_attr(feature = "cuda", cuda(embed))]
+    self_dispersal: ArcArray2D<ClosedUnitF64>,
+    marker: PhantomData<(M, G)>,
+}
+
+impl<M: MathsCore, G: RngCore<M>> InMemoryKernelDispersalSampler<M, G> {
+    /// Creates a new kernel dispersal sampler on the `habitat`.
+    ///
+    /// Since draws that land on uninhabitable locations are rejected, the
+    ///  self-dispersal probability depends on the habitable neighbourhood of
+    ///  each location. It is precomputed by integrating the kernel over a
+    ///  fixed grid of jump distance quantiles and directions.
+    #[must_use]
+    pub fn new(
+        kernel: DispersalKernel,
+        boundary: DispersalBoundary,
+        habitat: &InMemoryHabitat<M>,
+    ) -> Self {
+        let kernel_self_dispersal = match kernel {
+            DispersalKernel::Normal { sigma } => normal_self_dispersal(sigma),
+            DispersalKernel::Clark2Dt { shape_u, tail_p } => {
+                radial_self_dispersal::<M>(|jump_r| clark2dt::cdf::<M>(jump_r, shape_u, tail_p))
+            },
+            DispersalKernel::FatTailed { scale, shape } => {
+                radial_self_dispersal::<M>(|jump_r| lomax::cdf::<M>(jump_r, scale, shape))
+            },
+        };
+
+        let extent = habitat.get_extent();
+
+        let mut self_dispersal = VecArray2D::filled_with(
+            ClosedUnitF64::one(),
+            usize::from(extent.height()),
+            usize::from(extent.width()),
+        );
+
+        for (index, location) in extent.iter().enumerate() {
+            let non_self_dispersal =
+                habitable_non_self_dispersal::<M>(kernel, boundary, &location, habitat);
+
+            // If no other habitable location is reached, self-dispersal is guaranteed
+            if non_self_dispersal > 0.0_f64 {
+                // Safety:
+                // - kernel_self_dispersal and non_self_dispersal are both in [0, 1]
+                // - a / (a + (0, 1]) = [0, 1]
+                self_dispersal[(
+                    index / usize::from(extent.width()),
+                    index % usize::from(extent.width()),
+                )] = unsafe {
+                    ClosedUnitF64::new_unchecked(
+                        kernel_self_dispersal.get()
+                            / (kernel_self_dispersal.get() + non_self_dispersal.get()),
+                    )
+                };
+            }
+        }
+
+        Self {
+            kernel,
+            boundary,
+            self_dispersal: self_dispersal.switch_backend(),
+            marker: PhantomData::<(M, G)>,
+        }
+    }
+
+    fn sample_kernel_jump(&self, rng: &mut G) -> (f64, f64) {
+        let jump = match self.kernel {
+            DispersalKernel::Normal { sigma } => return rng.sample_2d_normal(0.0_f64, sigma),
+            DispersalKernel::Clark2Dt { shape_u, tail_p } => {
+                clark2dt::cdf_inverse::<M>(rng.sample_uniform_closed_open(), shape_u, tail_p)
+            },
+            DispersalKernel::FatTailed { scale, shape } => {
+                lomax::cdf_inverse::<M>(rng.sample_uniform_closed_open(), scale, shape)
+            },
+        };
+
+        let theta = rng.sample_uniform_open_closed().get() * 2.0 * core::f64::consts::PI;
+
+        (M::cos(theta) * jump, M::sin(theta) * jump)
+    }
+
+    fn sample_kernel_dispersal_from_location(
+        &self,
+        location: &Location,
+        habitat: &InMemoryHabitat<M>,
+        rng: &mut G,
+    ) -> Option<Location> {
+        let (dx, dy) = self.sample_kernel_jump(rng);
+
+        apply_jump::<M>(self.boundary, location, habitat, dx, dy)
+    }
+}
+
+impl<M: MathsCore, G: RngCore<M>> Clone for InMemoryKernelDispersalSampler<M, G> {
+    fn clone(&self) -> Self {
+        Self {
+            kernel: self.kernel,
+            boundary: self.boundary,
+            self_dispersal: self.self_dispersal.clone(),
+            marker: PhantomData::<(M, G)>,
+        }
+    }
+}
+
+#[contract_trait]
+impl<M: MathsCore, G: RngCore<M>> DispersalSampler<M, InMemoryHabitat<M>, G>
+    for InMemoryKernelDispersalSampler<M, G>
+{
+    #[must_use]
+    #[inline]
+    fn sample_dispersal_from_location(
+        &self,
+        location: &Location,
+        habitat: &InMemoryHabitat<M>,
+        rng: &mut G,
+    ) -> Location {
+        if rng.sample_event(self.get_self_dispersal_probability_at_location(location, habitat)) {
+            location.clone()
+        } else {
+            self.sample_non_self_dispersal_from_location(location, habitat, rng)
+        }
+    }
+}
+
+#[contract_trait]
+impl<M: MathsCore, G: RngCore<M>> SeparableDispersalSampler<M, InMemoryHabitat<M>, G>
+    for InMemoryKernelDispersalSampler<M, G>
+{
+    #[must_use]
+    fn sample_non_self_dispersal_from_location(
+        &self,
+        location: &Location,
+        habitat: &InMemoryHabitat<M>,
+        rng: &mut G,
+    ) -> Location {
+        // Rejection sample the dispersal kernel, which terminates since
+        //  non-self-dispersal requires a reachable habitable location by PRE
+        loop {
+            if let Some(target) = self.sample_kernel_dispersal_from_location(location, habitat, rng)
+            {
+                if &target != location && habitat.get_habitat_at_location(&target) > 0 {
+                    return target;
+                }
+            }
+        }
+    }
+
+    #[must_use]
+    fn get_self_dispersal_probability_at_location(
+        &self,
+        location: &Location,
+        habitat: &InMemoryHabitat<M>,
+    ) -> ClosedUnitF64 {
+        let extent = habitat.get_extent();
+
+        self.self_dispersal
+            .get(
+                location.y().wrapping_sub(extent.origin().y()) as usize,
+                location.x().wrapping_sub(extent.origin().x()) as usize,
+            )
+            .copied()
+            .unwrap_or_else(ClosedUnitF64::one)
+    }
+}
+
+fn apply_boundary<M: MathsCore>(
+    boundary: DispersalBoundary,
+    coordinate: u32,
+    jump: f64,
+    size: OffByOneU32,
+) -> Option<u32> {
+    // Discrete dispersal assumes lineage positions are centred on (0.5, 0.5)
+    let position = f64::from(coordinate) + 0.5 + jump;
+    #[allow(clippy::cast_precision_loss)]
+    let size_f64 = size.get() as f64;
+
+    let position = match boundary {
+        DispersalBoundary::AbsorbResample => {
+            if position < 0.0 || position >= size_f64 {
+                return None;
+            }
+
+            position
+        },
+        DispersalBoundary::Wrap => position - size_f64 * M::floor(position / size_f64),
+        DispersalBoundary::Reflect => {
+            let period = size_f64 * 2.0;
+            let position = position - period * M::floor(position / period);
+
+            if position >= size_f64 {
+                period - position
+            } else {
+                position
+            }
+        },
+    };
+
+    // Note: we still clamp to account for rounding errors
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    Some(M::floor(position.min(size_f64 - 1.0)) as u32)
+}
+
+fn apply_jump<M: MathsCore>(
+    boundary: DispersalBoundary,
+    location: &Location,
+    habitat: &InMemoryHabitat<M>,
+    dx: f64,
+    dy: f64,
+) -> Option<Location> {
+    let extent = habitat.get_extent();
+
+    let x = apply_boundary::<M>(boundary, location.x(), dx, extent.width())?;
+    let y = apply_boundary::<M>(boundary, location.y(), dy, extent.height())?;
+
+    Some(Location::new(x, y))
+}
+
+/// Integrates the probability that the `kernel` jumps from the `location` to
+///  a different habitable location over a grid of jump distance quantiles
+///  and directions
+fn habitable_non_self_dispersal<M: MathsCore>(
+    kernel: DispersalKernel,
+    boundary: DispersalBoundary,
+    location: &Location,
+    habitat: &InMemoryHabitat<M>,
+) -> ClosedUnitF64 {
+    if habitat.get_habitat_at_location(location) == 0 {
+        return ClosedUnitF64::zero();
+    }
+
+    let mut hits = 0_u32;
+
+    for i in 0..QUADRATURE_POINTS {
+        // Safety: (i + 0.5) / N in (0, 1) for i in [0, N)
+        let u01 = unsafe {
+            ClosedOpenUnitF64::new_unchecked((f64::from(i) + 0.5) / f64::from(QUADRATURE_POINTS))
+        };
+
+        let jump = match kernel {
+            // The distance of a 2D normal jump is Rayleigh distributed
+            DispersalKernel::Normal { sigma } => {
+                sigma.get() * M::sqrt(-2.0 * M::ln(1.0 - u01.get()))
+            },
+            DispersalKernel::Clark2Dt { shape_u, tail_p } => {
+                clark2dt::cdf_inverse::<M>(u01, shape_u, tail_p)
+            },
+            DispersalKernel::FatTailed { scale, shape } => {
+                lomax::cdf_inverse::<M>(u01, scale, shape)
+            },
+        };
+
+        for j in 0..QUADRATURE_POINTS {
+            let theta =
+                (f64::from(j) + 0.5) / f64::from(QUADRATURE_POINTS) * 2.0 * core::f64::consts::PI;
+
+            if let Some(target) = apply_jump::<M>(
+                boundary,
+                location,
+                habitat,
+                M::cos(theta) * jump,
+                M::sin(theta) * jump,
+            ) {
+                if &target != location && habitat.get_habitat_at_location(&target) > 0 {
+                    hits += 1;
+                }
+            }
+        }
+    }
+
+    // Safety: there are at most QUADRATURE_POINTS^2 hits
+    unsafe {
+        ClosedUnitF64::new_unchecked(
+            f64::from(hits) / (f64::from(QUADRATURE_POINTS) * f64::from(QUADRATURE_POINTS)),
+        )
+    }
+}
+
+fn normal_self_dispersal(sigma: NonNegativeF64) -> ClosedUnitF64 {
+    let self_dispersal_1d = if sigma > 0.0_f64 {
+        let probability = libm::erf(0.5 / (sigma.get() * core::f64::consts::SQRT_2));
+
+        // Safety: For non-negative values x (as both sigma and sqrt(2.0) are),
+        //         erf(0.5 / x) in [0.0; 1.0]
+        unsafe { ClosedUnitF64::new_unchecked(probability) }
+    } else {
+        ClosedUnitF64::one()
+    };
+
+    self_dispersal_1d * self_dispersal_1d
+}
+
+/// Numerically integrates the probability that a radially symmetric kernel
+///  with the jump distance `cdf` stays inside the unit square of its origin,
+///  see `AlmostInfiniteClark2DtDispersalSampler`
+fn radial_self_dispersal<M: MathsCore>(
+    cdf: impl Fn(NonNegativeF64) -> ClosedUnitF64,
+) -> ClosedUnitF64 {
+    // The integrand is smooth, so the midpoint rule converges quickly
+    const N: i32 = 1 << 12;
+
+    #[allow(clippy::useless_conversion)] // prepare for new range iterators
+    let self_dispersal = (0..N)
+        .into_iter()
+        .map(|i| core::f64::consts::PI * 0.25 * (f64::from(i) + 0.5) / f64::from(N))
+        .map(|phi| {
+            // Safety: cos([0, pi/4]) in [sqrt(2)/2, 1], and its inverse is non-negative
+            unsafe { NonNegativeF64::new_unchecked(0.5 / M::cos(phi)) }
+        })
+        .map(|jump_r| cdf(jump_r).get())
+        .sum::<f64>()
+        / f64::from(N);
+
+    // Safety: the average of the cdfs, which are all ClosedUnitF64,
+    //         is also in [0, 1]
+    // Note: we still clamp to account for rounding errors
+    unsafe { ClosedUnitF64::new_unchecked(self_dispersal.clamp(0.0, 1.0)) }
+}
+
+/// Lomax (Pareto type II) distributed jump distances:
+///
+/// r: dispersal jump distance (radius of a circle)
+/// s: distribution scale
+/// a: distribution shape, the tail decays as r ** -(a + 1)
+///
+/// cdf(r) = 1 - (1 + r / s) ** -a
+/// r = cdf^-1(u01) = s * ((1 / (1 - u01)) ** (1/a) - 1)
+mod lomax {
+    use necsim_core::cogs::MathsCore;
+    use necsim_core_bond::{ClosedOpenUnitF64, ClosedUnitF64, NonNegativeF64, PositiveF64};
+
+    pub fn cdf<M: MathsCore>(
+        jump_r: NonNegativeF64,
+        scale: PositiveF64,
+        shape: PositiveF64,
+    ) -> ClosedUnitF64 {
+        let u01 = 1.0 - M::pow(1.0 + jump_r.get() / scale.get(), -shape.get());
+
+        // Safety: (1 + [0, inf)) ** -(0, inf) in [0, 1]
+        // Note: we still clamp to account for rounding errors
+        unsafe { ClosedUnitF64::new_unchecked(u01.clamp(0.0, 1.0)) }
+    }
+
+    pub fn cdf_inverse<M: MathsCore>(
+        u01: ClosedOpenUnitF64,
+        scale: PositiveF64,
+        shape: PositiveF64,
+    ) -> f64 {
+        scale.get() * (M::pow(1.0 / (1.0 - u01.get()), 1.0 / shape.get()) - 1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use necsim_core::{
+        cogs::{DispersalSampler, Habitat, SeedableRng, SeparableDispersalSampler},
+        landscape::Location,
+    };
+    use necsim_core_bond::{NonNegativeF64, PositiveF64};
+
+    use crate::{
+        array2d::Array2D,
+        cogs::{
+            habitat::in_memory::InMemoryHabitat, maths::intrinsics::IntrinsicsMathsCore,
+            rng::wyhash::WyHash,
+        },
+    };
+
+    use super::{DispersalBoundary, DispersalKernel, InMemoryKernelDispersalSampler};
+
+    #[test]
+    fn test_dispersal_targets_are_habitable() {
+        let habitat = InMemoryHabitat::<IntrinsicsMathsCore>::try_new(
+            Array2D::from_row_major(&[1, 0, 2, 0, 0, 3, 1, 0, 4, 0, 0, 1], 3, 4).unwrap(),
+        )
+        .unwrap();
+
+        for kernel in [
+            DispersalKernel::Normal {
+                sigma: NonNegativeF64::new(2.0).unwrap(),
+            },
+            DispersalKernel::Clark2Dt {
+                shape_u: PositiveF64::new(1.0).unwrap(),
+                tail_p: PositiveF64::new(1.0).unwrap(),
+            },
+            DispersalKernel::FatTailed {
+                scale: PositiveF64::new(1.0).unwrap(),
+                shape: PositiveF64::new(1.5).unwrap(),
+            },
+        ] {
+            for boundary in [
+                DispersalBoundary::Reflect,
+                DispersalBoundary::AbsorbResample,
+                DispersalBoundary::Wrap,
+            ] {
+                let dispersal = InMemoryKernelDispersalSampler::new(kernel, boundary, &habitat);
+                let mut rng = WyHash::<IntrinsicsMathsCore>::seed_from_u64(42);
+
+                for origin in habitat.iter_habitable_locations() {
+                    for _ in 0..1000 {
+                        let target =
+                            dispersal.sample_dispersal_from_location(&origin, &habitat, &mut rng);
+                        assert!(habitat.get_habitat_at_location(&target) > 0);
+
+                        let target = dispersal
+                            .sample_non_self_dispersal_from_location(&origin, &habitat, &mut rng);
+                        assert!(habitat.get_habitat_at_location(&target) > 0);
+                        assert_ne!(target, origin);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_single_location_only_self_disperses() {
+        let habitat = InMemoryHabitat::<IntrinsicsMathsCore>::try_new(
+            Array2D::from_row_major(&[0, 0, 3, 0], 2, 2).unwrap(),
+        )
+        .unwrap();
+
+        let dispersal = InMemoryKernelDispersalSampler::<_, WyHash<_>>::new(
+            DispersalKernel::Normal {
+                sigma: NonNegativeF64::new(1.0).unwrap(),
+            },
+            DispersalBoundary::Wrap,
+            &habitat,
+        );
+
+        assert_eq!(
+            dispersal
+                .get_self_dispersal_probability_at_location(&Location::new(0, 1), &habitat)
+                .get(),
+            1.0_f64
+        );
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_fragmented_habitat_uses_local_neighbourhood() {
+        // An isolated location at (1, 1) and a 2x2 patch at (12, 12)
+        let mut map = [0_u32; 16 * 16];
+        map[16 + 1] = 1;
+        for (x, y) in [(12, 12), (13, 12), (12, 13), (13, 13)] {
+            map[y * 16 + x] = 2;
+        }
+
+        let habitat = InMemoryHabitat::<IntrinsicsMathsCore>::try_new(
+            Array2D::from_row_major(&map, 16, 16).unwrap(),
+        )
+        .unwrap();
+
+        let dispersal = InMemoryKernelDispersalSampler::new(
+            DispersalKernel::Normal {
+                sigma: NonNegativeF64::new(1.0).unwrap(),
+            },
+            DispersalBoundary::AbsorbResample,
+            &habitat,
+        );
+        let mut rng = WyHash::<IntrinsicsMathsCore>::seed_from_u64(42);
+
+        let isolated = Location::new(1, 1);
+
+        assert_eq!(
+            dispersal
+                .get_self_dispersal_probability_at_location(&isolated, &habitat)
+                .get(),
+            1.0_f64
+        );
+
+        for _ in 0..1000 {
+            assert_eq!(
+                dispersal.sample_dispersal_from_location(&isolated, &habitat, &mut rng),
+                isolated
+            );
+        }
+
+        let patch = Location::new(12, 12);
+
+        assert!(
+            dispersal
+                .get_self_dispersal_probability_at_location(&patch, &habitat)
+                .get()
+                < 1.0_f64
+        );
+
+        for _ in 0..1000 {
+            let target =
+                dispersal.sample_non_self_dispersal_from_location(&patch, &habitat, &mut rng);
+
+            assert!(habitat.get_habitat_at_location(&target) > 0);
+            assert_ne!(target, patch);
+        }
+    }
+}
@@ -1,7 +1,10 @@
 pub mod almost_infinite_clark2dt;
 pub mod almost_infinite_normal;
+pub mod fine_coarse;
 pub mod in_memory;
+pub mod kernel;
 pub mod non_spatial;
 pub mod spatially_implicit;
+pub mod spatially_implicit_islands;
 pub mod trespassing;
 pub mod wrapping_noise;
@@ -88,8 +88,12 @@ impl<M: MathsCore, G: RngCore<M>> SeparableDispersalSampler<M, NonSpatialHabitat
 
         let habitat_index_max =
             habitat.get_extent().width().get() * habitat.get_extent().height().get();
+        // The habitat's extent may have a non-zero (wrapping) origin, e.g. the
+        //  metacommunity of the spatially implicit scenario
         let current_location_index =
-            u64::from(location.y()) * habitat.get_extent().width().get() + u64::from(location.x());
+            u64::from(location.y().wrapping_sub(habitat.get_extent().origin().y()))
+                * habitat.get_extent().width().get()
+                + u64::from(location.x().wrapping_sub(habitat.get_extent().origin().x()));
 
         let dispersal_target_index = {
             // Safety: by PRE, `habitat_index_max` > 1
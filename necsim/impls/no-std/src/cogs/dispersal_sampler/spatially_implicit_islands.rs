@@ -0,0 +1,524 @@
+#![allow(non_local_definitions)] // FIXME: displaydoc
+
+use alloc::{sync::Arc, vec::Vec};
+
+use necsim_core::{
+    cogs::{DispersalSampler, Habitat, MathsCore, RngCore, SeparableDispersalSampler},
+    landscape::Location,
+};
+use necsim_core_bond::{ClosedUnitF64, OpenClosedUnitF64 as PositiveUnitF64};
+
+use crate::cogs::{
+    dispersal_sampler::non_spatial::NonSpatialDispersalSampler,
+    habitat::spatially_implicit_islands::SpatiallyImplicitIslandsHabitat,
+};
+
+/// Dispersal sampler in which lineages on a local island migrate to the
+///  metacommunity or to another island with per-island probabilities, and
+///  otherwise disperse uniformly within their island
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug)]
+#[cfg_attr(feature = "cuda", derive(rust_cuda::lend::LendRustToCuda))]
+#[cfg_attr(feature = "cuda", cuda(free = "M"))]
+pub struct SpatiallyImplicitIslandsDispersalSampler<M: MathsCore, G: RngCore<M>> {
+    #[cfg_attr(feature = "cuda", cuda(embed))]
+    meta: NonSpatialDispersalSampler<M, G>,
+    // Row-major islands x (1 + islands) matrix, where each row stores the
+    //  cumulative probabilities of migrating to the metacommunity and to
+    //  each island
+    #[cfg_attr(feature = "cuda", cuda(embed))]
+    cumulative_migration: Arc<[ClosedUnitF64]>,
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, displaydoc::Display)]
+pub enum SpatiallyImplicitIslandsDispersalSamplerError {
+    /// The island migration matrix must have one row and column per island.
+    IslandMigrationDimensionsMismatch,
+    /// The island migration matrix must have a zero diagonal.
+    IslandSelfMigration,
+    /** The combined migration probability to the metacommunity and to other
+    islands exceeds one on island {0}. */
+    MigrationExceedsOne(usize),
+}
+
+/// Destination of a lineage which disperses from a local island
+#[derive(Debug, PartialEq, Eq)]
+enum IslandDispersal {
+    Meta,
+    Island(usize),
+    Local,
+}
+
+impl<M: MathsCore, G: RngCore<M>> SpatiallyImplicitIslandsDispersalSampler<M, G> {
+    /// Creates a new dispersal sampler, where lineages on the `i`th island
+    ///  migrate to the metacommunity with probability `meta_migration[i]`
+    ///  and to the `j`th island with probability `island_migration[i][j]`
+    ///  per generation.
+    ///
+    /// # Errors
+    ///
+    /// Returns `IslandMigrationDimensionsMismatch` iff the
+    ///  `island_migration` matrix is not square with one row per island,
+    ///  `IslandSelfMigration` iff its diagonal is non-zero, and
+    ///  `MigrationExceedsOne` iff the migration probabilities of an island
+    ///  sum to more than one.
+    pub fn try_new(
+        meta_migration: &[PositiveUnitF64],
+        island_migration: Option<&[Vec<ClosedUnitF64>]>,
+    ) -> Result<Self, SpatiallyImplicitIslandsDispersalSamplerError> {
+        let num_islands = meta_migration.len();
+
+        if let Some(island_migration) = island_migration {
+            if island_migration.len() != num_islands
+                || island_migration.iter().any(|row| row.len() != num_islands)
+            {
+                return Err(
+                    SpatiallyImplicitIslandsDispersalSamplerError::IslandMigrationDimensionsMismatch,
+                );
+            }
+
+            if island_migration
+                .iter()
+                .enumerate()
+                .any(|(island, row)| row[island] != ClosedUnitF64::zero())
+            {
+                return Err(SpatiallyImplicitIslandsDispersalSamplerError::IslandSelfMigration);
+            }
+        }
+
+        let mut cumulative_migration = Vec::with_capacity(num_islands * (num_islands + 1));
+
+        for (island, meta_migration) in meta_migration.iter().enumerate() {
+            let mut cumulative = meta_migration.get();
+
+            cumulative_migration.push(ClosedUnitF64::from(*meta_migration));
+
+            for target in 0..num_islands {
+                if let Some(island_migration) = island_migration {
+                    cumulative += island_migration[island][target].get();
+                }
+
+                cumulative_migration.push(ClosedUnitF64::new(cumulative).map_err(|_| {
+                    SpatiallyImplicitIslandsDispersalSamplerError::MigrationExceedsOne(island)
+                })?);
+            }
+        }
+
+        Ok(Self {
+            meta: NonSpatialDispersalSampler::default(),
+            cumulative_migration: Arc::from(cumulative_migration),
+        })
+    }
+
+    fn cumulative_migration_from_island(
+        &self,
+        island: usize,
+        habitat: &SpatiallyImplicitIslandsHabitat<M>,
+    ) -> &[ClosedUnitF64] {
+        let row = habitat.num_islands() + 1;
+
+        self.cumulative_migration
+            .get((island * row)..((island + 1) * row))
+            .unwrap_or(&[])
+    }
+
+    fn sample_island_dispersal(
+        &self,
+        island: usize,
+        habitat: &SpatiallyImplicitIslandsHabitat<M>,
+        sample: f64,
+    ) -> IslandDispersal {
+        let cumulative_migration = self.cumulative_migration_from_island(island, habitat);
+
+        match cumulative_migration.partition_point(|cumulative| cumulative.get() <= sample) {
+            0 => IslandDispersal::Meta,
+            target if target < cumulative_migration.len() => IslandDispersal::Island(target - 1),
+            _ => IslandDispersal::Local,
+        }
+    }
+
+    fn get_local_dispersal_probability(
+        &self,
+        island: usize,
+        habitat: &SpatiallyImplicitIslandsHabitat<M>,
+    ) -> ClosedUnitF64 {
+        self.cumulative_migration_from_island(island, habitat)
+            .last()
+            .copied()
+            .unwrap_or_else(ClosedUnitF64::zero)
+            .one_minus()
+    }
+}
+
+impl<M: MathsCore, G: RngCore<M>> Clone for SpatiallyImplicitIslandsDispersalSampler<M, G> {
+    fn clone(&self) -> Self {
+        Self {
+            meta: self.meta.clone(),
+            cumulative_migration: self.cumulative_migration.clone(),
+        }
+    }
+}
+
+#[contract_trait]
+impl<M: MathsCore, G: RngCore<M>> DispersalSampler<M, SpatiallyImplicitIslandsHabitat<M>, G>
+    for SpatiallyImplicitIslandsDispersalSampler<M, G>
+{
+    #[must_use]
+    fn sample_dispersal_from_location(
+        &self,
+        location: &Location,
+        habitat: &SpatiallyImplicitIslandsHabitat<M>,
+        rng: &mut G,
+    ) -> Location {
+        use necsim_core::cogs::RngSampler;
+
+        // By PRE, location must be habitable, i.e. either on an island or in
+        //  the meta habitat
+        let Some(island) = habitat.get_island_at_location(location) else {
+            return self
+                .meta
+                .sample_dispersal_from_location(location, habitat.meta(), rng);
+        };
+
+        let sample = rng.sample_uniform_closed_open().get();
+
+        match self.sample_island_dispersal(island, habitat, sample) {
+            // Provide a dummy Location in the meta community to disperse from
+            IslandDispersal::Meta => self.meta.sample_dispersal_from_location(
+                habitat.meta().get_extent().origin(),
+                habitat.meta(),
+                rng,
+            ),
+            IslandDispersal::Island(target) => habitat.sample_location_on_island(target, None, rng),
+            IslandDispersal::Local => habitat.sample_location_on_island(island, None, rng),
+        }
+    }
+}
+
+#[contract_trait]
+impl<M: MathsCore, G: RngCore<M>>
+    SeparableDispersalSampler<M, SpatiallyImplicitIslandsHabitat<M>, G>
+    for SpatiallyImplicitIslandsDispersalSampler<M, G>
+{
+    #[must_use]
+    fn sample_non_self_dispersal_from_location(
+        &self,
+        location: &Location,
+        habitat: &SpatiallyImplicitIslandsHabitat<M>,
+        rng: &mut G,
+    ) -> Location {
+        use necsim_core::cogs::RngSampler;
+
+        // By PRE, location must be habitable, i.e. either on an island or in
+        //  the meta habitat
+        let Some(island) = habitat.get_island_at_location(location) else {
+            return self.meta.sample_non_self_dispersal_from_location(
+                location,
+                habitat.meta(),
+                rng,
+            );
+        };
+
+        // Only sample from the non-self dispersal probability mass, which
+        //  excludes the self-dispersal within the island
+        let sample = rng.sample_uniform_closed_open().get()
+            * self
+                .get_self_dispersal_probability_at_location(location, habitat)
+                .one_minus()
+                .get();
+
+        match self.sample_island_dispersal(island, habitat, sample) {
+            // Provide a dummy Location in the meta community to disperse from
+            // As the individual is dispersing to a different community,
+            //  we can use standard dispersal in the meta community
+            IslandDispersal::Meta => self.meta.sample_dispersal_from_location(
+                habitat.meta().get_extent().origin(),
+                habitat.meta(),
+                rng,
+            ),
+            IslandDispersal::Island(target) => habitat.sample_location_on_island(target, None, rng),
+            IslandDispersal::Local => {
+                habitat.sample_location_on_island(island, Some(location), rng)
+            },
+        }
+    }
+
+    #[must_use]
+    fn get_self_dispersal_probability_at_location(
+        &self,
+        location: &Location,
+        habitat: &SpatiallyImplicitIslandsHabitat<M>,
+    ) -> ClosedUnitF64 {
+        // By PRE, location must be habitable, i.e. either on an island or in
+        //  the meta habitat
+        let Some(island) = habitat.get_island_at_location(location) else {
+            return self
+                .meta
+                .get_self_dispersal_probability_at_location(location, habitat.meta());
+        };
+
+        let island_locations = habitat.get_island_extent(island).map_or(1.0_f64, |extent| {
+            f64::from(extent.width()) * f64::from(extent.height())
+        });
+
+        // Safety: the local dispersal probability is in [0.0; 1.0] and the
+        //         island has at least one location
+        //         => local / (width*height) in [0.0; 1.0]
+        unsafe {
+            ClosedUnitF64::new_unchecked(
+                self.get_local_dispersal_probability(island, habitat).get() / island_locations,
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::num::NonZeroU32;
+
+    use alloc::{vec, vec::Vec};
+
+    use necsim_core::{
+        cogs::{DispersalSampler, Habitat, SeedableRng, SeparableDispersalSampler},
+        landscape::Location,
+    };
+    use necsim_core_bond::{ClosedUnitF64, OffByOneU32, OpenClosedUnitF64 as PositiveUnitF64};
+
+    use crate::cogs::{
+        habitat::spatially_implicit_islands::SpatiallyImplicitIslandsHabitat,
+        maths::intrinsics::IntrinsicsMathsCore, rng::wyhash::WyHash,
+    };
+
+    use super::{
+        IslandDispersal, SpatiallyImplicitIslandsDispersalSampler,
+        SpatiallyImplicitIslandsDispersalSamplerError,
+    };
+
+    type Sampler =
+        SpatiallyImplicitIslandsDispersalSampler<IntrinsicsMathsCore, WyHash<IntrinsicsMathsCore>>;
+
+    fn habitat(islands: &[(u64, u64)]) -> SpatiallyImplicitIslandsHabitat<IntrinsicsMathsCore> {
+        let islands = islands
+            .iter()
+            .map(|(width, height)| {
+                (
+                    (
+                        OffByOneU32::new(*width).unwrap(),
+                        OffByOneU32::new(*height).unwrap(),
+                    ),
+                    NonZeroU32::new(2).unwrap(),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        SpatiallyImplicitIslandsHabitat::try_new(
+            &islands,
+            (OffByOneU32::new(4).unwrap(), OffByOneU32::new(4).unwrap()),
+            NonZeroU32::new(2).unwrap(),
+        )
+        .unwrap()
+    }
+
+    fn sampler(meta_migration: &[f64], island_migration: Option<&[&[f64]]>) -> Sampler {
+        try_sampler(meta_migration, island_migration).unwrap()
+    }
+
+    fn try_sampler(
+        meta_migration: &[f64],
+        island_migration: Option<&[&[f64]]>,
+    ) -> Result<Sampler, SpatiallyImplicitIslandsDispersalSamplerError> {
+        let meta_migration = meta_migration
+            .iter()
+            .map(|migration| PositiveUnitF64::new(*migration).unwrap())
+            .collect::<Vec<_>>();
+        let island_migration = island_migration.map(|island_migration| {
+            island_migration
+                .iter()
+                .map(|row| {
+                    row.iter()
+                        .map(|migration| ClosedUnitF64::new(*migration).unwrap())
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>()
+        });
+
+        SpatiallyImplicitIslandsDispersalSampler::try_new(
+            &meta_migration,
+            island_migration.as_deref(),
+        )
+    }
+
+    #[test]
+    fn test_invalid_migration() {
+        assert!(matches!(
+            try_sampler(&[0.25, 0.25], Some(&[&[0.0, 0.25]])),
+            Err(SpatiallyImplicitIslandsDispersalSamplerError::IslandMigrationDimensionsMismatch)
+        ));
+        assert!(matches!(
+            try_sampler(&[0.25, 0.25], Some(&[&[0.0, 0.25], &[0.25, 0.25]])),
+            Err(SpatiallyImplicitIslandsDispersalSamplerError::IslandSelfMigration)
+        ));
+        assert!(matches!(
+            try_sampler(&[0.25, 0.5], Some(&[&[0.0, 0.25], &[0.75, 0.0]])),
+            Err(SpatiallyImplicitIslandsDispersalSamplerError::MigrationExceedsOne(1))
+        ));
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_cumulative_migration_rows() {
+        let habitat = habitat(&[(1, 1), (1, 1), (1, 1)]);
+        let sampler = sampler(
+            &[0.125, 0.25, 1.0],
+            Some(&[&[0.0, 0.0, 0.5], &[0.25, 0.0, 0.25], &[0.0, 0.0, 0.0]]),
+        );
+
+        for (island, row) in [
+            (0, [0.125, 0.125, 0.125, 0.625]),
+            (1, [0.25, 0.5, 0.5, 0.75]),
+            (2, [1.0, 1.0, 1.0, 1.0]),
+        ] {
+            assert_eq!(
+                sampler
+                    .cumulative_migration_from_island(island, &habitat)
+                    .iter()
+                    .map(|cumulative| cumulative.get())
+                    .collect::<Vec<_>>(),
+                row
+            );
+        }
+
+        assert!(sampler
+            .cumulative_migration_from_island(3, &habitat)
+            .is_empty());
+
+        // Without an island migration matrix, lineages only migrate to the
+        //  metacommunity
+        let sampler = self::sampler(&[0.125, 0.25, 1.0], None);
+
+        assert_eq!(
+            sampler
+                .cumulative_migration_from_island(1, &habitat)
+                .iter()
+                .map(|cumulative| cumulative.get())
+                .collect::<Vec<_>>(),
+            vec![0.25; 4]
+        );
+    }
+
+    #[test]
+    fn test_island_dispersal_boundaries() {
+        let habitat = habitat(&[(1, 1), (1, 1), (1, 1), (1, 1)]);
+        // Islands 0, 1, and 3 have zero migration probability from island 0
+        let sampler = sampler(
+            &[0.25, 0.25, 0.25, 0.25],
+            Some(&[
+                &[0.0, 0.0, 0.25, 0.0],
+                &[0.0, 0.0, 0.0, 0.0],
+                &[0.0, 0.0, 0.0, 0.0],
+                &[0.0, 0.0, 0.0, 0.0],
+            ]),
+        );
+
+        for (sample, expected) in [
+            (0.0, IslandDispersal::Meta),
+            (0.25 - f64::EPSILON, IslandDispersal::Meta),
+            (0.25, IslandDispersal::Island(2)),
+            (0.5 - f64::EPSILON, IslandDispersal::Island(2)),
+            (0.5, IslandDispersal::Local),
+            (1.0 - f64::EPSILON, IslandDispersal::Local),
+        ] {
+            assert_eq!(
+                sampler.sample_island_dispersal(0, &habitat, sample),
+                expected,
+                "sample {sample}"
+            );
+        }
+
+        // Lineages which always migrate to the metacommunity never stay local
+        let sampler = self::sampler(&[1.0, 1.0, 1.0, 1.0], None);
+
+        assert_eq!(
+            sampler.sample_island_dispersal(3, &habitat, 1.0 - f64::EPSILON),
+            IslandDispersal::Meta
+        );
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_self_dispersal_complements_non_self_mass() {
+        let habitat = habitat(&[(2, 2), (3, 1)]);
+        let sampler = sampler(&[0.125, 0.25], Some(&[&[0.0, 0.375], &[0.0, 0.0]]));
+
+        for (location, local, locations) in [
+            (Location::new(1, 1), 0.5, 4.0),
+            (Location::new(2, 0), 0.75, 3.0),
+        ] {
+            let self_dispersal = sampler
+                .get_self_dispersal_probability_at_location(&location, &habitat)
+                .get();
+
+            assert_eq!(self_dispersal, local / locations);
+
+            // The non-self mass consists of the migration to other islands
+            //  and the metacommunity, and the local dispersal to the other
+            //  locations on the same island
+            let non_self = (1.0 - local) + local * (locations - 1.0) / locations;
+            assert!((self_dispersal + non_self - 1.0).abs() < f64::EPSILON);
+        }
+
+        let meta = habitat.meta().get_extent().origin().clone();
+
+        assert_eq!(
+            sampler
+                .get_self_dispersal_probability_at_location(&meta, &habitat)
+                .get(),
+            1.0 / 16.0
+        );
+    }
+
+    #[test]
+    fn test_non_self_dispersal_leaves_location() {
+        let habitat = habitat(&[(1, 2), (2, 2)]);
+        // Lineages on island 0 migrate rarely, so most dispersals are local
+        let sampler = sampler(&[0.125, 0.5], Some(&[&[0.0, 0.125], &[0.25, 0.0]]));
+        let mut rng = WyHash::<IntrinsicsMathsCore>::seed_from_u64(42);
+
+        let location = Location::new(0, 1);
+        let mut targets = [0_usize; 3];
+
+        for _ in 0..1000 {
+            let target =
+                sampler.sample_non_self_dispersal_from_location(&location, &habitat, &mut rng);
+
+            assert_ne!(target, location);
+
+            match habitat.get_island_at_location(&target) {
+                Some(0) => {
+                    assert_eq!(target, Location::new(0, 0));
+                    targets[0] += 1;
+                },
+                Some(island) => {
+                    assert_eq!(island, 1);
+                    targets[1] += 1;
+                },
+                None => {
+                    assert!(habitat.meta().get_extent().contains(&target));
+                    targets[2] += 1;
+                },
+            }
+        }
+
+        // All three destinations are reached, and the local one is the most
+        //  likely with 0.375 / 0.625
+        assert!(targets.iter().all(|count| *count > 0));
+        assert!(targets[0] > targets[1] && targets[0] > targets[2]);
+
+        for _ in 0..100 {
+            let target = sampler.sample_dispersal_from_location(&location, &habitat, &mut rng);
+
+            assert!(habitat.get_habitat_at_location(&target) > 0);
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use alloc::vec::Vec;
 use core::marker::PhantomData;
 
-use necsim_core_bond::{NonNegativeF64, PositiveF64};
+use necsim_core_bond::{ClosedUnitF64, NonNegativeF64, PositiveF64};
 
 use necsim_core::{
     cogs::{
@@ -62,6 +62,7 @@ impl<
         dispersal_target: Location,
         prior_time: NonNegativeF64,
         event_time: PositiveF64,
+        speciation_sample: ClosedUnitF64,
         simulation: &mut PartialSimulation<M, H, G, S>,
         rng: &mut G,
     ) -> Option<(
@@ -93,6 +94,7 @@ impl<
                 dispersal_target,
                 prior_time,
                 event_time,
+                speciation_sample,
                 coalescence_rng_sample: CoalescenceRngSample::new(rng),
                 tie_breaker: if self.decomposition.get_subdomain().rank() < target_subdomain {
                     TieBreaker::PreferImmigrant
@@ -9,7 +9,7 @@ use necsim_core::{
     lineage::{GlobalLineageReference, MigratingLineage, TieBreaker},
     simulation::partial::emigration_exit::PartialSimulation,
 };
-use necsim_core_bond::{NonNegativeF64, PositiveF64};
+use necsim_core_bond::{ClosedUnitF64, NonNegativeF64, PositiveF64};
 
 use crate::{
     cogs::lineage_store::independent::IndependentLineageStore, decomposition::Decomposition,
@@ -82,6 +82,7 @@ impl<
         dispersal_target: Location,
         prior_time: NonNegativeF64,
         event_time: PositiveF64,
+        speciation_sample: ClosedUnitF64,
         simulation: &mut PartialSimulation<M, H, G, IndependentLineageStore<M, H>>,
         rng: &mut G,
     ) -> Option<(
@@ -119,6 +120,7 @@ impl<
                 dispersal_target,
                 prior_time,
                 event_time,
+                speciation_sample,
                 coalescence_rng_sample: CoalescenceRngSample::new(rng),
                 tie_breaker: if self.decomposition.get_subdomain().rank() < target_subdomain {
                     TieBreaker::PreferImmigrant
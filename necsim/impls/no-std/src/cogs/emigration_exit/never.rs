@@ -4,7 +4,7 @@ use necsim_core::{
     lineage::GlobalLineageReference,
     simulation::partial::emigration_exit::PartialSimulation,
 };
-use necsim_core_bond::{NonNegativeF64, PositiveF64};
+use necsim_core_bond::{ClosedUnitF64, NonNegativeF64, PositiveF64};
 
 #[allow(clippy::module_name_repetitions)]
 #[derive(Debug, Default)]
@@ -32,6 +32,7 @@ impl<M: MathsCore, H: Habitat<M>, G: RngCore<M>, S: LineageStore<M, H>> Emigrati
         dispersal_target: Location,
         prior_time: NonNegativeF64,
         event_time: PositiveF64,
+        _speciation_sample: ClosedUnitF64,
         _simulation: &mut PartialSimulation<M, H, G, S>,
         _rng: &mut G,
     ) -> Option<(
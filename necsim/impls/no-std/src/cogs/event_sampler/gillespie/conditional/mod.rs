@@ -16,7 +16,7 @@ use necsim_core_bond::{NonNegativeF64, PositiveF64};
 
 use crate::cogs::{
     coalescence_sampler::conditional::ConditionalCoalescenceSampler,
-    event_sampler::gillespie::GillespieEventSampler,
+    event_sampler::{gillespie::GillespieEventSampler, protracted::ProtractedSpeciation},
 };
 
 mod probability;
@@ -35,6 +35,7 @@ pub struct ConditionalGillespieEventSampler<
     T: TurnoverRate<M, H>,
     N: SpeciationProbability<M, H>,
 > {
+    protraction: ProtractedSpeciation,
     #[allow(clippy::type_complexity)]
     marker: PhantomData<(M, H, G, S, X, D, T, N)>,
 }
@@ -51,7 +52,25 @@ impl<
     > Default for ConditionalGillespieEventSampler<M, H, G, S, X, D, T, N>
 {
     fn default() -> Self {
+        Self::new(ProtractedSpeciation::instantaneous())
+    }
+}
+
+impl<
+        M: MathsCore,
+        H: Habitat<M>,
+        G: RngCore<M>,
+        S: GloballyCoherentLineageStore<M, H>,
+        X: EmigrationExit<M, H, G, S>,
+        D: SeparableDispersalSampler<M, H, G>,
+        T: TurnoverRate<M, H>,
+        N: SpeciationProbability<M, H>,
+    > ConditionalGillespieEventSampler<M, H, G, S, X, D, T, N>
+{
+    #[must_use]
+    pub fn new(protraction: ProtractedSpeciation) -> Self {
         Self {
+            protraction,
             marker: PhantomData::<(M, H, G, S, X, D, T, N)>,
         }
     }
@@ -71,6 +90,7 @@ impl<
 {
     unsafe fn backup_unchecked(&self) -> Self {
         Self {
+            protraction: self.protraction,
             marker: PhantomData::<(M, H, G, S, X, D, T, N)>,
         }
     }
@@ -90,6 +110,7 @@ impl<
     for ConditionalGillespieEventSampler<M, H, G, S, X, D, T, N>
 {
     #[must_use]
+    #[allow(clippy::too_many_lines)]
     fn sample_event_for_lineage_at_event_time_or_emigrate<
         Q,
         Aux,
@@ -135,9 +156,24 @@ impl<
             false,
         );
 
+        // The event sample is below the speciation probability iff the event
+        //  speciates, so it is also reported as the event's speciation sample
         let event_sample = probability_at_location.total() * rng.sample_uniform_closed_open();
 
-        if event_sample < probability_at_location.speciation() {
+        // The event rates do not depend on the protracted speciation window:
+        //  - forced speciation replaces out-dispersal, which is never skipped
+        //  - suppressed speciation falls back to an unconditional dispersal, which
+        //    results in the correct out-dispersal and self-coalescence rates, though it
+        //    can produce a trivial self-dispersal event
+        let is_speciation_sample = event_sample < probability_at_location.speciation();
+        let is_out_dispersal_sample = !is_speciation_sample
+            && event_sample
+                < (probability_at_location.speciation().get()
+                    + probability_at_location.out_dispersal().get());
+
+        if (is_speciation_sample && !self.protraction.is_suppressed_at(event_time))
+            || (is_out_dispersal_sample && self.protraction.is_forced_at(event_time))
+        {
             // Speciation Event
             speciation(
                 SpeciationEvent {
@@ -145,21 +181,27 @@ impl<
                     prior_time,
                     event_time,
                     global_lineage_reference: global_reference,
+                    speciation_sample: event_sample.into(),
                 },
                 auxiliary,
             )
-        } else if event_sample
-            < (probability_at_location.speciation().get()
-                + probability_at_location.out_dispersal().get())
-        {
-            // Out-Dispersal Event
-            let dispersal_target = simulation
-                .dispersal_sampler
-                .sample_non_self_dispersal_from_location(
+        } else if is_speciation_sample || is_out_dispersal_sample {
+            // (Suppressed-Speciation-turned-Dispersal or) Out-Dispersal Event
+            let dispersal_target = if is_speciation_sample {
+                simulation.dispersal_sampler.sample_dispersal_from_location(
                     dispersal_origin.location(),
                     &simulation.habitat,
                     rng,
-                );
+                )
+            } else {
+                simulation
+                    .dispersal_sampler
+                    .sample_non_self_dispersal_from_location(
+                        dispersal_origin.location(),
+                        &simulation.habitat,
+                        rng,
+                    )
+            };
 
             // Check for emigration and return None iff lineage emigrated
             if let Some((
@@ -175,6 +217,7 @@ impl<
                     dispersal_target,
                     prior_time,
                     event_time,
+                    event_sample.into(),
                     simulation,
                     rng,
                 )
@@ -196,6 +239,7 @@ impl<
                         global_lineage_reference: global_reference,
                         target: dispersal_target,
                         interaction,
+                        speciation_sample: event_sample.into(),
                     },
                     auxiliary,
                 )
@@ -221,6 +265,7 @@ impl<
                     global_lineage_reference: global_reference,
                     target: dispersal_target,
                     interaction: LineageInteraction::Coalescence(coalescence),
+                    speciation_sample: event_sample.into(),
                 },
                 auxiliary,
             )
@@ -14,6 +14,8 @@ use necsim_core::{
 };
 use necsim_core_bond::{NonNegativeF64, PositiveF64};
 
+use crate::cogs::event_sampler::protracted::ProtractedSpeciation;
+
 use super::GillespieEventSampler;
 
 #[allow(clippy::module_name_repetitions)]
@@ -29,6 +31,7 @@ pub struct UnconditionalGillespieEventSampler<
     T: TurnoverRate<M, H>,
     N: SpeciationProbability<M, H>,
 > {
+    protraction: ProtractedSpeciation,
     #[allow(clippy::type_complexity)]
     marker: PhantomData<(M, H, G, S, X, D, C, T, N)>,
 }
@@ -46,7 +49,26 @@ impl<
     > Default for UnconditionalGillespieEventSampler<M, H, G, S, X, D, C, T, N>
 {
     fn default() -> Self {
+        Self::new(ProtractedSpeciation::instantaneous())
+    }
+}
+
+impl<
+        M: MathsCore,
+        H: Habitat<M>,
+        G: RngCore<M>,
+        S: GloballyCoherentLineageStore<M, H>,
+        X: EmigrationExit<M, H, G, S>,
+        D: DispersalSampler<M, H, G>,
+        C: CoalescenceSampler<M, H, S>,
+        T: TurnoverRate<M, H>,
+        N: SpeciationProbability<M, H>,
+    > UnconditionalGillespieEventSampler<M, H, G, S, X, D, C, T, N>
+{
+    #[must_use]
+    pub fn new(protraction: ProtractedSpeciation) -> Self {
         Self {
+            protraction,
             marker: PhantomData::<(M, H, G, S, X, D, C, T, N)>,
         }
     }
@@ -67,6 +89,7 @@ impl<
 {
     unsafe fn backup_unchecked(&self) -> Self {
         Self {
+            protraction: self.protraction,
             marker: PhantomData::<(M, H, G, S, X, D, C, T, N)>,
         }
     }
@@ -112,20 +135,26 @@ impl<
     ) -> Q {
         use necsim_core::cogs::RngSampler;
 
-        if rng.sample_event(
-            simulation
-                .speciation_probability
-                .get_speciation_probability_at_location(
-                    dispersal_origin.location(),
-                    &simulation.habitat,
-                ),
-        ) {
+        let speciation_sample = rng.sample_uniform_closed_open();
+
+        if speciation_sample
+            < self.protraction.speciation_probability_at(
+                simulation
+                    .speciation_probability
+                    .get_speciation_probability_at_location(
+                        dispersal_origin.location(),
+                        &simulation.habitat,
+                    ),
+                event_time,
+            )
+        {
             speciation(
                 SpeciationEvent {
                     origin: dispersal_origin,
                     prior_time,
                     event_time,
                     global_lineage_reference: global_reference,
+                    speciation_sample: speciation_sample.into(),
                 },
                 auxiliary,
             )
@@ -136,6 +165,22 @@ impl<
                 rng,
             );
 
+            // Forced speciation replaces out-dispersal after the maximum age
+            if self.protraction.is_forced_at(event_time)
+                && &dispersal_target != dispersal_origin.location()
+            {
+                return speciation(
+                    SpeciationEvent {
+                        origin: dispersal_origin,
+                        prior_time,
+                        event_time,
+                        global_lineage_reference: global_reference,
+                        speciation_sample: speciation_sample.into(),
+                    },
+                    auxiliary,
+                );
+            }
+
             // Check for emigration and return None iff lineage emigrated
             if let Some((
                 global_reference,
@@ -150,6 +195,7 @@ impl<
                     dispersal_target,
                     prior_time,
                     event_time,
+                    speciation_sample.into(),
                     simulation,
                     rng,
                 )
@@ -171,6 +217,7 @@ impl<
                         global_lineage_reference: global_reference,
                         target: dispersal_target,
                         interaction,
+                        speciation_sample: speciation_sample.into(),
                     },
                     auxiliary,
                 )
@@ -17,7 +17,10 @@ use crate::cogs::{
     lineage_store::independent::IndependentLineageStore,
 };
 
-use super::tracking::{MinSpeciationTrackingEventSampler, SpeciationSample};
+use super::{
+    protracted::ProtractedSpeciation,
+    tracking::{MinSpeciationTrackingEventSampler, SpeciationSample},
+};
 
 #[allow(clippy::module_name_repetitions)]
 #[derive(Debug)]
@@ -45,6 +48,7 @@ pub struct IndependentEventSampler<
 > {
     #[cfg_attr(feature = "cuda", cuda(embed))]
     min_spec_sample: Option<SpeciationSample>,
+    protraction: ProtractedSpeciation,
     marker: PhantomData<(M, H, G, X, D, T, N)>,
 }
 
@@ -59,8 +63,25 @@ impl<
     > Default for IndependentEventSampler<M, H, G, X, D, T, N>
 {
     fn default() -> Self {
+        Self::new(ProtractedSpeciation::instantaneous())
+    }
+}
+
+impl<
+        M: MathsCore,
+        H: Habitat<M>,
+        G: RngCore<M>,
+        X: EmigrationExit<M, H, G, IndependentLineageStore<M, H>>,
+        D: DispersalSampler<M, H, G>,
+        T: TurnoverRate<M, H>,
+        N: SpeciationProbability<M, H>,
+    > IndependentEventSampler<M, H, G, X, D, T, N>
+{
+    #[must_use]
+    pub fn new(protraction: ProtractedSpeciation) -> Self {
         Self {
             min_spec_sample: None,
+            protraction,
             marker: PhantomData::<(M, H, G, X, D, T, N)>,
         }
     }
@@ -80,6 +101,7 @@ impl<
     unsafe fn backup_unchecked(&self) -> Self {
         Self {
             min_spec_sample: self.min_spec_sample.clone(),
+            protraction: self.protraction,
             marker: PhantomData::<(M, H, G, X, D, T, N)>,
         }
     }
@@ -154,12 +176,15 @@ impl<
         );
 
         if speciation_sample
-            < simulation
-                .speciation_probability
-                .get_speciation_probability_at_location(
-                    dispersal_origin.location(),
-                    &simulation.habitat,
-                )
+            < self.protraction.speciation_probability_at(
+                simulation
+                    .speciation_probability
+                    .get_speciation_probability_at_location(
+                        dispersal_origin.location(),
+                        &simulation.habitat,
+                    ),
+                event_time,
+            )
         {
             speciation(
                 SpeciationEvent {
@@ -167,6 +192,7 @@ impl<
                     prior_time,
                     event_time,
                     global_lineage_reference: global_reference,
+                    speciation_sample: speciation_sample.into(),
                 },
                 auxiliary,
             )
@@ -177,6 +203,22 @@ impl<
                 rng,
             );
 
+            // Forced speciation replaces out-dispersal after the maximum age
+            if self.protraction.is_forced_at(event_time)
+                && &dispersal_target != dispersal_origin.location()
+            {
+                return speciation(
+                    SpeciationEvent {
+                        origin: dispersal_origin,
+                        prior_time,
+                        event_time,
+                        global_lineage_reference: global_reference,
+                        speciation_sample: speciation_sample.into(),
+                    },
+                    auxiliary,
+                );
+            }
+
             // Check for emigration and return None iff lineage emigrated
             if let Some((
                 global_reference,
@@ -191,6 +233,7 @@ impl<
                     dispersal_target,
                     prior_time,
                     event_time,
+                    speciation_sample.into(),
                     simulation,
                     rng,
                 )
@@ -212,6 +255,7 @@ impl<
                         global_lineage_reference: global_reference,
                         target: dispersal_target,
                         interaction,
+                        speciation_sample: speciation_sample.into(),
                     },
                     auxiliary,
                 )
@@ -1,4 +1,5 @@
 pub mod gillespie;
 pub mod independent;
+pub mod protracted;
 pub mod tracking;
 pub mod unconditional;
@@ -0,0 +1,107 @@
+#![allow(non_local_definitions)] // FIXME: displaydoc
+
+use necsim_core_bond::{ClosedUnitF64, NonNegativeF64, PositiveF64};
+
+/// Protracted speciation window of incipient species
+///
+/// A lineage can only speciate once it is at least `min` generations old,
+///  and is forced to speciate at its first event at or after it is `max`
+///  generations old which would disperse it out of its location.
+///
+/// Self-dispersal events never force speciation, since the event-skipping
+///  algorithm does not sample the trivial ones. Thus, all algorithms apply
+///  the same forcing rule. Note that `max` has no effect in a scenario
+///  without out-dispersal, e.g. a non-spatial scenario with a single deme.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Copy, Debug, PartialEq, TypeLayout)]
+#[repr(C)]
+pub struct ProtractedSpeciation {
+    min: NonNegativeF64,
+    max: PositiveF64,
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(displaydoc::Display, Debug)]
+pub enum ProtractedSpeciationError {
+    /** The minimum protracted speciation time {min:?} exceeds the maximum
+    protracted speciation time {max:?}. */
+    MinExceedsMax {
+        min: NonNegativeF64,
+        max: PositiveF64,
+    },
+}
+
+impl Default for ProtractedSpeciation {
+    fn default() -> Self {
+        Self::instantaneous()
+    }
+}
+
+impl ProtractedSpeciation {
+    /// # Errors
+    ///
+    /// Returns `ProtractedSpeciationError::MinExceedsMax` iff `min > max`.
+    pub fn new(min: NonNegativeF64, max: PositiveF64) -> Result<Self, ProtractedSpeciationError> {
+        if min > max {
+            return Err(ProtractedSpeciationError::MinExceedsMax { min, max });
+        }
+
+        Ok(Self { min, max })
+    }
+
+    /// Point-mutation speciation, which is neither suppressed nor forced
+    #[must_use]
+    pub const fn instantaneous() -> Self {
+        Self {
+            min: NonNegativeF64::zero(),
+            max: PositiveF64::infinity(),
+        }
+    }
+
+    #[must_use]
+    pub const fn min(&self) -> NonNegativeF64 {
+        self.min
+    }
+
+    #[must_use]
+    pub const fn max(&self) -> PositiveF64 {
+        self.max
+    }
+
+    #[must_use]
+    pub fn is_instantaneous(&self) -> bool {
+        self.min == 0.0_f64 && self.max == f64::INFINITY
+    }
+
+    /// Speciation is suppressed for all events before the minimum age
+    #[must_use]
+    pub fn is_suppressed_at(&self, event_time: PositiveF64) -> bool {
+        event_time < self.min
+    }
+
+    /// Speciation is forced for all out-dispersal events at or after the
+    ///  maximum age
+    #[must_use]
+    pub fn is_forced_at(&self, event_time: PositiveF64) -> bool {
+        event_time >= self.max
+    }
+
+    /// Adjusts the point-mutation `speciation_probability` of an event at
+    ///  `event_time` to account for suppressed speciation before the minimum
+    ///  age
+    ///
+    /// Forced speciation instead replaces out-dispersal events, see
+    ///  [`Self::is_forced_at`].
+    #[must_use]
+    pub fn speciation_probability_at(
+        &self,
+        speciation_probability: ClosedUnitF64,
+        event_time: PositiveF64,
+    ) -> ClosedUnitF64 {
+        if self.is_suppressed_at(event_time) {
+            ClosedUnitF64::zero()
+        } else {
+            speciation_probability
+        }
+    }
+}
@@ -12,6 +12,8 @@ use necsim_core::{
 };
 use necsim_core_bond::PositiveF64;
 
+use super::protracted::ProtractedSpeciation;
+
 #[allow(clippy::module_name_repetitions)]
 #[derive(Debug)]
 pub struct UnconditionalEventSampler<
@@ -25,6 +27,7 @@ pub struct UnconditionalEventSampler<
     T: TurnoverRate<M, H>,
     N: SpeciationProbability<M, H>,
 > {
+    protraction: ProtractedSpeciation,
     #[allow(clippy::type_complexity)]
     marker: PhantomData<(M, H, G, S, X, D, C, T, N)>,
 }
@@ -42,7 +45,26 @@ impl<
     > Default for UnconditionalEventSampler<M, H, G, S, X, D, C, T, N>
 {
     fn default() -> Self {
+        Self::new(ProtractedSpeciation::instantaneous())
+    }
+}
+
+impl<
+        M: MathsCore,
+        H: Habitat<M>,
+        G: RngCore<M>,
+        S: LocallyCoherentLineageStore<M, H>,
+        X: EmigrationExit<M, H, G, S>,
+        D: DispersalSampler<M, H, G>,
+        C: CoalescenceSampler<M, H, S>,
+        T: TurnoverRate<M, H>,
+        N: SpeciationProbability<M, H>,
+    > UnconditionalEventSampler<M, H, G, S, X, D, C, T, N>
+{
+    #[must_use]
+    pub fn new(protraction: ProtractedSpeciation) -> Self {
         Self {
+            protraction,
             marker: PhantomData::<(M, H, G, S, X, D, C, T, N)>,
         }
     }
@@ -63,6 +85,7 @@ impl<
 {
     unsafe fn backup_unchecked(&self) -> Self {
         Self {
+            protraction: self.protraction,
             marker: PhantomData::<(M, H, G, S, X, D, C, T, N)>,
         }
     }
@@ -108,20 +131,26 @@ impl<
     ) -> Q {
         use necsim_core::cogs::RngSampler;
 
-        if rng.sample_event(
-            simulation
-                .speciation_probability
-                .get_speciation_probability_at_location(
-                    dispersal_origin.location(),
-                    &simulation.habitat,
-                ),
-        ) {
+        let speciation_sample = rng.sample_uniform_closed_open();
+
+        if speciation_sample
+            < self.protraction.speciation_probability_at(
+                simulation
+                    .speciation_probability
+                    .get_speciation_probability_at_location(
+                        dispersal_origin.location(),
+                        &simulation.habitat,
+                    ),
+                event_time,
+            )
+        {
             speciation(
                 SpeciationEvent {
                     origin: dispersal_origin,
                     prior_time,
                     event_time,
                     global_lineage_reference: global_reference,
+                    speciation_sample: speciation_sample.into(),
                 },
                 auxiliary,
             )
@@ -132,6 +161,22 @@ impl<
                 rng,
             );
 
+            // Forced speciation replaces out-dispersal after the maximum age
+            if self.protraction.is_forced_at(event_time)
+                && &dispersal_target != dispersal_origin.location()
+            {
+                return speciation(
+                    SpeciationEvent {
+                        origin: dispersal_origin,
+                        prior_time,
+                        event_time,
+                        global_lineage_reference: global_reference,
+                        speciation_sample: speciation_sample.into(),
+                    },
+                    auxiliary,
+                );
+            }
+
             // Check for emigration and return None iff lineage emigrated
             if let Some((
                 global_reference,
@@ -146,6 +191,7 @@ impl<
                     dispersal_target,
                     prior_time,
                     event_time,
+                    speciation_sample.into(),
                     simulation,
                     rng,
                 )
@@ -167,6 +213,7 @@ impl<
                         global_lineage_reference: global_reference,
                         target: dispersal_target,
                         interaction,
+                        speciation_sample: speciation_sample.into(),
                     },
                     auxiliary,
                 )
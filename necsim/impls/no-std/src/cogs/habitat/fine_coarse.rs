@@ -0,0 +1,505 @@
+#![allow(non_local_definitions)] // FIXME: displaydoc
+
+use core::{marker::PhantomData, num::NonZeroU32};
+
+use alloc::{sync::Arc, vec::Vec};
+
+use necsim_core::{
+    cogs::{Habitat, MathsCore, RngCore, UniformlySampleableHabitat},
+    landscape::{IndexedLocation, LandscapeExtent, Location},
+};
+use necsim_core_bond::{OffByOneU32, OffByOneU64};
+
+use crate::array2d::Array2D;
+
+/// Habitat which embeds a high-resolution fine habitat map into a
+///  low-resolution coarse habitat map that covers the wider landscape.
+///
+/// The landscape is simulated at the resolution of the fine map, where each
+///  coarse cell covers `scale x scale` fine cells. The coarse map stores the
+///  habitat of each of these fine cells, and is overridden by the fine map,
+///  whose first cell is located at `offset` in fine cells.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug)]
+#[cfg_attr(feature = "cuda", derive(rust_cuda::lend::LendRustToCuda))]
+#[cfg_attr(feature = "cuda", cuda(free = "M"))]
+pub struct FineCoarseHabitat<M: MathsCore> {
+    #[cfg_attr(feature = "cuda", cuda(embed))]
+    fine: Arc<[u32]>,
+    #[cfg_attr(feature = "cuda", cuda(embed))]
+    fine_u64_injection: Arc<[u64]>,
+    #[cfg_attr(feature = "cuda", cuda(embed))]
+    fine_extent: LandscapeExtent,
+    #[cfg_attr(feature = "cuda", cuda(embed))]
+    coarse: Arc<[u32]>,
+    #[cfg_attr(feature = "cuda", cuda(embed))]
+    coarse_u64_injection: Arc<[u64]>,
+    coarse_width: u32,
+    scale: u32,
+    #[cfg_attr(feature = "cuda", cuda(embed))]
+    extent: LandscapeExtent,
+    marker: PhantomData<M>,
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, displaydoc::Display)]
+pub enum FineCoarseHabitatError {
+    /** The coarse map, scaled to the fine resolution, is empty or larger than
+    2^32. */
+    InvalidLandscapeSize,
+    /** The fine map is empty or, placed at its offset, not inside the coarse
+    map. */
+    FineMapOutOfBounds,
+    /// The landscape has no habitable locations.
+    EmptyHabitat,
+}
+
+impl<M: MathsCore> Clone for FineCoarseHabitat<M> {
+    fn clone(&self) -> Self {
+        Self {
+            fine: self.fine.clone(),
+            fine_u64_injection: self.fine_u64_injection.clone(),
+            fine_extent: self.fine_extent.clone(),
+            coarse: self.coarse.clone(),
+            coarse_u64_injection: self.coarse_u64_injection.clone(),
+            coarse_width: self.coarse_width,
+            scale: self.scale,
+            extent: self.extent.clone(),
+            marker: PhantomData::<M>,
+        }
+    }
+}
+
+#[contract_trait]
+impl<M: MathsCore> Habitat<M> for FineCoarseHabitat<M> {
+    type LocationIterator<'a> = impl Iterator<Item = Location> + 'a;
+
+    #[must_use]
+    fn is_finite(&self) -> bool {
+        true
+    }
+
+    #[must_use]
+    fn get_extent(&self) -> &LandscapeExtent {
+        &self.extent
+    }
+
+    #[must_use]
+    fn get_total_habitat(&self) -> OffByOneU64 {
+        // Safety: constructor ensures that there is at least one habitable location
+        unsafe {
+            OffByOneU64::new_unchecked(u128::from(
+                *self.coarse_u64_injection.last().unwrap_unchecked(),
+            ))
+        }
+    }
+
+    #[must_use]
+    fn get_habitat_at_location(&self, location: &Location) -> u32 {
+        match self.fine_index(location) {
+            Some(fine_index) => self.fine.get(fine_index),
+            None => self.coarse.get(self.coarse_index(location)),
+        }
+        .copied()
+        .unwrap_or(0)
+    }
+
+    #[must_use]
+    fn map_indexed_location_to_u64_injective(&self, indexed_location: &IndexedLocation) -> u64 {
+        let location = indexed_location.location();
+
+        let location_injection = if let Some(fine_index) = self.fine_index(location) {
+            self.fine_u64_injection
+                .get(fine_index)
+                .copied()
+                .unwrap_or(0)
+        } else {
+            let coarse_index = self.coarse_index(location);
+
+            // Only the coarse cell's fine cells outside the fine map are enumerated
+            let rank = self.rank_in_coarse_cell(location);
+
+            self.coarse_u64_injection
+                .get(coarse_index)
+                .copied()
+                .unwrap_or(0)
+                + u64::from(self.coarse.get(coarse_index).copied().unwrap_or(0)) * rank
+        };
+
+        location_injection + u64::from(indexed_location.index())
+    }
+
+    #[must_use]
+    fn iter_habitable_locations(&self) -> Self::LocationIterator<'_> {
+        self.extent
+            .iter()
+            .filter(move |location| self.get_habitat_at_location(location) > 0)
+    }
+}
+
+#[contract_trait]
+impl<M: MathsCore, G: RngCore<M>> UniformlySampleableHabitat<M, G> for FineCoarseHabitat<M> {
+    #[must_use]
+    #[inline]
+    fn sample_habitable_indexed_location(&self, rng: &mut G) -> IndexedLocation {
+        use necsim_core::cogs::RngSampler;
+
+        let indexed_location_index = rng.sample_index_u64(self.get_total_habitat().into());
+
+        // The fine map is enumerated first, the remaining coarse cells afterwards
+        if indexed_location_index < self.coarse_u64_injection[0] {
+            let fine_index = match self
+                .fine_u64_injection
+                .binary_search(&indexed_location_index)
+            {
+                Ok(index) => index,
+                Err(index) => index - 1,
+            };
+
+            // Skip over any fine cells without habitat
+            let fine_index = fine_index
+                + self.fine_u64_injection[fine_index..]
+                    .iter()
+                    .take_while(|injection| **injection == self.fine_u64_injection[fine_index])
+                    .count()
+                - 1;
+
+            #[allow(clippy::cast_possible_truncation)]
+            return IndexedLocation::new(
+                Location::new(
+                    self.fine_extent.origin().x()
+                        + (fine_index % usize::from(self.fine_extent.width())) as u32,
+                    self.fine_extent.origin().y()
+                        + (fine_index / usize::from(self.fine_extent.width())) as u32,
+                ),
+                (indexed_location_index - self.fine_u64_injection[fine_index]) as u32,
+            );
+        }
+
+        let coarse_index = match self
+            .coarse_u64_injection
+            .binary_search(&indexed_location_index)
+        {
+            Ok(index) => index,
+            Err(index) => index - 1,
+        };
+
+        // Skip over any coarse cells without (uncovered) habitat
+        let coarse_index = coarse_index
+            + self.coarse_u64_injection[coarse_index..]
+                .iter()
+                .take_while(|injection| **injection == self.coarse_u64_injection[coarse_index])
+                .count()
+            - 1;
+
+        let habitat = u64::from(self.coarse[coarse_index]);
+        let offset = indexed_location_index - self.coarse_u64_injection[coarse_index];
+
+        #[allow(clippy::cast_possible_truncation)]
+        let location = self.unrank_in_coarse_cell(
+            (coarse_index % (self.coarse_width as usize)) as u32,
+            (coarse_index / (self.coarse_width as usize)) as u32,
+            offset / habitat,
+        );
+
+        #[allow(clippy::cast_possible_truncation)]
+        IndexedLocation::new(location, (offset % habitat) as u32)
+    }
+}
+
+impl<M: MathsCore> FineCoarseHabitat<M> {
+    /// Creates a new habitat from the `fine` map, whose first cell is placed
+    ///  at `offset` inside the `coarse` map, where each coarse cell covers
+    ///  `scale x scale` fine cells.
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidLandscapeSize` iff the scaled coarse map is empty, or
+    ///  wider or taller than `2^32`.
+    ///
+    /// Returns `FineMapOutOfBounds` iff the fine map is empty, or not fully
+    ///  contained inside the scaled coarse map when placed at `offset`.
+    ///
+    /// Returns `EmptyHabitat` iff there is no habitat in the landscape.
+    #[debug_ensures(if let Ok(ret) = &ret {
+        old(coarse.num_columns()) * (old(scale.get()) as usize) == usize::from(
+            ret.get_extent().width()
+        ) && old(coarse.num_rows()) * (old(scale.get()) as usize) == usize::from(
+            ret.get_extent().height()
+        )
+    } else { true }, "habitat extent has the scaled dimension of the coarse array")]
+    pub fn try_new(
+        fine: Array2D<u32>,
+        coarse: Array2D<u32>,
+        scale: NonZeroU32,
+        offset: Location,
+    ) -> Result<Self, FineCoarseHabitatError> {
+        let scale = scale.get();
+
+        let (Ok(width), Ok(height)) = (
+            OffByOneU32::new((coarse.num_columns() as u64) * u64::from(scale)),
+            OffByOneU32::new((coarse.num_rows() as u64) * u64::from(scale)),
+        ) else {
+            return Err(FineCoarseHabitatError::InvalidLandscapeSize);
+        };
+        let extent = LandscapeExtent::new(Location::new(0, 0), width, height);
+
+        let (Ok(fine_width), Ok(fine_height)) = (
+            OffByOneU32::new(fine.num_columns() as u64),
+            OffByOneU32::new(fine.num_rows() as u64),
+        ) else {
+            return Err(FineCoarseHabitatError::FineMapOutOfBounds);
+        };
+        let fine_extent = LandscapeExtent::new(offset, fine_width, fine_height);
+
+        if (u64::from(fine_extent.origin().x()) + u64::from(fine_width)) > u64::from(width)
+            || (u64::from(fine_extent.origin().y()) + u64::from(fine_height)) > u64::from(height)
+        {
+            return Err(FineCoarseHabitatError::FineMapOutOfBounds);
+        }
+
+        #[allow(clippy::cast_possible_truncation)]
+        let coarse_width = coarse.num_columns() as u32;
+
+        let fine = fine.into_row_major().into_boxed_slice();
+        let coarse = coarse.into_row_major().into_boxed_slice();
+
+        let mut index_acc = 0_u64;
+
+        let mut fine_u64_injection = fine
+            .iter()
+            .map(|h| {
+                let injection = index_acc;
+                index_acc += u64::from(*h);
+                injection
+            })
+            .collect::<Vec<u64>>();
+        fine_u64_injection.push(index_acc);
+
+        let mut coarse_u64_injection = coarse
+            .iter()
+            .enumerate()
+            .map(|(coarse_index, h)| {
+                #[allow(clippy::cast_possible_truncation)]
+                let uncovered = Self::count_uncovered_in_coarse_cell(
+                    &fine_extent,
+                    scale,
+                    (coarse_index % (coarse_width as usize)) as u32,
+                    (coarse_index / (coarse_width as usize)) as u32,
+                );
+
+                let injection = index_acc;
+                index_acc += u64::from(*h) * uncovered;
+                injection
+            })
+            .collect::<Vec<u64>>();
+        coarse_u64_injection.push(index_acc);
+
+        if index_acc == 0 {
+            return Err(FineCoarseHabitatError::EmptyHabitat);
+        }
+
+        Ok(Self {
+            fine: Arc::from(fine),
+            fine_u64_injection: Arc::from(fine_u64_injection.into_boxed_slice()),
+            fine_extent,
+            coarse: Arc::from(coarse),
+            coarse_u64_injection: Arc::from(coarse_u64_injection.into_boxed_slice()),
+            coarse_width,
+            scale,
+            extent,
+            marker: PhantomData::<M>,
+        })
+    }
+
+    /// Returns the extent of the fine map inside the landscape
+    #[must_use]
+    pub fn get_fine_extent(&self) -> &LandscapeExtent {
+        &self.fine_extent
+    }
+
+    #[must_use]
+    pub(crate) fn fine_index(&self, location: &Location) -> Option<usize> {
+        if self.fine_extent.contains(location) {
+            Some(
+                ((location.y() - self.fine_extent.origin().y()) as usize)
+                    * usize::from(self.fine_extent.width())
+                    + ((location.x() - self.fine_extent.origin().x()) as usize),
+            )
+        } else {
+            None
+        }
+    }
+
+    fn coarse_index(&self, location: &Location) -> usize {
+        ((location.y() / self.scale) as usize) * (self.coarse_width as usize)
+            + ((location.x() / self.scale) as usize)
+    }
+
+    /// Returns the `[x0, x1) x [y0, y1)` rectangle, relative to the coarse
+    ///  cell, which is covered by the fine map
+    fn covered_in_coarse_cell(
+        fine_extent: &LandscapeExtent,
+        scale: u32,
+        cell_x: u32,
+        cell_y: u32,
+    ) -> ((u64, u64), (u64, u64)) {
+        let cell_x = u64::from(cell_x) * u64::from(scale);
+        let cell_y = u64::from(cell_y) * u64::from(scale);
+
+        let fine_x = u64::from(fine_extent.origin().x());
+        let fine_y = u64::from(fine_extent.origin().y());
+
+        let clamp = |fine: u64, cell: u64| fine.saturating_sub(cell).min(u64::from(scale));
+
+        (
+            (
+                clamp(fine_x, cell_x),
+                clamp(fine_x + u64::from(fine_extent.width()), cell_x),
+            ),
+            (
+                clamp(fine_y, cell_y),
+                clamp(fine_y + u64::from(fine_extent.height()), cell_y),
+            ),
+        )
+    }
+
+    fn count_uncovered_in_coarse_cell(
+        fine_extent: &LandscapeExtent,
+        scale: u32,
+        cell_x: u32,
+        cell_y: u32,
+    ) -> u64 {
+        let ((x0, x1), (y0, y1)) = Self::covered_in_coarse_cell(fine_extent, scale, cell_x, cell_y);
+
+        u64::from(scale) * u64::from(scale) - (x1 - x0) * (y1 - y0)
+    }
+
+    /// Returns the row-major rank of the `location` among the fine cells of
+    ///  its coarse cell that are not covered by the fine map
+    fn rank_in_coarse_cell(&self, location: &Location) -> u64 {
+        let ((x0, x1), (y0, y1)) = Self::covered_in_coarse_cell(
+            &self.fine_extent,
+            self.scale,
+            location.x() / self.scale,
+            location.y() / self.scale,
+        );
+
+        let x = u64::from(location.x() % self.scale);
+        let y = u64::from(location.y() % self.scale);
+
+        let covered_rows_before = y.clamp(y0, y1) - y0;
+        let covered_in_row_before = if (y0..y1).contains(&y) {
+            x.clamp(x0, x1) - x0
+        } else {
+            0
+        };
+
+        y * u64::from(self.scale) + x - covered_rows_before * (x1 - x0) - covered_in_row_before
+    }
+
+    /// Inverse of [`Self::rank_in_coarse_cell`]
+    fn unrank_in_coarse_cell(&self, cell_x: u32, cell_y: u32, rank: u64) -> Location {
+        let ((x0, x1), (y0, y1)) =
+            Self::covered_in_coarse_cell(&self.fine_extent, self.scale, cell_x, cell_y);
+
+        let scale = u64::from(self.scale);
+        let partial_width = scale - (x1 - x0);
+
+        let (x, y) = if rank < y0 * scale {
+            (rank % scale, rank / scale)
+        } else if rank - y0 * scale < (y1 - y0) * partial_width {
+            let rank = rank - y0 * scale;
+            let x = rank % partial_width;
+
+            (
+                if x < x0 { x } else { x + (x1 - x0) },
+                y0 + rank / partial_width,
+            )
+        } else {
+            let rank = rank - y0 * scale - (y1 - y0) * partial_width;
+
+            (rank % scale, y1 + rank / scale)
+        };
+
+        #[allow(clippy::cast_possible_truncation)]
+        Location::new(
+            cell_x * self.scale + (x as u32),
+            cell_y * self.scale + (y as u32),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::num::NonZeroU32;
+
+    use alloc::{vec, vec::Vec};
+
+    use necsim_core::{
+        cogs::{Habitat, SeedableRng, UniformlySampleableHabitat},
+        landscape::{IndexedLocation, Location},
+    };
+
+    use crate::{
+        array2d::Array2D,
+        cogs::{maths::intrinsics::IntrinsicsMathsCore, rng::wyhash::WyHash},
+    };
+
+    use super::FineCoarseHabitat;
+
+    fn habitat(offset: Location) -> FineCoarseHabitat<IntrinsicsMathsCore> {
+        let fine = Array2D::from_row_major(&[1, 0, 2, 3, 0, 1, 4, 1, 2, 0, 1, 5], 3, 4).unwrap();
+        let coarse = Array2D::from_row_major(&[2, 0, 1, 3, 1, 0], 2, 3).unwrap();
+
+        FineCoarseHabitat::try_new(fine, coarse, NonZeroU32::new(3).unwrap(), offset).unwrap()
+    }
+
+    #[test]
+    fn test_u64_injection_is_dense_bijection() {
+        for y in 0..=3 {
+            for x in 0..=5 {
+                let habitat = habitat(Location::new(x, y));
+
+                let mut injections = habitat
+                    .iter_habitable_locations()
+                    .flat_map(|location| {
+                        (0..habitat.get_habitat_at_location(&location))
+                            .map(move |index| IndexedLocation::new(location.clone(), index))
+                    })
+                    .map(|indexed_location| {
+                        habitat.map_indexed_location_to_u64_injective(&indexed_location)
+                    })
+                    .collect::<Vec<_>>();
+                injections.sort_unstable();
+
+                assert_eq!(
+                    injections,
+                    (0..u64::try_from(habitat.get_total_habitat().get()).unwrap())
+                        .collect::<Vec<_>>()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_uniform_sampling_inverts_u64_injection() {
+        let habitat = habitat(Location::new(4, 2));
+        let mut rng = WyHash::<IntrinsicsMathsCore>::seed_from_u64(42);
+
+        let mut counts = vec![0_u32; usize::try_from(habitat.get_total_habitat().get()).unwrap()];
+
+        for _ in 0..(1 << 16) {
+            let indexed_location = habitat.sample_habitable_indexed_location(&mut rng);
+
+            assert!(habitat.is_indexed_location_habitable(&indexed_location));
+
+            counts[usize::try_from(
+                habitat.map_indexed_location_to_u64_injective(&indexed_location),
+            )
+            .unwrap()] += 1;
+        }
+
+        assert!(counts.iter().all(|count| *count > 0));
+    }
+}
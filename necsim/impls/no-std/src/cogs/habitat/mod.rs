@@ -1,5 +1,7 @@
 pub mod almost_infinite;
+pub mod fine_coarse;
 pub mod in_memory;
 pub mod non_spatial;
 pub mod spatially_implicit;
+pub mod spatially_implicit_islands;
 pub mod wrapping_noise;
@@ -0,0 +1,519 @@
+#![allow(non_local_definitions)] // FIXME: displaydoc
+
+use core::num::{NonZeroU32, NonZeroU64};
+
+use alloc::{sync::Arc, vec::Vec};
+
+use necsim_core::{
+    cogs::{Habitat, MathsCore, RngCore, UniformlySampleableHabitat},
+    landscape::{IndexedLocation, LandscapeExtent, Location},
+};
+use necsim_core_bond::{OffByOneU32, OffByOneU64};
+
+use crate::cogs::habitat::non_spatial::NonSpatialHabitat;
+
+const SPATIALLY_IMPLICIT_ISLANDS_EXTENT: LandscapeExtent =
+    LandscapeExtent::new(Location::new(0, 0), OffByOneU32::max(), OffByOneU32::max());
+
+/// Habitat of several non-spatial local islands, which share one non-spatial
+///  metacommunity.
+///
+/// The islands are placed next to each other along the x-axis, starting at
+///  the origin, while the metacommunity is placed at the far end of the
+///  landscape, just like in the
+///  [`SpatiallyImplicitHabitat`](super::spatially_implicit::SpatiallyImplicitHabitat).
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug)]
+#[cfg_attr(feature = "cuda", derive(rust_cuda::lend::LendRustToCuda))]
+#[cfg_attr(feature = "cuda", cuda(free = "M"))]
+pub struct SpatiallyImplicitIslandsHabitat<M: MathsCore> {
+    #[cfg_attr(feature = "cuda", cuda(embed))]
+    island_origins: Arc<[u32]>,
+    #[cfg_attr(feature = "cuda", cuda(embed))]
+    island_widths: Arc<[OffByOneU32]>,
+    #[cfg_attr(feature = "cuda", cuda(embed))]
+    island_heights: Arc<[OffByOneU32]>,
+    #[cfg_attr(feature = "cuda", cuda(embed))]
+    island_demes: Arc<[u32]>,
+    // Number of individuals on all previous islands, with one extra entry
+    //  for the number of individuals on all islands
+    #[cfg_attr(feature = "cuda", cuda(embed))]
+    island_u64_injection: Arc<[u64]>,
+    #[cfg_attr(feature = "cuda", cuda(embed))]
+    meta: NonSpatialHabitat<M>,
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, displaydoc::Display)]
+pub enum SpatiallyImplicitIslandsHabitatError {
+    /// There must be at least one local island.
+    NoIslands,
+    /** The combined width of all islands and the metacommunity is larger
+    than 2^32. */
+    IslandsTooWide,
+    /** The combined number of individuals on all islands and in the
+    metacommunity is larger than 2^64. */
+    TooManyIndividuals,
+}
+
+impl<M: MathsCore> SpatiallyImplicitIslandsHabitat<M> {
+    /// Creates a new habitat with the `islands`, each given by their area
+    ///  and deme, and the metacommunity with the `meta_area` and `meta_deme`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `NoIslands` iff `islands` is empty, `IslandsTooWide` iff the
+    ///  islands and the metacommunity do not fit next to each other, and
+    ///  `TooManyIndividuals` iff the total number of individuals exceeds
+    ///  2^64.
+    pub fn try_new(
+        islands: &[((OffByOneU32, OffByOneU32), NonZeroU32)],
+        meta_area: (OffByOneU32, OffByOneU32),
+        meta_deme: NonZeroU32,
+    ) -> Result<Self, SpatiallyImplicitIslandsHabitatError> {
+        if islands.is_empty() {
+            return Err(SpatiallyImplicitIslandsHabitatError::NoIslands);
+        }
+
+        let mut island_origins = Vec::with_capacity(islands.len());
+        let mut island_u64_injection = Vec::with_capacity(islands.len() + 1);
+
+        let mut width = 0_u64;
+        let mut individuals = 0_u128;
+
+        for ((island_width, island_height), island_deme) in islands {
+            island_origins.push(
+                u32::try_from(width)
+                    .map_err(|_| SpatiallyImplicitIslandsHabitatError::IslandsTooWide)?,
+            );
+            island_u64_injection.push(
+                u64::try_from(individuals)
+                    .map_err(|_| SpatiallyImplicitIslandsHabitatError::TooManyIndividuals)?,
+            );
+
+            width += island_width.get();
+            individuals += OffByOneU64::from(*island_width).get()
+                * OffByOneU64::from(*island_height).get()
+                * OffByOneU64::from(*island_deme).get();
+        }
+
+        // The islands must not overlap with the metacommunity, which is placed
+        //  at the end of the landscape
+        if width + meta_area.0.get() > OffByOneU32::max().get() {
+            return Err(SpatiallyImplicitIslandsHabitatError::IslandsTooWide);
+        }
+
+        let meta_individuals = OffByOneU64::from(meta_area.0).get()
+            * OffByOneU64::from(meta_area.1).get()
+            * OffByOneU64::from(meta_deme).get();
+
+        if individuals + meta_individuals > OffByOneU64::max().get() {
+            return Err(SpatiallyImplicitIslandsHabitatError::TooManyIndividuals);
+        }
+
+        island_u64_injection.push(
+            u64::try_from(individuals)
+                .map_err(|_| SpatiallyImplicitIslandsHabitatError::TooManyIndividuals)?,
+        );
+
+        let meta = NonSpatialHabitat::new_with_offset(
+            Location::new(meta_area.0.inv(), meta_area.1.inv()),
+            meta_area,
+            meta_deme,
+        );
+
+        Ok(Self {
+            island_origins: Arc::from(island_origins),
+            island_widths: islands.iter().map(|((width, _), _)| *width).collect(),
+            island_heights: islands.iter().map(|((_, height), _)| *height).collect(),
+            island_demes: islands.iter().map(|(_, deme)| deme.get()).collect(),
+            island_u64_injection: Arc::from(island_u64_injection),
+            meta,
+        })
+    }
+
+    #[must_use]
+    pub fn meta(&self) -> &NonSpatialHabitat<M> {
+        &self.meta
+    }
+
+    #[must_use]
+    pub fn num_islands(&self) -> usize {
+        self.island_origins.len()
+    }
+
+    /// Returns the number of individuals on all islands
+    #[must_use]
+    pub fn get_total_island_habitat(&self) -> u64 {
+        self.island_u64_injection.last().copied().unwrap_or(0)
+    }
+
+    /// Returns the extent of the `island`, or `None` if it does not exist
+    #[must_use]
+    pub fn get_island_extent(&self, island: usize) -> Option<LandscapeExtent> {
+        Some(LandscapeExtent::new(
+            Location::new(*self.island_origins.get(island)?, 0),
+            *self.island_widths.get(island)?,
+            *self.island_heights.get(island)?,
+        ))
+    }
+
+    /// Returns the index of the island which contains the `location`, or
+    ///  `None` if the `location` is not on any island
+    #[must_use]
+    #[inline]
+    pub fn get_island_at_location(&self, location: &Location) -> Option<usize> {
+        let island = self
+            .island_origins
+            .partition_point(|origin| *origin <= location.x())
+            .checked_sub(1)?;
+
+        let x = location.x() - *self.island_origins.get(island)?;
+
+        if u64::from(x) < self.island_widths.get(island)?.get()
+            && u64::from(location.y()) < self.island_heights.get(island)?.get()
+        {
+            Some(island)
+        } else {
+            None
+        }
+    }
+
+    /// Samples a location on the `island` uniformly at random, excluding the
+    ///  `excluded` location on the island, if given.
+    ///
+    /// The `island` must exist, and, if a location is `excluded`, have at
+    ///  least two locations.
+    #[must_use]
+    #[inline]
+    pub fn sample_location_on_island<G: RngCore<M>>(
+        &self,
+        island: usize,
+        excluded: Option<&Location>,
+        rng: &mut G,
+    ) -> Location {
+        use necsim_core::cogs::RngSampler;
+
+        let origin = self.island_origins.get(island).copied().unwrap_or(0);
+        let width = self
+            .island_widths
+            .get(island)
+            .copied()
+            .unwrap_or_else(OffByOneU32::one)
+            .get();
+        let height = self
+            .island_heights
+            .get(island)
+            .copied()
+            .unwrap_or_else(OffByOneU32::one)
+            .get();
+
+        let index = match excluded {
+            None => {
+                // Safety: island width and height are both > 0
+                rng.sample_index_u64(unsafe { NonZeroU64::new_unchecked(width * height) })
+            },
+            Some(excluded) => {
+                let excluded_index =
+                    u64::from(excluded.y()) * width + u64::from(excluded.x().wrapping_sub(origin));
+
+                // Safety: by PRE, the island has more than one location
+                let index =
+                    rng.sample_index_u64(unsafe { NonZeroU64::new_unchecked(width * height - 1) });
+
+                if index >= excluded_index {
+                    index + 1
+                } else {
+                    index
+                }
+            },
+        };
+
+        #[allow(clippy::cast_possible_truncation)]
+        Location::new(
+            origin.wrapping_add((index % width) as u32),
+            (index / width) as u32,
+        )
+    }
+}
+
+impl<M: MathsCore> Clone for SpatiallyImplicitIslandsHabitat<M> {
+    fn clone(&self) -> Self {
+        Self {
+            island_origins: self.island_origins.clone(),
+            island_widths: self.island_widths.clone(),
+            island_heights: self.island_heights.clone(),
+            island_demes: self.island_demes.clone(),
+            island_u64_injection: self.island_u64_injection.clone(),
+            meta: self.meta.clone(),
+        }
+    }
+}
+
+#[contract_trait]
+impl<M: MathsCore> Habitat<M> for SpatiallyImplicitIslandsHabitat<M> {
+    type LocationIterator<'a> = impl Iterator<Item = Location> + 'a;
+
+    #[must_use]
+    fn is_finite(&self) -> bool {
+        true
+    }
+
+    #[must_use]
+    fn get_extent(&self) -> &LandscapeExtent {
+        &SPATIALLY_IMPLICIT_ISLANDS_EXTENT
+    }
+
+    #[must_use]
+    fn get_total_habitat(&self) -> OffByOneU64 {
+        let islands = self.get_total_island_habitat();
+
+        // Safety: the constructor ensures that the total habitat is <= 2^64,
+        //         and the metacommunity contains at least one individual
+        unsafe {
+            OffByOneU64::new_unchecked(u128::from(islands) + self.meta.get_total_habitat().get())
+        }
+    }
+
+    #[must_use]
+    fn get_habitat_at_location(&self, location: &Location) -> u32 {
+        if let Some(island) = self.get_island_at_location(location) {
+            self.island_demes.get(island).copied().unwrap_or(0)
+        } else if self.meta.get_extent().contains(location) {
+            self.meta.get_habitat_at_location(location)
+        } else {
+            0_u32
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    fn map_indexed_location_to_u64_injective(&self, indexed_location: &IndexedLocation) -> u64 {
+        let location = indexed_location.location();
+
+        if let Some(island) = self.get_island_at_location(location) {
+            let origin = self.island_origins.get(island).copied().unwrap_or(0);
+            let width = self
+                .island_widths
+                .get(island)
+                .map_or(1, |width| width.get());
+            let deme = self.island_demes.get(island).copied().unwrap_or(1);
+
+            self.island_u64_injection.get(island).copied().unwrap_or(0)
+                + (u64::from(location.y()) * width + u64::from(location.x() - origin))
+                    * u64::from(deme)
+                + u64::from(indexed_location.index())
+        } else {
+            self.get_total_island_habitat()
+                + self
+                    .meta
+                    .map_indexed_location_to_u64_injective(indexed_location)
+        }
+    }
+
+    #[must_use]
+    fn iter_habitable_locations(&self) -> Self::LocationIterator<'_> {
+        (0..self.num_islands())
+            .filter_map(move |island| self.get_island_extent(island))
+            .flat_map(|extent| extent.iter())
+            .chain(self.meta.iter_habitable_locations())
+    }
+}
+
+#[contract_trait]
+impl<M: MathsCore, G: RngCore<M>> UniformlySampleableHabitat<M, G>
+    for SpatiallyImplicitIslandsHabitat<M>
+{
+    #[must_use]
+    #[inline]
+    fn sample_habitable_indexed_location(&self, rng: &mut G) -> IndexedLocation {
+        use necsim_core::cogs::RngSampler;
+
+        let islands = self.get_total_island_habitat();
+
+        // Only the individuals on the local islands are sampled
+        // Safety: every island contains at least one individual
+        let index = rng.sample_index_u64(unsafe { NonZeroU64::new_unchecked(islands) });
+
+        let island = self
+            .island_u64_injection
+            .partition_point(|injection| *injection <= index)
+            - 1;
+
+        let origin = self.island_origins.get(island).copied().unwrap_or(0);
+        let width = self
+            .island_widths
+            .get(island)
+            .map_or(1, |width| width.get());
+        let deme = self.island_demes.get(island).copied().unwrap_or(1);
+
+        let index = index - self.island_u64_injection.get(island).copied().unwrap_or(0);
+        let location_index = index / u64::from(deme);
+
+        #[allow(clippy::cast_possible_truncation)]
+        IndexedLocation::new(
+            Location::new(
+                origin.wrapping_add((location_index % width) as u32),
+                (location_index / width) as u32,
+            ),
+            (index % u64::from(deme)) as u32,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::num::NonZeroU32;
+
+    use alloc::{collections::BTreeSet, vec::Vec};
+
+    use necsim_core::{
+        cogs::{Habitat, SeedableRng, UniformlySampleableHabitat},
+        landscape::{IndexedLocation, Location},
+    };
+    use necsim_core_bond::OffByOneU32;
+
+    use crate::cogs::{maths::intrinsics::IntrinsicsMathsCore, rng::wyhash::WyHash};
+
+    use super::{SpatiallyImplicitIslandsHabitat, SpatiallyImplicitIslandsHabitatError};
+
+    fn area(width: u64, height: u64) -> (OffByOneU32, OffByOneU32) {
+        (
+            OffByOneU32::new(width).unwrap(),
+            OffByOneU32::new(height).unwrap(),
+        )
+    }
+
+    fn three_islands() -> SpatiallyImplicitIslandsHabitat<IntrinsicsMathsCore> {
+        SpatiallyImplicitIslandsHabitat::try_new(
+            &[
+                (area(2, 3), NonZeroU32::new(2).unwrap()),
+                (area(1, 1), NonZeroU32::new(5).unwrap()),
+                (area(3, 2), NonZeroU32::new(1).unwrap()),
+            ],
+            area(4, 4),
+            NonZeroU32::new(3).unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_invalid_islands() {
+        assert!(matches!(
+            SpatiallyImplicitIslandsHabitat::<IntrinsicsMathsCore>::try_new(
+                &[],
+                area(4, 4),
+                NonZeroU32::new(3).unwrap(),
+            ),
+            Err(SpatiallyImplicitIslandsHabitatError::NoIslands)
+        ));
+
+        assert!(matches!(
+            SpatiallyImplicitIslandsHabitat::<IntrinsicsMathsCore>::try_new(
+                &[(area(1 << 31, 1), NonZeroU32::new(1).unwrap())],
+                area((1 << 31) + 1, 1),
+                NonZeroU32::new(1).unwrap(),
+            ),
+            Err(SpatiallyImplicitIslandsHabitatError::IslandsTooWide)
+        ));
+    }
+
+    #[test]
+    fn test_island_and_meta_locations() {
+        let habitat = three_islands();
+
+        assert_eq!(habitat.num_islands(), 3);
+        assert_eq!(habitat.get_total_island_habitat(), 2 * 3 * 2 + 5 + 3 * 2);
+        assert_eq!(
+            habitat.get_total_habitat().get(),
+            u128::from(habitat.get_total_island_habitat()) + 4 * 4 * 3
+        );
+
+        for (location, island, deme) in [
+            (Location::new(0, 0), Some(0), 2),
+            (Location::new(1, 2), Some(0), 2),
+            (Location::new(1, 3), None, 0),
+            (Location::new(2, 0), Some(1), 5),
+            (Location::new(2, 1), None, 0),
+            (Location::new(3, 0), Some(2), 1),
+            (Location::new(5, 1), Some(2), 1),
+            (Location::new(5, 2), None, 0),
+            (Location::new(6, 0), None, 0),
+            (Location::new(u32::MAX - 3, u32::MAX - 3), None, 3),
+            (Location::new(u32::MAX, u32::MAX), None, 3),
+            (Location::new(u32::MAX - 4, u32::MAX), None, 0),
+        ] {
+            assert_eq!(habitat.get_island_at_location(&location), island);
+            assert_eq!(habitat.get_habitat_at_location(&location), deme);
+            assert_eq!(
+                habitat.meta().get_extent().contains(&location),
+                island.is_none() && deme > 0
+            );
+        }
+
+        let island = habitat.get_island_extent(2).unwrap();
+        assert_eq!(island.origin(), &Location::new(3, 0));
+        assert_eq!((island.width().get(), island.height().get()), (3, 2));
+        assert!(habitat.get_island_extent(3).is_none());
+    }
+
+    #[test]
+    fn test_indexed_locations_map_to_u64_bijectively() {
+        let habitat = three_islands();
+
+        let indices = habitat
+            .iter_habitable_locations()
+            .flat_map(|location| {
+                (0..habitat.get_habitat_at_location(&location))
+                    .map(move |index| IndexedLocation::new(location.clone(), index))
+            })
+            .map(|indexed_location| {
+                u128::from(habitat.map_indexed_location_to_u64_injective(&indexed_location))
+            })
+            .collect::<Vec<_>>();
+
+        // Every individual on the islands and in the metacommunity has a
+        //  unique index, and the islands come first
+        assert_eq!(
+            indices,
+            (0..habitat.get_total_habitat().get()).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_samples_on_islands() {
+        let habitat = three_islands();
+        let mut rng = WyHash::<IntrinsicsMathsCore>::seed_from_u64(42);
+
+        let mut sampled = BTreeSet::new();
+
+        for _ in 0..1000 {
+            let indexed_location = habitat.sample_habitable_indexed_location(&mut rng);
+
+            assert!(habitat
+                .get_island_at_location(indexed_location.location())
+                .is_some());
+            assert!(
+                indexed_location.index()
+                    < habitat.get_habitat_at_location(indexed_location.location())
+            );
+
+            sampled.insert(habitat.map_indexed_location_to_u64_injective(&indexed_location));
+        }
+
+        // Only, and all, individuals on the islands are sampled
+        assert_eq!(
+            sampled,
+            (0..habitat.get_total_island_habitat()).collect::<BTreeSet<_>>()
+        );
+
+        let excluded = Location::new(4, 1);
+
+        for _ in 0..100 {
+            let location = habitat.sample_location_on_island(2, Some(&excluded), &mut rng);
+
+            assert_eq!(habitat.get_island_at_location(&location), Some(2));
+            assert_ne!(location, excluded);
+        }
+    }
+}
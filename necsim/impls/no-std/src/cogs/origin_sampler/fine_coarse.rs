@@ -0,0 +1,171 @@
+use core::{
+    convert::TryFrom,
+    fmt,
+    iter::{Iterator, Peekable},
+};
+
+use alloc::sync::Arc;
+
+use necsim_core::{
+    cogs::{Habitat, MathsCore},
+    landscape::{IndexedLocation, Location, LocationIterator},
+    lineage::Lineage,
+};
+
+use crate::cogs::{
+    habitat::fine_coarse::FineCoarseHabitat, origin_sampler::pre_sampler::OriginPreSampler,
+};
+
+use super::{TrustedOriginSampler, UntrustedOriginSampler};
+
+/// Origin sampler which only samples individuals inside the fine map of a
+///  [`FineCoarseHabitat`], optionally restricted by a row-major sample mask
+///  with the same dimensions as the fine map
+#[allow(clippy::module_name_repetitions)]
+pub struct FineCoarseOriginSampler<'h, M: MathsCore, I: Iterator<Item = u64>> {
+    pre_sampler: OriginPreSampler<M, I>,
+    last_index: u64,
+    location_iterator: Peekable<LocationIterator>,
+    next_location_index: u32,
+    habitat: &'h FineCoarseHabitat<M>,
+    sample_mask: Option<Arc<[bool]>>,
+    sampled_habitat: u64,
+}
+
+impl<'h, M: MathsCore, I: Iterator<Item = u64>> fmt::Debug for FineCoarseOriginSampler<'h, M, I> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct(stringify!(FineCoarseOriginSampler))
+            .field("pre_sampler", &self.pre_sampler)
+            .field("last_index", &self.last_index)
+            .field("location_iterator", &self.location_iterator)
+            .field("next_location_index", &self.next_location_index)
+            .field("habitat", &self.habitat)
+            .field("sampled_habitat", &self.sampled_habitat)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'h, M: MathsCore, I: Iterator<Item = u64>> FineCoarseOriginSampler<'h, M, I> {
+    /// Creates a new origin sampler, where the `sample_mask`, if given, must
+    ///  store whether each cell of the fine map is sampled in row-major order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `sample_mask` does not have one entry per fine map cell.
+    #[must_use]
+    pub fn new(
+        pre_sampler: OriginPreSampler<M, I>,
+        habitat: &'h FineCoarseHabitat<M>,
+        sample_mask: Option<Arc<[bool]>>,
+    ) -> Self {
+        let fine_extent = habitat.get_fine_extent();
+
+        if let Some(sample_mask) = &sample_mask {
+            assert_eq!(
+                sample_mask.len(),
+                usize::from(fine_extent.width()) * usize::from(fine_extent.height()),
+                "sample mask must have the dimensions of the fine map"
+            );
+        }
+
+        let mut sampler = Self {
+            pre_sampler,
+            last_index: 0_u64,
+            location_iterator: fine_extent.iter().peekable(),
+            next_location_index: 0_u32,
+            habitat,
+            sample_mask,
+            sampled_habitat: 0_u64,
+        };
+
+        let sampled_habitat = fine_extent
+            .iter()
+            .map(|location| u64::from(sampler.get_sampled_habitat_at_location(&location)))
+            .sum();
+        sampler.sampled_habitat = sampled_habitat;
+
+        sampler
+    }
+
+    fn get_sampled_habitat_at_location(&self, location: &Location) -> u32 {
+        let is_sampled = match (&self.sample_mask, self.habitat.fine_index(location)) {
+            (Some(sample_mask), Some(fine_index)) => {
+                sample_mask.get(fine_index).copied().unwrap_or(false)
+            },
+            (None, Some(_)) => true,
+            (_, None) => false,
+        };
+
+        if is_sampled {
+            self.habitat.get_habitat_at_location(location)
+        } else {
+            0_u32
+        }
+    }
+}
+
+#[contract_trait]
+impl<'h, M: MathsCore, I: Iterator<Item = u64>> UntrustedOriginSampler<'h, M>
+    for FineCoarseOriginSampler<'h, M, I>
+{
+    type Habitat = FineCoarseHabitat<M>;
+    type PreSampler = I;
+
+    fn habitat(&self) -> &'h Self::Habitat {
+        self.habitat
+    }
+
+    fn into_pre_sampler(self) -> OriginPreSampler<M, Self::PreSampler> {
+        self.pre_sampler
+    }
+
+    fn full_upper_bound_size_hint(&self) -> u64 {
+        #[allow(
+            clippy::cast_possible_truncation,
+            clippy::cast_sign_loss,
+            clippy::cast_precision_loss
+        )]
+        {
+            ((self.sampled_habitat as f64) * self.pre_sampler.get_sample_proportion().get()) as u64
+        }
+    }
+}
+
+unsafe impl<'h, M: MathsCore, I: Iterator<Item = u64>> TrustedOriginSampler<'h, M>
+    for FineCoarseOriginSampler<'h, M, I>
+{
+}
+
+impl<'h, M: MathsCore, I: Iterator<Item = u64>> Iterator for FineCoarseOriginSampler<'h, M, I> {
+    type Item = Lineage;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next_index = self.pre_sampler.next()?;
+        let mut index_difference = next_index - self.last_index;
+        self.last_index = next_index;
+
+        loop {
+            let location = self.location_iterator.peek()?.clone();
+            let sampled_habitat = self.get_sampled_habitat_at_location(&location);
+
+            if u64::from(self.next_location_index) + index_difference < u64::from(sampled_habitat) {
+                break;
+            }
+
+            index_difference -= u64::from(sampled_habitat - self.next_location_index);
+
+            self.next_location_index = 0;
+
+            self.location_iterator.next();
+        }
+
+        let next_location = self.location_iterator.peek()?;
+
+        self.next_location_index += u32::try_from(index_difference).unwrap();
+
+        Some(Lineage::new(
+            IndexedLocation::new(next_location.clone(), self.next_location_index),
+            self.habitat,
+        ))
+    }
+}
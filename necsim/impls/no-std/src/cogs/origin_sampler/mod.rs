@@ -4,12 +4,14 @@ use necsim_core::{
 };
 
 pub mod decomposition;
+pub mod fine_coarse;
 pub mod in_memory;
 pub mod non_spatial;
 pub mod pre_sampler;
 pub mod resuming;
 pub mod singleton_demes;
 pub mod spatially_implicit;
+pub mod spatially_implicit_islands;
 
 use pre_sampler::OriginPreSampler;
 
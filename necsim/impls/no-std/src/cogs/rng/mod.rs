@@ -1,3 +1,4 @@
 pub mod rand;
 pub mod seahash;
 pub mod wyhash;
+pub mod xoshiro;
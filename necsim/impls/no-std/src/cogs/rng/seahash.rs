@@ -67,8 +67,12 @@ impl<M: MathsCore> PrimeableRng<M> for SeaHash<M> {
     }
 }
 
+/// Diffuses the bits of `x` with the `SeaHash` diffusion function, which is a
+///  bijection that maps zero to zero
+#[allow(clippy::module_name_repetitions)]
+#[must_use]
 #[inline]
-const fn seahash_diffuse(mut x: u64) -> u64 {
+pub const fn seahash_diffuse(mut x: u64) -> u64 {
     // SeaHash diffusion function
     // https://docs.rs/seahash/4.1.0/src/seahash/helper.rs.html#75-92
 
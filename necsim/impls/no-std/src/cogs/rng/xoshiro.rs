@@ -0,0 +1,204 @@
+use core::marker::PhantomData;
+
+use necsim_core::cogs::{MathsCore, RngCore, SplittableRng};
+
+use serde::{Deserialize, Serialize};
+
+// SplitMix64 constants
+// https://prng.di.unimi.it/splitmix64.c
+const GOLDEN_GAMMA: u64 = 0x9e37_79b9_7f4a_7c15;
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Serialize, Deserialize, TypeLayout)]
+#[serde(deny_unknown_fields)]
+#[layout(free = "M")]
+#[repr(C)]
+pub struct Xoshiro256PlusPlus<M: MathsCore> {
+    state: [u64; 4],
+    #[serde(skip)]
+    marker: PhantomData<M>,
+}
+
+impl<M: MathsCore> Clone for Xoshiro256PlusPlus<M> {
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state,
+            marker: PhantomData::<M>,
+        }
+    }
+}
+
+impl<M: MathsCore> RngCore<M> for Xoshiro256PlusPlus<M> {
+    type Seed = [u8; 32];
+
+    #[must_use]
+    #[inline]
+    fn from_seed(seed: Self::Seed) -> Self {
+        let mut state = [0_u64; 4];
+
+        for (word, bytes) in state.iter_mut().zip(seed.chunks_exact(8)) {
+            let mut le_bytes = [0_u8; 8];
+            le_bytes.copy_from_slice(bytes);
+
+            *word = u64::from_le_bytes(le_bytes);
+        }
+
+        Self::from_state(state, 0_u64)
+    }
+
+    #[must_use]
+    #[inline]
+    fn sample_u64(&mut self) -> u64 {
+        // xoshiro256++ output and state transition functions
+        // https://prng.di.unimi.it/xoshiro256plusplus.c
+        let result = self.state[0]
+            .wrapping_add(self.state[3])
+            .rotate_left(23)
+            .wrapping_add(self.state[0]);
+
+        let t = self.state[1] << 17;
+
+        self.state[2] ^= self.state[0];
+        self.state[3] ^= self.state[1];
+        self.state[1] ^= self.state[2];
+        self.state[0] ^= self.state[3];
+
+        self.state[2] ^= t;
+
+        self.state[3] = self.state[3].rotate_left(45);
+
+        result
+    }
+}
+
+impl<M: MathsCore> SplittableRng<M> for Xoshiro256PlusPlus<M> {
+    fn split(self) -> (Self, Self) {
+        let left = self.derive_stream(0_u64);
+        let right = self.derive_stream(1_u64);
+
+        (left, right)
+    }
+
+    fn split_to_stream(self, stream: u64) -> Self {
+        self.derive_stream(stream)
+    }
+}
+
+impl<M: MathsCore> Xoshiro256PlusPlus<M> {
+    /// Derives an independent generator for the `stream` by diffusing every
+    ///  state word with a different `SplitMix64` output of the `stream`
+    fn derive_stream(&self, stream: u64) -> Self {
+        let mut splitmix = stream;
+
+        let mut state = self.state;
+
+        for word in &mut state {
+            splitmix = splitmix.wrapping_add(GOLDEN_GAMMA);
+
+            *word = splitmix64_mix(*word ^ splitmix64_mix(splitmix));
+        }
+
+        Self::from_state(state, stream)
+    }
+
+    fn from_state(mut state: [u64; 4], fallback: u64) -> Self {
+        // The all-zero state is the only invalid state of xoshiro256++,
+        //  so it is replaced with the SplitMix64 expansion of the fallback
+        if state == [0_u64; 4] {
+            let mut splitmix = fallback;
+
+            for word in &mut state {
+                splitmix = splitmix.wrapping_add(GOLDEN_GAMMA);
+
+                *word = splitmix64_mix(splitmix);
+            }
+        }
+
+        Self {
+            state,
+            marker: PhantomData::<M>,
+        }
+    }
+}
+
+#[inline]
+const fn splitmix64_mix(mut z: u64) -> u64 {
+    // SplitMix64 output function
+    // https://prng.di.unimi.it/splitmix64.c
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+
+    z ^ (z >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use necsim_core::cogs::{RngCore, SplittableRng};
+
+    use crate::cogs::maths::intrinsics::IntrinsicsMathsCore;
+
+    use super::Xoshiro256PlusPlus;
+
+    fn from_state(state: [u64; 4]) -> Xoshiro256PlusPlus<IntrinsicsMathsCore> {
+        let mut seed = [0_u8; 32];
+
+        for (bytes, word) in seed.chunks_exact_mut(8).zip(state) {
+            bytes.copy_from_slice(&word.to_le_bytes());
+        }
+
+        Xoshiro256PlusPlus::from_seed(seed)
+    }
+
+    fn samples(rng: &mut Xoshiro256PlusPlus<IntrinsicsMathsCore>) -> [u64; 10] {
+        core::array::from_fn(|_| rng.sample_u64())
+    }
+
+    #[test]
+    fn test_reference_output() {
+        // Output of the reference implementation seeded with [1, 2, 3, 4]
+        // https://prng.di.unimi.it/xoshiro256plusplus.c
+        assert_eq!(
+            samples(&mut from_state([1, 2, 3, 4])),
+            [
+                41_943_041,
+                58_720_359,
+                3_588_806_011_781_223,
+                3_591_011_842_654_386,
+                9_228_616_714_210_784_205,
+                9_973_669_472_204_895_162,
+                14_011_001_112_246_962_877,
+                12_406_186_145_184_390_807,
+                15_849_039_046_786_891_736,
+                10_450_023_813_501_588_000,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_zero_seed_is_replaced() {
+        let mut rng = from_state([0; 4]);
+
+        assert_ne!(rng.state, [0; 4]);
+        assert_ne!(samples(&mut rng), [0; 10]);
+    }
+
+    #[test]
+    fn test_split_streams_are_distinct() {
+        let rng = from_state([1, 2, 3, 4]);
+
+        let (mut left, mut right) = rng.clone().split();
+
+        let left = samples(&mut left);
+        let right = samples(&mut right);
+
+        assert_ne!(left, right);
+        assert_ne!(left, samples(&mut rng.clone()));
+
+        assert_eq!(left, samples(&mut rng.clone().split_to_stream(0)));
+        assert_eq!(right, samples(&mut rng.clone().split_to_stream(1)));
+        assert_ne!(
+            samples(&mut rng.clone().split_to_stream(2)),
+            samples(&mut rng.split_to_stream(3))
+        );
+    }
+}
@@ -0,0 +1,75 @@
+#![allow(non_local_definitions)] // FIXME: displaydoc
+
+use alloc::sync::Arc;
+
+use necsim_core::{
+    cogs::{Habitat, MathsCore, SpeciationProbability},
+    landscape::Location,
+};
+use necsim_core_bond::ClosedUnitF64;
+
+use crate::{array2d::Array2D, cogs::habitat::in_memory::InMemoryHabitat};
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "cuda", derive(rust_cuda::lend::LendRustToCuda))]
+pub struct InMemorySpeciationProbability {
+    #[cfg_attr(feature = "cuda", cuda(embed))]
+    speciation_probability: Arc<[ClosedUnitF64]>,
+}
+
+#[contract_trait]
+impl<M: MathsCore> SpeciationProbability<M, InMemoryHabitat<M>> for InMemorySpeciationProbability {
+    #[must_use]
+    #[inline]
+    fn get_speciation_probability_at_location(
+        &self,
+        location: &Location,
+        habitat: &InMemoryHabitat<M>,
+    ) -> ClosedUnitF64 {
+        let extent = habitat.get_extent();
+
+        self.speciation_probability
+            .get(
+                (location.y().wrapping_sub(extent.origin().y()) as usize)
+                    * usize::from(extent.width())
+                    + (location.x().wrapping_sub(extent.origin().x()) as usize),
+            )
+            .copied()
+            .unwrap_or_else(ClosedUnitF64::zero)
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(displaydoc::Display, Debug)]
+pub enum InMemorySpeciationProbabilityError {
+    /** The size of the speciation probability map is inconsistent with the
+    size of the habitat. */
+    DimensionsMismatch,
+}
+
+impl InMemorySpeciationProbability {
+    /// # Errors
+    ///
+    /// Returns `InMemorySpeciationProbabilityError::DimensionsMismatch` iff
+    ///  the `speciation_probability` map does not have the same dimensions
+    ///  as the `habitat`.
+    pub fn new<M: MathsCore>(
+        speciation_probability: Array2D<ClosedUnitF64>,
+        habitat: &InMemoryHabitat<M>,
+    ) -> Result<Self, InMemorySpeciationProbabilityError> {
+        let extent = habitat.get_extent();
+
+        if speciation_probability.num_columns() == usize::from(extent.width())
+            && speciation_probability.num_rows() == usize::from(extent.height())
+        {
+            Ok(Self {
+                speciation_probability: Arc::from(
+                    speciation_probability.into_row_major().into_boxed_slice(),
+                ),
+            })
+        } else {
+            Err(InMemorySpeciationProbabilityError::DimensionsMismatch)
+        }
+    }
+}
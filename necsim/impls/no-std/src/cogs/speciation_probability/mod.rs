@@ -1,2 +1,4 @@
+pub mod in_memory;
 pub mod spatially_implicit;
+pub mod spatially_implicit_islands;
 pub mod uniform;
@@ -0,0 +1,44 @@
+use necsim_core::{
+    cogs::{Habitat, MathsCore, SpeciationProbability},
+    landscape::Location,
+};
+use necsim_core_bond::{ClosedUnitF64, OpenClosedUnitF64 as PositiveUnitF64};
+
+use crate::cogs::habitat::spatially_implicit_islands::SpatiallyImplicitIslandsHabitat;
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "cuda", derive(rust_cuda::lend::LendRustToCuda))]
+#[allow(clippy::module_name_repetitions)]
+pub struct SpatiallyImplicitIslandsSpeciationProbability {
+    meta_speciation_probability: PositiveUnitF64,
+}
+
+impl SpatiallyImplicitIslandsSpeciationProbability {
+    #[must_use]
+    pub fn new(meta_speciation_probability: PositiveUnitF64) -> Self {
+        Self {
+            meta_speciation_probability,
+        }
+    }
+}
+
+#[contract_trait]
+impl<M: MathsCore> SpeciationProbability<M, SpatiallyImplicitIslandsHabitat<M>>
+    for SpatiallyImplicitIslandsSpeciationProbability
+{
+    #[must_use]
+    #[inline]
+    fn get_speciation_probability_at_location(
+        &self,
+        location: &Location,
+        habitat: &SpatiallyImplicitIslandsHabitat<M>,
+    ) -> ClosedUnitF64 {
+        // By PRE, location must be habitable, i.e. either on an island or in
+        //  the meta habitat
+        if habitat.meta().get_extent().contains(location) {
+            self.meta_speciation_probability.into()
+        } else {
+            ClosedUnitF64::zero()
+        }
+    }
+}
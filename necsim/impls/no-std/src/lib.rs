@@ -5,6 +5,8 @@
 #![feature(const_type_name)]
 #![feature(negative_impls)]
 #![feature(impl_trait_in_assoc_type)]
+#![feature(offset_of_enum)]
+#![feature(offset_of_nested)]
 #![allow(incomplete_features)]
 #![feature(specialization)]
 
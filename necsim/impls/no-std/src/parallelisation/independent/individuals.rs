@@ -1,4 +1,4 @@
-use alloc::collections::VecDeque;
+use alloc::{collections::VecDeque, vec::Vec};
 use core::{
     iter::FromIterator,
     num::{NonZeroU64, Wrapping},
@@ -77,6 +77,7 @@ pub fn simulate<
     lineages: L,
     dedup_cache: DedupCache,
     step_slice: NonZeroU64,
+    pause_before: Option<NonNegativeF64>,
     local_partition: &mut P,
 ) -> (
     Status,
@@ -87,6 +88,7 @@ pub fn simulate<
     let mut lineages = VecDeque::from_iter(lineages);
     let mut proxy = IgnoreProgressReporterProxy::from(local_partition);
     let mut min_spec_samples = dedup_cache.construct(lineages.len());
+    let mut paused_lineages = Vec::new();
 
     // Ensure that the progress bar starts with the expected target
     proxy.local_partition().report_progress_sync(
@@ -95,6 +97,7 @@ pub fn simulate<
 
     let mut total_steps = 0_u64;
     let mut max_time = NonNegativeF64::zero();
+    let mut previous_paused = false;
 
     while !lineages.is_empty()
         || simulation.active_lineage_sampler().number_active_lineages() > 0
@@ -111,9 +114,15 @@ pub fn simulate<
         let previous_speciation_sample =
             simulation.event_sampler_mut().replace_min_speciation(None);
 
-        if let Some(previous_speciation_sample) = previous_speciation_sample {
-            if min_spec_samples.insert(previous_speciation_sample) {
-                if let Some(previous_task) = previous_task {
+        let duplicate_individual = previous_speciation_sample
+            .map_or(false, |spec_sample| !min_spec_samples.insert(spec_sample));
+
+        if let Some(previous_task) = previous_task {
+            if !duplicate_individual {
+                // Paused lineages are not simulated again until the resume
+                if previous_paused {
+                    paused_lineages.push(previous_task);
+                } else {
                     lineages.push_back(previous_task);
                 }
             }
@@ -124,9 +133,15 @@ pub fn simulate<
         //  no coalescence occurs as coalescence would only be
         //  detected at the next shared duplicate event
 
+        previous_paused = false;
+
         let (new_time, new_steps) = simulation.simulate_incremental_early_stop(
-            |_, steps, _, _| {
-                if steps >= step_slice.get() {
+            |_, steps, next_event_time, _| {
+                if pause_before.map_or(false, |pause_before| next_event_time >= pause_before) {
+                    previous_paused = true;
+
+                    ControlFlow::Break(())
+                } else if steps >= step_slice.get() {
                     ControlFlow::Break(())
                 } else {
                     ControlFlow::Continue(())
@@ -144,5 +159,11 @@ pub fn simulate<
     let local_time = max_time;
     let local_steps = total_steps;
 
-    (Status::Done, local_time, local_steps, lineages)
+    let status = if paused_lineages.is_empty() {
+        Status::Done
+    } else {
+        Status::Paused
+    };
+
+    (status, local_time, local_steps, paused_lineages)
 }
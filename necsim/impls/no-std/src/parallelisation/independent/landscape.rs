@@ -179,6 +179,7 @@ pub fn simulate<
                 dispersal_target,
                 prior_time,
                 event_time,
+                speciation_sample,
                 coalescence_rng_sample,
                 tie_breaker: _,
             } = immigrant;
@@ -199,6 +200,7 @@ pub fn simulate<
                 global_lineage_reference: global_reference.clone(),
                 target: dispersal_target.clone(),
                 interaction: LineageInteraction::Maybe,
+                speciation_sample,
             });
 
             // Since the simulation has no internal immigration,
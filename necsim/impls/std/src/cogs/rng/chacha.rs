@@ -0,0 +1,161 @@
+use std::{fmt, marker::PhantomData};
+
+use rand_chacha::ChaCha8Rng;
+use rand_core::{RngCore as _, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+use necsim_core::cogs::{MathsCore, PrimeableRng, RngCore, SplittableRng};
+use necsim_impls_no_std::cogs::rng::seahash::seahash_diffuse;
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ChaCha8<M: MathsCore> {
+    inner: ChaCha8Rng,
+    #[serde(skip)]
+    marker: PhantomData<M>,
+}
+
+impl<M: MathsCore> Clone for ChaCha8<M> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            marker: PhantomData::<M>,
+        }
+    }
+}
+
+impl<M: MathsCore> fmt::Debug for ChaCha8<M> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("ChaCha8")
+            .field("stream", &self.inner.get_stream())
+            .field("word_pos", &self.inner.get_word_pos())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<M: MathsCore> RngCore<M> for ChaCha8<M> {
+    type Seed = [u8; 32];
+
+    #[must_use]
+    #[inline]
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self {
+            inner: ChaCha8Rng::from_seed(seed),
+            marker: PhantomData::<M>,
+        }
+    }
+
+    #[must_use]
+    #[inline]
+    fn sample_u64(&mut self) -> u64 {
+        self.inner.next_u64()
+    }
+}
+
+impl<M: MathsCore> SplittableRng<M> for ChaCha8<M> {
+    #[allow(clippy::identity_op)]
+    fn split(self) -> (Self, Self) {
+        let stream = self.inner.get_stream();
+
+        let mut left = self.clone();
+        left.inner.set_stream(stream.wrapping_mul(2) + 0);
+
+        let mut right = self;
+        right.inner.set_stream(stream.wrapping_mul(2) + 1);
+
+        (left, right)
+    }
+
+    fn split_to_stream(mut self, stream: u64) -> Self {
+        self.inner.set_stream(stream);
+
+        self
+    }
+}
+
+impl<M: MathsCore> PrimeableRng<M> for ChaCha8<M> {
+    #[inline]
+    fn prime_with(&mut self, location_index: u64, time_index: u64) {
+        // Every location has its own stream, in which every time index is
+        //  given 2^32 words of the ChaCha8 keystream. Since the word position
+        //  only has 68 bits, the time index's upper 28 bits, which are zero
+        //  unless the simulation runs for more than 2^36 time steps, are
+        //  instead diffused into the stream, such that large time indices
+        //  only alias each other with a hash collision
+        self.inner
+            .set_stream(location_index ^ seahash_diffuse(time_index >> TIME_INDEX_WORD_BITS));
+        self.inner
+            .set_word_pos(u128::from(time_index & TIME_INDEX_WORD_MASK) << 32);
+    }
+}
+
+/// Number of lower bits of the time index that fit into the 68 bit word
+///  position alongside the 2^32 words for each time index
+const TIME_INDEX_WORD_BITS: u32 = 68 - 32;
+const TIME_INDEX_WORD_MASK: u64 = (1 << TIME_INDEX_WORD_BITS) - 1;
+
+#[cfg(test)]
+mod tests {
+    use necsim_core::cogs::{PrimeableRng, RngCore};
+    use necsim_impls_no_std::cogs::maths::intrinsics::IntrinsicsMathsCore;
+
+    use super::{ChaCha8, TIME_INDEX_WORD_BITS};
+
+    fn primed(location_index: u64, time_index: u64) -> ChaCha8<IntrinsicsMathsCore> {
+        let mut rng = ChaCha8::from_seed([42; 32]);
+        rng.prime_with(location_index, time_index);
+        rng
+    }
+
+    fn samples(location_index: u64, time_index: u64) -> [u64; 4] {
+        let mut rng = primed(location_index, time_index);
+
+        core::array::from_fn(|_| rng.sample_u64())
+    }
+
+    #[test]
+    fn test_small_time_indices_select_the_word_position() {
+        let rng = primed(7, 3);
+
+        assert_eq!(rng.inner.get_stream(), 7);
+        assert_eq!(rng.inner.get_word_pos(), 3 << 32);
+
+        let rng = primed(7, (1 << TIME_INDEX_WORD_BITS) - 1);
+
+        assert_eq!(rng.inner.get_stream(), 7);
+        assert_eq!(
+            rng.inner.get_word_pos(),
+            u128::from((1_u64 << TIME_INDEX_WORD_BITS) - 1) << 32
+        );
+    }
+
+    #[test]
+    fn test_priming_is_deterministic() {
+        assert_eq!(samples(7, 3), samples(7, 3));
+        assert_eq!(samples(7, u64::MAX), samples(7, u64::MAX));
+
+        assert_ne!(samples(7, 3), samples(8, 3));
+        assert_ne!(samples(7, 3), samples(7, 4));
+    }
+
+    #[test]
+    fn test_large_time_indices_do_not_alias() {
+        for time_index in [0, 3, (1 << TIME_INDEX_WORD_BITS) - 1] {
+            let mut time_indices = [1_u64, 2, 3, 1 << 20, (1 << 28) - 1]
+                .map(|upper| (upper << TIME_INDEX_WORD_BITS) | time_index)
+                .to_vec();
+            time_indices.push(time_index);
+
+            for (i, a) in time_indices.iter().enumerate() {
+                for b in &time_indices[i + 1..] {
+                    assert_ne!(
+                        samples(7, *a),
+                        samples(7, *b),
+                        "time indices {a} and {b} alias"
+                    );
+                }
+            }
+        }
+    }
+}
@@ -1 +1,2 @@
+pub mod chacha;
 pub mod pcg;
@@ -0,0 +1,614 @@
+use std::{
+    fs::File,
+    io::{self, BufReader, BufWriter, Read, Write},
+};
+
+use serde::{Deserialize, Serialize};
+
+use necsim_core::{
+    event::{DispersalEvent, PackedEvent, SpeciationEvent, TypedEvent},
+    landscape::{IndexedLocation, Location},
+    lineage::{GlobalLineageReference, LineageInteraction},
+};
+use necsim_core_bond::{ClosedUnitF64, NonNegativeF64, PositiveF64};
+
+use super::EventLogHeader;
+
+/// Compression codec of the events in an event log segment
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EventLogCompression {
+    /// Uncompressed `PackedEvent`s
+    #[default]
+    None,
+    /// Delta-encoded events, compressed with zstd
+    Zstd,
+    /// Delta-encoded events, compressed with LZ4
+    Lz4,
+}
+
+/// Prefix of all segments, which is followed by the full header, and whose
+///  events carry their speciation samples
+///
+/// Legacy uncompressed segments instead directly start with the legacy header,
+///  whose first field is the little-endian bincode encoding of a positive
+///  `f64`. Its most significant byte is thus always below `0x80`, such that it
+///  can never be confused with this prefix.
+const SEGMENT_MAGIC: [u8; 8] = *b"necsim\x02\xff";
+
+/// Prefix of legacy compressed segments, which is followed by the full header,
+///  and whose events do not carry their speciation samples
+const LEGACY_COMPRESSED_SEGMENT_MAGIC: [u8; 8] = *b"necsim\x01\xff";
+
+/// Header of legacy uncompressed segments, which predates segment compression
+#[derive(Deserialize)]
+struct LegacyEventLogHeader {
+    min_time: PositiveF64,
+    max_time: PositiveF64,
+
+    length: usize,
+
+    with_speciation: bool,
+    with_dispersal: bool,
+}
+
+/// Event of legacy uncompressed segments, which predates speciation samples
+///
+/// Legacy events are assigned the speciation sample 0.0 if they speciated,
+///  and 1.0 otherwise, since their actual samples were not recorded.
+#[derive(Deserialize)]
+struct LegacyPackedEvent {
+    global_lineage_reference: GlobalLineageReference,
+    prior_time: f64,
+    event_time: f64,
+    origin: IndexedLocation,
+    target: IndexedLocation,
+    coalescence: GlobalLineageReference,
+}
+
+impl From<LegacyPackedEvent> for PackedEvent {
+    fn from(
+        LegacyPackedEvent {
+            global_lineage_reference,
+            prior_time,
+            event_time,
+            origin,
+            target,
+            coalescence,
+        }: LegacyPackedEvent,
+    ) -> Self {
+        // The event type is encoded in the signs of the prior and event times,
+        //  see `PackedEvent`
+        let prior_pos = prior_time.is_sign_positive();
+        let event_pos = event_time.is_sign_positive();
+
+        let prior_time = unsafe { NonNegativeF64::new_unchecked(prior_time.abs()) };
+        let event_time = unsafe { PositiveF64::new_unchecked(event_time.abs()) };
+
+        let interaction = match (prior_pos, event_pos) {
+            (false, true) => {
+                return Self::from(SpeciationEvent {
+                    global_lineage_reference,
+                    prior_time,
+                    event_time,
+                    origin,
+                    speciation_sample: ClosedUnitF64::zero(),
+                })
+            },
+            (false, false) => LineageInteraction::None,
+            (true, false) => LineageInteraction::Maybe,
+            (true, true) => LineageInteraction::Coalescence(coalescence),
+        };
+
+        Self::from(DispersalEvent {
+            global_lineage_reference,
+            prior_time,
+            event_time,
+            origin,
+            target,
+            interaction,
+            speciation_sample: ClosedUnitF64::one(),
+        })
+    }
+}
+
+enum SegmentEventWriter {
+    Plain(BufWriter<File>),
+    Zstd(zstd::Encoder<'static, BufWriter<File>>),
+    Lz4(lz4_flex::frame::FrameEncoder<BufWriter<File>>),
+}
+
+impl Write for SegmentEventWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            Self::Plain(writer) => writer.write(buf),
+            Self::Zstd(writer) => writer.write(buf),
+            Self::Lz4(writer) => writer.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            Self::Plain(writer) => writer.flush(),
+            Self::Zstd(writer) => writer.flush(),
+            Self::Lz4(writer) => writer.flush(),
+        }
+    }
+}
+
+pub struct SegmentWriter {
+    writer: SegmentEventWriter,
+    delta: Option<DeltaCodec>,
+}
+
+impl SegmentWriter {
+    /// Writes the `header` to the `file` and prepares writing the events
+    ///  with the header's compression codec
+    pub fn try_new(file: File, header: &EventLogHeader) -> anyhow::Result<Self> {
+        let mut writer = BufWriter::new(file);
+
+        writer.write_all(&SEGMENT_MAGIC)?;
+        bincode::serialize_into(&mut writer, header)?;
+
+        let writer = match header.compression {
+            EventLogCompression::None => SegmentEventWriter::Plain(writer),
+            EventLogCompression::Zstd => SegmentEventWriter::Zstd(zstd::Encoder::new(writer, 0)?),
+            EventLogCompression::Lz4 => {
+                SegmentEventWriter::Lz4(lz4_flex::frame::FrameEncoder::new(writer))
+            },
+        };
+
+        let delta = match header.compression {
+            EventLogCompression::None => None,
+            EventLogCompression::Zstd | EventLogCompression::Lz4 => Some(DeltaCodec::new(true)),
+        };
+
+        Ok(Self { writer, delta })
+    }
+
+    pub fn write_event(&mut self, event: &PackedEvent) -> anyhow::Result<()> {
+        match &mut self.delta {
+            None => bincode::serialize_into(&mut self.writer, event)?,
+            Some(delta) => delta.encode(&mut self.writer, event)?,
+        }
+
+        Ok(())
+    }
+
+    pub fn finish(self) -> anyhow::Result<()> {
+        let writer = match self.writer {
+            SegmentEventWriter::Plain(writer) => writer,
+            SegmentEventWriter::Zstd(writer) => writer.finish()?,
+            SegmentEventWriter::Lz4(writer) => writer.finish()?,
+        };
+
+        writer.into_inner()?;
+
+        Ok(())
+    }
+}
+
+enum SegmentEventReader {
+    Plain(BufReader<File>),
+    Zstd(BufReader<zstd::Decoder<'static, BufReader<File>>>),
+    Lz4(BufReader<lz4_flex::frame::FrameDecoder<BufReader<File>>>),
+}
+
+impl Read for SegmentEventReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match self {
+            Self::Plain(reader) => reader.read(buf),
+            Self::Zstd(reader) => reader.read(buf),
+            Self::Lz4(reader) => reader.read(buf),
+        }
+    }
+}
+
+enum SegmentEventDecoder {
+    Plain,
+    Legacy,
+    Delta(DeltaCodec),
+}
+
+pub struct SegmentReader {
+    reader: SegmentEventReader,
+    decoder: SegmentEventDecoder,
+}
+
+impl SegmentReader {
+    /// Reads the header from the `file` and prepares reading the events with
+    ///  the header's compression codec
+    pub fn try_new(file: File) -> anyhow::Result<(EventLogHeader, Self)> {
+        let mut reader = BufReader::new(file);
+
+        let mut prefix = [0_u8; SEGMENT_MAGIC.len()];
+        reader.read_exact(&mut prefix)?;
+
+        let with_speciation_samples = prefix == SEGMENT_MAGIC;
+
+        let header: EventLogHeader =
+            if with_speciation_samples || (prefix == LEGACY_COMPRESSED_SEGMENT_MAGIC) {
+                bincode::deserialize_from(&mut reader)?
+            } else {
+                let legacy: LegacyEventLogHeader =
+                    bincode::deserialize_from(prefix.as_slice().chain(&mut reader))?;
+
+                EventLogHeader::new(
+                    legacy.min_time,
+                    legacy.max_time,
+                    legacy.length,
+                    legacy.with_speciation,
+                    legacy.with_dispersal,
+                    EventLogCompression::None,
+                )
+            };
+
+        let reader = match header.compression {
+            EventLogCompression::None => SegmentEventReader::Plain(reader),
+            EventLogCompression::Zstd => {
+                SegmentEventReader::Zstd(BufReader::new(zstd::Decoder::with_buffer(reader)?))
+            },
+            EventLogCompression::Lz4 => {
+                SegmentEventReader::Lz4(BufReader::new(lz4_flex::frame::FrameDecoder::new(reader)))
+            },
+        };
+
+        let decoder = match header.compression {
+            EventLogCompression::None if with_speciation_samples => SegmentEventDecoder::Plain,
+            EventLogCompression::None => SegmentEventDecoder::Legacy,
+            EventLogCompression::Zstd | EventLogCompression::Lz4 => {
+                SegmentEventDecoder::Delta(DeltaCodec::new(with_speciation_samples))
+            },
+        };
+
+        Ok((header, Self { reader, decoder }))
+    }
+
+    /// Reads the next event, or returns `None` once the segment is exhausted
+    pub fn read_event(&mut self) -> Option<PackedEvent> {
+        match &mut self.decoder {
+            SegmentEventDecoder::Plain => bincode::deserialize_from(&mut self.reader).ok(),
+            SegmentEventDecoder::Legacy => {
+                bincode::deserialize_from::<_, LegacyPackedEvent>(&mut self.reader)
+                    .ok()
+                    .map(PackedEvent::from)
+            },
+            SegmentEventDecoder::Delta(delta) => delta.decode(&mut self.reader).ok(),
+        }
+    }
+}
+
+const SPECIATION_TAG: u8 = 0;
+const DISPERSAL_NONE_TAG: u8 = 1;
+const DISPERSAL_MAYBE_TAG: u8 = 2;
+const DISPERSAL_COALESCENCE_TAG: u8 = 3;
+
+/// Delta-encoding of sorted events
+///
+/// Every event is encoded as a tag byte followed by variable-length integers.
+///  Event times are encoded as the difference of their bit patterns to the
+///  previous event's time, which is small since positive `f64`s are ordered
+///  like their bit patterns and events are sorted by time. Prior times are
+///  encoded relative to the event time, lineage references relative to the
+///  previous event's reference, and dispersal targets and coalescence parents
+///  relative to the event's origin and lineage, respectively. The speciation
+///  sample is appended as the raw bits of its `f64`, unless the codec is used
+///  for legacy segments, which did not record speciation samples.
+struct DeltaCodec {
+    event_time: u64,
+    reference: u64,
+    speciation_samples: bool,
+}
+
+impl DeltaCodec {
+    fn new(speciation_samples: bool) -> Self {
+        Self {
+            event_time: 0,
+            reference: 0,
+            speciation_samples,
+        }
+    }
+
+    fn encode<W: Write>(&mut self, writer: &mut W, event: &PackedEvent) -> io::Result<()> {
+        let (tag, reference, prior_time, event_time, origin, speciation_sample, dispersal) =
+            match TypedEvent::from(event.clone()) {
+                TypedEvent::Speciation(SpeciationEvent {
+                    global_lineage_reference,
+                    prior_time,
+                    event_time,
+                    origin,
+                    speciation_sample,
+                }) => (
+                    SPECIATION_TAG,
+                    global_lineage_reference,
+                    prior_time,
+                    event_time,
+                    origin,
+                    speciation_sample,
+                    None,
+                ),
+                TypedEvent::Dispersal(DispersalEvent {
+                    global_lineage_reference,
+                    prior_time,
+                    event_time,
+                    origin,
+                    target,
+                    interaction,
+                    speciation_sample,
+                }) => (
+                    match interaction {
+                        LineageInteraction::None => DISPERSAL_NONE_TAG,
+                        LineageInteraction::Maybe => DISPERSAL_MAYBE_TAG,
+                        LineageInteraction::Coalescence(_) => DISPERSAL_COALESCENCE_TAG,
+                    },
+                    global_lineage_reference,
+                    prior_time,
+                    event_time,
+                    origin,
+                    speciation_sample,
+                    Some((target, interaction)),
+                ),
+            };
+
+        writer.write_all(&[tag])?;
+
+        let event_time = event_time.get().to_bits();
+        write_varint(writer, event_time.wrapping_sub(self.event_time))?;
+        write_varint(writer, event_time.wrapping_sub(prior_time.get().to_bits()))?;
+        self.event_time = event_time;
+
+        let reference = unsafe { reference.into_inner() };
+        #[allow(clippy::cast_possible_wrap)]
+        let reference_delta = reference.wrapping_sub(self.reference) as i64;
+        write_varint(writer, zigzag(reference_delta))?;
+        self.reference = reference;
+
+        write_varint(writer, u64::from(origin.location().x()))?;
+        write_varint(writer, u64::from(origin.location().y()))?;
+        write_varint(writer, u64::from(origin.index()))?;
+
+        if self.speciation_samples {
+            writer.write_all(&speciation_sample.get().to_bits().to_le_bytes())?;
+        }
+
+        if let Some((target, interaction)) = dispersal {
+            #[allow(clippy::cast_possible_wrap)]
+            let (dx, dy) = (
+                target.location().x().wrapping_sub(origin.location().x()) as i32,
+                target.location().y().wrapping_sub(origin.location().y()) as i32,
+            );
+
+            write_varint(writer, zigzag(i64::from(dx)))?;
+            write_varint(writer, zigzag(i64::from(dy)))?;
+            write_varint(writer, u64::from(target.index()))?;
+
+            if let Some(parent) = interaction.parent() {
+                #[allow(clippy::cast_possible_wrap)]
+                let parent_delta = unsafe { parent.into_inner() }.wrapping_sub(reference) as i64;
+                write_varint(writer, zigzag(parent_delta))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn decode<R: Read>(&mut self, reader: &mut R) -> io::Result<PackedEvent> {
+        let mut tag = [0_u8; 1];
+        reader.read_exact(&mut tag)?;
+        let [tag] = tag;
+
+        let event_time = read_varint(reader)?.wrapping_add(self.event_time);
+        let prior_time = event_time.wrapping_sub(read_varint(reader)?);
+        self.event_time = event_time;
+
+        #[allow(clippy::cast_sign_loss)]
+        let reference = self
+            .reference
+            .wrapping_add(unzigzag(read_varint(reader)?) as u64);
+        self.reference = reference;
+
+        let event_time =
+            PositiveF64::new(f64::from_bits(event_time)).map_err(|err| invalid_data(&err))?;
+        let prior_time =
+            NonNegativeF64::new(f64::from_bits(prior_time)).map_err(|err| invalid_data(&err))?;
+        let global_lineage_reference = unsafe { GlobalLineageReference::from_inner(reference) };
+
+        let origin = IndexedLocation::new(
+            Location::new(read_varint_u32(reader)?, read_varint_u32(reader)?),
+            read_varint_u32(reader)?,
+        );
+
+        let speciation_sample = if self.speciation_samples {
+            let mut bits = [0_u8; 8];
+            reader.read_exact(&mut bits)?;
+
+            Some(
+                ClosedUnitF64::new(f64::from_bits(u64::from_le_bytes(bits)))
+                    .map_err(|err| invalid_data(&err))?,
+            )
+        } else {
+            None
+        };
+
+        if tag == SPECIATION_TAG {
+            return Ok(PackedEvent::from(SpeciationEvent {
+                global_lineage_reference,
+                prior_time,
+                event_time,
+                origin,
+                speciation_sample: speciation_sample.unwrap_or_else(ClosedUnitF64::zero),
+            }));
+        }
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let target = IndexedLocation::new(
+            Location::new(
+                origin
+                    .location()
+                    .x()
+                    .wrapping_add(unzigzag(read_varint(reader)?) as u32),
+                origin
+                    .location()
+                    .y()
+                    .wrapping_add(unzigzag(read_varint(reader)?) as u32),
+            ),
+            read_varint_u32(reader)?,
+        );
+
+        let interaction = match tag {
+            DISPERSAL_NONE_TAG => LineageInteraction::None,
+            DISPERSAL_MAYBE_TAG => LineageInteraction::Maybe,
+            DISPERSAL_COALESCENCE_TAG => {
+                #[allow(clippy::cast_sign_loss)]
+                let parent = reference.wrapping_add(unzigzag(read_varint(reader)?) as u64);
+
+                LineageInteraction::Coalescence(unsafe {
+                    GlobalLineageReference::from_inner(parent)
+                })
+            },
+            tag => return Err(invalid_data(&format!("unknown event tag {tag}"))),
+        };
+
+        Ok(PackedEvent::from(DispersalEvent {
+            global_lineage_reference,
+            prior_time,
+            event_time,
+            origin,
+            target,
+            interaction,
+            speciation_sample: speciation_sample.unwrap_or_else(ClosedUnitF64::one),
+        }))
+    }
+}
+
+/// Maps a signed difference to an unsigned integer which is small if the
+///  difference is small in either direction
+#[allow(clippy::cast_sign_loss)]
+fn zigzag(delta: i64) -> u64 {
+    ((delta << 1) ^ (delta >> 63)) as u64
+}
+
+#[allow(clippy::cast_possible_wrap)]
+fn unzigzag(value: u64) -> i64 {
+    ((value >> 1) as i64) ^ -((value & 1) as i64)
+}
+
+fn write_varint<W: Write>(writer: &mut W, mut value: u64) -> io::Result<()> {
+    let mut buffer = [0_u8; 10];
+    let mut length = 0;
+
+    loop {
+        #[allow(clippy::cast_possible_truncation)]
+        let byte = (value & 0x7F) as u8;
+        value >>= 7;
+
+        if value == 0 {
+            buffer[length] = byte;
+            length += 1;
+            break;
+        }
+
+        buffer[length] = byte | 0x80;
+        length += 1;
+    }
+
+    writer.write_all(&buffer[..length])
+}
+
+fn read_varint<R: Read>(reader: &mut R) -> io::Result<u64> {
+    let mut value = 0_u64;
+
+    for shift in (0..64).step_by(7) {
+        let mut byte = [0_u8; 1];
+        reader.read_exact(&mut byte)?;
+        let [byte] = byte;
+
+        value |= u64::from(byte & 0x7F) << shift;
+
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+
+    Err(invalid_data(&"variable-length integer is too long"))
+}
+
+fn read_varint_u32<R: Read>(reader: &mut R) -> io::Result<u32> {
+    u32::try_from(read_varint(reader)?).map_err(|err| invalid_data(&err))
+}
+
+fn invalid_data<E: ToString + ?Sized>(error: &E) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use necsim_core::{
+        event::{DispersalEvent, PackedEvent, SpeciationEvent, TypedEvent},
+        landscape::{IndexedLocation, Location},
+        lineage::{GlobalLineageReference, LineageInteraction},
+    };
+    use necsim_core_bond::{ClosedUnitF64, NonNegativeF64, PositiveF64};
+
+    use super::DeltaCodec;
+
+    fn lineage(inner: u64) -> GlobalLineageReference {
+        unsafe { GlobalLineageReference::from_inner(inner) }
+    }
+
+    fn typed_debug(event: PackedEvent) -> String {
+        match event.into() {
+            TypedEvent::Speciation(event) => format!("{event:?}"),
+            TypedEvent::Dispersal(event) => format!("{event:?}"),
+        }
+    }
+
+    #[test]
+    fn test_delta_codec_roundtrip() {
+        let events = vec![
+            PackedEvent::from(DispersalEvent {
+                global_lineage_reference: lineage(42),
+                prior_time: NonNegativeF64::zero(),
+                event_time: PositiveF64::new(0.25).unwrap(),
+                origin: IndexedLocation::new(Location::new(0, u32::MAX), 3),
+                target: IndexedLocation::new(Location::new(u32::MAX, 1), 0),
+                interaction: LineageInteraction::Coalescence(lineage(7)),
+                speciation_sample: ClosedUnitF64::new(0.75).unwrap(),
+            }),
+            PackedEvent::from(DispersalEvent {
+                global_lineage_reference: lineage(7),
+                prior_time: NonNegativeF64::new(0.125).unwrap(),
+                event_time: PositiveF64::new(0.5).unwrap(),
+                origin: IndexedLocation::new(Location::new(10, 20), 0),
+                target: IndexedLocation::new(Location::new(12, 17), 1),
+                interaction: LineageInteraction::Maybe,
+                speciation_sample: ClosedUnitF64::one(),
+            }),
+            PackedEvent::from(SpeciationEvent {
+                global_lineage_reference: lineage(u64::MAX),
+                prior_time: NonNegativeF64::new(0.5).unwrap(),
+                event_time: PositiveF64::new(1.5).unwrap(),
+                origin: IndexedLocation::new(Location::new(12, 17), 1),
+                speciation_sample: ClosedUnitF64::new(0.125).unwrap(),
+            }),
+        ];
+
+        let mut bytes = Vec::new();
+        let mut encoder = DeltaCodec::new(true);
+        for event in &events {
+            encoder.encode(&mut bytes, event).unwrap();
+        }
+
+        assert!(bytes.len() < events.len() * std::mem::size_of::<PackedEvent>());
+
+        let mut reader = bytes.as_slice();
+        let mut decoder = DeltaCodec::new(true);
+        for event in events {
+            let event_decoded = decoder.decode(&mut reader).unwrap();
+
+            assert_eq!(typed_debug(event_decoded), typed_debug(event));
+        }
+
+        assert!(decoder.decode(&mut reader).is_err());
+    }
+}
@@ -3,9 +3,13 @@ use std::{cmp::Ordering, fmt};
 use necsim_core_bond::PositiveF64;
 use serde::{Deserialize, Serialize};
 
+mod codec;
 pub mod recorder;
 pub mod replay;
 
+#[allow(clippy::module_name_repetitions)]
+pub use codec::EventLogCompression;
+
 #[derive(Serialize, Deserialize, PartialEq)]
 #[allow(clippy::module_name_repetitions)]
 pub struct EventLogHeader {
@@ -16,6 +20,8 @@ pub struct EventLogHeader {
 
     with_speciation: bool,
     with_dispersal: bool,
+
+    compression: EventLogCompression,
 }
 
 impl fmt::Debug for EventLogHeader {
@@ -24,6 +30,7 @@ impl fmt::Debug for EventLogHeader {
             .field("min_time", &self.min_time)
             .field("max_time", &self.max_time)
             .field("length", &self.length)
+            .field("compression", &self.compression)
             .finish_non_exhaustive()
     }
 }
@@ -36,6 +43,7 @@ impl EventLogHeader {
         length: usize,
         with_speciation: bool,
         with_dispersal: bool,
+        compression: EventLogCompression,
     ) -> Self {
         Self {
             min_time,
@@ -43,6 +51,7 @@ impl EventLogHeader {
             length,
             with_speciation,
             with_dispersal,
+            compression,
         }
     }
 
@@ -70,6 +79,11 @@ impl EventLogHeader {
     pub fn with_dispersal(&self) -> bool {
         self.with_dispersal
     }
+
+    #[must_use]
+    pub fn compression(&self) -> EventLogCompression {
+        self.compression
+    }
 }
 
 impl Eq for EventLogHeader {}
@@ -19,7 +19,6 @@ use std::{
     convert::TryFrom,
     fmt,
     fs::{self, OpenOptions},
-    io::BufWriter,
     mem::ManuallyDrop,
     num::NonZeroUsize,
     path::{Component, Path, PathBuf},
@@ -30,7 +29,7 @@ use serde::{Deserialize, Serialize, Serializer};
 
 use necsim_core::event::{DispersalEvent, PackedEvent, SpeciationEvent};
 
-use super::EventLogHeader;
+use super::{codec::SegmentWriter, EventLogCompression, EventLogHeader};
 
 #[allow(clippy::module_name_repetitions)]
 pub struct EventLogRecorder {
@@ -41,6 +40,8 @@ pub struct EventLogRecorder {
 
     record_speciation: bool,
     record_dispersal: bool,
+
+    compression: EventLogCompression,
 }
 
 impl Drop for EventLogRecorder {
@@ -58,7 +59,11 @@ impl EventLogRecorder {
     /// # Errors
     ///
     /// Fails to construct iff `directory` is not a writable directory.
-    pub fn try_new(directory: PathBuf, segment_capacity: NonZeroUsize) -> Result<Self> {
+    pub fn try_new(
+        directory: PathBuf,
+        segment_capacity: NonZeroUsize,
+        compression: EventLogCompression,
+    ) -> Result<Self> {
         if let Some(parent) = directory.parent() {
             fs::create_dir_all(parent).with_context(|| {
                 format!("failed to ensure that the parent path for {directory:?} exists")
@@ -73,6 +78,8 @@ impl EventLogRecorder {
 
             record_speciation: false,
             record_dispersal: false,
+
+            compression,
         }
         .create_valid_directory()
     }
@@ -92,6 +99,7 @@ impl EventLogRecorder {
             buffer: Vec::with_capacity(self.segment_capacity.get()),
             record_speciation: self.record_speciation,
             record_dispersal: self.record_dispersal,
+            compression: self.compression,
         }
         .create_valid_directory()
     }
@@ -171,6 +179,20 @@ impl EventLogRecorder {
         }
     }
 
+    /// Writes all buffered events into a closed segment and returns the
+    ///  number of segments that have been written so far
+    ///
+    /// # Errors
+    ///
+    /// Fails iff writing the buffered events failed.
+    pub fn flush(&mut self) -> Result<usize> {
+        if !self.buffer.is_empty() {
+            self.sort_and_write_segment()?;
+        }
+
+        Ok(self.segment_index)
+    }
+
     fn sort_and_write_segment(&mut self) -> Result<()> {
         self.buffer.sort_unstable();
 
@@ -182,24 +204,23 @@ impl EventLogRecorder {
             .truncate(true)
             .write(true)
             .open(segment_path)?;
-        let mut buf_writer = BufWriter::new(segment_file);
-
-        bincode::serialize_into(
-            &mut buf_writer,
+        let mut segment_writer = SegmentWriter::try_new(
+            segment_file,
             &EventLogHeader::new(
                 self.buffer[0].event_time(),
                 self.buffer[self.buffer.len() - 1].event_time(),
                 self.buffer.len(),
                 self.record_speciation,
                 self.record_dispersal,
+                self.compression,
             ),
         )?;
 
         for event in self.buffer.drain(0..) {
-            bincode::serialize_into(&mut buf_writer, &event)?;
+            segment_writer.write_event(&event)?;
         }
 
-        buf_writer.into_inner()?;
+        segment_writer.finish()?;
 
         Ok(())
     }
@@ -220,6 +241,7 @@ impl fmt::Debug for EventLogRecorder {
             .field("directory", &self.directory)
             .field("segment_index", &self.segment_index)
             .field("buffer", &EventBufferLen(self.buffer.len()))
+            .field("compression", &self.compression)
             .finish_non_exhaustive()
     }
 }
@@ -231,13 +253,15 @@ pub struct EventLogConfig {
     directory: PathBuf,
     #[serde(default = "default_event_log_recorder_segment_capacity")]
     capacity: NonZeroUsize,
+    #[serde(default)]
+    compression: EventLogCompression,
 }
 
 impl<'a> TryFrom<EventLogRecorderRaw<'a>> for EventLogConfig {
     type Error = Error;
 
     fn try_from(raw: EventLogRecorderRaw) -> Result<Self, Self::Error> {
-        Self::try_new(raw.directory.into_owned(), raw.capacity)
+        Self::try_new(raw.directory.into_owned(), raw.capacity, raw.compression)
     }
 }
 
@@ -246,6 +270,7 @@ impl Serialize for EventLogConfig {
         EventLogRecorderRaw {
             directory: Cow::Borrowed(&self.directory),
             capacity: self.capacity,
+            compression: self.compression,
         }
         .serialize(serializer)
     }
@@ -256,10 +281,15 @@ impl EventLogConfig {
     ///
     /// Fails to construct iff the parent of `directory` cannot be created or
     /// is not a writable directory.
-    pub fn try_new(directory: PathBuf, capacity: NonZeroUsize) -> Result<Self> {
+    pub fn try_new(
+        directory: PathBuf,
+        capacity: NonZeroUsize,
+        compression: EventLogCompression,
+    ) -> Result<Self> {
         Self {
             directory,
             capacity,
+            compression,
         }
         .create_parent_directory()
     }
@@ -275,6 +305,7 @@ impl EventLogConfig {
         Self {
             directory: self.directory.join(child),
             capacity: self.capacity,
+            compression: self.compression,
         }
         .create_parent_directory()
     }
@@ -335,6 +366,16 @@ impl EventLogConfig {
         &self.directory
     }
 
+    #[must_use]
+    pub fn capacity(&self) -> NonZeroUsize {
+        self.capacity
+    }
+
+    #[must_use]
+    pub fn compression(&self) -> EventLogCompression {
+        self.compression
+    }
+
     /// # Errors
     ///
     /// Fails to construct iff `self.directory()` is not a writable directory.
@@ -342,7 +383,7 @@ impl EventLogConfig {
         let this = ManuallyDrop::new(self);
         // Safety: self will not be dropped and self.directory is only read once
         let directory = unsafe { std::ptr::read(&this.directory) };
-        EventLogRecorder::try_new(directory, this.capacity)
+        EventLogRecorder::try_new(directory, this.capacity, this.compression)
     }
 }
 
@@ -363,6 +404,8 @@ struct EventLogRecorderRaw<'a> {
     directory: Cow<'a, Path>,
     #[serde(default = "default_event_log_recorder_segment_capacity")]
     capacity: NonZeroUsize,
+    #[serde(default)]
+    compression: EventLogCompression,
 }
 
 fn default_event_log_recorder_segment_capacity() -> NonZeroUsize {
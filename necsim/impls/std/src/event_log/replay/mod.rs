@@ -6,8 +6,11 @@ use serde::{Deserialize, Serialize, Serializer};
 
 use necsim_core::event::PackedEvent;
 
+use super::EventLogHeader;
+
 mod globbed;
 pub mod segment;
+pub mod shard;
 mod sorted_segments;
 
 use globbed::GlobbedSortedSegments;
@@ -81,6 +84,27 @@ impl EventLogReplay {
     pub fn with_dispersal(&self) -> bool {
         self.with_dispersal
     }
+
+    /// Returns all event log segments, grouped into runs of segments which do
+    ///  not overlap in time
+    pub fn segment_groups(&self) -> impl Iterator<Item = &[SortedSegment]> {
+        self.frontier.iter().map(SortedSortedSegments::segments)
+    }
+
+    /// Skips all segments whose headers do not satisfy the `predicate`, e.g.
+    ///  because they lie entirely outside of a time window of interest.
+    ///
+    /// The first event of every segment group may already have been read and
+    ///  is still replayed, so the events must be filtered as well.
+    pub fn retain_segments<F: FnMut(&EventLogHeader) -> bool>(&mut self, mut predicate: F) {
+        self.frontier = std::mem::take(&mut self.frontier)
+            .into_iter()
+            .map(|mut group| {
+                group.retain(&mut predicate);
+                group
+            })
+            .collect();
+    }
 }
 
 impl FromIterator<SortedSegment> for anyhow::Result<EventLogReplay> {
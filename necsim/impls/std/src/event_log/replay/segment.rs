@@ -2,8 +2,7 @@ use std::{
     cmp::{Ord, Ordering},
     collections::VecDeque,
     fmt,
-    fs::{File, OpenOptions},
-    io::BufReader,
+    fs::OpenOptions,
     num::NonZeroUsize,
     path::{Path, PathBuf},
 };
@@ -12,13 +11,13 @@ use anyhow::Result;
 
 use necsim_core::event::PackedEvent;
 
-use crate::event_log::EventLogHeader;
+use crate::event_log::{codec::SegmentReader, EventLogHeader};
 
 #[allow(clippy::module_name_repetitions)]
 pub struct SortedSegment {
     path: PathBuf,
     header: EventLogHeader,
-    reader: BufReader<File>,
+    reader: SegmentReader,
     buffer: VecDeque<PackedEvent>,
     capacity: NonZeroUsize,
 }
@@ -39,20 +38,18 @@ impl SortedSegment {
     pub fn try_new(path: &Path, capacity: NonZeroUsize) -> Result<Self> {
         let file = OpenOptions::new().read(true).write(false).open(path)?;
 
-        let mut buf_reader = BufReader::new(file);
-
-        let header: EventLogHeader = bincode::deserialize_from(&mut buf_reader)?;
+        let (header, mut reader) = SegmentReader::try_new(file)?;
 
         let mut buffer = VecDeque::with_capacity(header.length.min(capacity.get()));
 
-        if let Ok(event) = bincode::deserialize_from(&mut buf_reader) {
+        if let Some(event) = reader.read_event() {
             buffer.push_back(event);
         }
 
         Ok(Self {
             path: path.to_owned(),
             header,
-            reader: buf_reader,
+            reader,
             buffer,
             capacity,
         })
@@ -95,7 +92,7 @@ impl Iterator for SortedSegment {
 
         if next_event.is_some() && self.buffer.is_empty() {
             for _ in 0..self.capacity.get() {
-                if let Ok(event) = bincode::deserialize_from(&mut self.reader) {
+                if let Some(event) = self.reader.read_event() {
                     self.buffer.push_back(event);
                 } else {
                     break;
@@ -0,0 +1,218 @@
+use std::{num::NonZeroUsize, path::PathBuf};
+
+use anyhow::Result;
+
+use necsim_core::event::PackedEvent;
+use necsim_core_bond::PositiveF64;
+
+use super::{segment::SortedSegment, EventLogReplay};
+
+/// Time window `[from, until)` of an event log, which can be replayed
+///  independently of all other time windows
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone)]
+pub struct EventLogShard {
+    segments: Vec<PathBuf>,
+    capacity: NonZeroUsize,
+
+    from: Option<PositiveF64>,
+    until: Option<PositiveF64>,
+}
+
+impl EventLogReplay {
+    /// Splits the event log into up to `shards` consecutive time windows,
+    ///  which contain roughly equal numbers of events if the events of every
+    ///  segment are uniformly distributed within its time range.
+    ///
+    /// Since all events with the same time fall into the same time window,
+    ///  duplicate events are never split across shards.
+    #[must_use]
+    pub fn time_shards(&self, shards: NonZeroUsize) -> Vec<EventLogShard> {
+        let segments = self.segment_groups().flatten().collect::<Vec<_>>();
+
+        let capacity = segments
+            .iter()
+            .map(|segment| segment.capacity())
+            .max()
+            .unwrap_or(NonZeroUsize::MIN);
+
+        #[allow(clippy::cast_precision_loss)]
+        let total = self.length() as f64;
+
+        let (Some(min_time), Some(max_time)) = (
+            segments
+                .iter()
+                .map(|segment| segment.header().min_time())
+                .min_by(|a, b| a.get().total_cmp(&b.get())),
+            segments
+                .iter()
+                .map(|segment| segment.header().max_time())
+                .max_by(|a, b| a.get().total_cmp(&b.get())),
+        ) else {
+            return Vec::new();
+        };
+
+        // Find the shard boundaries by bisection on the estimated number of
+        //  events which occur before each time
+        let mut boundaries = Vec::with_capacity(shards.get() + 1);
+        boundaries.push(None);
+
+        for k in 1..shards.get() {
+            #[allow(clippy::cast_precision_loss)]
+            let target = total * (k as f64) / (shards.get() as f64);
+
+            let (mut lower, mut upper) = (min_time.get(), max_time.get());
+
+            for _ in 0..64 {
+                let middle = lower + (upper - lower) * 0.5_f64;
+
+                if estimate_events_before(&segments, middle) < target {
+                    lower = middle;
+                } else {
+                    upper = middle;
+                }
+            }
+
+            let Ok(boundary) = PositiveF64::new(upper) else {
+                continue;
+            };
+
+            if boundary > min_time
+                && boundary <= max_time
+                && match boundaries.last() {
+                    Some(Some(last)) => boundary > *last,
+                    Some(None) | None => true,
+                }
+            {
+                boundaries.push(Some(boundary));
+            }
+        }
+
+        boundaries.push(None);
+
+        boundaries
+            .windows(2)
+            .map(|window| {
+                let (from, until) = (window[0], window[1]);
+
+                let segments = segments
+                    .iter()
+                    .filter(|segment| {
+                        let after_from = match from {
+                            Some(from) => segment.header().max_time() >= from,
+                            None => true,
+                        };
+                        let before_until = match until {
+                            Some(until) => segment.header().min_time() < until,
+                            None => true,
+                        };
+
+                        after_from && before_until
+                    })
+                    .map(|segment| segment.path().to_owned())
+                    .collect();
+
+                EventLogShard {
+                    segments,
+                    capacity,
+                    from,
+                    until,
+                }
+            })
+            .collect()
+    }
+}
+
+/// Estimates the number of events before `time`, assuming that the events of
+///  every segment are uniformly distributed within its time range
+fn estimate_events_before(segments: &[&SortedSegment], time: f64) -> f64 {
+    segments
+        .iter()
+        .map(|segment| {
+            let (min, max) = (
+                segment.header().min_time().get(),
+                segment.header().max_time().get(),
+            );
+
+            let fraction = if time <= min {
+                0.0_f64
+            } else if time > max {
+                1.0_f64
+            } else {
+                (time - min) / (max - min)
+            };
+
+            #[allow(clippy::cast_precision_loss)]
+            let length = segment.length() as f64;
+
+            length * fraction
+        })
+        .sum()
+}
+
+impl EventLogShard {
+    #[must_use]
+    pub fn from(&self) -> Option<PositiveF64> {
+        self.from
+    }
+
+    #[must_use]
+    pub fn until(&self) -> Option<PositiveF64> {
+        self.until
+    }
+
+    /// # Errors
+    ///
+    /// Fails if any of the shard's event log segments cannot be read
+    pub fn replay(&self) -> Result<EventLogShardReplay> {
+        let replay = if self.segments.is_empty() {
+            None
+        } else {
+            Some(
+                self.segments
+                    .iter()
+                    .map(|path| SortedSegment::try_new(path, self.capacity))
+                    .collect::<Result<Vec<_>>>()?
+                    .into_iter()
+                    .collect::<Result<EventLogReplay>>()?,
+            )
+        };
+
+        Ok(EventLogShardReplay {
+            replay,
+            from: self.from,
+            until: self.until,
+        })
+    }
+}
+
+/// Sorted replay of all events inside the time window of an [`EventLogShard`]
+#[allow(clippy::module_name_repetitions)]
+pub struct EventLogShardReplay {
+    replay: Option<EventLogReplay>,
+
+    from: Option<PositiveF64>,
+    until: Option<PositiveF64>,
+}
+
+impl Iterator for EventLogShardReplay {
+    type Item = PackedEvent;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let event = self.replay.as_mut()?.next()?;
+
+            if matches!(self.from, Some(from) if event.event_time() < from) {
+                continue;
+            }
+
+            if matches!(self.until, Some(until) if event.event_time() >= until) {
+                self.replay = None;
+
+                return None;
+            }
+
+            return Some(event);
+        }
+    }
+}
@@ -5,6 +5,8 @@ use std::{
 
 use necsim_core::event::PackedEvent;
 
+use crate::event_log::EventLogHeader;
+
 use super::segment::SortedSegment;
 
 #[allow(clippy::module_name_repetitions)]
@@ -58,6 +60,10 @@ impl SortedSortedSegments {
     pub fn segments(&self) -> &[SortedSegment] {
         &self.segments
     }
+
+    pub fn retain<F: FnMut(&EventLogHeader) -> bool>(&mut self, mut predicate: F) {
+        self.segments.retain(|segment| predicate(segment.header()));
+    }
 }
 
 impl Iterator for SortedSortedSegments {
@@ -81,4 +81,13 @@ pub trait LocalPartition<'p, R: Reporter>: Sized {
     fn wait_for_termination(&mut self) -> ControlFlow<(), ()>;
 
     fn report_progress_sync(&mut self, remaining: u64);
+
+    /// Flushes all recorded events into closed event log segments and returns
+    ///  the number of segments that this partition has written so far, or
+    ///  [`None`] if it does not record an event log.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error iff writing the buffered events failed.
+    fn flush_event_log(&mut self) -> anyhow::Result<Option<usize>>;
 }
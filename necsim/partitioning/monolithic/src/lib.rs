@@ -138,6 +138,13 @@ impl<'p, R: Reporter> LocalPartition<'p, R> for MonolithicLocalPartition<R> {
         }
     }
 
+    fn flush_event_log(&mut self) -> anyhow::Result<Option<usize>> {
+        match self {
+            Self::Live(partition) => partition.flush_event_log(),
+            Self::Recorded(partition) => partition.flush_event_log(),
+        }
+    }
+
     fn reduce_vote_min_time(
         &mut self,
         local_time: PositiveF64,
@@ -66,6 +66,10 @@ impl<'p, R: Reporter> LocalPartition<'p, R> for LiveMonolithicLocalPartition<R>
         vote
     }
 
+    fn flush_event_log(&mut self) -> anyhow::Result<Option<usize>> {
+        Ok(None)
+    }
+
     fn reduce_vote_min_time(
         &mut self,
         local_time: PositiveF64,
@@ -72,6 +72,13 @@ impl<'p, R: Reporter> LocalPartition<'p, R> for MpiLocalPartition<'p, R> {
         }
     }
 
+    fn flush_event_log(&mut self) -> anyhow::Result<Option<usize>> {
+        match self {
+            Self::Root(partition) => partition.flush_event_log(),
+            Self::Parallel(partition) => partition.flush_event_log(),
+        }
+    }
+
     fn reduce_vote_min_time(
         &mut self,
         local_time: PositiveF64,
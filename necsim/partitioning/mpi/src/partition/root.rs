@@ -112,6 +112,10 @@ impl<'p, R: Reporter> LocalPartition<'p, R> for MpiRootPartition<'p, R> {
         self.common.reduce_vote_any(vote)
     }
 
+    fn flush_event_log(&mut self) -> anyhow::Result<Option<usize>> {
+        self.recorder.flush().map(Some)
+    }
+
     fn reduce_vote_min_time(
         &mut self,
         local_time: PositiveF64,
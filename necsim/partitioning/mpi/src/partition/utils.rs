@@ -122,6 +122,7 @@ unsafe impl Equivalence for MpiMigratingLineage {
             global_reference: _,
             prior_time: _,
             event_time: _,
+            speciation_sample: _,
             coalescence_rng_sample: _,
             dispersal_target: _,
             dispersal_origin: _,
@@ -129,11 +130,12 @@ unsafe impl Equivalence for MpiMigratingLineage {
         };
 
         UserDatatype::structured(
-            &[1, 1, 1, 1, 2, 3, 1],
+            &[1, 1, 1, 1, 1, 2, 3, 1],
             &[
                 offset_of!(MigratingLineage, global_reference) as mpi::Address,
                 offset_of!(MigratingLineage, prior_time) as mpi::Address,
                 offset_of!(MigratingLineage, event_time) as mpi::Address,
+                offset_of!(MigratingLineage, speciation_sample) as mpi::Address,
                 offset_of!(MigratingLineage, coalescence_rng_sample) as mpi::Address,
                 offset_of!(MigratingLineage, dispersal_target) as mpi::Address,
                 offset_of!(MigratingLineage, dispersal_origin) as mpi::Address,
@@ -144,6 +146,7 @@ unsafe impl Equivalence for MpiMigratingLineage {
                 f64::equivalent_datatype(),
                 f64::equivalent_datatype(),
                 f64::equivalent_datatype(),
+                f64::equivalent_datatype(),
                 u32::equivalent_datatype(),
                 u32::equivalent_datatype(),
                 i8::equivalent_datatype(),
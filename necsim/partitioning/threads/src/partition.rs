@@ -198,6 +198,10 @@ impl<'p, R: Reporter> LocalPartition<'p, R> for ThreadsLocalPartition<R> {
         })
     }
 
+    fn flush_event_log(&mut self) -> anyhow::Result<Option<usize>> {
+        self.recorder.flush().map(Some)
+    }
+
     fn reduce_vote_min_time(
         &mut self,
         local_time: PositiveF64,
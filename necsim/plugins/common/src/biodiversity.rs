@@ -1,6 +1,7 @@
 use std::fmt;
 
 use necsim_core::{event::SpeciationEvent, impl_finalise, impl_report, reporter::Reporter};
+use necsim_plugins_core::export::MergeableReporter;
 
 #[allow(clippy::module_name_repetitions)]
 pub struct BiodiversityReporter {
@@ -54,6 +55,19 @@ impl Reporter for BiodiversityReporter {
     });
 }
 
+impl MergeableReporter for BiodiversityReporter {
+    fn fork(&self) -> Self {
+        Self::default()
+    }
+
+    fn merge(&mut self, shard: Self) {
+        // Shards are merged in ascending time order
+        self.last_event = shard.last_event.or(self.last_event.take());
+
+        self.biodiversity += shard.biodiversity;
+    }
+}
+
 impl Default for BiodiversityReporter {
     #[debug_ensures(ret.biodiversity == 0, "biodiversity initialised to 0")]
     fn default() -> Self {
@@ -7,6 +7,7 @@ use necsim_core::{
     reporter::Reporter,
 };
 use necsim_core_bond::NonNegativeF64;
+use necsim_plugins_core::export::MergeableReporter;
 
 #[allow(clippy::module_name_repetitions)]
 #[derive(Default)]
@@ -54,6 +55,34 @@ impl<'de> serde::Deserialize<'de> for EventCounterReporter {
     }
 }
 
+impl MergeableReporter for EventCounterReporter {
+    fn fork(&self) -> Self {
+        Self::default()
+    }
+
+    fn merge(&mut self, shard: Self) {
+        // Shards are merged in ascending time order
+        self.last_parent_prior_time = shard
+            .last_parent_prior_time
+            .or(self.last_parent_prior_time.take());
+        self.last_speciation_event = shard
+            .last_speciation_event
+            .or(self.last_speciation_event.take());
+        self.last_dispersal_event = shard
+            .last_dispersal_event
+            .or(self.last_dispersal_event.take());
+
+        self.raw_total += shard.raw_total;
+        self.speciation += shard.speciation;
+        self.out_dispersal += shard.out_dispersal;
+        self.self_dispersal += shard.self_dispersal;
+        self.out_coalescence += shard.out_coalescence;
+        self.self_coalescence += shard.self_coalescence;
+        self.late_dispersal_coalescence += shard.late_dispersal_coalescence;
+        self.late_coalescence += shard.late_coalescence;
+    }
+}
+
 impl Reporter for EventCounterReporter {
     impl_report!(speciation(&mut self, speciation: Used) {
         self.raw_total += 1;
@@ -13,9 +13,9 @@ pub mod progress;
 pub mod verbose;
 
 necsim_plugins_core::export_plugin!(
-    Biodiversity => biodiversity::BiodiversityReporter,
+    Biodiversity => biodiversity::BiodiversityReporter [mergeable],
     Progress => progress::ProgressReporter,
     Execution => execution_time::ExecutionTimeReporter,
-    Counter => event_counter::EventCounterReporter,
+    Counter => event_counter::EventCounterReporter [mergeable],
     Verbose => verbose::VerboseReporter,
 );
@@ -9,6 +9,41 @@ pub trait SerializeableReporter: Reporter + erased_serde::Serialize {
     fn reporter_name(&self) -> &'static str;
 }
 
+/// Reporter which can analyse disjoint time shards of an event log in
+///  parallel and merge their results before `finalise`
+///
+/// Every shard observes all events within its time window in order, but
+///  shards are processed independently and merged in ascending time order.
+#[allow(dead_code)]
+pub trait MergeableReporter: SerializeableReporter + Send + Sized {
+    /// Creates an empty reporter with the same configuration for one shard
+    #[must_use]
+    fn fork(&self) -> Self;
+
+    /// Merges the analysis of a `shard` into this reporter
+    fn merge(&mut self, shard: Self);
+}
+
+/// Reporter whose configuration decides at runtime which of its statically
+///  reported events it actually needs, e.g. because it filters them out
+#[allow(dead_code)]
+pub trait FilteredReporter: SerializeableReporter {
+    /// Whether the statically reported speciation events are needed
+    fn reports_speciation(&self) -> bool {
+        true
+    }
+
+    /// Whether the statically reported dispersal events are needed
+    fn reports_dispersal(&self) -> bool {
+        true
+    }
+
+    /// Whether the statically reported progress updates are needed
+    fn reports_progress(&self) -> bool {
+        true
+    }
+}
+
 pub struct ReporterPluginDeclaration {
     pub rustc_version: &'static str,
     pub core_version: &'static str,
@@ -30,6 +65,55 @@ pub struct ReporterPluginDeclaration {
     pub drop: unsafe extern "C" fn(ManuallyDrop<UnsafeReporterPlugin>),
 }
 
+#[derive(Copy, Clone)]
+pub struct ReporterPluginMerge {
+    #[allow(improper_ctypes_definitions)]
+    pub fork: unsafe extern "C" fn(&DynReporterPlugin) -> Box<DynReporterPlugin>,
+    #[allow(improper_ctypes_definitions)]
+    pub merge: unsafe extern "C" fn(&mut DynReporterPlugin, Box<DynReporterPlugin>),
+    #[allow(improper_ctypes_definitions)]
+    pub drop: unsafe extern "C" fn(Box<DynReporterPlugin>),
+}
+
+#[allow(dead_code)]
+impl ReporterPluginMerge {
+    #[must_use]
+    pub fn from_reporter<R: MergeableReporter>() -> Self {
+        #[allow(improper_ctypes_definitions)]
+        unsafe extern "C" fn fork<R: MergeableReporter>(
+            reporter: &DynReporterPlugin,
+        ) -> Box<DynReporterPlugin> {
+            // Safety: the type-erased reporter was created from an `R`
+            let reporter = &*std::ptr::from_ref(reporter).cast::<R>();
+
+            UnsafeReporterPlugin::from(reporter.fork()).reporter
+        }
+
+        #[allow(improper_ctypes_definitions)]
+        unsafe extern "C" fn merge<R: MergeableReporter>(
+            reporter: &mut DynReporterPlugin,
+            shard: Box<DynReporterPlugin>,
+        ) {
+            // Safety: both type-erased reporters were created from an `R`
+            let reporter = &mut *std::ptr::from_mut(reporter).cast::<R>();
+            let shard = Box::from_raw(Box::into_raw(shard).cast::<R>());
+
+            reporter.merge(*shard);
+        }
+
+        #[allow(improper_ctypes_definitions)]
+        extern "C" fn drop(shard: Box<DynReporterPlugin>) {
+            std::mem::drop(shard);
+        }
+
+        Self {
+            fork: fork::<R>,
+            merge: merge::<R>,
+            drop,
+        }
+    }
+}
+
 #[derive(Copy, Clone)]
 #[allow(dead_code)]
 pub struct ReporterPluginFilter {
@@ -59,6 +143,32 @@ pub type DynReporterPlugin = dyn SerializeableReporter<
 pub struct UnsafeReporterPlugin {
     pub(crate) reporter: Box<DynReporterPlugin>,
     pub(crate) filter: ReporterPluginFilter,
+    pub(crate) merge: Option<ReporterPluginMerge>,
+}
+
+#[allow(dead_code)]
+impl UnsafeReporterPlugin {
+    #[must_use]
+    pub fn from_mergeable<R: MergeableReporter>(reporter: R) -> Self {
+        Self {
+            merge: Some(ReporterPluginMerge::from_reporter::<R>()),
+            ..Self::from(reporter)
+        }
+    }
+
+    #[must_use]
+    pub fn from_filtered<R: FilteredReporter>(reporter: R) -> Self {
+        let filter = ReporterPluginFilter {
+            report_speciation: R::ReportSpeciation::VALUE && reporter.reports_speciation(),
+            report_dispersal: R::ReportDispersal::VALUE && reporter.reports_dispersal(),
+            report_progress: R::ReportProgress::VALUE && reporter.reports_progress(),
+        };
+
+        Self {
+            filter,
+            ..Self::from(reporter)
+        }
+    }
 }
 
 impl<R: SerializeableReporter> From<R> for UnsafeReporterPlugin {
@@ -91,6 +201,7 @@ impl<R: SerializeableReporter> From<R> for UnsafeReporterPlugin {
                 >(boxed_reporter)
             },
             filter: ReporterPluginFilter::from_reporter::<R>(),
+            merge: None,
         }
     }
 }
@@ -98,7 +209,16 @@ impl<R: SerializeableReporter> From<R> for UnsafeReporterPlugin {
 #[macro_export]
 #[allow(clippy::module_name_repetitions)]
 macro_rules! export_plugin {
-    ($($name:ident => $plugin:ty),+$(,)?) => {
+    (@into $reporter:ident) => {
+        $crate::export::UnsafeReporterPlugin::from($reporter)
+    };
+    (@into mergeable $reporter:ident) => {
+        $crate::export::UnsafeReporterPlugin::from_mergeable($reporter)
+    };
+    (@into filtered $reporter:ident) => {
+        $crate::export::UnsafeReporterPlugin::from_filtered($reporter)
+    };
+    ($($name:ident => $plugin:ty $([$mergeable:ident])?),+$(,)?) => {
         #[doc(hidden)]
         extern "C" fn __necsim_reporter_plugin_init(
             log: &'static dyn $crate::log::Log,
@@ -125,7 +245,9 @@ macro_rules! export_plugin {
 
             $crate::erased_serde::deserialize::<Reporters>(deserializer).map(|reporter| {
                 match reporter {
-                    $(Reporters::$name(reporter) => reporter.into()),*
+                    $(Reporters::$name(reporter) => {
+                        $crate::export_plugin!(@into $($mergeable)? reporter)
+                    }),*
                 }
             }).map(::std::mem::ManuallyDrop::new)
         }
@@ -15,7 +15,10 @@ use necsim_core::{
     },
 };
 
-use crate::{export::Reporters, import::ReporterPlugin};
+use crate::{
+    export::Reporters,
+    import::{ReporterPlugin, ReporterPluginShard},
+};
 
 use super::ReporterPluginLibrary;
 
@@ -63,6 +66,60 @@ impl<ReportSpeciation: Boolean, ReportDispersal: Boolean, ReportProgress: Boolea
         }
     }
 
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.plugins.is_empty()
+    }
+
+    /// Splits off all reporters which can analyse time shards of an event log
+    ///  in parallel, and returns the `(sequential, mergeable)` reporters
+    #[must_use]
+    pub fn split_mergeable(self) -> (Self, Self) {
+        let (mergeable, sequential): (Vec<ReporterPlugin>, Vec<ReporterPlugin>) = self
+            .plugins
+            .into_vec()
+            .into_iter()
+            .partition(ReporterPlugin::is_mergeable);
+
+        (
+            Self {
+                plugins: sequential.into_boxed_slice(),
+                marker: self.marker,
+            },
+            Self {
+                plugins: mergeable.into_boxed_slice(),
+                marker: self.marker,
+            },
+        )
+    }
+
+    /// Forks empty reporters for one time shard of an event log, or returns
+    ///  `None` if any reporter is not mergeable
+    #[must_use]
+    pub fn fork_shard(&self) -> Option<ReporterPluginShardVec<ReportSpeciation, ReportDispersal>> {
+        let shards = self
+            .plugins
+            .iter()
+            .map(ReporterPlugin::fork_shard)
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(ReporterPluginShardVec {
+            shards: shards.into_boxed_slice(),
+            marker: PhantomData::<(ReportSpeciation, ReportDispersal)>,
+        })
+    }
+
+    /// Merges the reporters of one time `shard`, which were forked from these
+    ///  reporters, back into them
+    pub fn merge_shard(
+        &mut self,
+        shard: ReporterPluginShardVec<ReportSpeciation, ReportDispersal>,
+    ) {
+        for (plugin, shard) in self.plugins.iter_mut().zip(shard.shards.into_vec()) {
+            plugin.merge_shard(shard);
+        }
+    }
+
     pub fn with_lifetime<Q, F: FnOnce(Self) -> Q>(self, inner: F) -> Q {
         let libraries = self
             .plugins
@@ -121,6 +178,41 @@ impl<ReportSpeciation: Boolean, ReportDispersal: Boolean, ReportProgress: Boolea
     }
 }
 
+/// Reporters which analyse one time shard of an event log, possibly on
+///  another thread, and which do not report progress
+pub struct ReporterPluginShardVec<ReportSpeciation: Boolean, ReportDispersal: Boolean> {
+    shards: Box<[ReporterPluginShard]>,
+    marker: PhantomData<(ReportSpeciation, ReportDispersal)>,
+}
+
+impl<ReportSpeciation: Boolean, ReportDispersal: Boolean> fmt::Debug
+    for ReporterPluginShardVec<ReportSpeciation, ReportDispersal>
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct(stringify!(ReporterPluginShardVec))
+            .field("shards", &self.shards)
+            .finish()
+    }
+}
+
+impl<ReportSpeciation: Boolean, ReportDispersal: Boolean> Reporter
+    for ReporterPluginShardVec<ReportSpeciation, ReportDispersal>
+{
+    impl_report!(speciation(&mut self, speciation: MaybeUsed<ReportSpeciation>) {
+        for shard in self.shards.iter_mut() {
+            shard.report_speciation(speciation.into());
+        }
+    });
+
+    impl_report!(dispersal(&mut self, dispersal: MaybeUsed<ReportDispersal>) {
+        for shard in self.shards.iter_mut() {
+            shard.report_dispersal(dispersal.into());
+        }
+    });
+
+    impl_report!(progress(&mut self, _progress: Ignored) {});
+}
+
 #[derive(Debug)]
 pub enum AnyReporterPluginVec {
     IgnoreSpeciationIgnoreDispersalIgnoreProgress(ReporterPluginVec<False, False, False>),
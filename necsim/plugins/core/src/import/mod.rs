@@ -3,5 +3,5 @@ mod plugin;
 mod serde;
 
 pub use self::serde::ReporterPluginLibrary;
-pub use combinator::{AnyReporterPluginVec, ReporterPluginVec};
-pub use plugin::ReporterPlugin;
+pub use combinator::{AnyReporterPluginVec, ReporterPluginShardVec, ReporterPluginVec};
+pub use plugin::{ReporterPlugin, ReporterPluginShard};
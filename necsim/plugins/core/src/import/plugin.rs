@@ -1,7 +1,12 @@
 use std::{fmt, mem::ManuallyDrop, rc::Rc};
 
+use necsim_core::{
+    event::{DispersalEvent, SpeciationEvent},
+    reporter::{boolean::True, used::MaybeUsed},
+};
+
 use crate::{
-    export::{DynReporterPlugin, ReporterPluginFilter, UnsafeReporterPlugin},
+    export::{DynReporterPlugin, ReporterPluginFilter, ReporterPluginMerge, UnsafeReporterPlugin},
     import::serde::PluginLibrary,
 };
 
@@ -11,6 +16,7 @@ pub struct ReporterPlugin {
 
     pub(crate) reporter: ManuallyDrop<Box<DynReporterPlugin>>,
     pub(crate) filter: ReporterPluginFilter,
+    pub(crate) merge: Option<ReporterPluginMerge>,
 
     pub(crate) finalised: bool,
 }
@@ -21,6 +27,35 @@ impl ReporterPlugin {
 
         std::mem::drop(self);
     }
+
+    pub(crate) fn is_mergeable(&self) -> bool {
+        self.merge.is_some()
+    }
+
+    pub(crate) fn fork_shard(&self) -> Option<ReporterPluginShard> {
+        let merge = self.merge?;
+
+        Some(ReporterPluginShard {
+            reporter: ManuallyDrop::new(unsafe { (merge.fork)(&**self.reporter) }),
+            filter: self.filter,
+            merge,
+        })
+    }
+
+    pub(crate) fn merge_shard(&mut self, mut shard: ReporterPluginShard) {
+        let Some(merge) = self.merge else {
+            return;
+        };
+
+        unsafe {
+            (merge.merge)(
+                &mut **self.reporter,
+                ManuallyDrop::take(&mut shard.reporter),
+            );
+        }
+
+        std::mem::forget(shard);
+    }
 }
 
 impl Drop for ReporterPlugin {
@@ -34,6 +69,7 @@ impl Drop for ReporterPlugin {
                 (self.library.declaration.drop)(ManuallyDrop::new(UnsafeReporterPlugin {
                     reporter: ManuallyDrop::take(&mut self.reporter),
                     filter: self.filter,
+                    merge: self.merge,
                 }));
             }
         }
@@ -45,3 +81,43 @@ impl fmt::Debug for ReporterPlugin {
         fmt::Debug::fmt(&*self.reporter, fmt)
     }
 }
+
+/// Empty fork of a mergeable [`ReporterPlugin`], which analyses one time shard
+///  of an event log, possibly on another thread, before it is merged back
+#[allow(clippy::module_name_repetitions)]
+pub struct ReporterPluginShard {
+    reporter: ManuallyDrop<Box<DynReporterPlugin>>,
+    filter: ReporterPluginFilter,
+    merge: ReporterPluginMerge,
+}
+
+// Safety: shards can only be forked from `MergeableReporter`s, which are `Send`
+unsafe impl Send for ReporterPluginShard {}
+
+impl ReporterPluginShard {
+    pub(crate) fn report_speciation(&mut self, speciation: &MaybeUsed<SpeciationEvent, True>) {
+        if self.filter.report_speciation {
+            self.reporter.report_speciation(speciation);
+        }
+    }
+
+    pub(crate) fn report_dispersal(&mut self, dispersal: &MaybeUsed<DispersalEvent, True>) {
+        if self.filter.report_dispersal {
+            self.reporter.report_dispersal(dispersal);
+        }
+    }
+}
+
+impl Drop for ReporterPluginShard {
+    fn drop(&mut self) {
+        unsafe {
+            (self.merge.drop)(ManuallyDrop::take(&mut self.reporter));
+        }
+    }
+}
+
+impl fmt::Debug for ReporterPluginShard {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&*self.reporter, fmt)
+    }
+}
@@ -122,6 +122,7 @@ impl<'de> serde::de::DeserializeSeed<'de> for RcPluginLibrary {
                     Ok(reporter) => Ok(ReporterPlugin {
                         library: self.library,
                         filter: reporter.filter,
+                        merge: reporter.merge,
                         reporter: ManuallyDrop::new(ManuallyDrop::into_inner(reporter).reporter),
                         finalised: false,
                     }),
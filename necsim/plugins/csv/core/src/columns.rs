@@ -0,0 +1,171 @@
+use std::fmt::{self, Write};
+
+use serde::{Deserialize, Serialize};
+
+use necsim_core::{
+    event::{DispersalEvent, SpeciationEvent},
+    lineage::LineageInteraction,
+};
+
+/// Column of the CSV output, one row is written per event
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CsvColumn {
+    /// Global reference of the event's lineage
+    #[serde(alias = "Ref")]
+    Reference,
+    /// Time of the event
+    Time,
+    /// Time of the previous event of the same lineage
+    PriorTime,
+    /// X coordinate of the origin location
+    X,
+    /// Y coordinate of the origin location
+    Y,
+    /// Index of the origin location
+    Index,
+    /// X coordinate of the dispersal target, empty for speciation
+    TargetX,
+    /// Y coordinate of the dispersal target, empty for speciation
+    TargetY,
+    /// Index of the dispersal target, empty for speciation
+    TargetIndex,
+    /// Interaction of the dispersal, i.e. `none`, `maybe`, or `coalescence`,
+    ///  empty for speciation
+    Interaction,
+    /// Reference of the parent lineage if the dispersal coalesced, else empty
+    Parent,
+    /// `true` for speciation and `false` for dispersal
+    Speciation,
+    /// `s` for speciation and `d` for dispersal
+    Type,
+}
+
+impl CsvColumn {
+    /// The six columns `reference,time,x,y,index,type`
+    #[must_use]
+    pub fn default_columns() -> Vec<Self> {
+        vec![
+            Self::Reference,
+            Self::Time,
+            Self::X,
+            Self::Y,
+            Self::Index,
+            Self::Type,
+        ]
+    }
+
+    #[must_use]
+    pub const fn header(self) -> &'static str {
+        match self {
+            Self::Reference => "reference",
+            Self::Time => "time",
+            Self::PriorTime => "prior_time",
+            Self::X => "x",
+            Self::Y => "y",
+            Self::Index => "index",
+            Self::TargetX => "target_x",
+            Self::TargetY => "target_y",
+            Self::TargetIndex => "target_index",
+            Self::Interaction => "interaction",
+            Self::Parent => "parent",
+            Self::Speciation => "speciation",
+            Self::Type => "type",
+        }
+    }
+}
+
+/// Type of event, used to filter the events written to the CSV output
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CsvEventType {
+    Speciation,
+    /// Dispersal which did not coalesce
+    Dispersal,
+    /// Dispersal which coalesced with another lineage
+    Coalescence,
+}
+
+impl CsvEventType {
+    #[must_use]
+    pub fn all_event_types() -> Vec<Self> {
+        vec![Self::Speciation, Self::Dispersal, Self::Coalescence]
+    }
+}
+
+pub enum CsvEvent<'a> {
+    Speciation(&'a SpeciationEvent),
+    Dispersal(&'a DispersalEvent),
+}
+
+impl CsvEvent<'_> {
+    #[must_use]
+    pub fn event_type(&self) -> CsvEventType {
+        match self {
+            Self::Speciation(_) => CsvEventType::Speciation,
+            Self::Dispersal(dispersal) if dispersal.interaction.is_coalescence() => {
+                CsvEventType::Coalescence
+            },
+            Self::Dispersal(_) => CsvEventType::Dispersal,
+        }
+    }
+
+    /// Writes the `column` of this event into the `row`
+    ///
+    /// # Errors
+    ///
+    /// Fails iff writing into the `row` fails.
+    pub fn write_column(&self, row: &mut String, column: CsvColumn) -> fmt::Result {
+        let (reference, prior_time, event_time, origin) = match self {
+            Self::Speciation(speciation) => (
+                &speciation.global_lineage_reference,
+                speciation.prior_time,
+                speciation.event_time,
+                &speciation.origin,
+            ),
+            Self::Dispersal(dispersal) => (
+                &dispersal.global_lineage_reference,
+                dispersal.prior_time,
+                dispersal.event_time,
+                &dispersal.origin,
+            ),
+        };
+        let dispersal = match self {
+            Self::Speciation(_) => None,
+            Self::Dispersal(dispersal) => Some(dispersal),
+        };
+
+        match column {
+            CsvColumn::Reference => write!(row, "{reference}"),
+            CsvColumn::Time => write!(row, "{}", event_time.get()),
+            CsvColumn::PriorTime => write!(row, "{}", prior_time.get()),
+            CsvColumn::X => write!(row, "{}", origin.location().x()),
+            CsvColumn::Y => write!(row, "{}", origin.location().y()),
+            CsvColumn::Index => write!(row, "{}", origin.index()),
+            CsvColumn::TargetX => match dispersal {
+                Some(dispersal) => write!(row, "{}", dispersal.target.location().x()),
+                None => Ok(()),
+            },
+            CsvColumn::TargetY => match dispersal {
+                Some(dispersal) => write!(row, "{}", dispersal.target.location().y()),
+                None => Ok(()),
+            },
+            CsvColumn::TargetIndex => match dispersal {
+                Some(dispersal) => write!(row, "{}", dispersal.target.index()),
+                None => Ok(()),
+            },
+            CsvColumn::Interaction => match dispersal.map(|dispersal| &dispersal.interaction) {
+                Some(LineageInteraction::None) => row.write_str("none"),
+                Some(LineageInteraction::Maybe) => row.write_str("maybe"),
+                Some(LineageInteraction::Coalescence(_)) => row.write_str("coalescence"),
+                None => Ok(()),
+            },
+            CsvColumn::Parent => {
+                match dispersal.and_then(|dispersal| dispersal.interaction.parent()) {
+                    Some(parent) => write!(row, "{parent}"),
+                    None => Ok(()),
+                }
+            },
+            CsvColumn::Speciation => write!(row, "{}", dispersal.is_none()),
+            CsvColumn::Type => row.write_char(if dispersal.is_none() { 's' } else { 'd' }),
+        }
+    }
+}
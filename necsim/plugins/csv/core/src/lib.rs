@@ -0,0 +1,4 @@
+#![deny(clippy::pedantic)]
+
+pub mod columns;
+pub mod writer;
@@ -0,0 +1,78 @@
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+};
+
+use serde::{Deserialize, Serialize};
+
+/// Compression of the CSV output file
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
+pub enum CsvCompression {
+    #[default]
+    None,
+    Gzip,
+    Zstd,
+}
+
+#[allow(clippy::module_name_repetitions)]
+pub enum CsvWriter {
+    Plain(BufWriter<File>),
+    Gzip(flate2::write::GzEncoder<BufWriter<File>>),
+    Zstd(zstd::stream::write::Encoder<'static, BufWriter<File>>),
+}
+
+impl CsvWriter {
+    /// Wraps the `file` in a buffered writer with the `compression`
+    ///
+    /// # Errors
+    ///
+    /// Fails iff the zstd encoder cannot be initialised.
+    pub fn new(file: File, compression: CsvCompression) -> io::Result<Self> {
+        let writer = BufWriter::new(file);
+
+        match compression {
+            CsvCompression::None => Ok(Self::Plain(writer)),
+            CsvCompression::Gzip => Ok(Self::Gzip(flate2::write::GzEncoder::new(
+                writer,
+                flate2::Compression::default(),
+            ))),
+            CsvCompression::Zstd => Ok(Self::Zstd(zstd::stream::write::Encoder::new(
+                writer,
+                zstd::DEFAULT_COMPRESSION_LEVEL,
+            )?)),
+        }
+    }
+
+    /// Flushes the writer and writes the trailer of the compressed stream
+    ///
+    /// # Errors
+    ///
+    /// Fails iff writing the trailer or flushing the file fails.
+    pub fn finish(self) -> io::Result<()> {
+        let mut writer = match self {
+            Self::Plain(writer) => writer,
+            Self::Gzip(encoder) => encoder.finish()?,
+            Self::Zstd(encoder) => encoder.finish()?,
+        };
+
+        writer.flush()
+    }
+}
+
+impl Write for CsvWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            Self::Plain(writer) => writer.write(buf),
+            Self::Gzip(encoder) => encoder.write(buf),
+            Self::Zstd(encoder) => encoder.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            Self::Plain(writer) => writer.flush(),
+            Self::Gzip(encoder) => encoder.flush(),
+            Self::Zstd(encoder) => encoder.flush(),
+        }
+    }
+}
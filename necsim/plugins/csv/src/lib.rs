@@ -3,32 +3,44 @@
 use std::{
     convert::TryFrom,
     fmt,
-    fs::{File, OpenOptions},
-    io::{self, BufWriter, Write},
+    fs::OpenOptions,
+    io::{self, Write},
     path::PathBuf,
 };
 
 use serde::{Deserialize, Serialize};
 
-use necsim_core::{
-    impl_finalise, impl_report, landscape::IndexedLocation, lineage::GlobalLineageReference,
-    reporter::Reporter,
+use necsim_core::{impl_finalise, impl_report, reporter::Reporter};
+use necsim_plugins_core::export::FilteredReporter;
+use necsim_plugins_csv_core::{
+    columns::{CsvColumn, CsvEvent, CsvEventType},
+    writer::{CsvCompression, CsvWriter},
 };
 
-necsim_plugins_core::export_plugin!(Csv => CsvReporter);
+necsim_plugins_core::export_plugin!(Csv => CsvReporter [filtered]);
 
 #[allow(clippy::module_name_repetitions)]
 #[derive(Deserialize)]
 #[serde(try_from = "CsvReporterArgs")]
 pub struct CsvReporter {
     output: PathBuf,
-    writer: Option<BufWriter<File>>,
+    columns: Vec<CsvColumn>,
+    delimiter: char,
+    compression: CsvCompression,
+    events: Vec<CsvEventType>,
+
+    writer: Option<CsvWriter>,
+    row: String,
 }
 
 impl fmt::Debug for CsvReporter {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
         fmt.debug_struct(stringify!(CsvReporter))
             .field("output", &self.output)
+            .field("columns", &self.columns)
+            .field("delimiter", &self.delimiter)
+            .field("compression", &self.compression)
+            .field("events", &self.events)
             .finish_non_exhaustive()
     }
 }
@@ -37,6 +49,10 @@ impl serde::Serialize for CsvReporter {
     fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         CsvReporterArgs {
             output: self.output.clone(),
+            columns: self.columns.clone(),
+            delimiter: self.delimiter,
+            compression: self.compression,
+            events: self.events.clone(),
         }
         .serialize(serializer)
     }
@@ -46,12 +62,43 @@ impl serde::Serialize for CsvReporter {
 #[serde(deny_unknown_fields)]
 struct CsvReporterArgs {
     output: PathBuf,
+    #[serde(default = "CsvColumn::default_columns")]
+    columns: Vec<CsvColumn>,
+    #[serde(default = "default_delimiter")]
+    delimiter: char,
+    #[serde(default)]
+    compression: CsvCompression,
+    #[serde(default = "CsvEventType::all_event_types")]
+    events: Vec<CsvEventType>,
+}
+
+fn default_delimiter() -> char {
+    ','
 }
 
 impl TryFrom<CsvReporterArgs> for CsvReporter {
     type Error = io::Error;
 
     fn try_from(args: CsvReporterArgs) -> Result<Self, Self::Error> {
+        if args.columns.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "the CSV reporter must write at least one column",
+            ));
+        }
+
+        if args.delimiter.is_alphanumeric()
+            || matches!(args.delimiter, '.' | '-' | '"' | '\n' | '\r')
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "the CSV delimiter {:?} is ambiguous with the column values",
+                    args.delimiter
+                ),
+            ));
+        }
+
         // Preliminary argument parsing check if the output is a writable file
         let file = OpenOptions::new()
             .create(true)
@@ -61,31 +108,31 @@ impl TryFrom<CsvReporterArgs> for CsvReporter {
 
         Ok(Self {
             output: args.output,
+            columns: args.columns,
+            delimiter: args.delimiter,
+            compression: args.compression,
+            events: args.events,
+
             writer: None,
+            row: String::new(),
         })
     }
 }
 
 impl Reporter for CsvReporter {
     impl_report!(speciation(&mut self, speciation: Used) {
-        self.write_event(
-            &speciation.global_lineage_reference,
-            speciation.event_time.get(), &speciation.origin, 's'
-        );
+        self.write_event(&CsvEvent::Speciation(speciation));
     });
 
     impl_report!(dispersal(&mut self, dispersal: Used) {
-        self.write_event(
-            &dispersal.global_lineage_reference,
-            dispersal.event_time.get(), &dispersal.origin, 'd'
-        );
+        self.write_event(&CsvEvent::Dispersal(dispersal));
     });
 
     impl_report!(progress(&mut self, _progress: Ignored) {});
 
     impl_finalise!((mut self) {
-        if let Some(writer) = &mut self.writer {
-            std::mem::drop(writer.flush());
+        if let Some(writer) = self.writer.take() {
+            std::mem::drop(writer.finish());
         }
     });
 
@@ -94,16 +141,23 @@ impl Reporter for CsvReporter {
             return Ok(());
         }
 
-        let result = (|| -> io::Result<BufWriter<File>> {
+        let result = (|| -> io::Result<CsvWriter> {
             let file = OpenOptions::new()
                 .create(true)
                 .truncate(true)
                 .write(true)
                 .open(&self.output)?;
 
-            let mut writer = BufWriter::new(file);
+            let mut writer = CsvWriter::new(file, self.compression)?;
+
+            for (i, column) in self.columns.iter().enumerate() {
+                if i > 0 {
+                    write!(writer, "{}", self.delimiter)?;
+                }
 
-            writeln!(writer, "reference,time,x,y,index,type")?;
+                writer.write_all(column.header().as_bytes())?;
+            }
+            writeln!(writer)?;
 
             Ok(writer)
         })();
@@ -119,25 +173,41 @@ impl Reporter for CsvReporter {
     }
 }
 
+impl FilteredReporter for CsvReporter {
+    fn reports_speciation(&self) -> bool {
+        self.events.contains(&CsvEventType::Speciation)
+    }
+
+    fn reports_dispersal(&self) -> bool {
+        self.events.contains(&CsvEventType::Dispersal)
+            || self.events.contains(&CsvEventType::Coalescence)
+    }
+}
+
 impl CsvReporter {
-    fn write_event(
-        &mut self,
-        reference: &GlobalLineageReference,
-        time: f64,
-        origin: &IndexedLocation,
-        r#type: char,
-    ) {
+    fn write_event(&mut self, event: &CsvEvent) {
+        if !self.events.contains(&event.event_type()) {
+            return;
+        }
+
         if let Some(writer) = &mut self.writer {
-            std::mem::drop(writeln!(
-                writer,
-                "{},{},{},{},{},{}",
-                reference,
-                time,
-                origin.location().x(),
-                origin.location().y(),
-                origin.index(),
-                r#type,
-            ));
+            self.row.clear();
+
+            for (i, column) in self.columns.iter().enumerate() {
+                if i > 0 {
+                    self.row.push(self.delimiter);
+                }
+
+                // Writing into a `String` cannot fail
+                let _ = event.write_column(&mut self.row, *column);
+            }
+
+            self.row.push('\n');
+
+            std::mem::drop(writer.write_all(self.row.as_bytes()));
         }
     }
 }
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,285 @@
+use std::{convert::TryFrom, io::Read, path::PathBuf};
+
+use necsim_core::{
+    event::{DispersalEvent, SpeciationEvent},
+    landscape::{IndexedLocation, Location},
+    lineage::{GlobalLineageReference, LineageInteraction},
+    reporter::Reporter,
+};
+use necsim_core_bond::{ClosedUnitF64, NonNegativeF64, PositiveF64};
+use necsim_plugins_core::export::FilteredReporter;
+use necsim_plugins_csv_core::{
+    columns::{CsvColumn, CsvEventType},
+    writer::CsvCompression,
+};
+
+use super::{CsvReporter, CsvReporterArgs};
+
+struct TempCsv(PathBuf);
+
+impl TempCsv {
+    fn new(name: &str) -> Self {
+        Self(std::env::temp_dir().join(format!("necsim-csv-{}-{name}.csv", std::process::id())))
+    }
+
+    fn read(&self, compression: CsvCompression) -> String {
+        let bytes = std::fs::read(&self.0).expect("the CSV file exists");
+
+        let mut csv = String::new();
+
+        match compression {
+            CsvCompression::None => csv = String::from_utf8(bytes).expect("the CSV is UTF-8"),
+            CsvCompression::Gzip => {
+                flate2::read::GzDecoder::new(bytes.as_slice())
+                    .read_to_string(&mut csv)
+                    .expect("the CSV is gzip compressed");
+            },
+            CsvCompression::Zstd => {
+                zstd::stream::read::Decoder::new(bytes.as_slice())
+                    .expect("the CSV is zstd compressed")
+                    .read_to_string(&mut csv)
+                    .expect("the CSV is zstd compressed");
+            },
+        }
+
+        csv
+    }
+}
+
+impl Drop for TempCsv {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
+
+fn lineage(inner: u64) -> GlobalLineageReference {
+    unsafe { GlobalLineageReference::from_inner(inner) }
+}
+
+fn speciation() -> SpeciationEvent {
+    SpeciationEvent {
+        global_lineage_reference: lineage(1),
+        prior_time: NonNegativeF64::new(0.5).unwrap(),
+        event_time: PositiveF64::new(1.5).unwrap(),
+        origin: IndexedLocation::new(Location::new(2, 3), 4),
+        speciation_sample: ClosedUnitF64::new(0.125).unwrap(),
+    }
+}
+
+fn dispersal() -> DispersalEvent {
+    DispersalEvent {
+        global_lineage_reference: lineage(5),
+        prior_time: NonNegativeF64::new(0.0).unwrap(),
+        event_time: PositiveF64::new(2.0).unwrap(),
+        origin: IndexedLocation::new(Location::new(6, 7), 0),
+        target: IndexedLocation::new(Location::new(8, 9), 1),
+        interaction: LineageInteraction::Maybe,
+        speciation_sample: ClosedUnitF64::new(0.75).unwrap(),
+    }
+}
+
+fn coalescence() -> DispersalEvent {
+    DispersalEvent {
+        global_lineage_reference: lineage(10),
+        prior_time: NonNegativeF64::new(1.0).unwrap(),
+        event_time: PositiveF64::new(3.25).unwrap(),
+        origin: IndexedLocation::new(Location::new(8, 9), 2),
+        target: IndexedLocation::new(Location::new(8, 9), 1),
+        interaction: LineageInteraction::Coalescence(lineage(5)),
+        speciation_sample: ClosedUnitF64::one(),
+    }
+}
+
+fn reporter(
+    csv: &TempCsv,
+    columns: Vec<CsvColumn>,
+    delimiter: char,
+    compression: CsvCompression,
+    events: Vec<CsvEventType>,
+) -> Result<CsvReporter, std::io::Error> {
+    CsvReporter::try_from(CsvReporterArgs {
+        output: csv.0.clone(),
+        columns,
+        delimiter,
+        compression,
+        events,
+    })
+}
+
+fn report_events(mut reporter: CsvReporter) {
+    reporter.initialise().expect("the CSV file can be created");
+
+    reporter.report_speciation((&speciation()).into());
+    reporter.report_dispersal((&dispersal()).into());
+    reporter.report_dispersal((&coalescence()).into());
+
+    reporter.finalise();
+}
+
+#[test]
+fn test_default_columns() {
+    let csv = TempCsv::new("default");
+
+    report_events(
+        reporter(
+            &csv,
+            CsvColumn::default_columns(),
+            ',',
+            CsvCompression::None,
+            CsvEventType::all_event_types(),
+        )
+        .unwrap(),
+    );
+
+    assert_eq!(
+        csv.read(CsvCompression::None),
+        concat!(
+            "reference,time,x,y,index,type\n",
+            "1,1.5,2,3,4,s\n",
+            "5,2,6,7,0,d\n",
+            "10,3.25,8,9,2,d\n",
+        )
+    );
+}
+
+#[test]
+fn test_column_selection_and_delimiter() {
+    let csv = TempCsv::new("columns");
+
+    report_events(
+        reporter(
+            &csv,
+            vec![
+                CsvColumn::Type,
+                CsvColumn::PriorTime,
+                CsvColumn::TargetX,
+                CsvColumn::TargetY,
+                CsvColumn::TargetIndex,
+                CsvColumn::Interaction,
+                CsvColumn::Parent,
+                CsvColumn::Speciation,
+            ],
+            '\t',
+            CsvCompression::None,
+            CsvEventType::all_event_types(),
+        )
+        .unwrap(),
+    );
+
+    assert_eq!(
+        csv.read(CsvCompression::None),
+        concat!(
+            "type\tprior_time\ttarget_x\ttarget_y\ttarget_index\tinteraction\tparent\tspeciation\n",
+            "s\t0.5\t\t\t\t\t\ttrue\n",
+            "d\t0\t8\t9\t1\tmaybe\t\tfalse\n",
+            "d\t1\t8\t9\t1\tcoalescence\t5\tfalse\n",
+        )
+    );
+}
+
+#[test]
+fn test_invalid_columns_and_delimiters() {
+    let csv = TempCsv::new("invalid");
+
+    assert!(reporter(
+        &csv,
+        Vec::new(),
+        ',',
+        CsvCompression::None,
+        CsvEventType::all_event_types(),
+    )
+    .is_err());
+
+    for delimiter in ['a', '7', '.', '-', '"', '\n', '\r'] {
+        assert!(reporter(
+            &csv,
+            CsvColumn::default_columns(),
+            delimiter,
+            CsvCompression::None,
+            CsvEventType::all_event_types(),
+        )
+        .is_err());
+    }
+
+    assert!(reporter(
+        &csv,
+        CsvColumn::default_columns(),
+        ';',
+        CsvCompression::None,
+        CsvEventType::all_event_types(),
+    )
+    .is_ok());
+}
+
+#[test]
+fn test_compression() {
+    for compression in [
+        CsvCompression::None,
+        CsvCompression::Gzip,
+        CsvCompression::Zstd,
+    ] {
+        let csv = TempCsv::new(&format!("{compression:?}"));
+
+        report_events(
+            reporter(
+                &csv,
+                vec![CsvColumn::Reference, CsvColumn::Type],
+                ',',
+                compression,
+                CsvEventType::all_event_types(),
+            )
+            .unwrap(),
+        );
+
+        assert_eq!(csv.read(compression), "reference,type\n1,s\n5,d\n10,d\n");
+
+        if !matches!(compression, CsvCompression::None) {
+            assert!(String::from_utf8(std::fs::read(&csv.0).unwrap())
+                .map_or(true, |plain| !plain.starts_with("reference")));
+        }
+    }
+}
+
+#[test]
+fn test_event_filter() {
+    for (events, expected, reports_speciation, reports_dispersal) in [
+        (
+            vec![CsvEventType::Speciation],
+            "reference\n1\n",
+            true,
+            false,
+        ),
+        (vec![CsvEventType::Dispersal], "reference\n5\n", false, true),
+        (
+            vec![CsvEventType::Coalescence],
+            "reference\n10\n",
+            false,
+            true,
+        ),
+        (
+            vec![CsvEventType::Speciation, CsvEventType::Coalescence],
+            "reference\n1\n10\n",
+            true,
+            true,
+        ),
+        (Vec::new(), "reference\n", false, false),
+    ] {
+        let csv = TempCsv::new(&format!("filter-{}", expected.len()));
+
+        let reporter = reporter(
+            &csv,
+            vec![CsvColumn::Reference],
+            ',',
+            CsvCompression::None,
+            events,
+        )
+        .unwrap();
+
+        assert_eq!(reporter.reports_speciation(), reports_speciation);
+        assert_eq!(reporter.reports_dispersal(), reports_dispersal);
+
+        report_events(reporter);
+
+        assert_eq!(csv.read(CsvCompression::None), expected);
+    }
+}
@@ -38,6 +38,7 @@ impl From<[u8; 24]> for SpeciesIdentity {
 }
 
 impl SpeciesIdentity {
+    #[must_use]
     pub fn from_speciation(origin: &IndexedLocation, time: PositiveF64) -> SpeciesIdentity {
         let location = (u64::from(origin.location().y()) << 32) | u64::from(origin.location().x());
         let index = (u64::from(origin.index()) + 1) << 16;
@@ -46,6 +47,7 @@ impl SpeciesIdentity {
         Self::from_raw(location, index, time)
     }
 
+    #[must_use]
     pub fn from_unspeciated(
         lineage: GlobalLineageReference,
         anchor: GlobalLineageReference,
@@ -57,7 +59,10 @@ impl SpeciesIdentity {
         Self::from_raw(lineage, marker, anchor)
     }
 
-    #[allow(dead_code)]
+    /// # Errors
+    ///
+    /// Returns `self` iff this identity was not created by
+    ///  [`Self::from_speciation`].
     pub fn try_into_speciation(self) -> Result<(IndexedLocation, PositiveF64), Self> {
         let (location, index, time) = self.copy_into_raw();
 
@@ -82,6 +87,10 @@ impl SpeciesIdentity {
         Ok((origin, time))
     }
 
+    /// # Errors
+    ///
+    /// Returns `self` iff this identity was not created by
+    ///  [`Self::from_unspeciated`].
     pub fn try_into_unspeciated(
         self,
     ) -> Result<(GlobalLineageReference, GlobalLineageReference), Self> {
@@ -177,7 +186,8 @@ impl SpeciesIdentity {
     }
 }
 
-const fn seahash_diffuse(mut x: u64) -> u64 {
+#[must_use]
+pub const fn seahash_diffuse(mut x: u64) -> u64 {
     // SeaHash diffusion function
     // https://docs.rs/seahash/4.1.0/src/seahash/helper.rs.html#75-92
 
@@ -0,0 +1,6 @@
+#![deny(clippy::pedantic)]
+
+pub mod identity;
+pub mod resolver;
+
+pub use identity::SpeciesIdentity;
@@ -0,0 +1,193 @@
+use std::collections::{hash_map::Entry, HashMap};
+
+use fnv::FnvBuildHasher;
+
+use necsim_core::{
+    event::{DispersalEvent, SpeciationEvent},
+    landscape::Location,
+    lineage::GlobalLineageReference,
+};
+use necsim_core_bond::NonNegativeF64;
+
+use crate::SpeciesIdentity;
+
+/// Resolves the species of all sampled individuals from the complete event
+///  stream of a simulation, keeping only their original locations.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Default)]
+pub struct LocationSpeciesResolver {
+    last_parent_prior_time: Option<(GlobalLineageReference, NonNegativeF64)>,
+    last_speciation_event: Option<SpeciationEvent>,
+    last_dispersal_event: Option<DispersalEvent>,
+
+    // Original (present-time) locations of all lineages
+    origins: HashMap<GlobalLineageReference, Location, FnvBuildHasher>,
+
+    // Child -> Parent lineage mapping
+    parents: HashMap<GlobalLineageReference, GlobalLineageReference, FnvBuildHasher>,
+
+    // Species originator -> Species identity mapping
+    species: HashMap<GlobalLineageReference, SpeciesIdentity, FnvBuildHasher>,
+}
+
+/// Species of all sampled individuals, as resolved by the
+///  [`LocationSpeciesResolver`]
+pub struct ResolvedSpecies {
+    /// `(location, species)` records of all individuals, where `species` is a
+    ///  dense species index
+    pub records: Vec<(Location, usize)>,
+    /// Identities of all species, indexed by their dense species index
+    pub identities: Vec<SpeciesIdentity>,
+    /// Number of lineages which had not yet speciated and were grouped into
+    ///  one placeholder species per common ancestor
+    pub unspeciated: u64,
+}
+
+impl LocationSpeciesResolver {
+    pub fn report_speciation(&mut self, speciation: &SpeciationEvent) {
+        if speciation.prior_time == 0.0_f64 {
+            self.store_individual_origin(
+                &speciation.global_lineage_reference,
+                speciation.origin.location(),
+            );
+        }
+
+        if Some(speciation) == self.last_speciation_event.as_ref() {
+            if let Some((parent, prior_time)) = &self.last_parent_prior_time {
+                if prior_time != &speciation.prior_time {
+                    let parent = parent.clone();
+                    self.store_individual_coalescence(
+                        &speciation.global_lineage_reference,
+                        &parent,
+                    );
+                }
+            }
+        } else {
+            self.store_individual_speciation(speciation);
+        }
+
+        self.last_speciation_event = Some(speciation.clone());
+        self.last_parent_prior_time = Some((
+            speciation.global_lineage_reference.clone(),
+            speciation.prior_time,
+        ));
+    }
+
+    pub fn report_dispersal(&mut self, dispersal: &DispersalEvent) {
+        if dispersal.prior_time == 0.0_f64 {
+            self.store_individual_origin(
+                &dispersal.global_lineage_reference,
+                dispersal.origin.location(),
+            );
+        }
+
+        if Some(dispersal) == self.last_dispersal_event.as_ref() {
+            if let Some((parent, prior_time)) = &self.last_parent_prior_time {
+                if prior_time != &dispersal.prior_time {
+                    let parent = parent.clone();
+                    self.store_individual_coalescence(&dispersal.global_lineage_reference, &parent);
+                }
+            }
+        } else if let Some(ref parent) = dispersal.interaction.parent() {
+            self.store_individual_coalescence(&dispersal.global_lineage_reference, parent);
+        }
+
+        self.last_dispersal_event = Some(dispersal.clone());
+        self.last_parent_prior_time = Some((
+            dispersal.global_lineage_reference.clone(),
+            dispersal.prior_time,
+        ));
+    }
+
+    fn store_individual_origin(&mut self, lineage: &GlobalLineageReference, origin: &Location) {
+        self.origins.insert(lineage.clone(), origin.clone());
+    }
+
+    fn store_individual_speciation(&mut self, speciation: &SpeciationEvent) {
+        // Resolve the actual parent, irrespective of duplicate individuals
+        let mut parent = &speciation.global_lineage_reference;
+        while let Some(parent_parent) = self.parents.get(parent) {
+            parent = parent_parent;
+        }
+
+        self.species.insert(
+            parent.clone(),
+            SpeciesIdentity::from_speciation(&speciation.origin, speciation.event_time),
+        );
+    }
+
+    fn store_individual_coalescence(
+        &mut self,
+        child: &GlobalLineageReference,
+        parent: &GlobalLineageReference,
+    ) {
+        // Resolve the actual child, irrespective of duplicate individuals
+        let mut child = child;
+        while let Some(child_parent) = self.parents.get(child) {
+            child = child_parent;
+        }
+        let child = child.clone();
+
+        // Resolve the actual parent, irrespective of duplicate individuals
+        let mut parent = parent;
+        while let Some(parent_parent) = self.parents.get(parent) {
+            parent = parent_parent;
+        }
+        let parent = parent.clone();
+
+        if child != parent {
+            self.parents.insert(child, parent);
+        }
+    }
+
+    #[must_use]
+    pub fn resolve(mut self) -> ResolvedSpecies {
+        let mut species_index: HashMap<SpeciesIdentity, usize, FnvBuildHasher> = HashMap::default();
+        let mut identities = Vec::new();
+        let mut unspeciated = 0_u64;
+
+        // Lineage ancestor union-find with path compression
+        let mut family = Vec::new();
+
+        let mut records = Vec::with_capacity(self.origins.len());
+
+        for (lineage, origin) in std::mem::take(&mut self.origins) {
+            // Find the ancestor that originated the species
+            let mut ancestor = lineage;
+            while let Some(ancestor_parent) = self.parents.get(&ancestor) {
+                family.push(ancestor.clone());
+                ancestor = ancestor_parent.clone();
+            }
+
+            // Compress the ancestry paths for all visited lineages
+            for child in family.drain(..) {
+                self.parents.insert(child, ancestor.clone());
+            }
+
+            let identity = if let Some(identity) = self.species.get(&ancestor) {
+                identity.clone()
+            } else {
+                unspeciated += 1;
+
+                SpeciesIdentity::from_unspeciated(ancestor.clone(), ancestor)
+            };
+
+            let next_index = species_index.len();
+            let index = match species_index.entry(identity) {
+                Entry::Occupied(occupied) => *occupied.get(),
+                Entry::Vacant(vacant) => {
+                    identities.push(vacant.key().clone());
+                    *vacant.insert(next_index)
+                },
+            };
+
+            records.push((origin, index));
+        }
+
+        ResolvedSpecies {
+            records,
+            identities,
+            unspeciated,
+        }
+    }
+}
@@ -0,0 +1,149 @@
+use std::{fmt, fs::File, io::BufWriter, path::PathBuf};
+
+use serde::{Deserialize, Deserializer, Serialize};
+
+use necsim_core::landscape::{LandscapeExtent, Location};
+use necsim_core_bond::NonNegativeF64;
+use necsim_plugins_species_core::resolver::LocationSpeciesResolver;
+
+mod reporter;
+mod summary;
+
+use summary::SpeciesCurves;
+
+/// Reporter that computes the species-area relationship and the
+///  distance-decay of similarity of the sampled individuals after their
+///  species have been resolved.
+///
+/// At `finalise`, the reporter writes a JSON summary with the number of
+///  individuals and the species richness inside each sample `area`, and the
+///  mean pairwise Sørensen and Jaccard similarity between the species sets of
+///  all pairs of occupied locations whose distance falls into each of the
+///  `[distances[i], distances[i+1])` distance bins.
+///
+/// Both curves compare the species sets of different places, which requires
+///  the species of all individuals to be resolved against the same complete
+///  genealogy. Thus, the reporter cannot analyse a time window or a region of
+///  an event log on its own, as individuals outside of it would be missing.
+///
+/// The `output` file must not exist yet.
+#[allow(clippy::module_name_repetitions)]
+pub struct SpeciesAreaDistanceDecayReporter {
+    resolver: LocationSpeciesResolver,
+
+    output: PathBuf,
+    areas: Vec<SampleArea>,
+    distances: Vec<NonNegativeF64>,
+}
+
+impl fmt::Debug for SpeciesAreaDistanceDecayReporter {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct(stringify!(SpeciesAreaDistanceDecayReporter))
+            .field("output", &self.output)
+            .field("areas", &self.areas)
+            .field("distances", &self.distances)
+            .finish_non_exhaustive()
+    }
+}
+
+impl serde::Serialize for SpeciesAreaDistanceDecayReporter {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SpeciesAreaDistanceDecayReporterArgs {
+            output: self.output.clone(),
+            areas: self.areas.clone(),
+            distances: self.distances.clone(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for SpeciesAreaDistanceDecayReporter {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let args = SpeciesAreaDistanceDecayReporterArgs::deserialize(deserializer)?;
+
+        if args.distances.len() == 1 {
+            return Err(serde::de::Error::custom(
+                "distances must list at least two distance bin edges",
+            ));
+        }
+
+        if let Some(&[low, high]) = args.distances.windows(2).find(|edges| edges[0] >= edges[1]) {
+            return Err(serde::de::Error::custom(format!(
+                "distance bin edges must be strictly increasing, but {low} is followed by {high}"
+            )));
+        }
+
+        Ok(Self {
+            resolver: LocationSpeciesResolver::default(),
+
+            output: args.output,
+            areas: args.areas,
+            distances: args.distances,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+#[serde(rename = "SpeciesAreaDistanceDecayReporter")]
+struct SpeciesAreaDistanceDecayReporterArgs {
+    output: PathBuf,
+    #[serde(default)]
+    areas: Vec<SampleArea>,
+    #[serde(default)]
+    distances: Vec<NonNegativeF64>,
+}
+
+/// Sample area shape, mirroring the `AlmostInfinite` scenario's samples
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub enum SampleArea {
+    Circle {
+        #[serde(default = "SampleArea::default_circle_sample_centre")]
+        centre: Location,
+        radius: u16,
+    },
+    Rectangle(LandscapeExtent),
+}
+
+impl SampleArea {
+    #[must_use]
+    pub const fn default_circle_sample_centre() -> Location {
+        const HABITAT_CENTRE: u32 = u32::MAX / 2;
+
+        Location::new(HABITAT_CENTRE, HABITAT_CENTRE)
+    }
+
+    #[must_use]
+    pub fn contains(&self, location: &Location) -> bool {
+        match self {
+            Self::Circle { centre, radius } => {
+                location.wrapping_distance_squared(centre)
+                    <= u64::from(*radius) * u64::from(*radius)
+            },
+            Self::Rectangle(extent) => extent.contains(location),
+        }
+    }
+}
+
+impl SpeciesAreaDistanceDecayReporter {
+    fn output_to_curves(self) -> std::io::Result<()> {
+        let species = self.resolver.resolve();
+
+        if species.unspeciated > 0 {
+            warn!(
+                "{} lineage(s) had not yet speciated and were grouped into placeholder species.",
+                species.unspeciated
+            );
+        }
+
+        let curves = SpeciesCurves::from_records(&species.records, self.areas, &self.distances);
+
+        let file = File::options()
+            .write(true)
+            .truncate(true)
+            .open(&self.output)?;
+
+        serde_json::to_writer_pretty(BufWriter::new(file), &curves).map_err(std::io::Error::from)
+    }
+}
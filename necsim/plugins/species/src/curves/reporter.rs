@@ -0,0 +1,37 @@
+use necsim_core::{impl_finalise, impl_report, reporter::Reporter};
+
+use super::SpeciesAreaDistanceDecayReporter;
+
+impl Reporter for SpeciesAreaDistanceDecayReporter {
+    impl_report!(speciation(&mut self, speciation: Used) {
+        self.resolver.report_speciation(speciation);
+    });
+
+    impl_report!(dispersal(&mut self, dispersal: Used) {
+        self.resolver.report_dispersal(dispersal);
+    });
+
+    impl_report!(progress(&mut self, _progress: Ignored) {});
+
+    impl_finalise!((self) {
+        let output = self.output.clone();
+
+        if let Err(err) = self.output_to_curves() {
+            error!("Failed to write the species-area and distance-decay curves to {output:?}:\n{err}");
+        }
+    });
+
+    fn initialise(&mut self) -> Result<(), String> {
+        std::fs::File::options()
+            .create_new(true)
+            .write(true)
+            .open(&self.output)
+            .map(|_| ())
+            .map_err(|err| {
+                format!(
+                    "Failed to create the species-area and distance-decay curves at {:?}:\n{err}",
+                    self.output
+                )
+            })
+    }
+}
@@ -0,0 +1,318 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use fnv::FnvBuildHasher;
+use serde::Serialize;
+
+use necsim_core::landscape::Location;
+use necsim_core_bond::NonNegativeF64;
+
+use super::SampleArea;
+
+/// Species-area and distance-decay curves of the sampled individuals
+#[derive(Debug, Serialize)]
+pub struct SpeciesCurves {
+    /// Species richness inside each sample area, in the configured order
+    pub areas: Vec<AreaRichness>,
+    /// Mean pairwise similarity between locations in each distance bin
+    pub distances: Vec<DistanceSimilarity>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AreaRichness {
+    pub area: SampleArea,
+    /// Number of sampled individuals inside the area
+    pub individuals: u64,
+    /// Number of species inside the area
+    pub richness: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DistanceSimilarity {
+    /// Inclusive lower edge of the distance bin
+    pub min: NonNegativeF64,
+    /// Exclusive upper edge of the distance bin
+    pub max: NonNegativeF64,
+    /// Number of pairs of occupied locations in the distance bin
+    pub pairs: u64,
+    /// Mean Sørensen similarity `2|A∩B| / (|A|+|B|)`
+    pub sorensen: Option<f64>,
+    /// Mean Jaccard similarity `|A∩B| / |A∪B|`
+    pub jaccard: Option<f64>,
+}
+
+impl SpeciesCurves {
+    /// Computes the curves from the `(location, species)` records of all
+    ///  sampled individuals, where `species` is a dense species index.
+    ///
+    /// The distance-decay curve only compares pairs of occupied locations
+    ///  which are closer than the last distance edge, such that its cost
+    ///  scales with the number of locations in each neighbourhood.
+    #[must_use]
+    pub fn from_records(
+        records: &[(Location, usize)],
+        areas: Vec<SampleArea>,
+        distances: &[NonNegativeF64],
+    ) -> Self {
+        let areas = areas
+            .into_iter()
+            .map(|area| {
+                let mut individuals = 0_u64;
+                let mut species = HashSet::<usize, FnvBuildHasher>::default();
+
+                for (location, index) in records {
+                    if area.contains(location) {
+                        individuals += 1;
+                        species.insert(*index);
+                    }
+                }
+
+                AreaRichness {
+                    area,
+                    individuals,
+                    richness: species.len() as u64,
+                }
+            })
+            .collect();
+
+        // Sorted and deduplicated species sets of all occupied locations
+        let mut location_species: BTreeMap<&Location, Vec<usize>> = BTreeMap::new();
+        for (location, index) in records {
+            location_species.entry(location).or_default().push(*index);
+        }
+        let location_species = location_species
+            .into_iter()
+            .map(|(location, mut species)| {
+                species.sort_unstable();
+                species.dedup();
+
+                (location, species)
+            })
+            .collect::<Vec<_>>();
+
+        let mut bins = vec![(0_u64, 0.0_f64, 0.0_f64); distances.len().saturating_sub(1)];
+
+        if let Some(max_distance) = distances.last() {
+            for (i, j) in nearby_pairs(&location_species, *max_distance) {
+                let ((location_a, species_a), (location_b, species_b)) =
+                    (&location_species[i], &location_species[j]);
+
+                #[allow(clippy::cast_precision_loss)]
+                let distance = (location_a.wrapping_distance_squared(location_b) as f64).sqrt();
+
+                let Some(bin) = distance_bin(distances, distance) else {
+                    continue;
+                };
+
+                #[allow(clippy::cast_precision_loss)]
+                let (shared, total) = (
+                    shared_species(species_a, species_b) as f64,
+                    (species_a.len() + species_b.len()) as f64,
+                );
+
+                let (pairs, sorensen, jaccard) = &mut bins[bin];
+                *pairs += 1;
+                *sorensen += 2.0_f64 * shared / total;
+                *jaccard += shared / (total - shared);
+            }
+        }
+
+        let distances = distances
+            .windows(2)
+            .zip(bins)
+            .map(|(edges, (pairs, sorensen, jaccard))| {
+                #[allow(clippy::cast_precision_loss)]
+                let mean = |sum: f64| {
+                    if pairs > 0 {
+                        Some(sum / (pairs as f64))
+                    } else {
+                        None
+                    }
+                };
+
+                DistanceSimilarity {
+                    min: edges[0],
+                    max: edges[1],
+                    pairs,
+                    sorensen: mean(sorensen),
+                    jaccard: mean(jaccard),
+                }
+            })
+            .collect();
+
+        Self { areas, distances }
+    }
+}
+
+/// Enumerates all pairs `(i, j)` with `i < j` of the `locations` which may
+///  be closer than `max_distance`, by grouping them into square buckets that
+///  are at least `max_distance` wide on the wrapping `u32` torus, such that
+///  only pairs in the same or in neighbouring buckets need to be compared
+fn nearby_pairs<T>(
+    locations: &[(&Location, T)],
+    max_distance: NonNegativeF64,
+) -> impl Iterator<Item = (usize, usize)> {
+    const TORUS: u64 = 1 << 32;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let bucket_size = (max_distance.get().ceil() as u64).clamp(1, TORUS);
+    // The last bucket absorbs the remainder, so that no bucket is too narrow
+    let num_buckets = TORUS / bucket_size;
+
+    let bucket_of =
+        move |coordinate: u32| (u64::from(coordinate) / bucket_size).min(num_buckets - 1);
+    let neighbours_of = move |bucket: u64| {
+        let mut neighbours = [
+            (bucket + num_buckets - 1) % num_buckets,
+            bucket,
+            (bucket + 1) % num_buckets,
+        ];
+        neighbours.sort_unstable();
+
+        let mut unique = Vec::with_capacity(neighbours.len());
+        for neighbour in neighbours {
+            if unique.last() != Some(&neighbour) {
+                unique.push(neighbour);
+            }
+        }
+        unique
+    };
+
+    let mut buckets: HashMap<(u64, u64), Vec<usize>, FnvBuildHasher> = HashMap::default();
+    for (i, (location, _)) in locations.iter().enumerate() {
+        buckets
+            .entry((bucket_of(location.x()), bucket_of(location.y())))
+            .or_default()
+            .push(i);
+    }
+
+    let mut pairs = Vec::new();
+
+    for ((bucket_x, bucket_y), members) in &buckets {
+        for neighbour_x in neighbours_of(*bucket_x) {
+            for neighbour_y in neighbours_of(*bucket_y) {
+                let Some(others) = buckets.get(&(neighbour_x, neighbour_y)) else {
+                    continue;
+                };
+
+                for i in members {
+                    for j in others {
+                        if i < j {
+                            pairs.push((*i, *j));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    // Sort the pairs to sum up the similarities in a deterministic order
+    pairs.sort_unstable();
+
+    pairs.into_iter()
+}
+
+/// Finds the bin `i` such that `edges[i] <= distance < edges[i+1]`
+fn distance_bin(edges: &[NonNegativeF64], distance: f64) -> Option<usize> {
+    let upper = edges.partition_point(|edge| edge.get() <= distance);
+
+    if upper == 0 || upper == edges.len() {
+        None
+    } else {
+        Some(upper - 1)
+    }
+}
+
+/// Counts the species shared between two sorted species sets
+fn shared_species(a: &[usize], b: &[usize]) -> usize {
+    let (mut i, mut j, mut shared) = (0, 0, 0);
+
+    while i < a.len() && j < b.len() {
+        match a[i].cmp(&b[j]) {
+            std::cmp::Ordering::Less => i += 1,
+            std::cmp::Ordering::Greater => j += 1,
+            std::cmp::Ordering::Equal => {
+                shared += 1;
+                i += 1;
+                j += 1;
+            },
+        }
+    }
+
+    shared
+}
+
+#[cfg(test)]
+mod tests {
+    use necsim_core::landscape::{LandscapeExtent, Location};
+    use necsim_core_bond::{NonNegativeF64, OffByOneU32};
+
+    use super::{super::SampleArea, SpeciesCurves};
+
+    #[test]
+    fn test_species_area_and_distance_decay() {
+        let records = [
+            (Location::new(0, 0), 0),
+            (Location::new(0, 0), 1),
+            (Location::new(1, 0), 1),
+            (Location::new(3, 0), 2),
+            (Location::new(3, 0), 2),
+        ];
+
+        let curves = SpeciesCurves::from_records(
+            &records,
+            vec![
+                SampleArea::Circle {
+                    centre: Location::new(0, 0),
+                    radius: 1,
+                },
+                SampleArea::Rectangle(LandscapeExtent::new(
+                    Location::new(0, 0),
+                    OffByOneU32::new(4).unwrap(),
+                    OffByOneU32::new(1).unwrap(),
+                )),
+            ],
+            &[
+                NonNegativeF64::new(0.0).unwrap(),
+                NonNegativeF64::new(1.5).unwrap(),
+                NonNegativeF64::new(10.0).unwrap(),
+            ],
+        );
+
+        assert_eq!(curves.areas[0].individuals, 3);
+        assert_eq!(curves.areas[0].richness, 2);
+        assert_eq!(curves.areas[1].individuals, 5);
+        assert_eq!(curves.areas[1].richness, 3);
+
+        // (0, 0) <-> (1, 0) share one of two species
+        assert_eq!(curves.distances[0].pairs, 1);
+        assert!((curves.distances[0].sorensen.unwrap() - 2.0 / 3.0).abs() < 1e-12);
+        assert!((curves.distances[0].jaccard.unwrap() - 0.5).abs() < 1e-12);
+
+        // (0, 0) <-> (3, 0) and (1, 0) <-> (3, 0) share no species
+        assert_eq!(curves.distances[1].pairs, 2);
+        assert!(curves.distances[1].sorensen.unwrap().abs() < f64::EPSILON);
+        assert!(curves.distances[1].jaccard.unwrap().abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_distance_decay_wraps_around_and_skips_far_pairs() {
+        let records = [
+            (Location::new(u32::MAX, 7), 0),
+            (Location::new(1, 7), 0),
+            (Location::new(1_000, 7), 0),
+        ];
+
+        let curves = SpeciesCurves::from_records(
+            &records,
+            Vec::new(),
+            &[
+                NonNegativeF64::new(0.0).unwrap(),
+                NonNegativeF64::new(5.0).unwrap(),
+            ],
+        );
+
+        // Only (u32::MAX, 7) <-> (1, 7) are closer than 5 across the edge
+        assert_eq!(curves.distances[0].pairs, 1);
+        assert!((curves.distances[0].sorensen.unwrap() - 1.0).abs() < f64::EPSILON);
+    }
+}
@@ -0,0 +1,177 @@
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter},
+    path::Path,
+};
+
+use arrow2::{
+    array::Array,
+    chunk::Chunk,
+    datatypes::Schema,
+    io::{ipc, parquet},
+};
+use serde::{Deserialize, Serialize};
+
+/// File format of a species dataframe
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
+pub enum DataFrameFormat {
+    /// Arrow IPC (Feather v2) file
+    #[default]
+    Feather,
+    /// Parquet file with row-group statistics
+    Parquet {
+        #[serde(default)]
+        compression: ParquetCompression,
+        #[serde(default = "default_row_group_size")]
+        row_group_size: usize,
+    },
+}
+
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
+pub enum ParquetCompression {
+    Uncompressed,
+    Snappy,
+    Gzip,
+    Lz4,
+    #[default]
+    Zstd,
+}
+
+fn default_row_group_size() -> usize {
+    1 << 20
+}
+
+impl From<ParquetCompression> for parquet::write::CompressionOptions {
+    fn from(compression: ParquetCompression) -> Self {
+        match compression {
+            ParquetCompression::Uncompressed => Self::Uncompressed,
+            ParquetCompression::Snappy => Self::Snappy,
+            ParquetCompression::Gzip => Self::Gzip(None),
+            ParquetCompression::Lz4 => Self::Lz4Raw,
+            ParquetCompression::Zstd => Self::Zstd(None),
+        }
+    }
+}
+
+/// Reads the schema, including its metadata, and all chunks of the species
+///  dataframe at `path`
+#[allow(clippy::module_name_repetitions, clippy::type_complexity)]
+pub fn read_dataframe(
+    path: &Path,
+    format: DataFrameFormat,
+) -> arrow2::error::Result<(Schema, Vec<Chunk<Box<dyn Array>>>)> {
+    let file = File::options().read(true).open(path)?;
+    let mut reader = BufReader::new(file);
+
+    match format {
+        DataFrameFormat::Feather => {
+            let metadata = ipc::read::read_file_metadata(&mut reader)?;
+            let schema = metadata.schema.clone();
+
+            let chunks = ipc::read::FileReader::new(reader, metadata, None, None)
+                .collect::<arrow2::error::Result<Vec<_>>>()?;
+
+            Ok((schema, chunks))
+        },
+        DataFrameFormat::Parquet { .. } => {
+            let metadata = parquet::read::read_metadata(&mut reader)?;
+            let schema = parquet::read::infer_schema(&metadata)?;
+
+            let chunks = parquet::read::FileReader::new(
+                reader,
+                metadata.row_groups,
+                schema.clone(),
+                None,
+                None,
+                None,
+            )
+            .collect::<arrow2::error::Result<Vec<_>>>()?;
+
+            Ok((schema, chunks))
+        },
+    }
+}
+
+/// Writes the `chunk` with the `schema`, including its metadata, as the
+///  species dataframe at `path`, replacing any existing dataframe
+#[allow(clippy::module_name_repetitions)]
+pub fn write_dataframe(
+    path: &Path,
+    format: DataFrameFormat,
+    schema: Schema,
+    chunk: &Chunk<Box<dyn Array>>,
+) -> arrow2::error::Result<()> {
+    let file = File::options().write(true).truncate(true).open(path)?;
+    let writer = BufWriter::new(file);
+
+    match format {
+        DataFrameFormat::Feather => {
+            let mut writer = ipc::write::FileWriter::new(
+                writer,
+                schema,
+                None,
+                ipc::write::WriteOptions { compression: None },
+            );
+            writer.start()?;
+            writer.write(chunk, None)?;
+            writer.finish()
+        },
+        DataFrameFormat::Parquet {
+            compression,
+            row_group_size,
+        } => {
+            let options = parquet::write::WriteOptions {
+                write_statistics: true,
+                compression: compression.into(),
+                version: parquet::write::Version::V2,
+                data_pagesize_limit: None,
+            };
+
+            let encodings = schema
+                .fields
+                .iter()
+                .map(|field| {
+                    parquet::write::transverse(&field.data_type, |_| {
+                        parquet::write::Encoding::Plain
+                    })
+                })
+                .collect();
+
+            // Split the chunk into row groups, each with their own statistics
+            let row_group_size = row_group_size.max(1);
+            let row_groups = (0..chunk.len())
+                .step_by(row_group_size)
+                .map(|offset| {
+                    let length = row_group_size.min(chunk.len() - offset);
+
+                    Chunk::try_new(
+                        chunk
+                            .arrays()
+                            .iter()
+                            .map(|array| array.sliced(offset, length))
+                            .collect(),
+                    )
+                })
+                .collect::<Vec<_>>();
+
+            let row_groups = parquet::write::RowGroupIterator::try_new(
+                row_groups.into_iter(),
+                &schema,
+                options,
+                encodings,
+            )?;
+
+            let mut writer = parquet::write::FileWriter::try_new(writer, schema, options)?;
+
+            for row_group in row_groups {
+                writer.write(row_group?)?;
+            }
+
+            writer.end(None).map(|_size| ())
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,229 @@
+use std::{fs::File, io::BufReader, path::PathBuf};
+
+use arrow2::{
+    array::PrimitiveArray,
+    io::parquet::read::{read_metadata, statistics},
+};
+
+use necsim_core::reporter::Reporter;
+
+use rustcoalescence_api::simulate::simulate_with_reporter;
+
+use crate::{
+    individual::feather::IndividualSpeciesFeatherReporter,
+    location::feather::LocationSpeciesFeatherReporter,
+};
+
+use super::{read_dataframe, DataFrameFormat, ParquetCompression};
+
+const ROW_GROUP_SIZE: usize = 64;
+
+struct TempDataFrame(PathBuf);
+
+impl TempDataFrame {
+    fn new(name: &str) -> Self {
+        Self(std::env::temp_dir().join(format!(
+            "necsim-species-dataframe-{}-{name}",
+            std::process::id()
+        )))
+    }
+}
+
+impl Drop for TempDataFrame {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
+
+fn simulate_independent<R: Reporter>(reporter: R) {
+    let config = "(
+        speciation: 0.1,
+        sample: Sample(percentage: 1.0),
+        rng: Seed(42),
+        maths: Reproducible,
+        algorithm: Independent(),
+        scenario: NonSpatial(area: (10, 10), deme: 10),
+        reporters: [],
+    )";
+
+    simulate_with_reporter(config, reporter).expect("the simulation succeeds");
+}
+
+fn parquet_format(compression: &str) -> String {
+    format!("Parquet(compression: {compression}, row_group_size: {ROW_GROUP_SIZE})")
+}
+
+/// Checks that the Parquet dataframe at `parquet` contains the same schema,
+///  metadata, and rows as the Feather dataframe at `feather`, split into row
+///  groups of at most `ROW_GROUP_SIZE` rows with matching statistics
+fn assert_parquet_matches_feather(feather: &TempDataFrame, parquet: &TempDataFrame) {
+    let (feather_schema, feather_chunks) =
+        read_dataframe(&feather.0, DataFrameFormat::Feather).expect("the feather dataframe exists");
+    let (parquet_schema, parquet_chunks) = read_dataframe(
+        &parquet.0,
+        DataFrameFormat::Parquet {
+            compression: ParquetCompression::default(),
+            row_group_size: ROW_GROUP_SIZE,
+        },
+    )
+    .expect("the parquet dataframe exists");
+
+    assert_eq!(parquet_schema, feather_schema);
+    assert!(parquet_schema.metadata.contains_key("last-event"));
+
+    let [feather_chunk] = &feather_chunks[..] else {
+        panic!("the feather dataframe has a single chunk");
+    };
+
+    let metadata = read_metadata(&mut BufReader::new(
+        File::open(&parquet.0).expect("the parquet dataframe exists"),
+    ))
+    .expect("the parquet metadata is valid");
+
+    assert_eq!(
+        metadata.row_groups.len(),
+        (feather_chunk.len() + ROW_GROUP_SIZE - 1) / ROW_GROUP_SIZE
+    );
+    assert!(
+        metadata.row_groups.len() > 1,
+        "the dataframe is split into several row groups"
+    );
+    assert_eq!(parquet_chunks.len(), metadata.row_groups.len());
+
+    let mut offset = 0;
+
+    for (chunk, row_group) in parquet_chunks.iter().zip(&metadata.row_groups) {
+        assert_eq!(chunk.len(), row_group.num_rows());
+        assert_eq!(
+            chunk.len(),
+            ROW_GROUP_SIZE.min(feather_chunk.len() - offset)
+        );
+
+        for (parquet_column, feather_column) in chunk.arrays().iter().zip(feather_chunk.arrays()) {
+            assert_eq!(
+                &**parquet_column,
+                &*feather_column.sliced(offset, chunk.len())
+            );
+        }
+
+        offset += chunk.len();
+    }
+
+    assert_eq!(offset, feather_chunk.len());
+
+    for (i, field) in parquet_schema.fields.iter().enumerate() {
+        let statistics =
+            statistics::deserialize(field, &metadata.row_groups).expect("statistics are written");
+
+        let null_counts = statistics
+            .null_count
+            .as_any()
+            .downcast_ref::<PrimitiveArray<u64>>()
+            .expect("null counts are u64");
+
+        for (chunk, null_count) in parquet_chunks.iter().zip(null_counts.iter()) {
+            assert_eq!(
+                null_count.copied(),
+                Some(chunk.arrays()[i].null_count() as u64)
+            );
+        }
+
+        // The x coordinate column is shared by both species dataframes
+        if field.name == "x" {
+            let min_values = statistics
+                .min_value
+                .as_any()
+                .downcast_ref::<PrimitiveArray<u32>>()
+                .expect("x coordinates are u32");
+            let max_values = statistics
+                .max_value
+                .as_any()
+                .downcast_ref::<PrimitiveArray<u32>>()
+                .expect("x coordinates are u32");
+
+            for ((chunk, min), max) in parquet_chunks.iter().zip(min_values).zip(max_values) {
+                let xs = chunk.arrays()[i]
+                    .as_any()
+                    .downcast_ref::<PrimitiveArray<u32>>()
+                    .expect("x coordinates are u32");
+
+                assert_eq!(min.copied(), xs.values_iter().min().copied());
+                assert_eq!(max.copied(), xs.values_iter().max().copied());
+            }
+        }
+    }
+}
+
+#[test]
+fn individual_parquet_round_trip() {
+    let feather = TempDataFrame::new("individual.feather");
+    let parquet = TempDataFrame::new("individual.parquet");
+
+    simulate_independent(
+        ron::from_str::<IndividualSpeciesFeatherReporter>(&format!(
+            "IndividualSpeciesFeatherReporter(output: {:?})",
+            feather.0,
+        ))
+        .expect("the feather reporter is valid"),
+    );
+
+    for compression in ["Uncompressed", "Snappy", "Gzip", "Lz4", "Zstd"] {
+        simulate_independent(
+            ron::from_str::<IndividualSpeciesFeatherReporter>(&format!(
+                "IndividualSpeciesFeatherReporter(output: {:?}, format: {})",
+                parquet.0,
+                parquet_format(compression),
+            ))
+            .expect("the parquet reporter is valid"),
+        );
+
+        assert_parquet_matches_feather(&feather, &parquet);
+
+        // The written Parquet dataframe can be resumed from
+        ron::from_str::<IndividualSpeciesFeatherReporter>(&format!(
+            "IndividualSpeciesFeatherReporter(output: {:?}, format: {}, mode: Resume)",
+            parquet.0,
+            parquet_format(compression),
+        ))
+        .expect("the parquet dataframe can be resumed");
+
+        std::fs::remove_file(&parquet.0).expect("the parquet dataframe exists");
+    }
+}
+
+#[test]
+fn location_parquet_round_trip() {
+    let feather = TempDataFrame::new("location.feather");
+    let parquet = TempDataFrame::new("location.parquet");
+
+    simulate_independent(
+        ron::from_str::<LocationSpeciesFeatherReporter>(&format!(
+            "LocationSpeciesFeatherReporter(output: {:?})",
+            feather.0,
+        ))
+        .expect("the feather reporter is valid"),
+    );
+
+    for compression in ["Uncompressed", "Snappy", "Gzip", "Lz4", "Zstd"] {
+        simulate_independent(
+            ron::from_str::<LocationSpeciesFeatherReporter>(&format!(
+                "LocationSpeciesFeatherReporter(output: {:?}, format: {})",
+                parquet.0,
+                parquet_format(compression),
+            ))
+            .expect("the parquet reporter is valid"),
+        );
+
+        assert_parquet_matches_feather(&feather, &parquet);
+
+        // The written Parquet dataframe can be resumed from
+        ron::from_str::<LocationSpeciesFeatherReporter>(&format!(
+            "LocationSpeciesFeatherReporter(output: {:?}, format: {}, mode: Resume)",
+            parquet.0,
+            parquet_format(compression),
+        ))
+        .expect("the parquet dataframe can be resumed");
+
+        std::fs::remove_file(&parquet.0).expect("the parquet dataframe exists");
+    }
+}
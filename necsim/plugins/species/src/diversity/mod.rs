@@ -0,0 +1,107 @@
+use std::{fmt, fs::File, io::BufWriter, path::PathBuf};
+
+use serde::{Deserialize, Deserializer, Serialize};
+
+use necsim_plugins_species_core::resolver::LocationSpeciesResolver;
+
+mod reporter;
+mod summary;
+
+use summary::DiversitySummary;
+
+/// Reporter that summarises the alpha, beta, and gamma diversity of the
+///  sampled individuals from the event stream.
+///
+/// At `finalise`, the reporter writes a JSON summary with the species
+///  richness, Shannon and Gini-Simpson indices of the landscape (gamma) and of
+///  every occupied location (alpha), the Whittaker and Shannon beta diversity,
+///  and the species abundance distribution in Preston octaves.
+///
+/// The abundances, and thus all diversity indices, are only correct if every
+///  sampled individual is assigned to its species, i.e. if the reporter sees
+///  the events of the entire simulation, not just a time window of its event
+///  log. Lineages which have not yet speciated, e.g. because the simulation
+///  was paused, are grouped into one placeholder species per common ancestor.
+///
+/// The `output` file must not exist yet.
+#[allow(clippy::module_name_repetitions)]
+pub struct SpeciesDiversitySummaryReporter {
+    resolver: LocationSpeciesResolver,
+
+    output: PathBuf,
+    locations: bool,
+}
+
+impl fmt::Debug for SpeciesDiversitySummaryReporter {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct(stringify!(SpeciesDiversitySummaryReporter))
+            .field("output", &self.output)
+            .field("locations", &self.locations)
+            .finish_non_exhaustive()
+    }
+}
+
+impl serde::Serialize for SpeciesDiversitySummaryReporter {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        SpeciesDiversitySummaryReporterArgs {
+            output: self.output.clone(),
+            locations: self.locations,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for SpeciesDiversitySummaryReporter {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let args = SpeciesDiversitySummaryReporterArgs::deserialize(deserializer)?;
+
+        Ok(Self {
+            resolver: LocationSpeciesResolver::default(),
+
+            output: args.output,
+            locations: args.locations,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+#[serde(rename = "SpeciesDiversitySummaryReporter")]
+struct SpeciesDiversitySummaryReporterArgs {
+    output: PathBuf,
+    #[serde(default = "default_locations")]
+    locations: bool,
+}
+
+fn default_locations() -> bool {
+    true
+}
+
+impl SpeciesDiversitySummaryReporter {
+    fn output_to_summary(self) -> std::io::Result<()> {
+        let species = self.resolver.resolve();
+
+        if species.unspeciated > 0 {
+            warn!(
+                "{} lineage(s) had not yet speciated and were grouped into placeholder species.",
+                species.unspeciated
+            );
+        }
+
+        let summary = DiversitySummary::from_records(
+            species
+                .records
+                .into_iter()
+                .map(|(location, species)| (location, species, 1_u64)),
+            species.unspeciated,
+            self.locations,
+        );
+
+        let file = File::options()
+            .write(true)
+            .truncate(true)
+            .open(&self.output)?;
+
+        serde_json::to_writer_pretty(BufWriter::new(file), &summary).map_err(std::io::Error::from)
+    }
+}
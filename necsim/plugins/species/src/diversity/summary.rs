@@ -0,0 +1,270 @@
+use std::collections::HashMap;
+
+use fnv::FnvBuildHasher;
+use serde::Serialize;
+
+use necsim_core::landscape::Location;
+
+/// Structured biodiversity summary of the sampled individuals
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Serialize)]
+pub struct DiversitySummary {
+    /// Number of sampled individuals
+    pub individuals: u64,
+    /// Number of lineages which had not yet speciated when the summary was
+    ///  produced, e.g. because the simulation was paused
+    pub unspeciated: u64,
+    /// Landscape-wide (gamma) diversity
+    pub gamma: Diversity,
+    /// Mean per-location (alpha) diversity across all occupied locations
+    pub alpha: MeanDiversity,
+    /// Turnover (beta) diversity between the locations
+    pub beta: BetaDiversity,
+    /// Species abundance distribution in Preston octaves, where the kth
+    ///  octave counts the species with an abundance in [2^k, 2^(k+1))
+    pub preston: Vec<u64>,
+    /// Per-location diversity of all occupied locations, ordered by location
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locations: Option<Vec<LocationDiversity>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Diversity {
+    /// Number of individuals
+    pub individuals: u64,
+    /// Species richness, i.e. the number of species
+    pub richness: u64,
+    /// Shannon index `H = -sum(p_i * ln(p_i))`
+    pub shannon: f64,
+    /// Gini-Simpson index `1 - sum(p_i^2)`
+    pub simpson: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MeanDiversity {
+    pub richness: f64,
+    pub shannon: f64,
+    pub simpson: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BetaDiversity {
+    /// Whittaker's beta diversity `gamma.richness / alpha.richness`
+    pub whittaker: f64,
+    /// Multiplicative Shannon beta diversity
+    ///  `exp(gamma.shannon) / exp(alpha.shannon)`
+    pub shannon: f64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LocationDiversity {
+    pub x: u32,
+    pub y: u32,
+    #[serde(flatten)]
+    pub diversity: Diversity,
+}
+
+impl Diversity {
+    #[must_use]
+    pub fn from_abundances<I: IntoIterator<Item = u64> + Clone>(abundances: I) -> Self {
+        let (individuals, richness) = abundances
+            .clone()
+            .into_iter()
+            .filter(|abundance| *abundance > 0)
+            .fold((0_u64, 0_u64), |(individuals, richness), abundance| {
+                (individuals + abundance, richness + 1)
+            });
+
+        if individuals == 0 {
+            return Self {
+                individuals,
+                richness,
+                shannon: 0.0_f64,
+                simpson: 0.0_f64,
+            };
+        }
+
+        #[allow(clippy::cast_precision_loss)]
+        let total = individuals as f64;
+
+        let (shannon, dominance) = abundances
+            .into_iter()
+            .filter(|abundance| *abundance > 0)
+            .fold((0.0_f64, 0.0_f64), |(shannon, dominance), abundance| {
+                #[allow(clippy::cast_precision_loss)]
+                let p = (abundance as f64) / total;
+
+                (shannon - p * p.ln(), dominance + p * p)
+            });
+
+        Self {
+            individuals,
+            richness,
+            // Avoid reporting -0.0 for single-species communities
+            shannon: shannon.max(0.0_f64),
+            simpson: (1.0_f64 - dominance).max(0.0_f64),
+        }
+    }
+}
+
+impl DiversitySummary {
+    /// Summarises the diversity from the `(location, species, count)` records
+    ///  of all sampled individuals, where `species` is a dense species index.
+    #[must_use]
+    pub fn from_records<I: IntoIterator<Item = (Location, usize, u64)>>(
+        records: I,
+        unspeciated: u64,
+        with_locations: bool,
+    ) -> Self {
+        let mut species_abundances: Vec<u64> = Vec::new();
+        let mut location_abundances: HashMap<
+            Location,
+            HashMap<usize, u64, FnvBuildHasher>,
+            FnvBuildHasher,
+        > = HashMap::default();
+
+        for (location, species, count) in records {
+            if species >= species_abundances.len() {
+                species_abundances.resize(species + 1, 0);
+            }
+            species_abundances[species] += count;
+
+            *location_abundances
+                .entry(location)
+                .or_default()
+                .entry(species)
+                .or_default() += count;
+        }
+
+        let gamma = Diversity::from_abundances(species_abundances.iter().copied());
+
+        let mut preston = Vec::new();
+        for abundance in species_abundances.iter().copied().filter(|a| *a > 0) {
+            let octave = (u64::BITS - 1 - abundance.leading_zeros()) as usize;
+
+            if octave >= preston.len() {
+                preston.resize(octave + 1, 0);
+            }
+            preston[octave] += 1;
+        }
+
+        let mut locations = location_abundances
+            .into_iter()
+            .map(|(location, abundances)| LocationDiversity {
+                x: location.x(),
+                y: location.y(),
+                diversity: Diversity::from_abundances(abundances.values().copied()),
+            })
+            .collect::<Vec<_>>();
+        locations.sort_unstable_by_key(|location| (location.y, location.x));
+
+        #[allow(clippy::cast_precision_loss)]
+        let num_locations = locations.len() as f64;
+
+        let alpha = if locations.is_empty() {
+            MeanDiversity {
+                richness: 0.0_f64,
+                shannon: 0.0_f64,
+                simpson: 0.0_f64,
+            }
+        } else {
+            #[allow(clippy::cast_precision_loss)]
+            MeanDiversity {
+                richness: locations
+                    .iter()
+                    .map(|location| location.diversity.richness as f64)
+                    .sum::<f64>()
+                    / num_locations,
+                shannon: locations
+                    .iter()
+                    .map(|location| location.diversity.shannon)
+                    .sum::<f64>()
+                    / num_locations,
+                simpson: locations
+                    .iter()
+                    .map(|location| location.diversity.simpson)
+                    .sum::<f64>()
+                    / num_locations,
+            }
+        };
+
+        let beta = if alpha.richness > 0.0_f64 {
+            #[allow(clippy::cast_precision_loss)]
+            BetaDiversity {
+                whittaker: (gamma.richness as f64) / alpha.richness,
+                shannon: (gamma.shannon - alpha.shannon).exp(),
+            }
+        } else {
+            BetaDiversity {
+                whittaker: 0.0_f64,
+                shannon: 0.0_f64,
+            }
+        };
+
+        Self {
+            individuals: gamma.individuals,
+            unspeciated,
+            gamma,
+            alpha,
+            beta,
+            preston,
+            locations: if with_locations {
+                Some(locations)
+            } else {
+                None
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use necsim_core::landscape::Location;
+
+    use super::{Diversity, DiversitySummary};
+
+    #[test]
+    fn test_diversity_indices() {
+        let single = Diversity::from_abundances([5_u64]);
+        assert_eq!(single.individuals, 5);
+        assert_eq!(single.richness, 1);
+        assert!(single.shannon.abs() < f64::EPSILON);
+        assert!(single.simpson.abs() < f64::EPSILON);
+
+        let even = Diversity::from_abundances([2_u64, 2, 0, 2, 2]);
+        assert_eq!(even.individuals, 8);
+        assert_eq!(even.richness, 4);
+        assert!((even.shannon - 4.0_f64.ln()).abs() < 1e-12);
+        assert!((even.simpson - 0.75_f64).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_alpha_beta_gamma_diversity() {
+        let summary = DiversitySummary::from_records(
+            [
+                (Location::new(0, 0), 0, 3),
+                (Location::new(0, 0), 1, 1),
+                (Location::new(1, 0), 2, 2),
+                (Location::new(1, 0), 0, 1),
+                (Location::new(0, 0), 0, 1),
+            ],
+            0,
+            true,
+        );
+
+        assert_eq!(summary.individuals, 8);
+        assert_eq!(summary.gamma.richness, 3);
+        assert!((summary.alpha.richness - 2.0_f64).abs() < 1e-12);
+        assert!((summary.beta.whittaker - 1.5_f64).abs() < 1e-12);
+        // Abundances 5, 1, 2 fall into the octaves 2, 0, 1
+        assert_eq!(summary.preston, [1, 1, 1]);
+
+        let locations = summary.locations.unwrap();
+        assert_eq!(locations.len(), 2);
+        assert_eq!((locations[0].x, locations[0].y), (0, 0));
+        assert_eq!(locations[0].diversity.individuals, 5);
+        assert_eq!(locations[0].diversity.richness, 2);
+        assert_eq!((locations[1].x, locations[1].y), (1, 0));
+        assert_eq!(locations[1].diversity.individuals, 3);
+    }
+}
@@ -0,0 +1,149 @@
+use std::fmt::Write;
+
+use super::tree::{Phylogeny, PhylogenyTree};
+
+impl Phylogeny {
+    /// Formats the phylogeny in the Newick format, with one tree per line
+    #[must_use]
+    pub fn to_newick(&self) -> String {
+        enum Step {
+            Enter(usize),
+            Comma,
+            Exit(usize),
+        }
+
+        let mut newick = String::new();
+
+        for tree in &self.trees {
+            // Iterative traversal, since phylogenies can be very deep
+            let mut stack = vec![Step::Enter(tree.root)];
+
+            while let Some(step) = stack.pop() {
+                match step {
+                    Step::Enter(node) if self.nodes[node].children.is_empty() => {
+                        self.push_newick_node(&mut newick, tree, node);
+                    },
+                    Step::Enter(node) => {
+                        newick.push('(');
+                        stack.push(Step::Exit(node));
+
+                        for (i, child) in self.nodes[node].children.iter().enumerate().rev() {
+                            stack.push(Step::Enter(*child));
+
+                            if i > 0 {
+                                stack.push(Step::Comma);
+                            }
+                        }
+                    },
+                    Step::Comma => newick.push(','),
+                    Step::Exit(node) => {
+                        newick.push(')');
+                        self.push_newick_node(&mut newick, tree, node);
+                    },
+                }
+            }
+
+            newick.push_str(";\n");
+        }
+
+        newick
+    }
+
+    fn push_newick_node(&self, newick: &mut String, tree: &PhylogenyTree, node: usize) {
+        let label = match &self.nodes[node].label {
+            Some(label) => Some(label),
+            None if node == tree.root => tree.label.as_ref(),
+            None => None,
+        };
+
+        if let Some(label) = label {
+            newick.push_str(label);
+        }
+
+        if let Some(length) = self.nodes[node].length {
+            newick.push(':');
+            newick.push_str(&length.to_string());
+        }
+    }
+
+    /// Formats the phylogeny in the `NeXML` format, with one `FloatTree` per
+    ///  tree and one operational taxonomic unit (OTU) per labelled tip
+    #[must_use]
+    pub fn to_nexml(&self) -> String {
+        let mut otus = String::new();
+        let mut trees = String::new();
+
+        for (t, tree) in self.trees.iter().enumerate() {
+            let _ = write!(trees, "    <tree id=\"t{t}\" xsi:type=\"nex:FloatTree\"");
+            if let Some(label) = &tree.label {
+                let _ = write!(trees, " label=\"{}\"", escape_xml(label));
+            }
+            trees.push_str(">\n");
+
+            let mut nodes = Vec::new();
+            let mut stack = vec![tree.root];
+
+            while let Some(node) = stack.pop() {
+                nodes.push(node);
+                stack.extend(self.nodes[node].children.iter().copied());
+            }
+
+            for node in &nodes {
+                let _ = write!(trees, "      <node id=\"n{node}\"");
+
+                if let Some(label) = &self.nodes[*node].label {
+                    let label = escape_xml(label);
+
+                    let _ = writeln!(otus, "    <otu id=\"o{node}\" label=\"{label}\"/>");
+                    let _ = write!(trees, " label=\"{label}\" otu=\"o{node}\"");
+                }
+
+                if *node == tree.root {
+                    trees.push_str(" root=\"true\"");
+                }
+
+                trees.push_str("/>\n");
+            }
+
+            if let Some(length) = self.nodes[tree.root].length {
+                let _ = writeln!(
+                    trees,
+                    "      <rootedge id=\"r{t}\" target=\"n{}\" length=\"{length}\"/>",
+                    tree.root
+                );
+            }
+
+            for node in &nodes {
+                for child in &self.nodes[*node].children {
+                    let _ = write!(
+                        trees,
+                        "      <edge id=\"e{child}\" source=\"n{node}\" target=\"n{child}\""
+                    );
+
+                    if let Some(length) = self.nodes[*child].length {
+                        let _ = write!(trees, " length=\"{length}\"");
+                    }
+
+                    trees.push_str("/>\n");
+                }
+            }
+
+            trees.push_str("    </tree>\n");
+        }
+
+        format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<nex:nexml version=\"0.9\" \
+             xmlns=\"http://www.nexml.org/2009\" xmlns:nex=\"http://www.nexml.org/2009\" \
+             xmlns:xsi=\"http://www.w3.org/2001/XMLSchema-instance\">\n  <otus \
+             id=\"otus\">\n{otus}  </otus>\n  <trees id=\"trees\" \
+             otus=\"otus\">\n{trees}  </trees>\n</nex:nexml>\n"
+        )
+    }
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -0,0 +1,130 @@
+use std::{fmt, fs::File, io::Write, path::PathBuf};
+
+use serde::{Deserialize, Deserializer, Serialize};
+
+use necsim_core::{
+    event::{DispersalEvent, SpeciationEvent},
+    lineage::GlobalLineageReference,
+};
+use necsim_core_bond::NonNegativeF64;
+
+mod format;
+mod reporter;
+mod tree;
+
+use tree::Genealogy;
+
+/// Reporter that reconstructs the coalescent genealogy of the sampled
+///  individuals from the speciation and dispersal events, and exports it as a
+///  Newick or `NeXML` phylogeny at `finalise`.
+///
+/// With `tips: Individuals`, the reporter produces one tree per species, whose
+///  tips are the sampled individuals. With `tips: Species`, every species is
+///  collapsed into a single tip to produce a species-level phylogeny. All
+///  branch lengths are measured in generations.
+///
+/// Every tree is rooted at the speciation event of its species, so the
+///  reporter needs the events that trace each sampled individual back to its
+///  speciation. Lineages without a reported speciation, e.g. because the
+///  simulation was paused, are rooted at their oldest reported ancestor
+///  without a root branch length.
+///
+/// The `output` file must not exist yet.
+#[allow(clippy::module_name_repetitions)]
+pub struct GenealogyTreeReporter {
+    last_parent_prior_time: Option<(GlobalLineageReference, NonNegativeF64)>,
+    last_speciation_event: Option<SpeciationEvent>,
+    last_dispersal_event: Option<DispersalEvent>,
+
+    genealogy: Genealogy,
+
+    output: PathBuf,
+    format: GenealogyFormat,
+    tips: GenealogyTips,
+}
+
+impl fmt::Debug for GenealogyTreeReporter {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct(stringify!(GenealogyTreeReporter))
+            .field("output", &self.output)
+            .field("format", &self.format)
+            .field("tips", &self.tips)
+            .finish_non_exhaustive()
+    }
+}
+
+impl serde::Serialize for GenealogyTreeReporter {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        GenealogyTreeReporterArgs {
+            output: self.output.clone(),
+            format: self.format,
+            tips: self.tips,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for GenealogyTreeReporter {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let args = GenealogyTreeReporterArgs::deserialize(deserializer)?;
+
+        Ok(Self {
+            last_parent_prior_time: None,
+            last_speciation_event: None,
+            last_dispersal_event: None,
+
+            genealogy: Genealogy::default(),
+
+            output: args.output,
+            format: args.format,
+            tips: args.tips,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+#[serde(rename = "GenealogyTreeReporter")]
+struct GenealogyTreeReporterArgs {
+    output: PathBuf,
+    #[serde(default)]
+    format: GenealogyFormat,
+    #[serde(default)]
+    tips: GenealogyTips,
+}
+
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
+enum GenealogyFormat {
+    #[default]
+    Newick,
+    #[serde(alias = "NeXML")]
+    Nexml,
+}
+
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
+enum GenealogyTips {
+    #[default]
+    Individuals,
+    Species,
+}
+
+impl GenealogyTreeReporter {
+    fn output_to_phylogeny(self) -> std::io::Result<()> {
+        let phylogeny = match self.tips {
+            GenealogyTips::Individuals => self.genealogy.into_individual_phylogeny(),
+            GenealogyTips::Species => self.genealogy.into_species_phylogeny(),
+        };
+
+        let phylogeny = match self.format {
+            GenealogyFormat::Newick => phylogeny.to_newick(),
+            GenealogyFormat::Nexml => phylogeny.to_nexml(),
+        };
+
+        let mut file = File::options()
+            .write(true)
+            .truncate(true)
+            .open(&self.output)?;
+
+        file.write_all(phylogeny.as_bytes())
+    }
+}
@@ -0,0 +1,359 @@
+use std::collections::{HashMap, HashSet};
+
+use fnv::FnvBuildHasher;
+
+use necsim_core::{landscape::IndexedLocation, lineage::GlobalLineageReference};
+use necsim_core_bond::{NonNegativeF64, PositiveF64};
+
+use crate::SpeciesIdentity;
+
+/// Coalescent genealogy of all sampled individuals
+#[derive(Default)]
+pub struct Genealogy {
+    // Original (present-time) locations of all lineages
+    origins: HashMap<GlobalLineageReference, IndexedLocation, FnvBuildHasher>,
+    // Child -> Parent lineage mapping
+    parents: HashMap<GlobalLineageReference, GlobalLineageReference, FnvBuildHasher>,
+    // Parent -> Children lineages and their coalescence times
+    children: HashMap<
+        GlobalLineageReference,
+        Vec<(GlobalLineageReference, NonNegativeF64)>,
+        FnvBuildHasher,
+    >,
+    // Species originator -> Speciation time and species identity mapping
+    speciations: HashMap<GlobalLineageReference, (PositiveF64, SpeciesIdentity), FnvBuildHasher>,
+}
+
+/// Phylogeny of one or more trees whose nodes are stored in an arena
+pub struct Phylogeny {
+    pub nodes: Vec<PhylogenyNode>,
+    pub trees: Vec<PhylogenyTree>,
+}
+
+pub struct PhylogenyNode {
+    pub label: Option<String>,
+    /// Length of the branch to the parent node, or of the root edge
+    pub length: Option<f64>,
+    pub children: Vec<usize>,
+}
+
+#[allow(clippy::module_name_repetitions)]
+pub struct PhylogenyTree {
+    pub label: Option<String>,
+    pub root: usize,
+}
+
+impl Genealogy {
+    pub fn store_individual_origin(
+        &mut self,
+        lineage: &GlobalLineageReference,
+        origin: &IndexedLocation,
+    ) {
+        self.origins.insert(lineage.clone(), origin.clone());
+    }
+
+    pub fn store_individual_speciation(
+        &mut self,
+        lineage: &GlobalLineageReference,
+        origin: &IndexedLocation,
+        time: PositiveF64,
+    ) {
+        // Resolve the actual parent, irrespective of duplicate individuals
+        let parent = self.resolve(lineage).clone();
+
+        self.speciations
+            .entry(parent)
+            .or_insert_with(|| (time, SpeciesIdentity::from_speciation(origin, time)));
+    }
+
+    pub fn store_individual_coalescence(
+        &mut self,
+        child: &GlobalLineageReference,
+        parent: &GlobalLineageReference,
+        time: NonNegativeF64,
+    ) {
+        // Duplicate individuals only coalesce once
+        if self.parents.contains_key(child) {
+            return;
+        }
+
+        // Resolve the actual parent, irrespective of duplicate individuals
+        let parent = self.resolve(parent).clone();
+
+        // Prevent a lookup-loop
+        if child != &parent {
+            self.parents.insert(child.clone(), parent.clone());
+            self.children
+                .entry(parent)
+                .or_default()
+                .push((child.clone(), time));
+        }
+    }
+
+    fn resolve<'a>(&'a self, lineage: &'a GlobalLineageReference) -> &'a GlobalLineageReference {
+        let mut lineage = lineage;
+        while let Some(parent) = self.parents.get(lineage) {
+            lineage = parent;
+        }
+        lineage
+    }
+
+    /// Reconstructs the genealogy as one tree per species, whose tips are the
+    ///  sampled individuals and whose internal nodes are their coalescences.
+    ///
+    /// Tips are labelled `x_y_index_lineage` and trees are labelled with their
+    ///  hex-encoded species identity. The root edge of every tree extends to
+    ///  the time of its speciation, if it has speciated.
+    #[must_use]
+    pub fn into_individual_phylogeny(self) -> Phylogeny {
+        let mut nodes = Vec::new();
+        let mut trees = Vec::new();
+
+        for root in self.roots() {
+            let (node, time) = self.build_lineage_tree(&root, &mut nodes);
+
+            let (label, length) = match self.speciations.get(&root) {
+                Some((speciation, identity)) => {
+                    (Some(hex::encode(**identity)), Some(speciation.get() - time))
+                },
+                None => (None, None),
+            };
+
+            nodes[node].length = length;
+
+            trees.push(PhylogenyTree { label, root: node });
+        }
+
+        Phylogeny { nodes, trees }
+    }
+
+    /// Collapses every species into a single tip, labelled with its
+    ///  hex-encoded species identity and abundance as `identity_abundance`,
+    ///  whose branch length is the age of the species.
+    ///
+    /// Since the coalescent stops tracing a lineage once it has speciated, the
+    ///  relationships between the species are unresolved and all species are
+    ///  joined in a single polytomy.
+    #[must_use]
+    pub fn into_species_phylogeny(self) -> Phylogeny {
+        let mut nodes = Vec::new();
+        let mut tips = Vec::new();
+
+        for root in self.roots() {
+            let abundance = self.count_lineages(&root);
+
+            let (identity, length) = match self.speciations.get(&root) {
+                Some((speciation, identity)) => (identity.clone(), Some(speciation.get())),
+                None => (
+                    SpeciesIdentity::from_unspeciated(root.clone(), root.clone()),
+                    None,
+                ),
+            };
+
+            tips.push(nodes.len());
+            nodes.push(PhylogenyNode {
+                label: Some(format!("{}_{abundance}", hex::encode(*identity))),
+                length,
+                children: Vec::new(),
+            });
+        }
+
+        let root = nodes.len();
+        nodes.push(PhylogenyNode {
+            label: None,
+            length: None,
+            children: tips,
+        });
+
+        Phylogeny {
+            nodes,
+            trees: vec![PhylogenyTree {
+                label: Some(String::from("species")),
+                root,
+            }],
+        }
+    }
+
+    /// Returns all lineages which have not coalesced, in ascending order
+    fn roots(&self) -> Vec<GlobalLineageReference> {
+        let mut roots = self
+            .origins
+            .keys()
+            .chain(self.children.keys())
+            .filter(|lineage| !self.parents.contains_key(lineage))
+            .cloned()
+            .collect::<HashSet<_, FnvBuildHasher>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+        roots.sort_unstable();
+        roots
+    }
+
+    fn count_lineages(&self, root: &GlobalLineageReference) -> u64 {
+        let mut count = 0_u64;
+        let mut stack = vec![root];
+
+        while let Some(lineage) = stack.pop() {
+            count += 1;
+
+            if let Some(children) = self.children.get(lineage) {
+                stack.extend(children.iter().map(|(child, _)| child));
+            }
+        }
+
+        count
+    }
+
+    /// Builds the subtree of the `root` lineage, in which every child lineage
+    ///  joins the lineage at its coalescence time, and returns the topmost
+    ///  node and its time.
+    fn build_lineage_tree(
+        &self,
+        root: &GlobalLineageReference,
+        nodes: &mut Vec<PhylogenyNode>,
+    ) -> (usize, f64) {
+        let mut built: HashMap<&GlobalLineageReference, (usize, f64), FnvBuildHasher> =
+            HashMap::default();
+
+        // Iterative post-order traversal, since genealogies can be very deep
+        let mut stack = vec![(root, false)];
+
+        while let Some((lineage, expanded)) = stack.pop() {
+            let children = self.children.get(lineage).map_or(&[][..], Vec::as_slice);
+
+            if !expanded {
+                stack.push((lineage, true));
+                stack.extend(children.iter().map(|(child, _)| (child, false)));
+
+                continue;
+            }
+
+            let label = if let Some(origin) = self.origins.get(lineage) {
+                format!(
+                    "{}_{}_{}_{lineage}",
+                    origin.location().x(),
+                    origin.location().y(),
+                    origin.index()
+                )
+            } else {
+                format!("{lineage}")
+            };
+
+            let mut current = (nodes.len(), 0.0_f64);
+            nodes.push(PhylogenyNode {
+                label: Some(label),
+                length: None,
+                children: Vec::new(),
+            });
+
+            let mut children = children.iter().collect::<Vec<_>>();
+            children.sort_by(|(child_a, time_a), (child_b, time_b)| {
+                (time_a, child_a).cmp(&(time_b, child_b))
+            });
+
+            for (child, time) in children {
+                let Some((subtree, subtree_time)) = built.remove(child) else {
+                    continue;
+                };
+
+                nodes[current.0].length = Some(time.get() - current.1);
+                nodes[subtree].length = Some(time.get() - subtree_time);
+
+                let node = nodes.len();
+                nodes.push(PhylogenyNode {
+                    label: None,
+                    length: None,
+                    children: vec![current.0, subtree],
+                });
+
+                current = (node, time.get());
+            }
+
+            built.insert(lineage, current);
+        }
+
+        built.remove(root).unwrap_or((0, 0.0_f64))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use necsim_core::{
+        landscape::{IndexedLocation, Location},
+        lineage::GlobalLineageReference,
+    };
+    use necsim_core_bond::{NonNegativeF64, PositiveF64};
+
+    use crate::SpeciesIdentity;
+
+    use super::Genealogy;
+
+    fn lineage(i: u32) -> GlobalLineageReference {
+        unsafe { GlobalLineageReference::from_inner(u64::from(i)) }
+    }
+
+    fn origin(i: u32) -> IndexedLocation {
+        IndexedLocation::new(Location::new(i, 0), 0)
+    }
+
+    fn species(i: u32, time: f64) -> String {
+        hex::encode(*SpeciesIdentity::from_speciation(
+            &origin(i),
+            PositiveF64::new(time).unwrap(),
+        ))
+    }
+
+    fn example_genealogy() -> Genealogy {
+        let mut genealogy = Genealogy::default();
+
+        for i in 0..4 {
+            genealogy.store_individual_origin(&lineage(i), &origin(i));
+        }
+
+        genealogy.store_individual_coalescence(
+            &lineage(1),
+            &lineage(0),
+            NonNegativeF64::new(1.0).unwrap(),
+        );
+        genealogy.store_individual_coalescence(
+            &lineage(2),
+            &lineage(0),
+            NonNegativeF64::new(3.0).unwrap(),
+        );
+        genealogy.store_individual_speciation(
+            &lineage(0),
+            &origin(5),
+            PositiveF64::new(5.0).unwrap(),
+        );
+        genealogy.store_individual_speciation(
+            &lineage(3),
+            &origin(6),
+            PositiveF64::new(0.5).unwrap(),
+        );
+
+        genealogy
+    }
+
+    #[test]
+    fn test_individual_phylogeny_newick() {
+        assert_eq!(
+            example_genealogy().into_individual_phylogeny().to_newick(),
+            format!(
+                "((0_0_0_0:1,1_0_0_1:1):2,2_0_0_2:3){}:2;\n3_0_0_3:0.5;\n",
+                species(5, 5.0)
+            )
+        );
+    }
+
+    #[test]
+    fn test_species_phylogeny_newick() {
+        assert_eq!(
+            example_genealogy().into_species_phylogeny().to_newick(),
+            format!(
+                "({}_3:5,{}_1:0.5)species;\n",
+                species(5, 5.0),
+                species(6, 0.5)
+            )
+        );
+    }
+}
@@ -1,17 +1,16 @@
-use std::{collections::BTreeMap, fs::File, io::BufWriter};
+use std::collections::BTreeMap;
 
 use arrow2::{
-    array::{FixedSizeBinaryArray, PrimitiveArray},
+    array::{FixedSizeBinaryArray, MutableUtf8Array, PrimitiveArray, Utf8Array},
     bitmap::MutableBitmap,
     buffer::Buffer,
     chunk::Chunk,
     datatypes::{DataType, Field, Schema},
-    io::ipc::write::{FileWriter, WriteOptions},
 };
 use necsim_core::{landscape::IndexedLocation, lineage::GlobalLineageReference};
 use necsim_core_bond::PositiveF64;
 
-use crate::{LastEventState, SpeciesIdentity};
+use crate::{dataframe::write_dataframe, LastEventState, SpeciesIdentity};
 
 use super::IndividualSpeciesFeatherReporter;
 
@@ -36,6 +35,12 @@ impl IndividualSpeciesFeatherReporter {
             parent = parent_parent;
         }
 
+        // A lineage that migrated into the static metacommunity has already
+        //  originated its species there
+        if self.species.contains_key(parent) {
+            return;
+        }
+
         self.species.insert(
             parent.clone(),
             SpeciesIdentity::from_speciation(origin, time),
@@ -54,6 +59,12 @@ impl IndividualSpeciesFeatherReporter {
         }
         let child = child.clone();
 
+        // The ancestry of a lineage that migrated into the static
+        //  metacommunity, which has originated its species, is ignored
+        if self.species.contains_key(&child) {
+            return;
+        }
+
         // Resolve the actual parent, irrespective of duplicate individuals
         let mut parent = parent;
         while let Some(parent_parent) = self.parents.get(parent) {
@@ -67,14 +78,9 @@ impl IndividualSpeciesFeatherReporter {
         }
     }
 
+    #[allow(clippy::too_many_lines)]
     pub(super) fn output_to_dataframe(mut self) -> arrow2::error::Result<()> {
-        let file = File::options()
-            .write(true)
-            .truncate(true)
-            .open(&self.output)?;
-        let writer = BufWriter::new(file);
-
-        let expected_fields = vec![
+        let mut expected_fields = vec![
             Field::new("id", DataType::UInt64, false),
             Field::new("x", DataType::UInt32, false),
             Field::new("y", DataType::UInt32, false),
@@ -83,6 +89,10 @@ impl IndividualSpeciesFeatherReporter {
             Field::new("species", DataType::FixedSizeBinary(24), true),
         ];
 
+        if self.metacommunity.is_some() {
+            expected_fields.push(Field::new("metacommunity", DataType::Utf8, true));
+        }
+
         let mut metadata = BTreeMap::new();
         metadata.insert(
             String::from("last-event"),
@@ -100,16 +110,10 @@ impl IndividualSpeciesFeatherReporter {
             })?,
         );
 
-        let mut writer = FileWriter::new(
-            writer,
-            Schema {
-                fields: expected_fields,
-                metadata,
-            },
-            None,
-            WriteOptions { compression: None },
-        );
-        writer.start()?;
+        let schema = Schema {
+            fields: expected_fields,
+            metadata,
+        };
 
         let mut ids = Vec::with_capacity(self.origins.len());
         let mut xs = Vec::with_capacity(self.origins.len());
@@ -135,6 +139,12 @@ impl IndividualSpeciesFeatherReporter {
 
         let mut species = Vec::with_capacity(self.origins.len() * 24);
         let mut has_speciated = MutableBitmap::from_len_zeroed(self.origins.len());
+        let mut metacommunity_species =
+            MutableUtf8Array::<i32>::with_capacity(if self.metacommunity.is_some() {
+                self.origins.len()
+            } else {
+                0
+            });
 
         // Lineage ancestor union-find with path compression
         let mut family = Vec::new();
@@ -158,6 +168,16 @@ impl IndividualSpeciesFeatherReporter {
             } else {
                 species.extend_from_slice(&[0; 24]);
             }
+
+            // Only species that were originated by a migration into the
+            //  metacommunity draw a metacommunity species
+            if let Some(metacommunity) = &self.metacommunity {
+                metacommunity_species.push(
+                    self.species
+                        .get(&ancestor)
+                        .and_then(|identity| metacommunity.draw_migrant(identity)),
+                );
+            }
         }
 
         let ids = PrimitiveArray::from_vec(ids);
@@ -171,16 +191,23 @@ impl IndividualSpeciesFeatherReporter {
             Some(has_speciated.into()),
         )?;
 
-        let chunk = Chunk::try_new(vec![
+        let mut columns = vec![
             ids.boxed(),
             xs.boxed(),
             ys.boxed(),
             is.boxed(),
             parents.boxed(),
             species.boxed(),
-        ])?;
-        writer.write(&chunk, None)?;
+        ];
+
+        if self.metacommunity.is_some() {
+            let metacommunity_species: Utf8Array<i32> = metacommunity_species.into();
+
+            columns.push(metacommunity_species.boxed());
+        }
+
+        let chunk = Chunk::try_new(columns)?;
 
-        writer.finish()
+        write_dataframe(&self.output, self.format, schema, &chunk)
     }
 }
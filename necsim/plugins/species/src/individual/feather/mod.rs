@@ -1,4 +1,4 @@
-use std::{collections::HashMap, convert::TryFrom, fmt, fs::File, io::BufReader, path::PathBuf};
+use std::{collections::HashMap, convert::TryFrom, fmt, fs::File, path::PathBuf};
 
 use arrow2::{
     array::{FixedSizeBinaryArray, PrimitiveArray},
@@ -14,7 +14,11 @@ use necsim_core::{
 };
 use necsim_core_bond::NonNegativeF64;
 
-use crate::{LastEventState, SpeciesIdentity};
+use crate::{
+    dataframe::{read_dataframe, DataFrameFormat},
+    metacommunity::{Metacommunity, MetacommunityArgs},
+    LastEventState, SpeciesIdentity,
+};
 
 mod dataframe;
 mod reporter;
@@ -32,8 +36,13 @@ pub struct IndividualSpeciesFeatherReporter {
     // Species originator -> Species identities mapping
     species: HashMap<GlobalLineageReference, SpeciesIdentity, FnvBuildHasher>,
 
+    // Static external metacommunity from which the species are drawn
+    metacommunity: Option<Metacommunity>,
+
     output: PathBuf,
+    format: DataFrameFormat,
     mode: SpeciesLocationsMode,
+    metacommunity_args: Option<MetacommunityArgs>,
     init: bool,
 }
 
@@ -49,7 +58,9 @@ impl fmt::Debug for IndividualSpeciesFeatherReporter {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
         fmt.debug_struct(stringify!(IndividualSpeciesFeatherReporter))
             .field("output", &self.output)
+            .field("format", &self.format)
             .field("mode", &self.mode)
+            .field("metacommunity", &self.metacommunity_args)
             .finish_non_exhaustive()
     }
 }
@@ -58,7 +69,9 @@ impl serde::Serialize for IndividualSpeciesFeatherReporter {
     fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         IndividualSpeciesFeatherReporterArgs {
             output: self.output.clone(),
+            format: self.format,
             mode: self.mode.clone(),
+            metacommunity: self.metacommunity_args.clone(),
         }
         .serialize(serializer)
     }
@@ -69,6 +82,13 @@ impl<'de> Deserialize<'de> for IndividualSpeciesFeatherReporter {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         let args = IndividualSpeciesFeatherReporterArgs::deserialize(deserializer)?;
 
+        let metacommunity = args
+            .metacommunity
+            .as_ref()
+            .map(Metacommunity::try_from)
+            .transpose()
+            .map_err(serde::de::Error::custom)?;
+
         let mut self_last_parent_prior_time = None;
         let mut self_last_speciation_event = None;
         let mut self_last_dispersal_event = None;
@@ -78,14 +98,8 @@ impl<'de> Deserialize<'de> for IndividualSpeciesFeatherReporter {
         let mut self_species = HashMap::default();
 
         if matches!(args.mode, SpeciesLocationsMode::Resume) {
-            let file = File::options()
-                .read(true)
-                .open(&args.output)
-                .map_err(serde::de::Error::custom)?;
-            let mut reader = BufReader::new(file);
-
-            let metadata = arrow2::io::ipc::read::read_file_metadata(&mut reader)
-                .map_err(serde::de::Error::custom)?;
+            let (schema, chunks) =
+                read_dataframe(&args.output, args.format).map_err(serde::de::Error::custom)?;
 
             let expected_fields = vec![
                 Field::new("id", DataType::UInt64, false),
@@ -96,13 +110,22 @@ impl<'de> Deserialize<'de> for IndividualSpeciesFeatherReporter {
                 Field::new("species", DataType::FixedSizeBinary(24), true),
             ];
 
-            if metadata.schema.fields != expected_fields {
+            // The metacommunity species column is re-derived from the species
+            //  column and can thus be ignored when resuming
+            let has_expected_fields = schema.fields.starts_with(&expected_fields)
+                && match &schema.fields[expected_fields.len()..] {
+                    [] => true,
+                    [metacommunity] => metacommunity.name == "metacommunity",
+                    _ => false,
+                };
+
+            if !has_expected_fields {
                 return Err(serde::de::Error::custom(
                     "species dataframe schema mismatch",
                 ));
             }
 
-            let last_event = match metadata.schema.metadata.get("last-event") {
+            let last_event = match schema.metadata.get("last-event") {
                 Some(last_event) => LastEventState::from_string(last_event).map_err(|()| {
                     serde::de::Error::custom("invalid resume metadata in species dataframe")
                 })?,
@@ -117,10 +140,8 @@ impl<'de> Deserialize<'de> for IndividualSpeciesFeatherReporter {
             self_last_speciation_event = last_event.last_speciation_event;
             self_last_dispersal_event = last_event.last_dispersal_event;
 
-            for chunk in arrow2::io::ipc::read::FileReader::new(reader, metadata, None, None) {
-                let chunk = chunk.map_err(serde::de::Error::custom)?;
-
-                let [ids, xs, ys, is, parents, species] = chunk.columns() else {
+            for chunk in chunks {
+                let [ids, xs, ys, is, parents, species, ..] = chunk.columns() else {
                     return Err(serde::de::Error::custom(
                         "corrupted species dataframe schema",
                     ));
@@ -217,8 +238,12 @@ impl<'de> Deserialize<'de> for IndividualSpeciesFeatherReporter {
             parents: self_parents,
             species: self_species,
 
+            metacommunity,
+
             output: args.output,
+            format: args.format,
             mode: args.mode,
+            metacommunity_args: args.metacommunity,
             init: false,
         })
     }
@@ -230,7 +255,11 @@ impl<'de> Deserialize<'de> for IndividualSpeciesFeatherReporter {
 struct IndividualSpeciesFeatherReporterArgs {
     output: PathBuf,
     #[serde(default)]
+    format: DataFrameFormat,
+    #[serde(default)]
     mode: SpeciesLocationsMode,
+    #[serde(default)]
+    metacommunity: Option<MetacommunityArgs>,
 }
 
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
@@ -1,2 +1,3 @@
 pub mod feather;
+pub mod rates;
 pub mod sqlite;
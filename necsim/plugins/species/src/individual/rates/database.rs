@@ -0,0 +1,124 @@
+use necsim_core::{landscape::IndexedLocation, lineage::GlobalLineageReference};
+
+use rusqlite::types::Value;
+
+use super::IndividualSpeciesRatesSQLiteReporter;
+
+impl IndividualSpeciesRatesSQLiteReporter {
+    pub(super) fn store_individual_origin(
+        &mut self,
+        lineage: &GlobalLineageReference,
+        origin: &IndexedLocation,
+    ) {
+        self.origins.insert(lineage.clone(), origin.clone());
+
+        for genealogy in &mut self.genealogies {
+            genealogy.store_individual_origin(lineage);
+        }
+    }
+
+    pub(super) fn store_individual_coalescence(
+        &mut self,
+        child: &GlobalLineageReference,
+        parent: &GlobalLineageReference,
+    ) {
+        for genealogy in &mut self.genealogies {
+            genealogy.store_individual_coalescence(child, parent);
+        }
+    }
+
+    pub(super) fn initialise_sqlite_connection(&mut self) -> rusqlite::Result<()> {
+        self.connection
+            .pragma_update(None, "cache_size", self.cache.get())?;
+
+        self.connection.execute_batch(&format!(
+            "CREATE TABLE {} (
+                        id         INTEGER NOT NULL,
+                        x          INTEGER NOT NULL,
+                        y          INTEGER NOT NULL,
+                        i          INTEGER NOT NULL,
+                        speciation REAL NOT NULL,
+                        species    TEXT,
+                        PRIMARY KEY (id, speciation)
+                    );",
+            self.table,
+        ))?;
+
+        let mut schema: Vec<Vec<Value>> = Vec::new();
+
+        // Collect the schema information for the species rates table
+        self.connection
+            .pragma(None, "table_info", &self.table, |row| {
+                let mut schema_row = Vec::new();
+
+                for col in 0..row.as_ref().column_count() {
+                    schema_row.push(Value::from(row.get_ref(col)?));
+                }
+
+                schema.push(schema_row);
+
+                Ok(())
+            })?;
+
+        // Check that the species rates table has been created correctly
+        if schema.len() != 6 {
+            return Err(rusqlite::Error::SqliteFailure(
+                rusqlite::ffi::Error {
+                    code: rusqlite::ffi::ErrorCode::SchemaChanged,
+                    extended_code: 0,
+                },
+                Some(format!(
+                    "Invalid schema for the species rates table {}",
+                    self.table
+                )),
+            ));
+        }
+
+        Ok(())
+    }
+
+    pub(super) fn output_to_database(mut self) -> rusqlite::Result<()> {
+        let tx = self
+            .connection
+            .transaction_with_behavior(rusqlite::TransactionBehavior::Exclusive)?;
+
+        let mut insertion = tx.prepare(&format!(
+            "INSERT OR REPLACE INTO {} VALUES (:id, :x, :y, :i, :speciation, :species)",
+            self.table,
+        ))?;
+
+        for genealogy in self.genealogies {
+            let rate = genealogy.rate();
+
+            for (lineage, species) in genealogy.into_species() {
+                let Some(origin) = self.origins.get(&lineage) else {
+                    continue;
+                };
+
+                // Positional parameters boost performance
+                insertion.execute(rusqlite::params![
+                    /* :id */ to_i64(unsafe { lineage.into_inner() }),
+                    /* :x */ to_i32(origin.location().x()),
+                    /* :y */ to_i32(origin.location().y()),
+                    /* :i */ to_i32(origin.index()),
+                    /* :speciation */ rate.get(),
+                    /* :species */ species.map(|species| hex::encode(*species)),
+                ])?;
+            }
+        }
+
+        insertion.finalize()?;
+
+        tx.commit()?;
+
+        self.connection.close().map_err(|(_, err)| err)
+    }
+}
+
+const fn to_i32(x: u32) -> i32 {
+    i32::from_ne_bytes(x.to_ne_bytes())
+}
+
+const fn to_i64(x: u64) -> i64 {
+    i64::from_ne_bytes(x.to_ne_bytes())
+}
@@ -0,0 +1,144 @@
+use std::collections::HashMap;
+
+use fnv::FnvBuildHasher;
+
+use necsim_core::{landscape::IndexedLocation, lineage::GlobalLineageReference};
+use necsim_core_bond::{ClosedUnitF64, OpenClosedUnitF64 as PositiveUnitF64, PositiveF64};
+
+use crate::SpeciesIdentity;
+
+/// Genealogy of the sampled individuals at a single speciation rate
+///
+/// Every lineage of the simulation points to the ancestral cluster of all
+///  individuals that have coalesced into it since it last speciated at this
+///  rate. The clusters are connected by a union-find structure, whose roots
+///  store the species identities.
+#[allow(clippy::module_name_repetitions)]
+pub struct SpeciationRateGenealogy {
+    rate: PositiveUnitF64,
+
+    // Original (present-time) ancestral clusters of all lineages
+    origins: HashMap<GlobalLineageReference, usize, FnvBuildHasher>,
+    // Lineage -> Current ancestral cluster mapping
+    clusters: HashMap<GlobalLineageReference, usize, FnvBuildHasher>,
+    // Child -> Parent ancestral cluster union-find
+    parents: Vec<usize>,
+    // Ancestral cluster -> Species identities mapping
+    species: HashMap<usize, SpeciesIdentity, FnvBuildHasher>,
+}
+
+impl SpeciationRateGenealogy {
+    #[must_use]
+    pub fn new(rate: PositiveUnitF64) -> Self {
+        Self {
+            rate,
+
+            origins: HashMap::default(),
+            clusters: HashMap::default(),
+            parents: Vec::new(),
+            species: HashMap::default(),
+        }
+    }
+
+    #[must_use]
+    pub fn rate(&self) -> PositiveUnitF64 {
+        self.rate
+    }
+
+    /// Checks if an event with the uniform speciation `sample` would have
+    ///  been a speciation at this genealogy's rate
+    #[must_use]
+    pub fn speciates(&self, sample: ClosedUnitF64) -> bool {
+        sample < self.rate.get()
+    }
+
+    pub fn store_individual_origin(&mut self, lineage: &GlobalLineageReference) {
+        let cluster = self.cluster(lineage);
+
+        self.origins.insert(lineage.clone(), cluster);
+    }
+
+    pub fn store_individual_speciation(
+        &mut self,
+        lineage: &GlobalLineageReference,
+        origin: &IndexedLocation,
+        time: PositiveF64,
+    ) {
+        let cluster = self.cluster(lineage);
+        let ancestor = self.ancestor(cluster);
+
+        self.species
+            .insert(ancestor, SpeciesIdentity::from_speciation(origin, time));
+
+        // All individuals that coalesce into the lineage later on belong to a
+        //  new ancestral cluster that has not yet speciated
+        let cluster = self.new_cluster();
+        self.clusters.insert(lineage.clone(), cluster);
+    }
+
+    pub fn store_individual_coalescence(
+        &mut self,
+        child: &GlobalLineageReference,
+        parent: &GlobalLineageReference,
+    ) {
+        let child_cluster = self.cluster(child);
+        let child_ancestor = self.ancestor(child_cluster);
+
+        let parent_cluster = self.cluster(parent);
+        let parent_ancestor = self.ancestor(parent_cluster);
+
+        // Prevent a lookup-loop, can occur after `Resume`
+        if child_ancestor != parent_ancestor {
+            self.parents[child_ancestor] = parent_ancestor;
+        }
+    }
+
+    /// Returns the species identities of all sampled individuals, which
+    ///  have speciated at this genealogy's rate
+    pub fn into_species(
+        mut self,
+    ) -> impl Iterator<Item = (GlobalLineageReference, Option<SpeciesIdentity>)> {
+        let origins = std::mem::take(&mut self.origins);
+
+        origins.into_iter().map(move |(lineage, cluster)| {
+            let ancestor = self.ancestor(cluster);
+
+            (lineage, self.species.get(&ancestor).cloned())
+        })
+    }
+
+    fn cluster(&mut self, lineage: &GlobalLineageReference) -> usize {
+        if let Some(cluster) = self.clusters.get(lineage) {
+            return *cluster;
+        }
+
+        let cluster = self.new_cluster();
+        self.clusters.insert(lineage.clone(), cluster);
+
+        cluster
+    }
+
+    fn new_cluster(&mut self) -> usize {
+        let cluster = self.parents.len();
+        self.parents.push(cluster);
+
+        cluster
+    }
+
+    fn ancestor(&mut self, cluster: usize) -> usize {
+        let mut ancestor = cluster;
+        while self.parents[ancestor] != ancestor {
+            ancestor = self.parents[ancestor];
+        }
+
+        // Compress the ancestry path for all visited clusters
+        let mut child = cluster;
+        while self.parents[child] != ancestor {
+            let parent = self.parents[child];
+            self.parents[child] = ancestor;
+            child = parent;
+        }
+
+        ancestor
+    }
+}
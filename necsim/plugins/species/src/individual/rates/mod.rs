@@ -0,0 +1,157 @@
+use std::{collections::HashMap, fmt, num::NonZeroI32, path::PathBuf};
+
+use fnv::FnvBuildHasher;
+use rusqlite::{Connection, OpenFlags};
+use serde::{Deserialize, Deserializer, Serialize};
+
+use necsim_core::{
+    event::{DispersalEvent, SpeciationEvent},
+    landscape::IndexedLocation,
+    lineage::GlobalLineageReference,
+};
+use necsim_core_bond::OpenClosedUnitF64 as PositiveUnitF64;
+
+mod database;
+mod genealogy;
+mod reporter;
+
+#[cfg(test)]
+mod tests;
+
+use genealogy::SpeciationRateGenealogy;
+
+/// Reporter that re-derives the species identities of all individuals for
+///  several speciation rates from a single simulation which was run at the
+///  `minimum` speciation rate.
+///
+/// Every event reports the uniform sample which decided whether the lineage
+///  speciated, i.e. the lineage speciated iff the sample lies below the
+///  speciation probability. A dispersal event, at which the lineage did not
+///  speciate at the `minimum` rate, is thus a speciation at a higher rate iff
+///  its sample lies below that rate. The species for the different rates are
+///  therefore nested, and replaying an event log from the `Independent`
+///  algorithm produces exactly the species of a direct simulation at the
+///  higher rate.
+///
+/// The reporter assumes that the speciation probability was uniform across
+///  the landscape and that speciation was not protracted. The event-skipping
+///  Gillespie algorithm does not report its trivial self-dispersal events,
+///  whose speciation decisions are thus lost, and should not be used to
+///  simulate the events for this reporter.
+#[allow(clippy::module_name_repetitions)]
+pub struct IndividualSpeciesRatesSQLiteReporter {
+    last_parent: Option<GlobalLineageReference>,
+    last_speciation_event: Option<SpeciationEvent>,
+    last_dispersal_event: Option<DispersalEvent>,
+
+    // Original (present-time) locations of all lineages
+    origins: HashMap<GlobalLineageReference, IndexedLocation, FnvBuildHasher>,
+    // Speciation rate -> Lineage genealogy mapping
+    genealogies: Vec<SpeciationRateGenealogy>,
+
+    output: PathBuf,
+    table: String,
+    minimum: PositiveUnitF64,
+    cache: NonZeroI32,
+
+    connection: Connection,
+}
+
+impl fmt::Debug for IndividualSpeciesRatesSQLiteReporter {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct(stringify!(IndividualSpeciesRatesSQLiteReporter))
+            .field("output", &self.output)
+            .field("table", &self.table)
+            .field("minimum", &self.minimum)
+            .field(
+                "rates",
+                &self
+                    .genealogies
+                    .iter()
+                    .map(SpeciationRateGenealogy::rate)
+                    .collect::<Vec<_>>(),
+            )
+            .field("cache", &self.cache)
+            .finish_non_exhaustive()
+    }
+}
+
+impl serde::Serialize for IndividualSpeciesRatesSQLiteReporter {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        IndividualSpeciesRatesSQLiteReporterArgs {
+            output: self.output.clone(),
+            table: self.table.clone(),
+            minimum: self.minimum,
+            rates: self
+                .genealogies
+                .iter()
+                .map(SpeciationRateGenealogy::rate)
+                .collect(),
+            cache: self.cache,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for IndividualSpeciesRatesSQLiteReporter {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut args = IndividualSpeciesRatesSQLiteReporterArgs::deserialize(deserializer)?;
+
+        if let Some(rate) = args.rates.iter().find(|rate| **rate < args.minimum) {
+            return Err(serde::de::Error::custom(format!(
+                "speciation rate {rate} is below the minimum speciation rate {}",
+                args.minimum
+            )));
+        }
+
+        args.rates.sort_unstable();
+        args.rates.dedup();
+
+        let connection = Connection::open_with_flags(
+            &args.output,
+            OpenFlags::SQLITE_OPEN_CREATE | OpenFlags::SQLITE_OPEN_READ_WRITE,
+        )
+        .map_err(serde::de::Error::custom)?;
+
+        Ok(Self {
+            last_parent: None,
+            last_speciation_event: None,
+            last_dispersal_event: None,
+
+            origins: HashMap::default(),
+            genealogies: args
+                .rates
+                .into_iter()
+                .map(SpeciationRateGenealogy::new)
+                .collect(),
+
+            output: args.output,
+            table: args.table,
+            minimum: args.minimum,
+            cache: args.cache,
+
+            connection,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+#[serde(rename = "IndividualSpeciesRatesSQLiteReporter")]
+struct IndividualSpeciesRatesSQLiteReporterArgs {
+    output: PathBuf,
+    #[serde(default = "default_table_name")]
+    table: String,
+    minimum: PositiveUnitF64,
+    rates: Vec<PositiveUnitF64>,
+    #[serde(default = "default_cache_size")]
+    cache: NonZeroI32,
+}
+
+fn default_table_name() -> String {
+    String::from("SPECIES_RATES")
+}
+
+fn default_cache_size() -> NonZeroI32 {
+    NonZeroI32::new(1_000_000_i32).unwrap()
+}
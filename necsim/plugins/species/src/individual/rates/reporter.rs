@@ -0,0 +1,72 @@
+use necsim_core::{impl_finalise, impl_report, reporter::Reporter};
+
+use super::IndividualSpeciesRatesSQLiteReporter;
+
+impl Reporter for IndividualSpeciesRatesSQLiteReporter {
+    impl_report!(speciation(&mut self, speciation: Used) {
+        if speciation.prior_time == 0.0_f64 {
+            self.store_individual_origin(&speciation.global_lineage_reference, &speciation.origin);
+        }
+
+        // A speciation at the minimum rate is a speciation at all higher rates
+        for genealogy in &mut self.genealogies {
+            genealogy.store_individual_speciation(
+                &speciation.global_lineage_reference, &speciation.origin, speciation.event_time,
+            );
+        }
+
+        if Some(speciation) == self.last_speciation_event.as_ref() {
+            if let Some(parent) = self.last_parent.clone() {
+                self.store_individual_coalescence(&speciation.global_lineage_reference, &parent);
+            }
+        }
+
+        self.last_speciation_event = Some(speciation.clone());
+        self.last_parent = Some(speciation.global_lineage_reference.clone());
+    });
+
+    impl_report!(dispersal(&mut self, dispersal: Used) {
+        if dispersal.prior_time == 0.0_f64 {
+            self.store_individual_origin(&dispersal.global_lineage_reference, &dispersal.origin);
+        }
+
+        // The dispersal might have been a speciation at a higher rate
+        for genealogy in &mut self.genealogies {
+            if genealogy.speciates(dispersal.speciation_sample) {
+                genealogy.store_individual_speciation(
+                    &dispersal.global_lineage_reference, &dispersal.origin, dispersal.event_time,
+                );
+            }
+        }
+
+        // Duplicate events of coalesced individuals are linked even if they
+        //  already coalesced earlier, since the duplicates might have split
+        //  into separate ancestral clusters when they speciated at a higher rate
+        if Some(dispersal) == self.last_dispersal_event.as_ref() {
+            if let Some(parent) = self.last_parent.clone() {
+                self.store_individual_coalescence(&dispersal.global_lineage_reference, &parent);
+            }
+        } else if let Some(ref parent) = dispersal.interaction.parent() {
+            self.store_individual_coalescence(&dispersal.global_lineage_reference, parent);
+        }
+
+        self.last_dispersal_event = Some(dispersal.clone());
+        self.last_parent = Some(dispersal.global_lineage_reference.clone());
+    });
+
+    impl_report!(progress(&mut self, _progress: Ignored) {});
+
+    impl_finalise!((mut self) {
+        let table = self.table.clone();
+        let output = self.output.clone();
+
+        if let Err(err) = self.output_to_database() {
+            error!("Failed to write the lineage species to table {table:?} at {output:?}:\n{err}");
+        }
+    });
+
+    fn initialise(&mut self) -> Result<(), String> {
+        self.initialise_sqlite_connection()
+            .map_err(|err| format!("Failed to initialise the SQLite species rates list:\n{err}",))
+    }
+}
@@ -0,0 +1,116 @@
+use std::{collections::HashMap, path::PathBuf};
+
+use necsim_core::reporter::Reporter;
+
+use rustcoalescence_api::simulate::simulate_with_reporter;
+
+use crate::individual::sqlite::IndividualSpeciesSQLiteReporter;
+
+use super::IndividualSpeciesRatesSQLiteReporter;
+
+const MINIMUM: f64 = 0.01;
+const RATES: [f64; 2] = [0.1, 0.5];
+
+struct TempDatabase(PathBuf);
+
+impl TempDatabase {
+    fn new(name: &str) -> Self {
+        Self(std::env::temp_dir().join(format!(
+            "necsim-speciation-rates-{}-{name}.sqlite",
+            std::process::id()
+        )))
+    }
+}
+
+impl Drop for TempDatabase {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
+
+fn simulate_independent<R: Reporter>(speciation: f64, reporter: R) {
+    let config = format!(
+        "(
+            speciation: {speciation},
+            sample: Sample(percentage: 1.0),
+            rng: Seed(42),
+            maths: Reproducible,
+            algorithm: Independent(),
+            scenario: NonSpatial(area: (10, 10), deme: 10),
+            reporters: [],
+        )"
+    );
+
+    simulate_with_reporter(&config, reporter).expect("the simulation succeeds");
+}
+
+fn read_species(database: &TempDatabase, query: &str) -> HashMap<i64, Option<String>> {
+    let connection = rusqlite::Connection::open(&database.0).expect("the database exists");
+
+    let mut statement = connection.prepare(query).expect("the query is valid");
+
+    let species = statement
+        .query_map([], |row| Ok((row.get("id")?, row.get("species")?)))
+        .expect("the species can be queried")
+        .collect::<Result<HashMap<_, _>, _>>()
+        .expect("the species can be read");
+
+    species
+}
+
+/// Re-deriving the species of a simulation at the minimum speciation rate for
+///  a higher rate, using the speciation samples recorded in its events,
+///  reproduces the species of a direct simulation at the higher rate
+#[test]
+fn replayed_rates_match_direct_simulations() {
+    let replayed = TempDatabase::new("replayed");
+
+    simulate_independent(
+        MINIMUM,
+        ron::from_str::<IndividualSpeciesRatesSQLiteReporter>(&format!(
+            "IndividualSpeciesRatesSQLiteReporter(output: {:?}, minimum: {MINIMUM}, rates: \
+             {RATES:?})",
+            replayed.0,
+        ))
+        .expect("the rates reporter is valid"),
+    );
+
+    let mut richness = Vec::new();
+
+    for rate in RATES {
+        let direct = TempDatabase::new(&format!("direct-{rate}"));
+
+        simulate_independent(
+            rate,
+            ron::from_str::<IndividualSpeciesSQLiteReporter>(&format!(
+                "IndividualSpeciesSQLiteReporter(output: {:?})",
+                direct.0,
+            ))
+            .expect("the species reporter is valid"),
+        );
+
+        let replayed_species = read_species(
+            &replayed,
+            &format!("SELECT id, species FROM SPECIES_RATES WHERE speciation = {rate}"),
+        );
+        let direct_species = read_species(&direct, "SELECT id, species FROM SPECIES_LOCATIONS");
+
+        assert_eq!(direct_species.len(), 1000);
+        assert!(direct_species.values().all(Option::is_some));
+        assert_eq!(
+            replayed_species, direct_species,
+            "the species replayed at rate {rate} differ from a direct simulation"
+        );
+
+        let mut species = direct_species.into_values().collect::<Vec<_>>();
+        species.sort_unstable();
+        species.dedup();
+
+        richness.push(species.len());
+    }
+
+    assert!(
+        richness[0] < richness[1],
+        "a higher speciation rate produces more species"
+    );
+}
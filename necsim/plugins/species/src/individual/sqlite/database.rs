@@ -33,6 +33,12 @@ impl IndividualSpeciesSQLiteReporter {
             parent = parent_parent;
         }
 
+        // A lineage that migrated into the static metacommunity has already
+        //  originated its species there
+        if self.species.contains_key(parent) {
+            return;
+        }
+
         self.species.insert(
             parent.clone(),
             SpeciesIdentity::from_speciation(origin, time),
@@ -51,6 +57,12 @@ impl IndividualSpeciesSQLiteReporter {
         }
         let child = child.clone();
 
+        // The ancestry of a lineage that migrated into the static
+        //  metacommunity, which has originated its species, is ignored
+        if self.species.contains_key(&child) {
+            return;
+        }
+
         // Resolve the actual parent, irrespective of duplicate individuals
         let mut parent = parent;
         while let Some(parent_parent) = self.parents.get(parent) {
@@ -78,13 +90,18 @@ impl IndividualSpeciesSQLiteReporter {
                             y       INTEGER NOT NULL,
                             i       INTEGER NOT NULL,
                             parent  INTEGER,
-                            species TEXT
+                            species TEXT{}
                         );
                         CREATE TABLE {METADATA_TABLE} (
                             key     TEXT PRIMARY KEY NOT NULL,
                             value   TEXT NOT NULL
                         );",
                 self.table,
+                if self.metacommunity.is_some() {
+                    ",\n                            metacommunity TEXT"
+                } else {
+                    ""
+                },
             ))?;
         }
 
@@ -104,59 +121,72 @@ impl IndividualSpeciesSQLiteReporter {
                 Ok(())
             })?;
 
+        let mut expected_schema = vec![
+            vec![
+                Value::Integer(0),
+                Value::Text(String::from("id")),
+                Value::Text(String::from("INTEGER")),
+                Value::Integer(1),
+                Value::Null,
+                Value::Integer(1),
+            ],
+            vec![
+                Value::Integer(1),
+                Value::Text(String::from("x")),
+                Value::Text(String::from("INTEGER")),
+                Value::Integer(1),
+                Value::Null,
+                Value::Integer(0),
+            ],
+            vec![
+                Value::Integer(2),
+                Value::Text(String::from("y")),
+                Value::Text(String::from("INTEGER")),
+                Value::Integer(1),
+                Value::Null,
+                Value::Integer(0),
+            ],
+            vec![
+                Value::Integer(3),
+                Value::Text(String::from("i")),
+                Value::Text(String::from("INTEGER")),
+                Value::Integer(1),
+                Value::Null,
+                Value::Integer(0),
+            ],
+            vec![
+                Value::Integer(4),
+                Value::Text(String::from("parent")),
+                Value::Text(String::from("INTEGER")),
+                Value::Integer(0),
+                Value::Null,
+                Value::Integer(0),
+            ],
+            vec![
+                Value::Integer(5),
+                Value::Text(String::from("species")),
+                Value::Text(String::from("TEXT")),
+                Value::Integer(0),
+                Value::Null,
+                Value::Integer(0),
+            ],
+        ];
+
+        // The metacommunity species column is re-derived from the species
+        //  column and can thus be ignored when resuming
+        if self.metacommunity.is_some() {
+            expected_schema.push(vec![
+                Value::Integer(6),
+                Value::Text(String::from("metacommunity")),
+                Value::Text(String::from("TEXT")),
+                Value::Integer(0),
+                Value::Null,
+                Value::Integer(0),
+            ]);
+        }
+
         // Check that the schema of the species locations table matches
-        if schema
-            != vec![
-                vec![
-                    Value::Integer(0),
-                    Value::Text(String::from("id")),
-                    Value::Text(String::from("INTEGER")),
-                    Value::Integer(1),
-                    Value::Null,
-                    Value::Integer(1),
-                ],
-                vec![
-                    Value::Integer(1),
-                    Value::Text(String::from("x")),
-                    Value::Text(String::from("INTEGER")),
-                    Value::Integer(1),
-                    Value::Null,
-                    Value::Integer(0),
-                ],
-                vec![
-                    Value::Integer(2),
-                    Value::Text(String::from("y")),
-                    Value::Text(String::from("INTEGER")),
-                    Value::Integer(1),
-                    Value::Null,
-                    Value::Integer(0),
-                ],
-                vec![
-                    Value::Integer(3),
-                    Value::Text(String::from("i")),
-                    Value::Text(String::from("INTEGER")),
-                    Value::Integer(1),
-                    Value::Null,
-                    Value::Integer(0),
-                ],
-                vec![
-                    Value::Integer(4),
-                    Value::Text(String::from("parent")),
-                    Value::Text(String::from("INTEGER")),
-                    Value::Integer(0),
-                    Value::Null,
-                    Value::Integer(0),
-                ],
-                vec![
-                    Value::Integer(5),
-                    Value::Text(String::from("species")),
-                    Value::Text(String::from("TEXT")),
-                    Value::Integer(0),
-                    Value::Null,
-                    Value::Integer(0),
-                ],
-            ]
-        {
+        if schema != expected_schema {
             return Err(rusqlite::Error::SqliteFailure(
                 rusqlite::ffi::Error {
                     code: rusqlite::ffi::ErrorCode::SchemaChanged,
@@ -320,8 +350,13 @@ impl IndividualSpeciesSQLiteReporter {
             .transaction_with_behavior(rusqlite::TransactionBehavior::Exclusive)?;
 
         let mut insertion = tx.prepare(&format!(
-            "INSERT OR REPLACE INTO {} VALUES (:id, :x, :y, :i, :parent, :species)",
+            "INSERT OR REPLACE INTO {} VALUES (:id, :x, :y, :i, :parent, :species{})",
             self.table,
+            if self.metacommunity.is_some() {
+                ", :metacommunity"
+            } else {
+                ""
+            },
         ))?;
 
         // Lineage ancestor union-find with path compression
@@ -342,21 +377,37 @@ impl IndividualSpeciesSQLiteReporter {
                 ancestors.insert(child, ancestor.clone());
             }
 
+            let id = to_i64(unsafe { lineage.clone().into_inner() });
+            let parent = self
+                .parents
+                .get(&lineage)
+                .map(|parent| to_i64(unsafe { parent.clone().into_inner() }));
+            let species = self.species.get(&ancestor);
+
             // Positional parameters boost performance
-            insertion.execute(rusqlite::params![
-                /* :id */ to_i64(unsafe { lineage.clone().into_inner() }),
-                /* :x */ to_i32(origin.location().x()),
-                /* :y */ to_i32(origin.location().y()),
-                /* :i */ to_i32(origin.index()),
-                /* :parent */
-                self.parents
-                    .get(&lineage)
-                    .map(|parent| to_i64(unsafe { parent.clone().into_inner() })),
-                /* :species */
-                self.species
-                    .get(&ancestor)
-                    .map(|species| hex::encode(**species)),
-            ])?;
+            if let Some(metacommunity) = &self.metacommunity {
+                // Only species that were originated by a migration into the
+                //  metacommunity draw a metacommunity species
+                insertion.execute(rusqlite::params![
+                    /* :id */ id,
+                    /* :x */ to_i32(origin.location().x()),
+                    /* :y */ to_i32(origin.location().y()),
+                    /* :i */ to_i32(origin.index()),
+                    /* :parent */ parent,
+                    /* :species */ species.map(|species| hex::encode(**species)),
+                    /* :metacommunity */
+                    species.and_then(|species| metacommunity.draw_migrant(species)),
+                ])?;
+            } else {
+                insertion.execute(rusqlite::params![
+                    /* :id */ id,
+                    /* :x */ to_i32(origin.location().x()),
+                    /* :y */ to_i32(origin.location().y()),
+                    /* :i */ to_i32(origin.index()),
+                    /* :parent */ parent,
+                    /* :species */ species.map(|species| hex::encode(**species)),
+                ])?;
+            }
         }
 
         insertion.finalize()?;
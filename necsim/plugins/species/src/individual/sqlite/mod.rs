@@ -11,7 +11,10 @@ use necsim_core::{
 };
 use necsim_core_bond::NonNegativeF64;
 
-use crate::SpeciesIdentity;
+use crate::{
+    metacommunity::{Metacommunity, MetacommunityArgs},
+    SpeciesIdentity,
+};
 
 mod database;
 mod reporter;
@@ -29,10 +32,14 @@ pub struct IndividualSpeciesSQLiteReporter {
     // Species originator -> Species identities mapping
     species: HashMap<GlobalLineageReference, SpeciesIdentity, FnvBuildHasher>,
 
+    // Static external metacommunity from which the species are drawn
+    metacommunity: Option<Metacommunity>,
+
     output: PathBuf,
     table: String,
     mode: SpeciesLocationsMode,
     cache: NonZeroI32,
+    metacommunity_args: Option<MetacommunityArgs>,
 
     connection: Connection,
 }
@@ -44,6 +51,7 @@ impl fmt::Debug for IndividualSpeciesSQLiteReporter {
             .field("table", &self.table)
             .field("mode", &self.mode)
             .field("cache", &self.cache)
+            .field("metacommunity", &self.metacommunity_args)
             .finish_non_exhaustive()
     }
 }
@@ -55,6 +63,7 @@ impl serde::Serialize for IndividualSpeciesSQLiteReporter {
             table: self.table.clone(),
             mode: self.mode.clone(),
             cache: self.cache,
+            metacommunity: self.metacommunity_args.clone(),
         }
         .serialize(serializer)
     }
@@ -64,6 +73,13 @@ impl<'de> Deserialize<'de> for IndividualSpeciesSQLiteReporter {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         let args = IndividualSpeciesSQLiteReporterArgs::deserialize(deserializer)?;
 
+        let metacommunity = args
+            .metacommunity
+            .as_ref()
+            .map(Metacommunity::try_from)
+            .transpose()
+            .map_err(serde::de::Error::custom)?;
+
         let connection = Connection::open_with_flags(
             &args.output,
             match args.mode {
@@ -84,10 +100,13 @@ impl<'de> Deserialize<'de> for IndividualSpeciesSQLiteReporter {
             parents: HashMap::default(),
             species: HashMap::default(),
 
+            metacommunity,
+
             output: args.output,
             table: args.table,
             mode: args.mode,
             cache: args.cache,
+            metacommunity_args: args.metacommunity,
 
             connection,
         })
@@ -105,6 +124,8 @@ struct IndividualSpeciesSQLiteReporterArgs {
     mode: SpeciesLocationsMode,
     #[serde(default = "default_cache_size")]
     cache: NonZeroI32,
+    #[serde(default)]
+    metacommunity: Option<MetacommunityArgs>,
 }
 
 fn default_table_name() -> String {
@@ -37,8 +37,16 @@ impl Reporter for IndividualSpeciesSQLiteReporter {
                     self.store_individual_coalescence(&dispersal.global_lineage_reference, &parent);
                 }
             }
-        } else if let Some(ref parent) = dispersal.interaction.parent() {
-            self.store_individual_coalescence(&dispersal.global_lineage_reference, parent);
+        } else {
+            // A lineage that migrates into the static metacommunity originates
+            //  its species from there at its migration
+            if matches!(&self.metacommunity, Some(metacommunity) if metacommunity.is_migration(dispersal)) {
+                self.store_individual_speciation(&dispersal.global_lineage_reference, &dispersal.target, dispersal.event_time);
+            }
+
+            if let Some(ref parent) = dispersal.interaction.parent() {
+                self.store_individual_coalescence(&dispersal.global_lineage_reference, parent);
+            }
         }
 
         self.last_dispersal_event = Some(dispersal.clone());
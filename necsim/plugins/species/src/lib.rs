@@ -3,17 +3,25 @@
 #[macro_use]
 extern crate log;
 
-mod identity;
+mod curves;
+mod dataframe;
+mod diversity;
+mod genealogy;
 mod individual;
 mod location;
+mod metacommunity;
 mod state;
 
-use identity::SpeciesIdentity;
+use necsim_plugins_species_core::SpeciesIdentity;
 use state::LastEventState;
 
 // Register the reporter plugins
 necsim_plugins_core::export_plugin!(
     IndividualSpeciesSQLite => individual::sqlite::IndividualSpeciesSQLiteReporter,
+    IndividualSpeciesRatesSQLite => individual::rates::IndividualSpeciesRatesSQLiteReporter,
     IndividualSpeciesFeather => individual::feather::IndividualSpeciesFeatherReporter,
     LocationSpeciesFeather => location::feather::LocationSpeciesFeatherReporter,
+    SpeciesDiversitySummary => diversity::SpeciesDiversitySummaryReporter,
+    SpeciesAreaDistanceDecay => curves::SpeciesAreaDistanceDecayReporter,
+    GenealogyTree => genealogy::GenealogyTreeReporter,
 );
@@ -1,15 +1,10 @@
-use std::{
-    collections::{hash_map::Entry, BTreeMap, HashMap},
-    fs::File,
-    io::BufWriter,
-};
+use std::collections::{hash_map::Entry, BTreeMap, HashMap};
 
 use arrow2::{
     array::{FixedSizeBinaryArray, PrimitiveArray},
     buffer::Buffer,
     chunk::Chunk,
     datatypes::{DataType, Field, Schema},
-    io::ipc::write::{FileWriter, WriteOptions},
 };
 use fnv::FnvBuildHasher;
 use necsim_core::{
@@ -18,7 +13,7 @@ use necsim_core::{
 };
 use necsim_core_bond::PositiveF64;
 
-use crate::{LastEventState, SpeciesIdentity};
+use crate::{dataframe::write_dataframe, LastEventState, SpeciesIdentity};
 
 use super::LocationSpeciesFeatherReporter;
 
@@ -76,12 +71,6 @@ impl LocationSpeciesFeatherReporter {
 
     #[allow(clippy::too_many_lines)]
     pub(super) fn output_to_dataframe(mut self) -> arrow2::error::Result<()> {
-        let file = File::options()
-            .write(true)
-            .truncate(true)
-            .open(&self.output)?;
-        let writer = BufWriter::new(file);
-
         let expected_fields = vec![
             Field::new("x", DataType::UInt32, false),
             Field::new("y", DataType::UInt32, false),
@@ -106,16 +95,10 @@ impl LocationSpeciesFeatherReporter {
             })?,
         );
 
-        let mut writer = FileWriter::new(
-            writer,
-            Schema {
-                fields: expected_fields,
-                metadata,
-            },
-            None,
-            WriteOptions { compression: None },
-        );
-        writer.start()?;
+        let schema = Schema {
+            fields: expected_fields,
+            metadata,
+        };
 
         let mut xs = Vec::new();
         let mut ys = Vec::new();
@@ -249,8 +232,7 @@ impl LocationSpeciesFeatherReporter {
             species.boxed(),
             counts.boxed(),
         ])?;
-        writer.write(&chunk, None)?;
 
-        writer.finish()
+        write_dataframe(&self.output, self.format, schema, &chunk)
     }
 }
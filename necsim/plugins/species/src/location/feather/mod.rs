@@ -1,4 +1,4 @@
-use std::{collections::HashMap, convert::TryFrom, fmt, fs::File, io::BufReader, path::PathBuf};
+use std::{collections::HashMap, convert::TryFrom, fmt, fs::File, path::PathBuf};
 
 use arrow2::{
     array::{FixedSizeBinaryArray, PrimitiveArray},
@@ -14,7 +14,10 @@ use necsim_core::{
 };
 use necsim_core_bond::{ClosedUnitF64, NonNegativeF64, PositiveF64};
 
-use crate::{LastEventState, SpeciesIdentity};
+use crate::{
+    dataframe::{read_dataframe, DataFrameFormat},
+    LastEventState, SpeciesIdentity,
+};
 
 mod dataframe;
 mod reporter;
@@ -41,6 +44,7 @@ pub struct LocationSpeciesFeatherReporter {
     speciated: Vec<(Location, SpeciesIdentity, u64)>,
 
     output: PathBuf,
+    format: DataFrameFormat,
     deduplication_probability: ClosedUnitF64,
     mode: SpeciesLocationsMode,
     init: bool,
@@ -58,6 +62,7 @@ impl fmt::Debug for LocationSpeciesFeatherReporter {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
         fmt.debug_struct(stringify!(LocationSpeciesFeatherReporter))
             .field("output", &self.output)
+            .field("format", &self.format)
             .field(
                 "deduplication",
                 &SpeciesDeduplicationMode::from(self.deduplication_probability),
@@ -71,6 +76,7 @@ impl serde::Serialize for LocationSpeciesFeatherReporter {
     fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         LocationSpeciesFeatherReporterArgs {
             output: self.output.clone(),
+            format: self.format,
             deduplication: SpeciesDeduplicationMode::from(self.deduplication_probability),
             mode: self.mode.clone(),
         }
@@ -95,14 +101,8 @@ impl<'de> Deserialize<'de> for LocationSpeciesFeatherReporter {
         let mut self_speciated = Vec::default();
 
         if matches!(args.mode, SpeciesLocationsMode::Resume) {
-            let file = File::options()
-                .read(true)
-                .open(&args.output)
-                .map_err(serde::de::Error::custom)?;
-            let mut reader = BufReader::new(file);
-
-            let metadata = arrow2::io::ipc::read::read_file_metadata(&mut reader)
-                .map_err(serde::de::Error::custom)?;
+            let (schema, chunks) =
+                read_dataframe(&args.output, args.format).map_err(serde::de::Error::custom)?;
 
             let expected_fields = vec![
                 Field::new("x", DataType::UInt32, false),
@@ -111,13 +111,13 @@ impl<'de> Deserialize<'de> for LocationSpeciesFeatherReporter {
                 Field::new("count", DataType::UInt64, false),
             ];
 
-            if metadata.schema.fields != expected_fields {
+            if schema.fields != expected_fields {
                 return Err(serde::de::Error::custom(
                     "species dataframe schema mismatch",
                 ));
             }
 
-            let last_event = match metadata.schema.metadata.get("last-event") {
+            let last_event = match schema.metadata.get("last-event") {
                 Some(last_event) => LastEventState::from_string(last_event).map_err(|()| {
                     serde::de::Error::custom("invalid resume metadata in species dataframe")
                 })?,
@@ -132,9 +132,7 @@ impl<'de> Deserialize<'de> for LocationSpeciesFeatherReporter {
             self_last_speciation_event = last_event.last_speciation_event;
             self_last_dispersal_event = last_event.last_dispersal_event;
 
-            for chunk in arrow2::io::ipc::read::FileReader::new(reader, metadata, None, None) {
-                let chunk = chunk.map_err(serde::de::Error::custom)?;
-
+            for chunk in chunks {
                 let [xs, ys, species, counts] = chunk.columns() else {
                     return Err(serde::de::Error::custom(
                         "corrupted species dataframe schema",
@@ -227,6 +225,7 @@ impl<'de> Deserialize<'de> for LocationSpeciesFeatherReporter {
             },
 
             output: args.output,
+            format: args.format,
             mode: args.mode,
             init: false,
         })
@@ -239,6 +238,8 @@ impl<'de> Deserialize<'de> for LocationSpeciesFeatherReporter {
 struct LocationSpeciesFeatherReporterArgs {
     output: PathBuf,
     #[serde(default)]
+    format: DataFrameFormat,
+    #[serde(default)]
     deduplication: SpeciesDeduplicationMode,
     #[serde(default)]
     mode: SpeciesLocationsMode,
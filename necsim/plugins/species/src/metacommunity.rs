@@ -0,0 +1,469 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use arrow2::{
+    array::{Array, PrimitiveArray, Utf8Array},
+    datatypes::DataType,
+};
+use serde::{Deserialize, Serialize};
+
+use necsim_core::{
+    event::DispersalEvent,
+    landscape::{LandscapeExtent, Location},
+};
+use necsim_core_bond::OffByOneU32;
+use necsim_plugins_species_core::identity::seahash_diffuse;
+
+use crate::{
+    dataframe::{read_dataframe, DataFrameFormat},
+    SpeciesIdentity,
+};
+
+/// Static external metacommunity, given as an observed species abundance
+///  table, from which migrating lineages draw their species
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+#[serde(rename = "Metacommunity")]
+pub struct MetacommunityArgs {
+    abundances: MetacommunityAbundances,
+    /// Area of the simulated metacommunity, which the `SpatiallyImplicit`
+    ///  and `SpatiallyImplicitIslands` scenarios place at the far end of the
+    ///  landscape, and into which lineages migrate
+    meta_area: (OffByOneU32, OffByOneU32),
+    seed: u64,
+}
+
+/// Species abundance table with a `species` id and a `count` column
+#[allow(clippy::module_name_repetitions)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum MetacommunityAbundances {
+    /// CSV file with an optional `species,count` header row
+    Csv(PathBuf),
+    /// Arrow IPC (Feather v2) file with a string or integer `species` column
+    ///  and an integer `count` column
+    Feather(PathBuf),
+}
+
+#[derive(Debug)]
+pub struct Metacommunity {
+    species: Vec<String>,
+    // Inclusive prefix sums of the species abundances
+    cumulative: Vec<u64>,
+    meta: LandscapeExtent,
+    seed: u64,
+}
+
+impl TryFrom<&MetacommunityArgs> for Metacommunity {
+    type Error = String;
+
+    fn try_from(args: &MetacommunityArgs) -> Result<Self, Self::Error> {
+        let abundances = match &args.abundances {
+            MetacommunityAbundances::Csv(path) => read_csv_abundances(path),
+            MetacommunityAbundances::Feather(path) => read_feather_abundances(path),
+        }?;
+
+        let mut species = Vec::with_capacity(abundances.len());
+        let mut cumulative = Vec::with_capacity(abundances.len());
+
+        let mut total = 0_u64;
+
+        for (id, count) in abundances {
+            // Species which were not observed can never be drawn
+            if count == 0 {
+                continue;
+            }
+
+            total = total
+                .checked_add(count)
+                .ok_or("the total metacommunity abundance overflows")?;
+
+            species.push(id);
+            cumulative.push(total);
+        }
+
+        if species.is_empty() {
+            return Err(String::from(
+                "the metacommunity must contain at least one individual",
+            ));
+        }
+
+        let (meta_width, meta_height) = args.meta_area;
+
+        Ok(Self {
+            species,
+            cumulative,
+            meta: LandscapeExtent::new(
+                Location::new(meta_width.inv(), meta_height.inv()),
+                meta_width,
+                meta_height,
+            ),
+            seed: args.seed,
+        })
+    }
+}
+
+impl Metacommunity {
+    /// Returns whether the `dispersal` is a migration out of the local
+    ///  community into the metacommunity, at which the migrating lineage
+    ///  originates the species [`SpeciesIdentity::from_speciation`] of its
+    ///  dispersal target and event time
+    #[must_use]
+    pub fn is_migration(&self, dispersal: &DispersalEvent) -> bool {
+        !self.meta.contains(dispersal.origin.location())
+            && self.meta.contains(dispersal.target.location())
+    }
+
+    /// Draws the metacommunity species of the local `species` iff it was
+    ///  originated by a migration, or returns `None` iff it arose by
+    ///  speciation inside the local community
+    #[must_use]
+    pub fn draw_migrant(&self, species: &SpeciesIdentity) -> Option<&str> {
+        match species.clone().try_into_speciation() {
+            Ok((origin, _time)) if self.meta.contains(origin.location()) => {
+                Some(self.draw(species))
+            },
+            _ => None,
+        }
+    }
+
+    /// Draws the metacommunity species of the lineage which migrated out of
+    ///  the local community and thereby originated the local `species`.
+    ///
+    /// The draw is proportional to the metacommunity species abundances and
+    ///  only depends on the local species identity and the seed, such that it
+    ///  is independent of the order in which the events are reported.
+    fn draw(&self, species: &SpeciesIdentity) -> &str {
+        let mut hash = seahash_diffuse(self.seed);
+
+        for word in species.chunks_exact(8) {
+            let mut bytes = [0_u8; 8];
+            bytes.copy_from_slice(word);
+
+            hash = seahash_diffuse(hash ^ u64::from_le_bytes(bytes));
+        }
+
+        let total = self.cumulative.last().copied().unwrap_or(0);
+
+        // Multiply-shift mapping of the uniform hash onto [0, total)
+        #[allow(clippy::cast_possible_truncation)]
+        let individual = ((u128::from(hash) * u128::from(total)) >> 64) as u64;
+
+        let index = self
+            .cumulative
+            .partition_point(|cumulative| *cumulative <= individual);
+
+        &self.species[index]
+    }
+}
+
+fn read_csv_abundances(path: &Path) -> Result<Vec<(String, u64)>, String> {
+    let csv = fs::read_to_string(path).map_err(|err| {
+        format!("failed to read the metacommunity abundances from {path:?}: {err}")
+    })?;
+
+    let mut abundances = Vec::new();
+
+    for (i, line) in csv.lines().enumerate() {
+        let line = line.trim();
+
+        if line.is_empty() {
+            continue;
+        }
+
+        let Some((species, count)) = line.split_once(',') else {
+            return Err(format!(
+                "line {} of the metacommunity abundances {path:?} is not a `species,count` pair",
+                i + 1
+            ));
+        };
+
+        let (species, count) = (species.trim(), count.trim());
+
+        match count.parse() {
+            Ok(count) => abundances.push((String::from(species), count)),
+            // The first line may be a header row
+            Err(_) if i == 0 => (),
+            Err(err) => {
+                return Err(format!(
+                    "line {} of the metacommunity abundances {path:?} has an invalid count \
+                     {count:?}: {err}",
+                    i + 1
+                ))
+            },
+        }
+    }
+
+    Ok(abundances)
+}
+
+fn read_feather_abundances(path: &Path) -> Result<Vec<(String, u64)>, String> {
+    let (schema, chunks) = read_dataframe(path, DataFrameFormat::Feather).map_err(|err| {
+        format!("failed to read the metacommunity abundances from {path:?}: {err}")
+    })?;
+
+    let column = |name: &str| {
+        schema
+            .fields
+            .iter()
+            .position(|field| field.name == name)
+            .ok_or_else(|| {
+                format!("the metacommunity abundances {path:?} are missing the {name:?} column")
+            })
+    };
+
+    let species_column = column("species")?;
+    let count_column = column("count")?;
+
+    let mut abundances = Vec::new();
+
+    for chunk in chunks {
+        let species =
+            read_species_column(chunk.arrays()[species_column].as_ref()).ok_or_else(|| {
+                format!(
+                    "the species column of the metacommunity abundances {path:?} must be a \
+                     non-null string or integer column"
+                )
+            })?;
+        let counts = read_count_column(chunk.arrays()[count_column].as_ref()).ok_or_else(|| {
+            format!(
+                "the count column of the metacommunity abundances {path:?} must be a non-null, \
+                 non-negative integer column"
+            )
+        })?;
+
+        abundances.extend(species.into_iter().zip(counts));
+    }
+
+    Ok(abundances)
+}
+
+fn read_species_column(array: &dyn Array) -> Option<Vec<String>> {
+    if array.null_count() > 0 {
+        return None;
+    }
+
+    match array.data_type() {
+        DataType::Utf8 => Some(
+            array
+                .as_any()
+                .downcast_ref::<Utf8Array<i32>>()?
+                .values_iter()
+                .map(String::from)
+                .collect(),
+        ),
+        DataType::LargeUtf8 => Some(
+            array
+                .as_any()
+                .downcast_ref::<Utf8Array<i64>>()?
+                .values_iter()
+                .map(String::from)
+                .collect(),
+        ),
+        DataType::UInt64 => Some(
+            array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<u64>>()?
+                .values_iter()
+                .map(u64::to_string)
+                .collect(),
+        ),
+        DataType::Int64 => Some(
+            array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<i64>>()?
+                .values_iter()
+                .map(i64::to_string)
+                .collect(),
+        ),
+        _ => None,
+    }
+}
+
+fn read_count_column(array: &dyn Array) -> Option<Vec<u64>> {
+    if array.null_count() > 0 {
+        return None;
+    }
+
+    match array.data_type() {
+        DataType::UInt64 => Some(
+            array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<u64>>()?
+                .values_iter()
+                .copied()
+                .collect(),
+        ),
+        DataType::UInt32 => Some(
+            array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<u32>>()?
+                .values_iter()
+                .map(|count| u64::from(*count))
+                .collect(),
+        ),
+        DataType::Int64 => array
+            .as_any()
+            .downcast_ref::<PrimitiveArray<i64>>()?
+            .values_iter()
+            .map(|count| u64::try_from(*count).ok())
+            .collect(),
+        DataType::Int32 => array
+            .as_any()
+            .downcast_ref::<PrimitiveArray<i32>>()?
+            .values_iter()
+            .map(|count| u64::try_from(*count).ok())
+            .collect(),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::PathBuf};
+
+    use arrow2::{
+        array::{Array, PrimitiveArray, Utf8Array},
+        chunk::Chunk,
+        datatypes::{DataType, Field, Schema},
+    };
+
+    use necsim_core::landscape::{IndexedLocation, Location};
+    use necsim_core_bond::{OffByOneU32, PositiveF64};
+
+    use crate::{
+        dataframe::{write_dataframe, DataFrameFormat},
+        SpeciesIdentity,
+    };
+
+    use super::{Metacommunity, MetacommunityAbundances, MetacommunityArgs};
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!(
+            "necsim-metacommunity-{}-{name}",
+            std::process::id()
+        ))
+    }
+
+    fn metacommunity(abundances: MetacommunityAbundances) -> Result<Metacommunity, String> {
+        Metacommunity::try_from(&MetacommunityArgs {
+            abundances,
+            meta_area: (OffByOneU32::new(10).unwrap(), OffByOneU32::new(10).unwrap()),
+            seed: 42,
+        })
+    }
+
+    fn csv_metacommunity(name: &str, csv: &str) -> Result<Metacommunity, String> {
+        let path = temp_path(name);
+        fs::write(&path, csv).unwrap();
+
+        let metacommunity = metacommunity(MetacommunityAbundances::Csv(path.clone()));
+
+        fs::remove_file(path).unwrap();
+
+        metacommunity
+    }
+
+    fn migrant_species(i: u32) -> SpeciesIdentity {
+        SpeciesIdentity::from_speciation(
+            &IndexedLocation::new(Location::new(u32::MAX, u32::MAX - (i % 10)), i),
+            PositiveF64::new(f64::from(i) + 1.0).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_csv_abundances() {
+        let metacommunity = csv_metacommunity("csv", "a,1\n b , 2 \n\nc,3\n").unwrap();
+
+        assert_eq!(metacommunity.species, vec!["a", "b", "c"]);
+        assert_eq!(metacommunity.cumulative, vec![1, 3, 6]);
+    }
+
+    #[test]
+    fn test_csv_header_row() {
+        let metacommunity = csv_metacommunity("header", "species,count\na,1\nb,2\n").unwrap();
+
+        assert_eq!(metacommunity.species, vec!["a", "b"]);
+        assert_eq!(metacommunity.cumulative, vec![1, 3]);
+
+        // Only the first row may be a header row
+        assert!(csv_metacommunity("late-header", "a,1\nspecies,count\n").is_err());
+        assert!(csv_metacommunity("no-pair", "a,1\nb\n").is_err());
+    }
+
+    #[test]
+    fn test_zero_counts() {
+        let metacommunity = csv_metacommunity("zero", "a,0\nb,2\nc,0\n").unwrap();
+
+        assert_eq!(metacommunity.species, vec!["b"]);
+        assert_eq!(metacommunity.cumulative, vec![2]);
+
+        for i in 0..100 {
+            assert_eq!(metacommunity.draw(&migrant_species(i)), "b");
+        }
+
+        assert!(csv_metacommunity("empty", "species,count\na,0\n").is_err());
+    }
+
+    #[test]
+    fn test_feather_abundances() {
+        let path = temp_path("feather");
+
+        let schema = Schema::from(vec![
+            Field::new("count", DataType::Int64, false),
+            Field::new("species", DataType::Utf8, false),
+        ]);
+        let chunk = Chunk::<Box<dyn Array>>::new(vec![
+            PrimitiveArray::from_vec(vec![4_i64, 0, 5]).boxed(),
+            Utf8Array::<i32>::from_slice(["a", "b", "c"]).boxed(),
+        ]);
+
+        fs::File::create(&path).unwrap();
+        write_dataframe(&path, DataFrameFormat::Feather, schema, &chunk).unwrap();
+
+        let metacommunity = metacommunity(MetacommunityAbundances::Feather(path.clone()));
+
+        fs::remove_file(path).unwrap();
+
+        let metacommunity = metacommunity.unwrap();
+
+        assert_eq!(metacommunity.species, vec!["a", "c"]);
+        assert_eq!(metacommunity.cumulative, vec![4, 9]);
+    }
+
+    #[test]
+    fn test_draws_are_proportional_to_abundance() {
+        let metacommunity = csv_metacommunity("proportional", "a,1\nb,3\nc,6\n").unwrap();
+
+        let mut draws = [0_u32; 3];
+
+        for i in 0..100_000 {
+            match metacommunity.draw(&migrant_species(i)) {
+                "a" => draws[0] += 1,
+                "b" => draws[1] += 1,
+                "c" => draws[2] += 1,
+                species => panic!("drew unknown species {species:?}"),
+            }
+        }
+
+        for (draws, expected) in draws.into_iter().zip([10_000, 30_000, 60_000]) {
+            assert!(draws.abs_diff(expected) < 1_000, "{draws} vs {expected}");
+        }
+    }
+
+    #[test]
+    fn test_only_migrants_draw() {
+        let metacommunity = csv_metacommunity("migrants", "a,1\n").unwrap();
+
+        assert_eq!(metacommunity.draw_migrant(&migrant_species(0)), Some("a"));
+
+        // Species that arose by speciation inside the local community
+        let local = SpeciesIdentity::from_speciation(
+            &IndexedLocation::new(Location::new(0, 0), 0),
+            PositiveF64::new(1.0).unwrap(),
+        );
+        assert_eq!(metacommunity.draw_migrant(&local), None);
+    }
+}
@@ -284,6 +284,24 @@ link_kernel!(
     necsim_impls_no_std::cogs::speciation_probability::spatially_implicit::SpatiallyImplicitSpeciationProbability
 );
 
+#[cfg(feature = "spatially-implicit-islands-scenario")]
+link_kernel!(
+    necsim_impls_no_std::cogs::habitat::spatially_implicit_islands::SpatiallyImplicitIslandsHabitat<
+        necsim_impls_cuda::cogs::maths::NvptxMathsCore
+    >,
+    necsim_impls_no_std::cogs::dispersal_sampler::spatially_implicit_islands::SpatiallyImplicitIslandsDispersalSampler<
+        necsim_impls_cuda::cogs::maths::NvptxMathsCore,
+        necsim_impls_cuda::cogs::rng::CudaRng<
+            necsim_impls_cuda::cogs::maths::NvptxMathsCore,
+            necsim_impls_no_std::cogs::rng::wyhash::WyHash<
+                necsim_impls_cuda::cogs::maths::NvptxMathsCore
+            >,
+        >,
+    >,
+    necsim_impls_no_std::cogs::turnover_rate::uniform::UniformTurnoverRate,
+    necsim_impls_no_std::cogs::speciation_probability::spatially_implicit_islands::SpatiallyImplicitIslandsSpeciationProbability
+);
+
 #[cfg(feature = "almost-infinite-normal-dispersal-scenario")]
 link_kernel!(
     necsim_impls_no_std::cogs::habitat::almost_infinite::AlmostInfiniteHabitat<
@@ -341,6 +359,27 @@ link_kernel!(
     necsim_impls_no_std::cogs::speciation_probability::uniform::UniformSpeciationProbability
 );
 
+#[cfg(feature = "spatially-explicit-uniform-turnover-scenario")]
+link_kernel!(
+    necsim_impls_no_std::cogs::habitat::in_memory::InMemoryHabitat<
+        necsim_impls_cuda::cogs::maths::NvptxMathsCore
+    >,
+    necsim_impls_no_std::cogs::dispersal_sampler::in_memory::packed_separable_alias::InMemoryPackedSeparableAliasDispersalSampler<
+        necsim_impls_cuda::cogs::maths::NvptxMathsCore,
+        necsim_impls_no_std::cogs::habitat::in_memory::InMemoryHabitat<
+            necsim_impls_cuda::cogs::maths::NvptxMathsCore
+        >,
+        necsim_impls_cuda::cogs::rng::CudaRng<
+            necsim_impls_cuda::cogs::maths::NvptxMathsCore,
+            necsim_impls_no_std::cogs::rng::wyhash::WyHash<
+                necsim_impls_cuda::cogs::maths::NvptxMathsCore
+            >,
+        >,
+    >,
+    necsim_impls_no_std::cogs::turnover_rate::uniform::UniformTurnoverRate,
+    necsim_impls_no_std::cogs::speciation_probability::in_memory::InMemorySpeciationProbability
+);
+
 #[cfg(feature = "spatially-explicit-turnover-map-scenario")]
 link_kernel!(
     necsim_impls_no_std::cogs::habitat::in_memory::InMemoryHabitat<
@@ -362,6 +401,63 @@ link_kernel!(
     necsim_impls_no_std::cogs::speciation_probability::uniform::UniformSpeciationProbability
 );
 
+#[cfg(feature = "spatially-explicit-turnover-map-scenario")]
+link_kernel!(
+    necsim_impls_no_std::cogs::habitat::in_memory::InMemoryHabitat<
+        necsim_impls_cuda::cogs::maths::NvptxMathsCore
+    >,
+    necsim_impls_no_std::cogs::dispersal_sampler::in_memory::packed_separable_alias::InMemoryPackedSeparableAliasDispersalSampler<
+        necsim_impls_cuda::cogs::maths::NvptxMathsCore,
+        necsim_impls_no_std::cogs::habitat::in_memory::InMemoryHabitat<
+            necsim_impls_cuda::cogs::maths::NvptxMathsCore
+        >,
+        necsim_impls_cuda::cogs::rng::CudaRng<
+            necsim_impls_cuda::cogs::maths::NvptxMathsCore,
+            necsim_impls_no_std::cogs::rng::wyhash::WyHash<
+                necsim_impls_cuda::cogs::maths::NvptxMathsCore
+            >,
+        >,
+    >,
+    necsim_impls_no_std::cogs::turnover_rate::in_memory::InMemoryTurnoverRate,
+    necsim_impls_no_std::cogs::speciation_probability::in_memory::InMemorySpeciationProbability
+);
+
+#[cfg(feature = "spatially-explicit-fine-coarse-scenario")]
+link_kernel!(
+    necsim_impls_no_std::cogs::habitat::fine_coarse::FineCoarseHabitat<
+        necsim_impls_cuda::cogs::maths::NvptxMathsCore
+    >,
+    necsim_impls_no_std::cogs::dispersal_sampler::fine_coarse::FineCoarseApproximateNormalDispersalSampler<
+        necsim_impls_cuda::cogs::maths::NvptxMathsCore,
+        necsim_impls_cuda::cogs::rng::CudaRng<
+            necsim_impls_cuda::cogs::maths::NvptxMathsCore,
+            necsim_impls_no_std::cogs::rng::wyhash::WyHash<
+                necsim_impls_cuda::cogs::maths::NvptxMathsCore
+            >,
+        >,
+    >,
+    necsim_impls_no_std::cogs::turnover_rate::uniform::UniformTurnoverRate,
+    necsim_impls_no_std::cogs::speciation_probability::uniform::UniformSpeciationProbability
+);
+
+#[cfg(feature = "spatially-explicit-kernel-scenario")]
+link_kernel!(
+    necsim_impls_no_std::cogs::habitat::in_memory::InMemoryHabitat<
+        necsim_impls_cuda::cogs::maths::NvptxMathsCore
+    >,
+    necsim_impls_no_std::cogs::dispersal_sampler::kernel::InMemoryKernelDispersalSampler<
+        necsim_impls_cuda::cogs::maths::NvptxMathsCore,
+        necsim_impls_cuda::cogs::rng::CudaRng<
+            necsim_impls_cuda::cogs::maths::NvptxMathsCore,
+            necsim_impls_no_std::cogs::rng::wyhash::WyHash<
+                necsim_impls_cuda::cogs::maths::NvptxMathsCore
+            >,
+        >,
+    >,
+    necsim_impls_no_std::cogs::turnover_rate::uniform::UniformTurnoverRate,
+    necsim_impls_no_std::cogs::speciation_probability::uniform::UniformSpeciationProbability
+);
+
 #[cfg(feature = "wrapping-noise-scenario")]
 link_kernel!(
     necsim_impls_no_std::cogs::habitat::wrapping_noise::WrappingNoiseHabitat<
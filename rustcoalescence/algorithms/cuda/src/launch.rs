@@ -107,10 +107,11 @@ where
         speciation_probability,
         origin_sampler_auxiliary,
         decomposition_auxiliary,
+        protracted_speciation,
         ..
     } = scenario;
     let coalescence_sampler = IndependentCoalescenceSampler::default();
-    let event_sampler = IndependentEventSampler::default();
+    let event_sampler = IndependentEventSampler::new(protracted_speciation);
 
     let (lineage_store, dispersal_sampler, active_lineage_sampler, lineages, passthrough) =
         match args.parallelism_mode {
@@ -67,6 +67,31 @@ pub enum ParallelismMode {
     Averaging(AveragingParallelismMode),
 }
 
+impl ParallelismMode {
+    /// # Errors
+    ///
+    /// Returns an error message iff the parallelism mode is incompatible with
+    ///  the `partition_size`.
+    pub fn check_partition_size(&self, partition_size: PartitionSize) -> Result<(), String> {
+        match self {
+            Self::Monolithic if !partition_size.is_monolithic() => Err(format!(
+                "parallelism_mode {self:?} is incompatible with non-monolithic partitioning."
+            )),
+            Self::Optimistic(..)
+            | Self::Lockstep
+            | Self::OptimisticLockstep
+            | Self::Averaging(..)
+                if partition_size.is_monolithic() =>
+            {
+                Err(format!(
+                    "parallelism_mode {self:?} is incompatible with monolithic partitioning."
+                ))
+            },
+            _ => Ok(()),
+        }
+    }
+}
+
 impl<'de> DeserializeState<'de, PartitionSize> for ParallelismMode {
     fn deserialize_state<D>(
         partition_size: &mut PartitionSize,
@@ -75,30 +100,13 @@ impl<'de> DeserializeState<'de, PartitionSize> for ParallelismMode {
     where
         D: serde::de::Deserializer<'de>,
     {
-        use serde::de::Error;
-
         let parallelism_mode = ParallelismMode::deserialize(deserializer)?;
 
-        match parallelism_mode {
-            ParallelismMode::Monolithic if !partition_size.is_monolithic() => {
-                Err(D::Error::custom(format!(
-                    "parallelism_mode {parallelism_mode:?} is incompatible with non-monolithic \
-                     partitioning."
-                )))
-            },
-            ParallelismMode::Optimistic(..)
-            | ParallelismMode::Lockstep
-            | ParallelismMode::OptimisticLockstep
-            | ParallelismMode::Averaging(..)
-                if partition_size.is_monolithic() =>
-            {
-                Err(D::Error::custom(format!(
-                    "parallelism_mode {parallelism_mode:?} is incompatible with monolithic \
-                     partitioning."
-                )))
-            },
-            partition_mode => Ok(partition_mode),
-        }
+        parallelism_mode
+            .check_partition_size(*partition_size)
+            .map_err(serde::de::Error::custom)?;
+
+        Ok(parallelism_mode)
     }
 }
 
@@ -7,7 +7,7 @@ use necsim_core::{
     lineage::{Lineage, LineageInteraction},
     reporter::Reporter,
 };
-use necsim_core_bond::PositiveF64;
+use necsim_core_bond::{ClosedUnitF64, PositiveF64};
 
 use necsim_impls_no_std::cogs::{
     active_lineage_sampler::{
@@ -21,7 +21,9 @@ use necsim_impls_no_std::cogs::{
     dispersal_sampler::trespassing::{
         uniform::UniformAntiTrespassingDispersalSampler, TrespassingDispersalSampler,
     },
-    event_sampler::gillespie::conditional::ConditionalGillespieEventSampler,
+    event_sampler::{
+        gillespie::conditional::ConditionalGillespieEventSampler, protracted::ProtractedSpeciation,
+    },
     origin_sampler::{resuming::ResumingOriginSampler, TrustedOriginSampler},
 };
 use necsim_partitioning_core::LocalPartition;
@@ -119,6 +121,7 @@ where
         coalescence_sampler: &ConditionalCoalescenceSampler<M, O::Habitat, S>,
         turnover_rate: &O::TurnoverRate,
         speciation_probability: &O::SpeciationProbability,
+        protracted_speciation: ProtractedSpeciation,
         local_partition: &mut P,
     ) -> Result<
         (
@@ -144,7 +147,7 @@ where
         let habitat = origin_sampler.habitat();
         let pre_sampler = origin_sampler.into_pre_sampler();
 
-        let event_sampler = ConditionalGillespieEventSampler::default();
+        let event_sampler = ConditionalGillespieEventSampler::new(protracted_speciation);
         let dispersal_sampler = TrespassingDispersalSampler::new(
             dispersal_sampler,
             UniformAntiTrespassingDispersalSampler::default(),
@@ -190,6 +193,8 @@ where
                             origin: coalescing_lineage.indexed_location.clone(),
                             target: coalescing_lineage.indexed_location,
                             interaction: LineageInteraction::Coalescence(parent),
+                            // the fixup coalescence makes no speciation decision
+                            speciation_sample: ClosedUnitF64::one(),
                         })
                             .into(),
                     );
@@ -9,7 +9,9 @@ use necsim_core::{
 use necsim_impls_no_std::cogs::{
     active_lineage_sampler::alias::location::LocationAliasActiveLineageSampler,
     coalescence_sampler::conditional::ConditionalCoalescenceSampler,
-    event_sampler::gillespie::conditional::ConditionalGillespieEventSampler,
+    event_sampler::{
+        gillespie::conditional::ConditionalGillespieEventSampler, protracted::ProtractedSpeciation,
+    },
     origin_sampler::TrustedOriginSampler,
 };
 use necsim_partitioning_core::LocalPartition;
@@ -70,6 +72,7 @@ where
         coalescence_sampler: &ConditionalCoalescenceSampler<M, O::Habitat, S>,
         turnover_rate: &O::TurnoverRate,
         speciation_probability: &O::SpeciationProbability,
+        protracted_speciation: ProtractedSpeciation,
         _local_partition: &mut P,
     ) -> Result<
         (
@@ -92,7 +95,7 @@ where
     where
         O::Habitat: 'h,
     {
-        let event_sampler = ConditionalGillespieEventSampler::default();
+        let event_sampler = ConditionalGillespieEventSampler::new(protracted_speciation);
 
         let (lineage_store, active_lineage_sampler) =
             LocationAliasActiveLineageSampler::init_with_store(
@@ -8,7 +8,9 @@ use necsim_core::{
 
 use necsim_impls_no_std::cogs::{
     coalescence_sampler::conditional::ConditionalCoalescenceSampler,
-    event_sampler::gillespie::conditional::ConditionalGillespieEventSampler,
+    event_sampler::{
+        gillespie::conditional::ConditionalGillespieEventSampler, protracted::ProtractedSpeciation,
+    },
     origin_sampler::TrustedOriginSampler,
 };
 use necsim_partitioning_core::LocalPartition;
@@ -56,7 +58,7 @@ pub trait EventSkippingLineageStoreSampleInitialiser<
         I,
     >;
 
-    #[allow(clippy::type_complexity)]
+    #[allow(clippy::type_complexity, clippy::too_many_arguments)]
     fn init<
         'h,
         'p,
@@ -73,6 +75,7 @@ pub trait EventSkippingLineageStoreSampleInitialiser<
         coalescence_sampler: &ConditionalCoalescenceSampler<M, O::Habitat, S>,
         turnover_rate: &O::TurnoverRate,
         speciation_probability: &O::SpeciationProbability,
+        protracted_speciation: ProtractedSpeciation,
         local_partition: &mut P,
     ) -> Result<
         (
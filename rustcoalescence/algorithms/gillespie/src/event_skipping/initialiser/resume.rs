@@ -11,7 +11,9 @@ use necsim_core_bond::NonNegativeF64;
 use necsim_impls_no_std::cogs::{
     active_lineage_sampler::alias::location::LocationAliasActiveLineageSampler,
     coalescence_sampler::conditional::ConditionalCoalescenceSampler,
-    event_sampler::gillespie::conditional::ConditionalGillespieEventSampler,
+    event_sampler::{
+        gillespie::conditional::ConditionalGillespieEventSampler, protracted::ProtractedSpeciation,
+    },
     origin_sampler::{resuming::ResumingOriginSampler, TrustedOriginSampler},
 };
 use necsim_partitioning_core::LocalPartition;
@@ -76,6 +78,7 @@ where
         coalescence_sampler: &ConditionalCoalescenceSampler<M, O::Habitat, S>,
         turnover_rate: &O::TurnoverRate,
         speciation_probability: &O::SpeciationProbability,
+        protracted_speciation: ProtractedSpeciation,
         _local_partition: &mut P,
     ) -> Result<
         (
@@ -101,7 +104,7 @@ where
         let habitat = origin_sampler.habitat();
         let pre_sampler = origin_sampler.into_pre_sampler();
 
-        let event_sampler = ConditionalGillespieEventSampler::default();
+        let event_sampler = ConditionalGillespieEventSampler::new(protracted_speciation);
 
         let (lineage_store, active_lineage_sampler, exceptional_lineages) =
             LocationAliasActiveLineageSampler::resume_with_store(
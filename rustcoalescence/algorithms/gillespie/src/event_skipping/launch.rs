@@ -66,6 +66,7 @@ where
                 speciation_probability,
                 origin_sampler_auxiliary,
                 decomposition_auxiliary: _,
+                protracted_speciation,
                 ..
             } = scenario;
             let coalescence_sampler = ConditionalCoalescenceSampler::default();
@@ -81,6 +82,7 @@ where
                 &coalescence_sampler,
                 &turnover_rate,
                 &speciation_probability,
+                protracted_speciation,
                 local_partition,
             )?;
 
@@ -137,6 +139,7 @@ where
                 speciation_probability,
                 origin_sampler_auxiliary,
                 decomposition_auxiliary,
+                protracted_speciation,
                 ..
             } = scenario;
             let coalescence_sampler = ConditionalCoalescenceSampler::default();
@@ -162,6 +165,7 @@ where
                 &coalescence_sampler,
                 &turnover_rate,
                 &speciation_probability,
+                protracted_speciation,
                 local_partition,
             )?;
 
@@ -4,7 +4,7 @@ use necsim_core::{
     lineage::{Lineage, LineageInteraction},
     reporter::Reporter,
 };
-use necsim_core_bond::PositiveF64;
+use necsim_core_bond::{ClosedUnitF64, PositiveF64};
 
 use necsim_impls_no_std::cogs::{
     active_lineage_sampler::{
@@ -151,6 +151,8 @@ impl<L: ExactSizeIterator<Item = Lineage>, M: MathsCore, G: RngCore<M>, O: Scena
                             origin: coalescing_lineage.indexed_location.clone(),
                             target: coalescing_lineage.indexed_location,
                             interaction: LineageInteraction::Coalescence(parent),
+                            // the fixup coalescence makes no speciation decision
+                            speciation_sample: ClosedUnitF64::one(),
                         })
                             .into(),
                     );
@@ -65,10 +65,11 @@ where
                 speciation_probability,
                 origin_sampler_auxiliary,
                 decomposition_auxiliary: _,
+                protracted_speciation,
                 ..
             } = scenario;
             let coalescence_sampler = UnconditionalCoalescenceSampler::default();
-            let event_sampler = UnconditionalEventSampler::default();
+            let event_sampler = UnconditionalEventSampler::new(protracted_speciation);
 
             let (lineage_store, dispersal_sampler, active_lineage_sampler): (
                 O::LineageStore<ClassicalLineageStore<M, O::Habitat>>,
@@ -133,10 +134,11 @@ where
                 speciation_probability,
                 origin_sampler_auxiliary,
                 decomposition_auxiliary,
+                protracted_speciation,
                 ..
             } = scenario;
             let coalescence_sampler = UnconditionalCoalescenceSampler::default();
-            let event_sampler = UnconditionalEventSampler::default();
+            let event_sampler = UnconditionalEventSampler::new(protracted_speciation);
 
             let decomposition = O::decompose(
                 &habitat,
@@ -38,6 +38,39 @@ pub enum ParallelismMode {
     Probabilistic(ProbabilisticParallelismMode),
 }
 
+impl ParallelismMode {
+    /// # Errors
+    ///
+    /// Returns an error message iff the parallelism mode is incompatible with
+    ///  the `partition_size`.
+    pub fn check_partition_size(&self, partition_size: PartitionSize) -> Result<(), String> {
+        match self {
+            Self::Monolithic(..) | Self::IsolatedIndividuals(..) | Self::IsolatedLandscape(..)
+                if !partition_size.is_monolithic() =>
+            {
+                Err(format!(
+                    "parallelism_mode {self:?} is incompatible with non-monolithic partitioning."
+                ))
+            },
+            Self::Individuals | Self::Landscape | Self::Probabilistic(..)
+                if partition_size.is_monolithic() =>
+            {
+                Err(format!(
+                    "parallelism_mode {self:?} is incompatible with monolithic partitioning."
+                ))
+            },
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns whether the partitions of a simulation in this parallelism
+    ///  mode can pause together without exchanging any lineages
+    #[must_use]
+    pub fn can_pause_partitions_independently(&self) -> bool {
+        matches!(self, Self::Individuals)
+    }
+}
+
 impl<'de> DeserializeState<'de, PartitionSize> for ParallelismMode {
     fn deserialize_state<D>(
         partition_size: &mut PartitionSize,
@@ -46,33 +79,13 @@ impl<'de> DeserializeState<'de, PartitionSize> for ParallelismMode {
     where
         D: serde::de::Deserializer<'de>,
     {
-        use serde::de::Error;
-
         let parallelism_mode = ParallelismMode::deserialize(deserializer)?;
 
-        match parallelism_mode {
-            ParallelismMode::Monolithic(..)
-            | ParallelismMode::IsolatedIndividuals(..)
-            | ParallelismMode::IsolatedLandscape(..)
-                if !partition_size.is_monolithic() =>
-            {
-                Err(D::Error::custom(format!(
-                    "parallelism_mode {parallelism_mode:?} is incompatible with non-monolithic \
-                     partitioning."
-                )))
-            },
-            ParallelismMode::Individuals
-            | ParallelismMode::Landscape
-            | ParallelismMode::Probabilistic(..)
-                if partition_size.is_monolithic() =>
-            {
-                Err(D::Error::custom(format!(
-                    "parallelism_mode {parallelism_mode:?} is incompatible with monolithic \
-                     partitioning."
-                )))
-            },
-            partition_mode => Ok(partition_mode),
-        }
+        parallelism_mode
+            .check_partition_size(*partition_size)
+            .map_err(serde::de::Error::custom)?;
+
+        Ok(parallelism_mode)
     }
 }
 
@@ -72,10 +72,11 @@ pub fn initialise_and_simulate<
                 speciation_probability,
                 origin_sampler_auxiliary,
                 decomposition_auxiliary,
+                protracted_speciation,
                 ..
             } = scenario;
             let coalescence_sampler = IndependentCoalescenceSampler::default();
-            let event_sampler = IndependentEventSampler::default();
+            let event_sampler = IndependentEventSampler::new(protracted_speciation);
 
             let (lineage_store, dispersal_sampler, active_lineage_sampler, lineages, passthrough) =
                 match args.parallelism_mode {
@@ -166,10 +167,11 @@ pub fn initialise_and_simulate<
                 speciation_probability,
                 origin_sampler_auxiliary,
                 decomposition_auxiliary: _,
+                protracted_speciation,
                 ..
             } = scenario;
             let coalescence_sampler = IndependentCoalescenceSampler::default();
-            let event_sampler = IndependentEventSampler::default();
+            let event_sampler = IndependentEventSampler::new(protracted_speciation);
 
             let (lineage_store, dispersal_sampler, active_lineage_sampler, lineages, _passthrough) =
                 lineage_store_sampler_initialiser.init(
@@ -201,18 +203,32 @@ pub fn initialise_and_simulate<
             }
             .build();
 
-            let (_status, time, steps, _lineages) =
+            let (status, time, steps, lineages) =
                 parallelisation::independent::individuals::simulate(
                     &mut simulation,
                     lineages,
                     args.dedup_cache,
                     args.step_slice,
+                    pause_before,
                     local_partition,
                 );
 
-            // TODO: Adapt for parallel pausing
+            // All partitions must pause together, even if some have no
+            //  lineages left to pause
+            let paused = local_partition.reduce_vote_any(matches!(status, Status::Paused));
+
             // TODO: Adapt for lineage passthrough
-            Ok(SimulationOutcome::Done { time, steps })
+            if paused {
+                Ok(SimulationOutcome::Paused {
+                    time,
+                    steps,
+                    lineages: lineages.into_iter().collect(),
+                    rng: simulation.rng_mut().clone(),
+                    marker: PhantomData::<M>,
+                })
+            } else {
+                Ok(SimulationOutcome::Done { time, steps })
+            }
         },
         ParallelismMode::Landscape => {
             let ScenarioCogs {
@@ -222,10 +238,11 @@ pub fn initialise_and_simulate<
                 speciation_probability,
                 origin_sampler_auxiliary,
                 decomposition_auxiliary,
+                protracted_speciation,
                 ..
             } = scenario;
             let coalescence_sampler = IndependentCoalescenceSampler::default();
-            let event_sampler = IndependentEventSampler::default();
+            let event_sampler = IndependentEventSampler::new(protracted_speciation);
 
             let decomposition = O::decompose(
                 &habitat,
@@ -286,10 +303,11 @@ pub fn initialise_and_simulate<
                 speciation_probability,
                 origin_sampler_auxiliary,
                 decomposition_auxiliary,
+                protracted_speciation,
                 ..
             } = scenario;
             let coalescence_sampler = IndependentCoalescenceSampler::default();
-            let event_sampler = IndependentEventSampler::default();
+            let event_sampler = IndependentEventSampler::new(protracted_speciation);
 
             let decomposition = O::decompose(
                 &habitat,
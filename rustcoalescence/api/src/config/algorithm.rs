@@ -0,0 +1,230 @@
+use serde::{Serialize, Serializer};
+
+use necsim_partitioning_core::partition::PartitionSize;
+
+#[cfg(any(
+    feature = "gillespie-algorithms",
+    feature = "independent-algorithm",
+    feature = "cuda-algorithm"
+))]
+use rustcoalescence_algorithms::AlgorithmParamters;
+
+#[cfg(any(
+    feature = "gillespie-algorithms",
+    feature = "independent-algorithm",
+    feature = "cuda-algorithm"
+))]
+use super::rng::generator::RngGeneratorFamily;
+use super::rng::RngGenerator;
+
+#[derive(Debug, DeserializeState)]
+#[serde(deserialize_state = "PartitionSize")]
+pub enum Algorithm {
+    #[cfg(feature = "gillespie-algorithms")]
+    #[serde(alias = "Classical")]
+    Gillespie(
+        #[serde(deserialize_state)]
+        <rustcoalescence_algorithms_gillespie::gillespie::GillespieAlgorithm as rustcoalescence_algorithms::AlgorithmParamters>::Arguments,
+    ),
+    #[cfg(feature = "gillespie-algorithms")]
+    #[serde(alias = "SkippingGillespie")]
+    EventSkipping(
+        #[serde(deserialize_state)]
+        <rustcoalescence_algorithms_gillespie::event_skipping::EventSkippingAlgorithm as AlgorithmParamters>::Arguments,
+    ),
+    #[cfg(feature = "cuda-algorithm")]
+    #[serde(alias = "CUDA")]
+    Cuda(#[serde(deserialize_state)] <rustcoalescence_algorithms_cuda::CudaAlgorithm as AlgorithmParamters>::Arguments),
+    #[cfg(feature = "independent-algorithm")]
+    Independent(
+        #[serde(deserialize_state)] <rustcoalescence_algorithms_independent::IndependentAlgorithm as AlgorithmParamters>::Arguments,
+    ),
+}
+
+impl Algorithm {
+    /// # Errors
+    ///
+    /// Returns an error iff the algorithm's parallelism mode is incompatible
+    ///  with the `partition_size`.
+    #[allow(unused_variables)]
+    pub fn check(&self, partition_size: PartitionSize) -> anyhow::Result<()> {
+        #[allow(unreachable_patterns)]
+        let result: Result<(), String> = match self {
+            #[cfg(feature = "gillespie-algorithms")]
+            Self::Gillespie(args) | Self::EventSkipping(args) => {
+                args.parallelism_mode.check_partition_size(partition_size)
+            },
+            #[cfg(feature = "cuda-algorithm")]
+            Self::Cuda(args) => args.parallelism_mode.check_partition_size(partition_size),
+            #[cfg(feature = "independent-algorithm")]
+            Self::Independent(args) => args.parallelism_mode.check_partition_size(partition_size),
+            _ => Ok(()),
+        };
+
+        result.map_err(anyhow::Error::msg)
+    }
+
+    /// Returns whether the algorithm can checkpoint a partitioned simulation
+    #[must_use]
+    pub fn supports_parallel_checkpoints(&self) -> bool {
+        #[allow(unreachable_patterns)]
+        match self {
+            #[cfg(feature = "independent-algorithm")]
+            Self::Independent(args) => args.parallelism_mode.can_pause_partitions_independently(),
+            _ => false,
+        }
+    }
+
+    /// Returns the generator with which the algorithm is simulated if the
+    ///  config does not specify one
+    #[must_use]
+    pub fn default_rng_generator(&self) -> RngGenerator {
+        #[allow(unreachable_patterns)]
+        match self {
+            #[cfg(feature = "gillespie-algorithms")]
+            Self::Gillespie(_) | Self::EventSkipping(_) => RngGenerator::Pcg,
+            _ => RngGenerator::WyHash,
+        }
+    }
+
+    /// Returns whether this build of rustcoalescence supports simulating the
+    ///  algorithm with the `generator`, i.e. iff the generator is
+    ///  [`PrimeableRng`](necsim_core::cogs::PrimeableRng) for the
+    ///  `Independent` and `CUDA` algorithms and
+    ///  [`SplittableRng`](necsim_core::cogs::SplittableRng) for the
+    ///  `Gillespie` and `EventSkipping` algorithms
+    #[must_use]
+    pub fn supports_rng_generator(&self, generator: RngGenerator) -> bool {
+        #[allow(unreachable_patterns)]
+        match (self, generator) {
+            (_, generator) if generator == self.default_rng_generator() => true,
+            #[cfg(all(feature = "gillespie-algorithms", feature = "xoshiro-rng"))]
+            (Self::Gillespie(_) | Self::EventSkipping(_), RngGenerator::Xoshiro256PlusPlus) => true,
+            #[cfg(all(feature = "gillespie-algorithms", feature = "chacha-rng"))]
+            (Self::Gillespie(_) | Self::EventSkipping(_), RngGenerator::ChaCha8) => true,
+            #[cfg(all(feature = "independent-algorithm", feature = "seahash-rng"))]
+            (Self::Independent(_), RngGenerator::SeaHash) => true,
+            #[cfg(all(feature = "independent-algorithm", feature = "chacha-rng"))]
+            (Self::Independent(_), RngGenerator::ChaCha8) => true,
+            _ => false,
+        }
+    }
+}
+
+/// Algorithm which the typed
+///  [`SimulationBuilder`](crate::simulate::SimulationBuilder) can select
+#[cfg(any(
+    feature = "gillespie-algorithms",
+    feature = "independent-algorithm",
+    feature = "cuda-algorithm"
+))]
+#[allow(clippy::module_name_repetitions)]
+pub trait AlgorithmSelection: AlgorithmParamters {
+    fn into_algorithm(args: Self::Arguments) -> Algorithm;
+}
+
+/// Algorithm which this build of rustcoalescence supports simulating with
+///  the generator family `F`, see [`Algorithm::supports_rng_generator`]
+#[cfg(any(
+    feature = "gillespie-algorithms",
+    feature = "independent-algorithm",
+    feature = "cuda-algorithm"
+))]
+pub trait SupportsRngGenerator<F: RngGeneratorFamily>: AlgorithmSelection {}
+
+#[cfg(feature = "gillespie-algorithms")]
+impl AlgorithmSelection for rustcoalescence_algorithms_gillespie::gillespie::GillespieAlgorithm {
+    fn into_algorithm(args: Self::Arguments) -> Algorithm {
+        Algorithm::Gillespie(args)
+    }
+}
+
+#[cfg(feature = "gillespie-algorithms")]
+impl AlgorithmSelection
+    for rustcoalescence_algorithms_gillespie::event_skipping::EventSkippingAlgorithm
+{
+    fn into_algorithm(args: Self::Arguments) -> Algorithm {
+        Algorithm::EventSkipping(args)
+    }
+}
+
+#[cfg(feature = "cuda-algorithm")]
+impl AlgorithmSelection for rustcoalescence_algorithms_cuda::CudaAlgorithm {
+    fn into_algorithm(args: Self::Arguments) -> Algorithm {
+        Algorithm::Cuda(args)
+    }
+}
+
+#[cfg(feature = "independent-algorithm")]
+impl AlgorithmSelection for rustcoalescence_algorithms_independent::IndependentAlgorithm {
+    fn into_algorithm(args: Self::Arguments) -> Algorithm {
+        Algorithm::Independent(args)
+    }
+}
+
+macro_rules! impl_supports_rng_generator {
+    ($(#[$meta:meta] $algorithm:ty => [$($(#[$genmeta:meta])* $generator:ident),*]),*) => {
+        $($(
+            #[$meta]
+            $(#[$genmeta])*
+            impl SupportsRngGenerator<super::rng::generator::$generator> for $algorithm {}
+        )*)*
+    };
+}
+
+impl_supports_rng_generator! {
+    #[cfg(feature = "gillespie-algorithms")]
+    rustcoalescence_algorithms_gillespie::gillespie::GillespieAlgorithm => [
+        Pcg,
+        #[cfg(feature = "xoshiro-rng")] Xoshiro256PlusPlus,
+        #[cfg(feature = "chacha-rng")] ChaCha8
+    ],
+    #[cfg(feature = "gillespie-algorithms")]
+    rustcoalescence_algorithms_gillespie::event_skipping::EventSkippingAlgorithm => [
+        Pcg,
+        #[cfg(feature = "xoshiro-rng")] Xoshiro256PlusPlus,
+        #[cfg(feature = "chacha-rng")] ChaCha8
+    ],
+    #[cfg(feature = "cuda-algorithm")]
+    rustcoalescence_algorithms_cuda::CudaAlgorithm => [WyHash],
+    #[cfg(feature = "independent-algorithm")]
+    rustcoalescence_algorithms_independent::IndependentAlgorithm => [
+        WyHash,
+        #[cfg(feature = "seahash-rng")] SeaHash,
+        #[cfg(feature = "chacha-rng")] ChaCha8
+    ]
+}
+
+impl Serialize for Algorithm {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[allow(unreachable_patterns, clippy::single_match_else)]
+        match self {
+            #[cfg(feature = "gillespie-algorithms")]
+            Self::Gillespie(args) => {
+                serializer.serialize_newtype_variant(stringify!(Algorithm), 0, "Gillespie", args)
+            },
+            #[cfg(feature = "gillespie-algorithms")]
+            Self::EventSkipping(args) => serializer.serialize_newtype_variant(
+                stringify!(Algorithm),
+                1,
+                "EventSkipping",
+                args,
+            ),
+            #[cfg(feature = "cuda-algorithm")]
+            Self::Cuda(args) => {
+                serializer.serialize_newtype_variant(stringify!(Algorithm), 2, "CUDA", args)
+            },
+            #[cfg(feature = "independent-algorithm")]
+            Self::Independent(args) => {
+                serializer.serialize_newtype_variant(stringify!(Algorithm), 3, "Independent", args)
+            },
+            _ => {
+                std::mem::drop(serializer);
+
+                Err(serde::ser::Error::custom(
+                    "rustcoalescence must be compiled to support at least one algorithm",
+                ))
+            },
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use necsim_core_bond::PositiveF64;
+use necsim_partitioning_core::partition::PartitionSize;
+
+use super::algorithm::Algorithm;
+
+/// Periodic checkpoints of a running simulation, from which it is resumed
+///  automatically if it is restarted with the same config
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Checkpoint {
+    pub directory: PathBuf,
+    pub every: CheckpointInterval,
+    /// Hash of the normalised config of the checkpointed simulation, which
+    ///  defaults to the hash of the config that contains this checkpoint
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub config_hash: Option<u64>,
+    /// Event log segments that had been closed when the checkpoint was
+    ///  written, which is empty unless the simulation resumes from it
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub event_log: Vec<EventLogBoundary>,
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Copy, Clone, Debug, Serialize, Deserialize)]
+pub enum CheckpointInterval {
+    /// Checkpoint every `n` simulated generations
+    Generations(PositiveF64),
+    /// Checkpoint (approximately) every `n` wall-clock minutes
+    Minutes(PositiveF64),
+}
+
+/// The segments that one partition had written into its event log when a
+///  checkpoint was written
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EventLogBoundary {
+    pub directory: PathBuf,
+    /// Rank of the partition, which records its events into the child log
+    ///  directory of the same name, if the simulation is partitioned
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub partition: Option<u32>,
+    pub segments: usize,
+}
+
+impl Checkpoint {
+    #[must_use]
+    pub fn new(directory: PathBuf, every: CheckpointInterval) -> Self {
+        Self {
+            directory,
+            every,
+            config_hash: None,
+            event_log: Vec::new(),
+        }
+    }
+
+    /// # Errors
+    ///
+    /// Returns an error iff checkpointing is incompatible with the
+    ///  `partition_size`, i.e. iff a partitioned simulation uses an
+    ///  `algorithm` whose partitions cannot pause independently.
+    pub fn check(
+        &self,
+        partition_size: PartitionSize,
+        algorithm: &Algorithm,
+    ) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            partition_size.is_monolithic() || algorithm.supports_parallel_checkpoints(),
+            "Parallel checkpointing requires the `Independent` algorithm with the `Individuals` \
+             parallelism mode."
+        );
+
+        Ok(())
+    }
+}
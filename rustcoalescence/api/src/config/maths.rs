@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+/// Selection of the implementation of the floating point maths functions
+#[derive(Copy, Clone, Debug, Default, Serialize, Deserialize)]
+pub enum Maths {
+    /// Fast compiler intrinsics, whose results can differ between platforms
+    #[default]
+    Intrinsics,
+    /// Software implementations, which are bit-for-bit reproducible across
+    ///  platforms
+    Reproducible,
+}
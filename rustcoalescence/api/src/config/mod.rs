@@ -1,6 +1,9 @@
 pub mod algorithm;
+pub mod checkpoint;
+pub mod maths;
 pub mod partitioning;
 pub mod pause;
+pub mod protracted;
 pub mod rng;
 pub mod sample;
 pub mod scenario;
@@ -20,6 +20,7 @@ pub enum Partitioning {
 }
 
 impl Partitioning {
+    #[must_use]
     pub fn get_size(&self) -> PartitionSize {
         use necsim_partitioning_core::Partitioning;
 
@@ -60,6 +61,7 @@ impl Partitioning {
         )),
         allow(dead_code)
     )]
+    #[must_use]
     pub fn get_logical_partition_size<
         M: MathsCore,
         G: RngCore<M>,
@@ -93,6 +95,7 @@ impl Partitioning {
         )),
         allow(dead_code)
     )]
+    #[must_use]
     pub fn will_report_live(&self, event_log: &Option<EventLogConfig>) -> bool {
         // TODO: get this information from the partitioning
         match self {
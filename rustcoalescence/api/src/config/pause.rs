@@ -57,6 +57,26 @@ pub struct FuturePause {
     pub mode: PauseMode,
 }
 
+impl Pause {
+    /// # Errors
+    ///
+    /// Returns an error iff the pause is incompatible with the
+    ///  `partition_size` or its `mode`.
+    pub fn check(&self, partition_size: PartitionSize) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            partition_size.is_monolithic(),
+            "Parallel pausing is not yet supported."
+        );
+
+        anyhow::ensure!(
+            !matches!(self.mode, PauseMode::FixUp) || self.before != NonNegativeF64::zero(),
+            "pause mode `FixUp` requires a positive non-zero pause time"
+        );
+
+        Ok(())
+    }
+}
+
 impl<'de> DeserializeState<'de, PartitionSize> for Pause {
     fn deserialize_state<D: Deserializer<'de>>(
         partition_size: &mut PartitionSize,
@@ -64,24 +84,18 @@ impl<'de> DeserializeState<'de, PartitionSize> for Pause {
     ) -> Result<Self, D::Error> {
         let raw = PauseRaw::deserialize(deserializer)?;
 
-        if !partition_size.is_monolithic() {
-            return Err(serde::de::Error::custom(
-                "Parallel pausing is not yet supported.",
-            ));
-        }
-
-        if matches!(raw.mode, PauseMode::FixUp) && raw.before == NonNegativeF64::zero() {
-            return Err(serde::de::Error::custom(
-                "pause mode `FixUp` requires a positive non-zero pause time",
-            ));
-        }
-
-        Ok(Pause {
+        let pause = Pause {
             before: raw.before,
             config: raw.config,
             destiny: raw.destiny,
             mode: raw.mode,
-        })
+        };
+
+        pause
+            .check(*partition_size)
+            .map_err(serde::de::Error::custom)?;
+
+        Ok(pause)
     }
 }
 
@@ -134,6 +148,9 @@ impl TryFrom<PathBuf> for ResumeConfig {
 }
 
 impl ResumeConfig {
+    /// # Errors
+    ///
+    /// Returns an error iff writing the resume config to its file failed.
     pub fn write(mut self, config: &str) -> anyhow::Result<()> {
         std::io::Write::write_fmt(&mut self.file, format_args!("{config}\n"))?;
 
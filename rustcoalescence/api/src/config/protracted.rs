@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+use necsim_core_bond::{NonNegativeF64, PositiveF64};
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Protracted {
+    #[serde(alias = "min_speciation_gen")]
+    pub min: NonNegativeF64,
+    #[serde(alias = "max_speciation_gen")]
+    pub max: PositiveF64,
+}
@@ -0,0 +1,45 @@
+//! Type-level [`RngGenerator`] families, with which the typed
+//!  [`SimulationBuilder`](crate::simulate::SimulationBuilder) only
+//!  accepts generators that its algorithm supports
+
+use super::RngGenerator;
+
+/// Type-level [`RngGenerator`]
+pub trait RngGeneratorFamily {
+    const GENERATOR: RngGenerator;
+}
+
+/// Type-level [`RngGenerator::Pcg`]
+pub enum Pcg {}
+
+/// Type-level [`RngGenerator::WyHash`]
+pub enum WyHash {}
+
+/// Type-level [`RngGenerator::SeaHash`]
+pub enum SeaHash {}
+
+/// Type-level [`RngGenerator::Xoshiro256PlusPlus`]
+pub enum Xoshiro256PlusPlus {}
+
+/// Type-level [`RngGenerator::ChaCha8`]
+pub enum ChaCha8 {}
+
+impl RngGeneratorFamily for Pcg {
+    const GENERATOR: RngGenerator = RngGenerator::Pcg;
+}
+
+impl RngGeneratorFamily for WyHash {
+    const GENERATOR: RngGenerator = RngGenerator::WyHash;
+}
+
+impl RngGeneratorFamily for SeaHash {
+    const GENERATOR: RngGenerator = RngGenerator::SeaHash;
+}
+
+impl RngGeneratorFamily for Xoshiro256PlusPlus {
+    const GENERATOR: RngGenerator = RngGenerator::Xoshiro256PlusPlus;
+}
+
+impl RngGeneratorFamily for ChaCha8 {
+    const GENERATOR: RngGenerator = RngGenerator::ChaCha8;
+}
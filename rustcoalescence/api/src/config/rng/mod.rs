@@ -0,0 +1,377 @@
+use std::{fmt, marker::PhantomData};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use necsim_core::cogs::{MathsCore, RngCore};
+use necsim_partitioning_core::partition::PartitionSize;
+
+mod base32;
+pub mod generator;
+
+use self::base32::Base32String;
+
+#[derive(Debug, Serialize)]
+#[serde(bound = "")]
+pub enum Rng<M: MathsCore, G: RngCore<M>> {
+    Seed(u64),
+    Sponge(Base32String),
+    State(Base32RngState<M, G>),
+}
+
+/// Initialisation of the random number generator, which, unlike [`Rng`],
+///  does not depend on the type of the generator
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Serialize)]
+#[serde(rename = "Rng")]
+pub enum RngSeed {
+    Entropy,
+    Seed(u64),
+    #[serde(serialize_with = "serialize_rng_sponge")]
+    Sponge(Vec<u8>),
+}
+
+impl Default for RngSeed {
+    fn default() -> Self {
+        Self::Entropy
+    }
+}
+
+/// Family of the random number generator, which defaults to the algorithm's
+///  default generator if it is not specified
+#[allow(clippy::module_name_repetitions)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RngGenerator {
+    /// Permuted congruential generator, which is splittable
+    Pcg,
+    /// `WyHash`-based generator, which is primeable
+    WyHash,
+    /// `SeaHash`-based generator, which is primeable
+    SeaHash,
+    /// `xoshiro256++` generator, which is splittable
+    Xoshiro256PlusPlus,
+    /// `ChaCha` stream cipher with 8 rounds, which is both splittable and
+    ///  primeable
+    ChaCha8,
+}
+
+/// Initialisation of the random number generator wrapped in its
+///  [`RngGenerator`], e.g. `Pcg(Seed(42))`
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Serialize)]
+#[serde(rename = "Rng")]
+pub enum RngWithGenerator<T> {
+    Pcg(T),
+    WyHash(T),
+    SeaHash(T),
+    Xoshiro256PlusPlus(T),
+    ChaCha8(T),
+}
+
+impl<T> RngWithGenerator<T> {
+    #[must_use]
+    pub fn new(generator: RngGenerator, rng: T) -> Self {
+        match generator {
+            RngGenerator::Pcg => Self::Pcg(rng),
+            RngGenerator::WyHash => Self::WyHash(rng),
+            RngGenerator::SeaHash => Self::SeaHash(rng),
+            RngGenerator::Xoshiro256PlusPlus => Self::Xoshiro256PlusPlus(rng),
+            RngGenerator::ChaCha8 => Self::ChaCha8(rng),
+        }
+    }
+}
+
+/// Initialisation of the random number generator, which optionally
+///  specifies its [`RngGenerator`] and is only resolved into an [`Rng`] once
+///  the type of the generator is known
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Deserialize)]
+#[serde(try_from = "AnyRngRaw")]
+pub struct AnyRng {
+    generator: Option<RngGenerator>,
+    seed: AnyRngSeed,
+}
+
+#[derive(Debug)]
+enum AnyRngSeed {
+    Entropy,
+    Seed(u64),
+    Sponge(Base32String),
+    State(Base32String),
+    StateElseSponge(Base32String),
+}
+
+impl Default for AnyRng {
+    fn default() -> Self {
+        Self {
+            generator: None,
+            seed: AnyRngSeed::Entropy,
+        }
+    }
+}
+
+#[allow(dead_code)]
+pub struct Base32RngState<M: MathsCore, G: RngCore<M>> {
+    rng: G,
+    marker: PhantomData<M>,
+}
+
+impl AnyRng {
+    #[must_use]
+    pub fn new(generator: Option<RngGenerator>, seed: RngSeed) -> Self {
+        let seed = match seed {
+            RngSeed::Entropy => AnyRngSeed::Entropy,
+            RngSeed::Seed(seed) => AnyRngSeed::Seed(seed),
+            RngSeed::Sponge(sponge) => AnyRngSeed::Sponge(Base32String::new(&sponge)),
+        };
+
+        Self { generator, seed }
+    }
+
+    #[must_use]
+    pub fn generator(&self) -> Option<RngGenerator> {
+        self.generator
+    }
+
+    /// Resolves the initialisation for the generator `G`, where `Entropy` is
+    ///  drawn into a fresh sponge
+    ///
+    /// # Errors
+    ///
+    /// Returns an error iff `Entropy` is used with a partitioned simulation,
+    ///  drawing the entropy failed, or the state is not a valid state of `G`.
+    pub fn into_rng<M: MathsCore, G: RngCore<M>>(
+        self,
+        partition_size: PartitionSize,
+    ) -> anyhow::Result<Rng<M, G>> {
+        let rng = match self.seed {
+            AnyRngSeed::Entropy => {
+                let mut entropy = G::Seed::default();
+
+                loop {
+                    getrandom::getrandom(entropy.as_mut()).map_err(anyhow::Error::msg)?;
+
+                    // Ensure that no protected state sponges are generated
+                    if ProtectedState::from_bytes(entropy.as_mut()).is_none() {
+                        break;
+                    }
+                }
+
+                let sponge = Base32String::new(entropy.as_mut());
+
+                anyhow::ensure!(
+                    partition_size.is_monolithic(),
+                    "`Entropy` rng initialisation cannot be used with partitioned \
+                     simulations.\n\nTry using `Sponge({sponge})` instead."
+                );
+
+                Rng::Sponge(sponge)
+            },
+            AnyRngSeed::Seed(seed) => Rng::Seed(seed),
+            AnyRngSeed::Sponge(sponge) => Rng::Sponge(sponge),
+            AnyRngSeed::State(state) => Rng::State(Base32RngState::try_from_state(&state)?),
+            AnyRngSeed::StateElseSponge(state) => {
+                match bincode::Options::deserialize(bincode::options(), &state) {
+                    Ok(rng) => Rng::State(Base32RngState {
+                        rng,
+                        marker: PhantomData::<M>,
+                    }),
+                    Err(_) => Rng::Sponge(state),
+                }
+            },
+        };
+
+        Ok(rng)
+    }
+}
+
+impl TryFrom<AnyRngRaw> for AnyRng {
+    type Error = &'static str;
+
+    fn try_from(raw: AnyRngRaw) -> Result<Self, Self::Error> {
+        let (generator, raw) = match raw {
+            AnyRngRaw::Pcg(raw) => (RngGenerator::Pcg, *raw),
+            AnyRngRaw::WyHash(raw) => (RngGenerator::WyHash, *raw),
+            AnyRngRaw::SeaHash(raw) => (RngGenerator::SeaHash, *raw),
+            AnyRngRaw::Xoshiro256PlusPlus(raw) => (RngGenerator::Xoshiro256PlusPlus, *raw),
+            AnyRngRaw::ChaCha8(raw) => (RngGenerator::ChaCha8, *raw),
+            raw => {
+                return Ok(Self {
+                    generator: None,
+                    seed: raw.try_into_seed()?,
+                })
+            },
+        };
+
+        Ok(Self {
+            generator: Some(generator),
+            seed: raw.try_into_seed()?,
+        })
+    }
+}
+
+impl AnyRngRaw {
+    fn try_into_seed(self) -> Result<AnyRngSeed, &'static str> {
+        match self {
+            Self::Entropy => Ok(AnyRngSeed::Entropy),
+            Self::Seed(seed) => Ok(AnyRngSeed::Seed(seed)),
+            Self::Sponge(sponge) => Ok(AnyRngSeed::Sponge(sponge)),
+            Self::State(state) => Ok(AnyRngSeed::State(state)),
+            Self::StateElseSponge(state) => Ok(AnyRngSeed::StateElseSponge(state)),
+            Self::Pcg(_)
+            | Self::WyHash(_)
+            | Self::SeaHash(_)
+            | Self::Xoshiro256PlusPlus(_)
+            | Self::ChaCha8(_) => Err("the rng generator cannot be specified more than once"),
+        }
+    }
+}
+
+impl<M: MathsCore, G: RngCore<M>> From<G> for Base32RngState<M, G> {
+    fn from(rng: G) -> Self {
+        Self {
+            rng,
+            marker: PhantomData::<M>,
+        }
+    }
+}
+
+impl<M: MathsCore, G: RngCore<M>> Base32RngState<M, G> {
+    #[must_use]
+    #[allow(dead_code)]
+    pub fn into(self) -> G {
+        self.rng
+    }
+}
+
+impl<M: MathsCore, G: RngCore<M>> fmt::Debug for Base32RngState<M, G> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match ProtectedState::serialize(&self.rng) {
+            Ok(state) => Base32String::new(&state).fmt(fmt),
+            Err(_) => fmt.write_str("InvalidRngState"),
+        }
+    }
+}
+
+impl<M: MathsCore, G: RngCore<M>> Serialize for Base32RngState<M, G> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let state = ProtectedState::serialize(&self.rng).map_err(serde::ser::Error::custom)?;
+
+        Base32String::new(&state).serialize(serializer)
+    }
+}
+
+impl<M: MathsCore, G: RngCore<M>> Base32RngState<M, G> {
+    fn try_from_state(state: &Base32String) -> anyhow::Result<Self> {
+        if let Some(state) = ProtectedState::from_bytes(state) {
+            if let Ok(rng) = ProtectedState::deserialize(state) {
+                return Ok(Self {
+                    rng,
+                    marker: PhantomData::<M>,
+                });
+            }
+        }
+
+        Err(anyhow::anyhow!("invalid RNG state {state}"))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename = "Rng")]
+enum AnyRngRaw {
+    Entropy,
+    Seed(u64),
+    #[serde(deserialize_with = "deserialize_rng_sponge")]
+    Sponge(Base32String),
+    #[serde(deserialize_with = "deserialize_rng_state")]
+    State(Base32String),
+    #[serde(deserialize_with = "deserialize_rng_state_else_sponge")]
+    StateElseSponge(Base32String),
+    Pcg(Box<AnyRngRaw>),
+    WyHash(Box<AnyRngRaw>),
+    SeaHash(Box<AnyRngRaw>),
+    Xoshiro256PlusPlus(Box<AnyRngRaw>),
+    ChaCha8(Box<AnyRngRaw>),
+}
+
+#[allow(clippy::ptr_arg)]
+fn serialize_rng_sponge<S: Serializer>(sponge: &Vec<u8>, serializer: S) -> Result<S::Ok, S::Error> {
+    Base32String::new(sponge).serialize(serializer)
+}
+
+fn deserialize_rng_sponge<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Base32String, D::Error> {
+    let sponge = Base32String::deserialize(deserializer)?;
+
+    if ProtectedState::from_bytes(&sponge).is_some() {
+        return Err(serde::de::Error::custom(format!(
+            "invalid RNG sponge but valid RNG state {sponge}\n\nDid you mean to use the `State` \
+             or `StateElseSponge` variants?"
+        )));
+    }
+
+    Ok(sponge)
+}
+
+fn deserialize_rng_state<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Base32String, D::Error> {
+    let state = Base32String::deserialize(deserializer)?;
+
+    if ProtectedState::from_bytes(&state).is_none() {
+        return Err(serde::de::Error::custom(format!(
+            "invalid RNG state {state}"
+        )));
+    }
+
+    Ok(state)
+}
+
+fn deserialize_rng_state_else_sponge<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Base32String, D::Error> {
+    let state_else_sponge = Base32String::deserialize(deserializer)?;
+
+    if let Some(state_else_sponge) = ProtectedState::from_bytes(&state_else_sponge) {
+        return Ok(Base32String::new(state_else_sponge.into_bytes()));
+    }
+
+    Err(serde::de::Error::custom(format!(
+        "invalid RNG state or sponge {state_else_sponge}"
+    )))
+}
+
+struct ProtectedState<'a> {
+    state: &'a [u8],
+}
+
+impl<'a> ProtectedState<'a> {
+    fn serialize<T: Serialize>(value: &T) -> bincode::Result<Vec<u8>> {
+        let mut state = bincode::Options::serialize(bincode::options(), value)?;
+
+        let checksum = adler::adler32_slice(&state);
+
+        state.extend_from_slice(&checksum.to_le_bytes());
+
+        Ok(state)
+    }
+
+    fn deserialize<T: Deserialize<'a>>(self) -> bincode::Result<T> {
+        bincode::Options::deserialize(bincode::options(), self.state)
+    }
+
+    fn from_bytes(bytes: &'a [u8]) -> Option<Self> {
+        let (state, checksum) = bytes.split_last_chunk()?;
+        let checksum = u32::from_le_bytes(*checksum);
+
+        if adler::adler32_slice(state) != checksum {
+            return None;
+        }
+
+        Some(Self { state })
+    }
+
+    fn into_bytes(self) -> &'a [u8] {
+        self.state
+    }
+}
@@ -16,6 +16,10 @@ pub struct Sample {
     pub percentage: ClosedUnitF64,
     pub origin: SampleOrigin,
     pub mode: SampleMode,
+    /// Time at which a `Resume`d simulation continues, i.e. the time before
+    ///  which the resumed lineages were paused
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start: Option<NonNegativeF64>,
 }
 
 impl Default for Sample {
@@ -26,6 +30,7 @@ impl Default for Sample {
             percentage: raw.percentage,
             origin: raw.origin,
             mode: raw.mode,
+            start: raw.start,
         }
     }
 }
@@ -59,14 +64,19 @@ pub struct SampleModeRestart {
     pub after: NonNegativeF64,
 }
 
-impl<'de> DeserializeState<'de, &'de Option<Pause>> for Sample {
-    fn deserialize_state<D: Deserializer<'de>>(
-        pause: &mut &'de Option<Pause>,
-        deserializer: D,
-    ) -> Result<Self, D::Error> {
-        let raw = SampleRaw::deserialize_state(pause, deserializer)?;
+impl Sample {
+    /// # Errors
+    ///
+    /// Returns an error iff the sample's origin, mode, or start time are
+    ///  incompatible with each other or with the `pause`.
+    pub fn check(&self, pause: &Option<Pause>) -> anyhow::Result<()> {
+        self.origin.check(pause)?;
+
+        self.check_mode(pause)
+    }
 
-        match (&raw.origin, &raw.mode) {
+    fn check_mode(&self, pause: &Option<Pause>) -> anyhow::Result<()> {
+        match (&self.origin, &self.mode) {
             (SampleOrigin::Habitat, SampleMode::Genesis)
             | (
                 SampleOrigin::List(_) | SampleOrigin::Bincode(_),
@@ -76,43 +86,40 @@ impl<'de> DeserializeState<'de, &'de Option<Pause>> for Sample {
                 SampleOrigin::Habitat,
                 SampleMode::Resume | SampleMode::FixUp(_) | SampleMode::Restart(_),
             ) => {
-                return Err(serde::de::Error::custom(
-                    "`Habitat` origin is only compatible with `Genesis` mode",
-                ));
+                anyhow::bail!("`Habitat` origin is only compatible with `Genesis` mode")
             },
             (SampleOrigin::List(_) | SampleOrigin::Bincode(_), SampleMode::Genesis) => {
-                return Err(serde::de::Error::custom(
-                    "`Genesis` mode is only compatible with `Habitat` origin",
-                ));
+                anyhow::bail!("`Genesis` mode is only compatible with `Habitat` origin")
             },
         }
 
-        let pre_resume_bound = match &raw.mode {
+        anyhow::ensure!(
+            self.start.is_none() || matches!(self.mode, SampleMode::Resume),
+            "a `start` time is only compatible with `Resume` mode"
+        );
+
+        let pre_resume_bound = match &self.mode {
             SampleMode::Genesis | SampleMode::Resume => None,
             SampleMode::FixUp(_) => {
-                if let Some(pause) = pause {
-                    match pause.mode {
-                        PauseMode::Resume => {
-                            return Err(serde::de::Error::custom(
-                                "`FixUp` sample mode is incompatible with `Resume` pause mode,\n \
-                                 use `Restart` instead",
-                            ))
-                        },
-                        PauseMode::FixUp | PauseMode::Restart => (),
-                    }
-
-                    match PositiveF64::new(pause.before.get()) {
-                        Ok(fix_at) => Some(fix_at),
-                        Err(_) => {
-                            return Err(serde::de::Error::custom(
-                                "`FixUp` mode cannot be used at simulation genesis time 0.0",
-                            ))
-                        },
-                    }
-                } else {
-                    return Err(serde::de::Error::custom(
-                        "`FixUp` mode requires an immediate pause to save the fixed individuals",
-                    ));
+                let Some(pause) = pause else {
+                    anyhow::bail!(
+                        "`FixUp` mode requires an immediate pause to save the fixed individuals"
+                    )
+                };
+
+                match pause.mode {
+                    PauseMode::Resume => anyhow::bail!(
+                        "`FixUp` sample mode is incompatible with `Resume` pause mode,\n use \
+                         `Restart` instead"
+                    ),
+                    PauseMode::FixUp | PauseMode::Restart => (),
+                }
+
+                match PositiveF64::new(pause.before.get()) {
+                    Ok(fix_at) => Some(fix_at),
+                    Err(_) => {
+                        anyhow::bail!("`FixUp` mode cannot be used at simulation genesis time 0.0")
+                    },
                 }
             },
             SampleMode::Restart(SampleModeRestart { after }) => {
@@ -120,28 +127,57 @@ impl<'de> DeserializeState<'de, &'de Option<Pause>> for Sample {
             },
         };
 
-        let lineages = match &raw.origin {
+        let lineages = match &self.origin {
             SampleOrigin::Habitat => None,
             SampleOrigin::List(lineages) => Some(lineages.iter()),
             SampleOrigin::Bincode(loader) => Some(loader.get_lineages().iter()),
         };
 
+        if let (Some(lineages), Some(start)) = (lineages.clone(), self.start) {
+            for lineage in lineages {
+                anyhow::ensure!(
+                    lineage.last_event_time <= start,
+                    "Lineage #{} at time {} is after the resume start time {}",
+                    lineage.global_reference,
+                    lineage.last_event_time,
+                    start
+                );
+            }
+        }
+
         if let (Some(lineages), Some(pre_resume_bound)) = (lineages, pre_resume_bound) {
             for lineage in lineages {
-                if lineage.last_event_time >= pre_resume_bound {
-                    return Err(serde::de::Error::custom(format!(
-                        "Lineage #{} at time {} is not before the resume point",
-                        lineage.global_reference, lineage.last_event_time
-                    )));
-                }
+                anyhow::ensure!(
+                    lineage.last_event_time < pre_resume_bound,
+                    "Lineage #{} at time {} is not before the resume point",
+                    lineage.global_reference,
+                    lineage.last_event_time
+                );
             }
         }
 
-        Ok(Self {
+        Ok(())
+    }
+}
+
+impl<'de> DeserializeState<'de, &'de Option<Pause>> for Sample {
+    fn deserialize_state<D: Deserializer<'de>>(
+        pause: &mut &'de Option<Pause>,
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let raw = SampleRaw::deserialize_state(pause, deserializer)?;
+
+        let sample = Self {
             percentage: raw.percentage,
             origin: raw.origin,
             mode: raw.mode,
-        })
+            start: raw.start,
+        };
+
+        // The sample origin has already been checked during its deserialisation
+        sample.check_mode(pause).map_err(serde::de::Error::custom)?;
+
+        Ok(sample)
     }
 }
 
@@ -155,6 +191,7 @@ struct SampleRaw {
     #[serde(deserialize_state)]
     origin: SampleOrigin,
     mode: SampleMode,
+    start: Option<NonNegativeF64>,
 }
 
 impl Default for SampleRaw {
@@ -163,6 +200,7 @@ impl Default for SampleRaw {
             percentage: ClosedUnitF64::one(),
             origin: SampleOrigin::Habitat,
             mode: SampleMode::Genesis,
+            start: None,
         }
     }
 }
@@ -46,46 +46,54 @@ impl fmt::Debug for SampleOrigin {
     }
 }
 
-impl<'de> DeserializeState<'de, &'de Option<Pause>> for SampleOrigin {
-    fn deserialize_state<D: Deserializer<'de>>(
-        pause: &mut &'de Option<Pause>,
-        deserializer: D,
-    ) -> Result<Self, D::Error> {
-        let raw = SampleOriginRaw::deserialize(deserializer)?;
-
+impl SampleOrigin {
+    /// # Errors
+    ///
+    /// Returns an error iff the origin is incompatible with the `pause`, or
+    ///  if it contains duplicate lineage references.
+    pub fn check(&self, pause: &Option<Pause>) -> anyhow::Result<()> {
         if let Some(pause) = pause {
-            if matches!(pause.destiny, SampleDestiny::List)
-                && !matches!(raw, SampleOriginRaw::List(_))
-            {
-                return Err(serde::de::Error::custom(format!(
-                    "`List` pause destiny requires `List` origin sample, found `{raw}`"
-                )));
-            }
+            anyhow::ensure!(
+                !matches!(pause.destiny, SampleDestiny::List) || matches!(self, Self::List(_)),
+                "`List` pause destiny requires `List` origin sample, found `{self}`"
+            );
         }
 
-        let lineages = match &raw {
-            SampleOriginRaw::Habitat => return Ok(Self::Habitat),
-            SampleOriginRaw::List(lineages) => lineages.iter(),
-            SampleOriginRaw::Bincode(loader) => loader.get_lineages().iter(),
+        let lineages = match self {
+            Self::Habitat => return Ok(()),
+            Self::List(lineages) => lineages.iter(),
+            Self::Bincode(loader) => loader.get_lineages().iter(),
         };
 
         let mut global_references =
             HashSet::with_capacity_and_hasher(lineages.len(), FnvBuildHasher::default());
 
         for lineage in lineages {
-            if !global_references.insert(lineage.global_reference.clone()) {
-                return Err(serde::de::Error::custom(format!(
-                    "duplicate lineage reference #{}",
-                    lineage.global_reference
-                )));
-            }
+            anyhow::ensure!(
+                global_references.insert(lineage.global_reference.clone()),
+                "duplicate lineage reference #{}",
+                lineage.global_reference
+            );
         }
 
-        match raw {
-            SampleOriginRaw::Habitat => Ok(Self::Habitat),
-            SampleOriginRaw::List(lineages) => Ok(Self::List(lineages)),
-            SampleOriginRaw::Bincode(loader) => Ok(Self::Bincode(loader)),
-        }
+        Ok(())
+    }
+}
+
+impl<'de> DeserializeState<'de, &'de Option<Pause>> for SampleOrigin {
+    fn deserialize_state<D: Deserializer<'de>>(
+        pause: &mut &'de Option<Pause>,
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        let origin = match SampleOriginRaw::deserialize(deserializer)? {
+            SampleOriginRaw::Habitat => Self::Habitat,
+            SampleOriginRaw::List(lineages) => Self::List(lineages),
+            SampleOriginRaw::Bincode(loader) => Self::Bincode(loader),
+        };
+
+        origin.check(pause).map_err(serde::de::Error::custom)?;
+
+        Ok(origin)
     }
 }
 
@@ -95,13 +103,3 @@ enum SampleOriginRaw {
     List(Vec<Lineage>),
     Bincode(LineageFileLoader),
 }
-
-impl fmt::Display for SampleOriginRaw {
-    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            Self::Habitat => fmt.write_str("Habitat"),
-            Self::List(_) => fmt.write_str("List"),
-            Self::Bincode(_) => fmt.write_str("Bincode"),
-        }
-    }
-}
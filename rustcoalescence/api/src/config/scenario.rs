@@ -10,10 +10,27 @@ pub enum Scenario {
     SpatiallyExplicitTurnoverMap(
         rustcoalescence_scenarios::spatially_explicit::map::SpatiallyExplicitTurnoverMapArguments,
     ),
+    #[cfg(feature = "spatially-explicit-temporal-scenario")]
+    SpatiallyExplicitTemporal(
+        rustcoalescence_scenarios::spatially_explicit::temporal::SpatiallyExplicitTemporalArguments,
+        rustcoalescence_algorithms::strategy::RestartFixUpStrategy,
+    ),
+    #[cfg(feature = "spatially-explicit-fine-coarse-scenario")]
+    SpatiallyExplicitFineCoarse(
+        rustcoalescence_scenarios::spatially_explicit::fine_coarse::SpatiallyExplicitFineCoarseArguments,
+    ),
+    #[cfg(feature = "spatially-explicit-kernel-scenario")]
+    SpatiallyExplicitKernel(
+        rustcoalescence_scenarios::spatially_explicit::kernel::SpatiallyExplicitKernelArguments,
+    ),
     #[cfg(feature = "non-spatial-scenario")]
     NonSpatial(rustcoalescence_scenarios::non_spatial::NonSpatialArguments),
     #[cfg(feature = "spatially-implicit-scenario")]
     SpatiallyImplicit(rustcoalescence_scenarios::spatially_implicit::SpatiallyImplicitArguments),
+    #[cfg(feature = "spatially-implicit-islands-scenario")]
+    SpatiallyImplicitIslands(
+        rustcoalescence_scenarios::spatially_implicit_islands::SpatiallyImplicitIslandsArguments,
+    ),
     #[cfg(feature = "almost-infinite-normal-dispersal-scenario")]
     AlmostInfiniteNormalDispersal(rustcoalescence_scenarios::almost_infinite::normal::AlmostInfiniteNormalDispersalArguments),
     #[cfg(feature = "almost-infinite-clark2dt-dispersal-scenario")]
@@ -34,10 +51,29 @@ impl Serialize for Scenario {
             Self::SpatiallyExplicitTurnoverMap(ref args) => ScenarioRaw::SpatiallyExplicit(
                 rustcoalescence_scenarios::spatially_explicit::SpatiallyExplicitArguments::from_map(args),
             ),
+            #[cfg(feature = "spatially-explicit-temporal-scenario")]
+            Self::SpatiallyExplicitTemporal(ref args, ref fixup) => {
+                ScenarioRaw::SpatiallyExplicitTemporal(SpatiallyExplicitTemporalArgumentsRaw {
+                    snapshots: rustcoalescence_scenarios::spatially_explicit::temporal::SpatiallyExplicitTemporalSnapshots::from_args(args),
+                    fixup: fixup.clone(),
+                })
+            },
+            #[cfg(feature = "spatially-explicit-fine-coarse-scenario")]
+            Self::SpatiallyExplicitFineCoarse(ref args) => ScenarioRaw::SpatiallyExplicitFineCoarse(
+                rustcoalescence_scenarios::spatially_explicit::fine_coarse::SpatiallyExplicitFineCoarseConfig::from_args(args),
+            ),
+            #[cfg(feature = "spatially-explicit-kernel-scenario")]
+            Self::SpatiallyExplicitKernel(ref args) => ScenarioRaw::SpatiallyExplicitKernel(
+                rustcoalescence_scenarios::spatially_explicit::kernel::SpatiallyExplicitKernelConfig::from_args(args),
+            ),
             #[cfg(feature = "non-spatial-scenario")]
             Self::NonSpatial(ref args) => ScenarioRaw::NonSpatial(args.clone()),
             #[cfg(feature = "spatially-implicit-scenario")]
             Self::SpatiallyImplicit(ref args) => ScenarioRaw::SpatiallyImplicit(args.clone()),
+            #[cfg(feature = "spatially-implicit-islands-scenario")]
+            Self::SpatiallyImplicitIslands(ref args) => {
+                ScenarioRaw::SpatiallyImplicitIslands(args.clone())
+            },
             #[cfg(feature = "almost-infinite-normal-dispersal-scenario")]
             Self::AlmostInfiniteNormalDispersal(ref args) => ScenarioRaw::AlmostInfinite(
                 rustcoalescence_scenarios::almost_infinite::AlmostInfiniteArguments::from_normal(args),
@@ -76,10 +112,29 @@ impl<'de> Deserialize<'de> for Scenario {
                     },
                 }
             },
+            #[cfg(feature = "spatially-explicit-temporal-scenario")]
+            ScenarioRaw::SpatiallyExplicitTemporal(args) => Ok(Self::SpatiallyExplicitTemporal(
+                args.snapshots
+                    .try_load()
+                    .map_err(serde::de::Error::custom)?,
+                args.fixup,
+            )),
+            #[cfg(feature = "spatially-explicit-fine-coarse-scenario")]
+            ScenarioRaw::SpatiallyExplicitFineCoarse(args) => {
+                Ok(Self::SpatiallyExplicitFineCoarse(
+                    args.try_load().map_err(serde::de::Error::custom)?,
+                ))
+            },
+            #[cfg(feature = "spatially-explicit-kernel-scenario")]
+            ScenarioRaw::SpatiallyExplicitKernel(args) => Ok(Self::SpatiallyExplicitKernel(
+                args.try_load().map_err(serde::de::Error::custom)?,
+            )),
             #[cfg(feature = "non-spatial-scenario")]
             ScenarioRaw::NonSpatial(args) => Ok(Self::NonSpatial(args)),
             #[cfg(feature = "spatially-implicit-scenario")]
             ScenarioRaw::SpatiallyImplicit(args) => Ok(Self::SpatiallyImplicit(args)),
+            #[cfg(feature = "spatially-implicit-islands-scenario")]
+            ScenarioRaw::SpatiallyImplicitIslands(args) => Ok(Self::SpatiallyImplicitIslands(args)),
             #[cfg(any(
                 feature = "almost-infinite-normal-dispersal-scenario",
                 feature = "almost-infinite-clark2dt-dispersal-scenario",
@@ -110,10 +165,24 @@ enum ScenarioRaw {
         feature = "spatially-explicit-turnover-map-scenario",
     ))]
     SpatiallyExplicit(rustcoalescence_scenarios::spatially_explicit::SpatiallyExplicitArguments),
+    #[cfg(feature = "spatially-explicit-temporal-scenario")]
+    SpatiallyExplicitTemporal(SpatiallyExplicitTemporalArgumentsRaw),
+    #[cfg(feature = "spatially-explicit-fine-coarse-scenario")]
+    SpatiallyExplicitFineCoarse(
+        rustcoalescence_scenarios::spatially_explicit::fine_coarse::SpatiallyExplicitFineCoarseConfig,
+    ),
+    #[cfg(feature = "spatially-explicit-kernel-scenario")]
+    SpatiallyExplicitKernel(
+        rustcoalescence_scenarios::spatially_explicit::kernel::SpatiallyExplicitKernelConfig,
+    ),
     #[cfg(feature = "non-spatial-scenario")]
     NonSpatial(rustcoalescence_scenarios::non_spatial::NonSpatialArguments),
     #[cfg(feature = "spatially-implicit-scenario")]
     SpatiallyImplicit(rustcoalescence_scenarios::spatially_implicit::SpatiallyImplicitArguments),
+    #[cfg(feature = "spatially-implicit-islands-scenario")]
+    SpatiallyImplicitIslands(
+        rustcoalescence_scenarios::spatially_implicit_islands::SpatiallyImplicitIslandsArguments,
+    ),
     #[cfg(any(
         feature = "almost-infinite-normal-dispersal-scenario",
         feature = "almost-infinite-clark2dt-dispersal-scenario",
@@ -122,3 +191,14 @@ enum ScenarioRaw {
     #[cfg(feature = "wrapping-noise-scenario")]
     WrappingNoise(rustcoalescence_scenarios::wrapping_noise::WrappingNoiseArguments),
 }
+
+#[cfg(feature = "spatially-explicit-temporal-scenario")]
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+#[serde(rename = "SpatiallyExplicitTemporal")]
+struct SpatiallyExplicitTemporalArgumentsRaw {
+    snapshots:
+        rustcoalescence_scenarios::spatially_explicit::temporal::SpatiallyExplicitTemporalSnapshots,
+    #[serde(default)]
+    fixup: rustcoalescence_algorithms::strategy::RestartFixUpStrategy,
+}
@@ -0,0 +1,16 @@
+#![deny(clippy::pedantic)]
+#![feature(unwrap_infallible)]
+#![feature(split_array)]
+#![feature(result_flattening)]
+
+#[macro_use]
+extern crate serde_derive_state;
+
+#[macro_use]
+extern crate log;
+
+pub mod config;
+pub mod simulate;
+pub mod utils;
+
+mod reporter;
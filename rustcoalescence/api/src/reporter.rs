@@ -55,6 +55,40 @@ impl<ReportSpeciation: Boolean, ReportDispersal: Boolean, ReportProgress: Boolea
     }
 }
 
+pub struct InProcessReporterContext<R: Reporter> {
+    reporter: R,
+}
+
+impl<R: Reporter> fmt::Debug for InProcessReporterContext<R> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct(stringify!(InProcessReporterContext))
+            .field("reporter", &self.reporter)
+            .finish()
+    }
+}
+
+impl<R: Reporter> InProcessReporterContext<R> {
+    pub fn new(reporter: R) -> Self {
+        Self { reporter }
+    }
+}
+
+impl<R: Reporter> ReporterContext for InProcessReporterContext<R> {
+    type Reporter = R;
+
+    fn try_build<KeepSpeciation: Boolean, KeepDispersal: Boolean, KeepProgress: Boolean>(
+        self,
+    ) -> anyhow::Result<FilteredReporter<Self::Reporter, KeepSpeciation, KeepDispersal, KeepProgress>>
+    {
+        let mut reporter = self.reporter;
+
+        match reporter.initialise() {
+            Ok(()) => Ok(FilteredReporter::from(reporter)),
+            Err(err) => Err(anyhow::Error::msg(err)),
+        }
+    }
+}
+
 #[cfg_attr(
     not(any(
         feature = "gillespie-algorithms",
@@ -0,0 +1,256 @@
+use anyhow::Context;
+
+use necsim_core::reporter::{NullReporter, Reporter};
+use necsim_core_bond::OpenClosedUnitF64 as PositiveUnitF64;
+use necsim_impls_no_std::cogs::event_sampler::protracted::ProtractedSpeciation;
+use necsim_impls_std::event_log::recorder::EventLogConfig;
+
+#[cfg(any(
+    feature = "gillespie-algorithms",
+    feature = "independent-algorithm",
+    feature = "cuda-algorithm"
+))]
+use crate::config::{algorithm::SupportsRngGenerator, rng::generator::RngGeneratorFamily};
+use crate::{
+    config::{
+        algorithm::Algorithm,
+        checkpoint::Checkpoint,
+        maths::Maths,
+        partitioning::Partitioning,
+        pause::Pause,
+        protracted::Protracted,
+        rng::{AnyRng, RngGenerator, RngSeed, RngWithGenerator},
+        sample::Sample,
+        scenario::Scenario,
+    },
+    utils::{parse::try_print, ser::BufferingSerializeResult},
+};
+
+use super::{
+    checkpoint, simulate_normalised_with_reporter, simulate_with_reporter, BufferingSimulateArgs,
+    ParsedSimulateArgs, SimulationOutcome,
+};
+
+/// Typed builder for an in-process simulation
+///
+/// The builder is validated and normalised in the same way as the config that
+///  the `rustcoalescence simulate` subcommand accepts, which is returned by
+///  [`SimulationBuilder::to_config`].
+/// All events are reported to the in-process reporter, several of which can
+///  be combined using the `ReporterGroup!` macro.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug)]
+pub struct SimulationBuilder<R: Reporter = NullReporter> {
+    speciation: PositiveUnitF64,
+    protracted: ProtractedSpeciation,
+    sample: Sample,
+    pause: Option<Pause>,
+    checkpoint: Option<Checkpoint>,
+    rng: RngSeed,
+    rng_generator: Option<RngGenerator>,
+    scenario: Scenario,
+    algorithm: Algorithm,
+    maths: Maths,
+    partitioning: Partitioning,
+    event_log: Option<EventLogConfig>,
+    reporter: R,
+}
+
+impl SimulationBuilder {
+    #[must_use]
+    pub fn new(speciation: PositiveUnitF64, scenario: Scenario, algorithm: Algorithm) -> Self {
+        Self {
+            speciation,
+            protracted: ProtractedSpeciation::default(),
+            sample: Sample::default(),
+            pause: None,
+            checkpoint: None,
+            rng: RngSeed::default(),
+            rng_generator: None,
+            scenario,
+            algorithm,
+            maths: Maths::default(),
+            partitioning: Partitioning::default(),
+            event_log: None,
+            reporter: NullReporter,
+        }
+    }
+}
+
+impl<R: Reporter> SimulationBuilder<R> {
+    #[must_use]
+    pub fn protracted(mut self, protracted: ProtractedSpeciation) -> Self {
+        self.protracted = protracted;
+        self
+    }
+
+    #[must_use]
+    pub fn sample(mut self, sample: Sample) -> Self {
+        self.sample = sample;
+        self
+    }
+
+    #[must_use]
+    pub fn pause(mut self, pause: Pause) -> Self {
+        self.pause = Some(pause);
+        self
+    }
+
+    /// Periodically checkpoints the simulation, which resumes from the latest
+    ///  checkpoint if it is performed again
+    #[must_use]
+    pub fn checkpoint(mut self, checkpoint: Checkpoint) -> Self {
+        self.checkpoint = Some(checkpoint);
+        self
+    }
+
+    #[must_use]
+    pub fn rng(mut self, rng: RngSeed) -> Self {
+        self.rng = rng;
+        self
+    }
+
+    /// Replaces the algorithm `A` and selects the family `F` of its random
+    ///  number generator, which `A` must support, instead of `A`'s default
+    ///  generator
+    #[cfg(any(
+        feature = "gillespie-algorithms",
+        feature = "independent-algorithm",
+        feature = "cuda-algorithm"
+    ))]
+    #[must_use]
+    pub fn algorithm_with_rng_generator<A: SupportsRngGenerator<F>, F: RngGeneratorFamily>(
+        mut self,
+        algorithm: A::Arguments,
+    ) -> Self {
+        self.algorithm = A::into_algorithm(algorithm);
+        self.rng_generator = Some(F::GENERATOR);
+        self
+    }
+
+    /// Selects the maths functions, which default to [`Maths::Intrinsics`]
+    #[must_use]
+    pub fn maths(mut self, maths: Maths) -> Self {
+        self.maths = maths;
+        self
+    }
+
+    #[must_use]
+    pub fn partitioning(mut self, partitioning: Partitioning) -> Self {
+        self.partitioning = partitioning;
+        self
+    }
+
+    #[must_use]
+    pub fn event_log(mut self, event_log: EventLogConfig) -> Self {
+        self.event_log = Some(event_log);
+        self
+    }
+
+    /// Replaces the in-process reporter, which defaults to the
+    ///  [`NullReporter`]
+    #[must_use]
+    pub fn reporter<R2: Reporter>(self, reporter: R2) -> SimulationBuilder<R2> {
+        SimulationBuilder {
+            speciation: self.speciation,
+            protracted: self.protracted,
+            sample: self.sample,
+            pause: self.pause,
+            checkpoint: self.checkpoint,
+            rng: self.rng,
+            rng_generator: self.rng_generator,
+            scenario: self.scenario,
+            algorithm: self.algorithm,
+            maths: self.maths,
+            partitioning: self.partitioning,
+            event_log: self.event_log,
+            reporter,
+        }
+    }
+
+    /// Returns the config of this simulation in the format of the
+    ///  `rustcoalescence simulate` subcommand
+    ///
+    /// # Errors
+    ///
+    /// Returns an error iff any part of the config cannot be serialised.
+    pub fn to_config(&self) -> anyhow::Result<String> {
+        let protracted = (!self.protracted.is_instantaneous()).then(|| Protracted {
+            min: self.protracted.min(),
+            max: self.protracted.max(),
+        });
+
+        let rng = match self.rng_generator {
+            Some(rng_generator) => {
+                BufferingSerializeResult::from(&RngWithGenerator::new(rng_generator, &self.rng))
+            },
+            None => BufferingSerializeResult::from(&self.rng),
+        };
+
+        BufferingSimulateArgs::builder()
+            .speciation(&self.speciation)
+            .protracted(&protracted)
+            .sample(&self.sample)
+            .pause(&self.pause)
+            .checkpoint(&self.checkpoint)
+            .rng(rng)
+            .scenario(&self.scenario)
+            .algorithm(&self.algorithm)
+            .maths(&self.maths)
+            .partitioning(&self.partitioning)
+            .log(&self.event_log)
+            .reporters(&Vec::<()>::new())
+            .build()
+            .map_err(anyhow::Error::new)
+            .and_then(|simulate_args| try_print(&simulate_args))
+            .context("Failed to normalise the simulation config.")
+    }
+
+    /// Performs the simulation in-process and reports all events to the
+    ///  reporter.
+    ///
+    /// If the simulation pauses, the config to resume it is written to the
+    ///  file specified by the [`Pause`]. If the simulation is checkpointed,
+    ///  it resumes from the latest checkpoint in its directory.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error iff the config is invalid, or initialising or
+    ///  performing the simulation failed.
+    pub fn simulate(self) -> anyhow::Result<SimulationOutcome> {
+        let mut checkpoint = self.checkpoint.clone();
+
+        if let Some(checkpoint) = &mut checkpoint {
+            let config_hash = match checkpoint.config_hash {
+                Some(config_hash) => config_hash,
+                None => checkpoint::config_hash(&self.to_config()?),
+            };
+            checkpoint.config_hash = Some(config_hash);
+
+            if let Some(resume_args) =
+                checkpoint::latest_resume_config(&checkpoint.directory, config_hash)?
+            {
+                return simulate_with_reporter(&resume_args, self.reporter);
+            }
+        }
+
+        let (args, mut normalised_args) = ParsedSimulateArgs {
+            partitioning: self.partitioning,
+            event_log: self.event_log,
+            speciation_probability_per_generation: self.speciation,
+            protracted_speciation: self.protracted,
+            sample: self.sample,
+            scenario: self.scenario,
+            algorithm: self.algorithm,
+            maths: self.maths,
+            pause: self.pause,
+            checkpoint,
+            rng: AnyRng::new(self.rng_generator, self.rng),
+        }
+        .normalise()?;
+
+        normalised_args.reporters(&Vec::<()>::new());
+
+        simulate_normalised_with_reporter(args, self.reporter, normalised_args)
+    }
+}
@@ -0,0 +1,453 @@
+use std::{
+    fs,
+    num::NonZeroUsize,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use anyhow::{Context, Result};
+
+use necsim_core::{
+    cogs::{MathsCore, RngCore},
+    lineage::Lineage,
+    reporter::Reporter,
+};
+use necsim_core_bond::{ClosedUnitF64, NonNegativeF64, PositiveF64};
+use necsim_impls_std::{
+    event_log::{recorder::EventLogConfig, EventLogCompression},
+    lineage_file::{loader::LineageFileLoader, saver::LineageFileSaver},
+};
+use necsim_partitioning_core::{partition::PartitionSize, LocalPartition};
+
+use crate::{
+    config::{
+        checkpoint::{Checkpoint, CheckpointInterval, EventLogBoundary},
+        rng::{Base32RngState, Rng as RngArgs, RngGenerator, RngWithGenerator},
+        sample::{Sample, SampleMode, SampleOrigin},
+    },
+    utils::parse::try_print,
+};
+
+use super::{super::BufferingSimulateArgsBuilder, checkpoint_path, latest_index};
+
+/// Minimum length, in generations, of the chunks between which a simulation
+///  that is checkpointed every few minutes pauses
+const MIN_CHUNK_GENERATIONS: f64 = 1.0_f64;
+/// Minimum length of the chunks relative to the estimated number of
+///  generations that are simulated between two checkpoints
+const MIN_CHUNK_FRACTION: f64 = 0.125_f64;
+/// Maximum factor by which the chunk length grows from one chunk to the next
+const MAX_CHUNK_GROWTH: f64 = 16.0_f64;
+
+/// Writes periodic checkpoints of a simulation, which pauses at the times
+///  returned by [`Checkpointer::next_stop`]
+#[derive(Clone)]
+pub(in super::super) struct Checkpointer {
+    directory: PathBuf,
+    every: CheckpointInterval,
+    config_hash: u64,
+    normalised_args: BufferingSimulateArgsBuilder,
+    rng_generator: RngGenerator,
+    event_log: Option<CheckpointEventLog>,
+    index: usize,
+    last_checkpoint: Instant,
+    chunk: CheckpointChunk,
+}
+
+#[derive(Clone)]
+struct CheckpointEventLog {
+    directory: PathBuf,
+    capacity: NonZeroUsize,
+    compression: EventLogCompression,
+}
+
+#[derive(Clone, Copy)]
+struct CheckpointChunk {
+    length: PositiveF64,
+    started: Instant,
+    until: NonNegativeF64,
+}
+
+impl Checkpointer {
+    pub fn try_new(
+        checkpoint: &Checkpoint,
+        normalised_args: &BufferingSimulateArgsBuilder,
+        rng_generator: RngGenerator,
+        event_log: Option<&EventLogConfig>,
+    ) -> Result<Self> {
+        let config_hash = checkpoint
+            .config_hash
+            .context("The checkpoint is missing the hash of the simulation config.")?;
+
+        fs::create_dir_all(&checkpoint.directory).with_context(|| {
+            format!(
+                "Failed to create the checkpoint directory {:?}.",
+                checkpoint.directory
+            )
+        })?;
+        let directory = checkpoint.directory.canonicalize()?;
+
+        // Never reuse the index of an existing checkpoint or event log
+        let index = latest_index(&directory, "checkpoint-", ".ron")?
+            .max(latest_index(&directory, "log-", "")?)
+            .map_or(0, |latest| latest + 1);
+
+        let length = match checkpoint.every {
+            CheckpointInterval::Generations(generations) => generations,
+            // The first chunk only measures the simulation speed
+            CheckpointInterval::Minutes(_) => PositiveF64::one(),
+        };
+
+        Ok(Self {
+            directory,
+            every: checkpoint.every,
+            config_hash,
+            normalised_args: normalised_args.clone(),
+            rng_generator,
+            event_log: event_log.map(|event_log| CheckpointEventLog {
+                directory: event_log.directory().to_owned(),
+                capacity: event_log.capacity(),
+                compression: event_log.compression(),
+            }),
+            index,
+            last_checkpoint: Instant::now(),
+            chunk: CheckpointChunk {
+                length,
+                started: Instant::now(),
+                until: NonNegativeF64::zero(),
+            },
+        })
+    }
+
+    /// Starts a new chunk of the simulation at `time` and returns the time
+    ///  before which the simulation should pause for the next checkpoint
+    pub fn next_stop(&mut self, time: NonNegativeF64) -> NonNegativeF64 {
+        self.chunk.started = Instant::now();
+        self.chunk.until = (time + self.chunk.length).into();
+
+        self.chunk.until
+    }
+
+    /// Records that the simulation has paused at the end of the current chunk
+    ///  and writes a checkpoint if one is due. Returns the lineages with which
+    ///  the local partition continues.
+    ///
+    /// All partitions must reach the end of the chunk together, since they
+    ///  agree on whether to write a checkpoint and on the next chunk length.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error iff writing the checkpoint failed in any partition.
+    pub fn reached<'p, M: MathsCore, G: RngCore<M>, R: Reporter, P: LocalPartition<'p, R>>(
+        &mut self,
+        local_partition: &mut P,
+        lineages: Vec<Lineage>,
+        rng: &G,
+    ) -> Result<Vec<Lineage>> {
+        let CheckpointInterval::Minutes(minutes) = self.every else {
+            return self.write(local_partition, lineages, rng);
+        };
+
+        let interval = Duration::from_secs_f64(minutes.get() * 60.0_f64);
+
+        // The simulation speed of the current chunk excludes writing the checkpoint
+        let elapsed = self.chunk.started.elapsed().as_secs_f64();
+
+        let due = local_partition
+            .reduce_vote_any(self.last_checkpoint.elapsed() >= interval.mul_f64(0.95_f64));
+
+        let (lineages, remaining) = if due {
+            (self.write(local_partition, lineages, rng)?, interval)
+        } else {
+            (
+                lineages,
+                interval.saturating_sub(self.last_checkpoint.elapsed()),
+            )
+        };
+
+        // Estimate the length of the next chunk from the simulation speed of
+        //  the current chunk, which may have been too short to measure it well
+        let max_length = self.chunk.length.get() * MAX_CHUNK_GROWTH;
+
+        let length = if elapsed > 0.0_f64 {
+            let generations_per_second = self.chunk.length.get() / elapsed;
+
+            // Do not pause more often than necessary shortly before a checkpoint
+            let min_length = (generations_per_second * interval.as_secs_f64() * MIN_CHUNK_FRACTION)
+                .max(MIN_CHUNK_GENERATIONS);
+
+            (generations_per_second * remaining.as_secs_f64())
+                .max(min_length)
+                .min(max_length)
+        } else {
+            max_length
+        };
+
+        let length = PositiveF64::new(length).unwrap_or(self.chunk.length);
+
+        // All partitions continue with the shortest estimated chunk length
+        self.chunk.length = match local_partition.reduce_vote_min_time(length) {
+            Ok(length) | Err(length) => length,
+        };
+
+        Ok(lineages)
+    }
+
+    /// Removes the checkpoints after the simulation has finished, such that
+    ///  it is not resumed again. The event logs are kept.
+    pub fn finish(self) {
+        for index in 0..self.index {
+            remove_checkpoint(&self.directory, index);
+        }
+
+        info!(
+            "The checkpoints in {:?} were removed as the simulation has finished.",
+            self.directory
+        );
+    }
+
+    fn write<'p, M: MathsCore, G: RngCore<M>, R: Reporter, P: LocalPartition<'p, R>>(
+        &mut self,
+        local_partition: &mut P,
+        lineages: Vec<Lineage>,
+        rng: &G,
+    ) -> Result<Vec<Lineage>> {
+        let partition = local_partition.get_partition();
+
+        let lineages = if partition.size().is_monolithic() {
+            let segments = local_partition
+                .flush_event_log()
+                .context("Failed to flush the event log.")?;
+
+            let lineages_path = checkpoint_path(&self.directory, self.index, "lineages");
+            write_lineages(&lineages_path, &lineages)?;
+
+            let mut resume_args = self.normalised_args.clone();
+            resume_args.rng(&RngWithGenerator::new(
+                self.rng_generator,
+                RngArgs::State(Base32RngState::<M, G>::from(rng.clone())),
+            ));
+
+            self.write_resume_config(
+                resume_args,
+                &lineages_path,
+                segments
+                    .and_then(|segments| self.event_log_boundary(None, segments))
+                    .into_iter()
+                    .collect(),
+            )?;
+
+            lineages
+        } else {
+            // Every partition first writes its own part of the checkpoint
+            let result = self.write_partition(local_partition, &lineages);
+            all_partitions_succeeded(local_partition, result)?;
+
+            // The root partition then merges all parts into the checkpoint
+            let result = if partition.is_root() {
+                self.merge_partitions(partition.size())
+            } else {
+                Ok(())
+            };
+            all_partitions_succeeded(local_partition, result)?;
+
+            // All partitions continue with the merged lineages, which are
+            //  partitioned in the same way as when resuming the checkpoint
+            LineageFileLoader::try_new(&checkpoint_path(&self.directory, self.index, "lineages"))
+                .map(LineageFileLoader::into_lineages)
+                .context("Failed to read the checkpointed lineages.")?
+        };
+
+        self.index += 1;
+        self.last_checkpoint = Instant::now();
+
+        Ok(lineages)
+    }
+
+    /// Writes the lineages and the event log boundary of one partition of a
+    ///  parallel simulation
+    fn write_partition<'p, R: Reporter, P: LocalPartition<'p, R>>(
+        &self,
+        local_partition: &mut P,
+        lineages: &[Lineage],
+    ) -> Result<()> {
+        let rank = local_partition.get_partition().rank();
+
+        let segments = local_partition
+            .flush_event_log()
+            .context("Failed to flush the event log.")?;
+
+        write_lineages(
+            &partition_path(&self.directory, self.index, rank, "lineages"),
+            lineages,
+        )?;
+
+        if let Some(segments) = segments {
+            let path = partition_path(&self.directory, self.index, rank, "segments");
+
+            fs::write(&path, segments.to_string())
+                .with_context(|| format!("Failed to write the event log boundary {path:?}."))?;
+        }
+
+        Ok(())
+    }
+
+    /// Merges the parts of all partitions of a parallel simulation into one
+    ///  checkpoint, which resumes from the RNG seed of the original config as
+    ///  the partitions' algorithm reprimes its RNG before every event
+    fn merge_partitions(&self, partition_size: PartitionSize) -> Result<()> {
+        let mut lineages = Vec::new();
+        let mut event_log = Vec::new();
+
+        for rank in 0..partition_size.get() {
+            let path = partition_path(&self.directory, self.index, rank, "lineages");
+
+            lineages.extend(
+                LineageFileLoader::try_new(&path)
+                    .map(LineageFileLoader::into_lineages)
+                    .with_context(|| {
+                        format!("Failed to read the checkpointed lineages {path:?}.")
+                    })?,
+            );
+
+            if self.event_log.is_some() {
+                let path = partition_path(&self.directory, self.index, rank, "segments");
+
+                let segments = fs::read_to_string(&path)
+                    .map_err(anyhow::Error::new)
+                    .and_then(|segments| Ok(segments.trim().parse::<usize>()?))
+                    .with_context(|| format!("Failed to read the event log boundary {path:?}."))?;
+
+                event_log.extend(self.event_log_boundary(Some(rank), segments));
+            }
+        }
+
+        let lineages_path = checkpoint_path(&self.directory, self.index, "lineages");
+        write_lineages(&lineages_path, &lineages)?;
+
+        self.write_resume_config(self.normalised_args.clone(), &lineages_path, event_log)?;
+
+        for rank in 0..partition_size.get() {
+            remove_partition_checkpoint(&self.directory, self.index, rank);
+        }
+
+        Ok(())
+    }
+
+    fn event_log_boundary(
+        &self,
+        partition: Option<u32>,
+        segments: usize,
+    ) -> Option<EventLogBoundary> {
+        self.event_log.as_ref().map(|event_log| EventLogBoundary {
+            directory: event_log.directory.clone(),
+            partition,
+            segments,
+        })
+    }
+
+    fn write_resume_config(
+        &self,
+        mut resume_args: BufferingSimulateArgsBuilder,
+        lineages_path: &Path,
+        event_log: Vec<EventLogBoundary>,
+    ) -> Result<()> {
+        resume_args
+            .sample(&Sample {
+                percentage: ClosedUnitF64::one(),
+                origin: SampleOrigin::Bincode(
+                    LineageFileLoader::try_new(lineages_path)
+                        .context("Failed to write the checkpointed lineages.")?,
+                ),
+                mode: SampleMode::Resume,
+                start: Some(self.chunk.until),
+            })
+            .checkpoint(&Some(Checkpoint {
+                directory: self.directory.clone(),
+                every: self.every,
+                config_hash: Some(self.config_hash),
+                event_log,
+            }));
+
+        // The resumed simulation records its events into a fresh event log
+        if let Some(event_log) = &self.event_log {
+            resume_args.log(&Some(EventLogConfig::try_new(
+                self.directory.join(format!("log-{}", self.index)),
+                event_log.capacity,
+                event_log.compression,
+            )?));
+        }
+
+        let resume_str = resume_args
+            .build()
+            .map_err(anyhow::Error::new)
+            .and_then(|resume_args| try_print(&resume_args))
+            .context("Failed to generate the config to resume from the checkpoint.")?;
+
+        let path = checkpoint_path(&self.directory, self.index, "ron");
+        let temp_path = checkpoint_path(&self.directory, self.index, "ron.tmp");
+
+        fs::write(&temp_path, resume_str.trim_start_matches("Simulate"))
+            .and_then(|()| fs::rename(&temp_path, &path))
+            .with_context(|| format!("Failed to write the checkpoint {path:?}."))?;
+
+        for index in 0..self.index {
+            remove_checkpoint(&self.directory, index);
+        }
+
+        info!(
+            "The simulation wrote the checkpoint {:?} before time {}.",
+            path, self.chunk.until
+        );
+
+        Ok(())
+    }
+}
+
+/// Ensures that either all partitions continue or all fail together
+fn all_partitions_succeeded<'p, R: Reporter, P: LocalPartition<'p, R>>(
+    local_partition: &mut P,
+    result: Result<()>,
+) -> Result<()> {
+    let failed = local_partition.reduce_vote_any(result.is_err());
+
+    result?;
+
+    anyhow::ensure!(
+        !failed,
+        "Another partition failed to write its part of the checkpoint."
+    );
+
+    Ok(())
+}
+
+fn write_lineages(path: &Path, lineages: &[Lineage]) -> Result<()> {
+    // Remove the remains of an interrupted attempt to write this checkpoint
+    if path.exists() {
+        fs::remove_file(path)?;
+    }
+
+    LineageFileSaver::try_new(path)
+        .and_then(|lineage_file| lineage_file.write(lineages.iter()))
+        .context("Failed to write the checkpointed lineages.")
+}
+
+fn partition_path(directory: &Path, index: usize, rank: u32, extension: &str) -> PathBuf {
+    directory.join(format!("checkpoint-{index}.partition-{rank}.{extension}"))
+}
+
+fn remove_checkpoint(directory: &Path, index: usize) {
+    std::mem::drop(fs::remove_file(checkpoint_path(directory, index, "ron")));
+    std::mem::drop(fs::remove_file(checkpoint_path(
+        directory, index, "lineages",
+    )));
+}
+
+fn remove_partition_checkpoint(directory: &Path, index: usize, rank: u32) {
+    std::mem::drop(fs::remove_file(partition_path(
+        directory, index, rank, "lineages",
+    )));
+    std::mem::drop(fs::remove_file(partition_path(
+        directory, index, rank, "segments",
+    )));
+}
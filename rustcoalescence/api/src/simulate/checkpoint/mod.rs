@@ -0,0 +1,182 @@
+use std::{
+    borrow::Cow,
+    fs,
+    hash::Hasher,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result};
+use fnv::FnvHasher;
+use serde::Deserialize;
+
+use crate::{config::checkpoint::Checkpoint, utils::parse::try_parse};
+
+#[cfg(any(
+    feature = "gillespie-algorithms",
+    feature = "independent-algorithm",
+    feature = "cuda-algorithm"
+))]
+mod checkpointer;
+
+#[cfg(any(
+    feature = "gillespie-algorithms",
+    feature = "independent-algorithm",
+    feature = "cuda-algorithm"
+))]
+pub(super) use checkpointer::Checkpointer;
+
+/// Returns the config to resume from the latest checkpoint in the directory
+///  specified in the `checkpoint` field of the `ron_args` config, or the
+///  `ron_args` themselves if no checkpoint has been written yet.
+pub(super) fn resume_from_latest(ron_args: &str) -> Result<Cow<str>> {
+    let SimulateArgsCheckpointOnly { checkpoint } = try_parse("simulate", ron_args)?;
+
+    let Some(checkpoint) = checkpoint else {
+        return Ok(Cow::Borrowed(ron_args));
+    };
+
+    let config_hash = checkpoint
+        .config_hash
+        .unwrap_or_else(|| config_hash(ron_args));
+
+    match latest_resume_config(&checkpoint.directory, config_hash)? {
+        Some(resume_args) => Ok(Cow::Owned(resume_args)),
+        None => Ok(Cow::Borrowed(ron_args)),
+    }
+}
+
+/// Returns the config to resume from the latest checkpoint in the
+///  `directory`, or [`None`] if no checkpoint has been written yet.
+///
+/// The checkpoint must have been written by a simulation whose config has
+///  the `config_hash`.
+pub(super) fn latest_resume_config(directory: &Path, config_hash: u64) -> Result<Option<String>> {
+    let Some(latest) = latest_index(directory, "checkpoint-", ".ron")? else {
+        return Ok(None);
+    };
+
+    let path = checkpoint_path(directory, latest, "ron");
+
+    let resume_args = fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read the checkpoint {path:?}."))?;
+
+    let SimulateArgsCheckpointOnly { checkpoint } = try_parse("simulate", &resume_args)
+        .with_context(|| format!("Failed to parse the checkpoint {path:?}."))?;
+
+    anyhow::ensure!(
+        checkpoint.and_then(|checkpoint| checkpoint.config_hash) == Some(config_hash),
+        "The checkpoint {path:?} was written by a simulation with a different config.\n\nIf you \
+         want to start a new simulation, remove the checkpoint directory {directory:?} or choose \
+         a different one."
+    );
+
+    info!("The simulation will resume from the checkpoint {:?}.", path);
+
+    Ok(Some(resume_args))
+}
+
+/// Returns the hash of the normalised `ron_args` config, from which all
+///  comments, whitespace outside of strings, and trailing commas are removed
+pub(super) fn config_hash(ron_args: &str) -> u64 {
+    let mut hasher = FnvHasher::default();
+    let mut hash = |c: char| hasher.write(c.encode_utf8(&mut [0_u8; 4]).as_bytes());
+
+    let mut chars = ron_args.chars().peekable();
+    let mut trailing_comma = false;
+
+    while let Some(c) = chars.next() {
+        match c {
+            '/' if chars.peek() == Some(&'/') => {
+                chars.by_ref().find(|c| *c == '\n');
+            },
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+
+                // Block comments may be nested
+                let mut depth = 1_usize;
+
+                while depth > 0 {
+                    match (chars.next(), chars.peek()) {
+                        (None, _) => break,
+                        (Some('/'), Some('*')) => {
+                            chars.next();
+                            depth += 1;
+                        },
+                        (Some('*'), Some('/')) => {
+                            chars.next();
+                            depth -= 1;
+                        },
+                        _ => (),
+                    }
+                }
+            },
+            c if c.is_whitespace() => (),
+            ',' => trailing_comma = true,
+            c => {
+                if trailing_comma && !matches!(c, ')' | ']' | '}') {
+                    hash(',');
+                }
+                trailing_comma = false;
+
+                hash(c);
+
+                // Strings and characters are hashed verbatim
+                if matches!(c, '"' | '\'') {
+                    while let Some(s) = chars.next() {
+                        hash(s);
+
+                        if s == '\\' {
+                            if let Some(escaped) = chars.next() {
+                                hash(escaped);
+                            }
+                        } else if s == c {
+                            break;
+                        }
+                    }
+                }
+            },
+        }
+    }
+
+    hasher.finish()
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "Simulate")]
+struct SimulateArgsCheckpointOnly {
+    #[serde(default)]
+    checkpoint: Option<Checkpoint>,
+}
+
+fn checkpoint_path(directory: &Path, index: usize, extension: &str) -> PathBuf {
+    directory.join(format!("checkpoint-{index}.{extension}"))
+}
+
+/// Returns the largest index `k` of an entry named `{prefix}{k}{suffix}`
+///  inside the `directory`, if any
+fn latest_index(directory: &Path, prefix: &str, suffix: &str) -> Result<Option<usize>> {
+    if !directory.exists() {
+        return Ok(None);
+    }
+
+    let mut latest = None;
+
+    for entry in fs::read_dir(directory)
+        .with_context(|| format!("Failed to read the checkpoint directory {directory:?}."))?
+    {
+        let file_name = entry?.file_name();
+
+        let Some(index) = file_name
+            .to_str()
+            .and_then(|name| name.strip_prefix(prefix))
+            .and_then(|name| name.strip_suffix(suffix))
+            .and_then(|index| index.parse::<usize>().ok())
+        else {
+            continue;
+        };
+
+        latest = latest.max(Some(index));
+    }
+
+    Ok(latest)
+}
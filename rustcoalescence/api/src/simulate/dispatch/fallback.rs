@@ -0,0 +1,69 @@
+use necsim_core::reporter::Reporter;
+use necsim_core_bond::{NonNegativeF64, OpenClosedUnitF64 as PositiveUnitF64};
+use necsim_impls_no_std::cogs::event_sampler::protracted::ProtractedSpeciation;
+use necsim_impls_std::event_log::recorder::EventLogConfig;
+use necsim_partitioning_core::reporter::ReporterContext;
+use necsim_plugins_core::import::AnyReporterPluginVec;
+
+use crate::{
+    config::{
+        algorithm::Algorithm, checkpoint::Checkpoint, maths::Maths, partitioning::Partitioning,
+        rng::AnyRng, sample::Sample, scenario::Scenario,
+    },
+    simulate::SimulationOutcome,
+};
+
+use super::super::BufferingSimulateArgsBuilder;
+
+#[allow(clippy::too_many_arguments, clippy::needless_pass_by_value)]
+pub(in super::super) fn dispatch(
+    _partitioning: Partitioning,
+    _event_log: Option<EventLogConfig>,
+    _reporters: AnyReporterPluginVec,
+
+    _speciation_probability_per_generation: PositiveUnitF64,
+    _protracted_speciation: ProtractedSpeciation,
+    _sample: Sample,
+    _scenario: Scenario,
+    _algorithm: Algorithm,
+    _maths: Maths,
+    _pause_before: Option<NonNegativeF64>,
+    _checkpoint: Option<Checkpoint>,
+
+    _rng: AnyRng,
+    _normalised_args: &mut BufferingSimulateArgsBuilder,
+) -> anyhow::Result<SimulationOutcome> {
+    extern "C" {
+        fn simulate_dispatch_without_algorithm() -> !;
+    }
+
+    unsafe { simulate_dispatch_without_algorithm() }
+}
+
+#[allow(clippy::too_many_arguments, clippy::needless_pass_by_value)]
+pub(in super::super) fn dispatch_with_reporter_context<
+    R: Reporter,
+    P: ReporterContext<Reporter = R>,
+>(
+    _partitioning: Partitioning,
+    _event_log: Option<EventLogConfig>,
+    _reporter_context: P,
+
+    _speciation_probability_per_generation: PositiveUnitF64,
+    _protracted_speciation: ProtractedSpeciation,
+    _sample: Sample,
+    _scenario: Scenario,
+    _algorithm: Algorithm,
+    _maths: Maths,
+    _pause_before: Option<NonNegativeF64>,
+    _checkpoint: Option<Checkpoint>,
+
+    _rng: AnyRng,
+    _normalised_args: &mut BufferingSimulateArgsBuilder,
+) -> anyhow::Result<SimulationOutcome> {
+    extern "C" {
+        fn simulate_dispatch_without_algorithm() -> !;
+    }
+
+    unsafe { simulate_dispatch_without_algorithm() }
+}
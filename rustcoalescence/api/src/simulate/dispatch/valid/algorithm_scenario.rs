@@ -0,0 +1,490 @@
+use necsim_core::{cogs::MathsCore, reporter::Reporter};
+use necsim_core_bond::{NonNegativeF64, OpenClosedUnitF64 as PositiveUnitF64};
+#[cfg(all(feature = "independent-algorithm", feature = "seahash-rng"))]
+use necsim_impls_no_std::cogs::rng::seahash::SeaHash;
+#[cfg(feature = "independent-algorithm")]
+use necsim_impls_no_std::cogs::rng::wyhash::WyHash;
+#[cfg(all(feature = "gillespie-algorithms", feature = "xoshiro-rng"))]
+use necsim_impls_no_std::cogs::rng::xoshiro::Xoshiro256PlusPlus;
+#[cfg(any(
+    feature = "spatially-explicit-uniform-turnover-scenario",
+    feature = "spatially-explicit-turnover-map-scenario",
+    feature = "spatially-explicit-temporal-scenario",
+))]
+use necsim_impls_no_std::cogs::speciation_probability::{
+    in_memory::InMemorySpeciationProbability, uniform::UniformSpeciationProbability,
+};
+use necsim_impls_no_std::cogs::{
+    event_sampler::protracted::ProtractedSpeciation, maths::reproducible::ReproducibleMathsCore,
+};
+#[cfg(all(
+    any(feature = "gillespie-algorithms", feature = "independent-algorithm"),
+    feature = "chacha-rng",
+))]
+use necsim_impls_std::cogs::rng::chacha::ChaCha8;
+#[cfg(feature = "gillespie-algorithms")]
+use necsim_impls_std::cogs::rng::pcg::Pcg;
+use necsim_impls_std::event_log::recorder::EventLogConfig;
+use necsim_partitioning_core::reporter::ReporterContext;
+
+use rustcoalescence_algorithms::AlgorithmDefaults;
+
+#[cfg(feature = "cuda-algorithm")]
+use rustcoalescence_algorithms_cuda::CudaAlgorithm;
+#[cfg(feature = "gillespie-algorithms")]
+use rustcoalescence_algorithms_gillespie::{
+    event_skipping::EventSkippingAlgorithm, gillespie::GillespieAlgorithm,
+};
+#[cfg(feature = "independent-algorithm")]
+use rustcoalescence_algorithms_independent::IndependentAlgorithm;
+
+#[cfg(feature = "almost-infinite-clark2dt-dispersal-scenario")]
+use rustcoalescence_scenarios::almost_infinite::clark2dt::AlmostInfiniteClark2DtDispersalScenario;
+#[cfg(feature = "almost-infinite-normal-dispersal-scenario")]
+use rustcoalescence_scenarios::almost_infinite::normal::AlmostInfiniteNormalDispersalScenario;
+#[cfg(feature = "non-spatial-scenario")]
+use rustcoalescence_scenarios::non_spatial::NonSpatialScenario;
+#[cfg(feature = "spatially-explicit-fine-coarse-scenario")]
+use rustcoalescence_scenarios::spatially_explicit::fine_coarse::SpatiallyExplicitFineCoarseScenario;
+#[cfg(feature = "spatially-explicit-kernel-scenario")]
+use rustcoalescence_scenarios::spatially_explicit::kernel::SpatiallyExplicitKernelScenario;
+#[cfg(any(
+    feature = "spatially-explicit-turnover-map-scenario",
+    feature = "spatially-explicit-temporal-scenario",
+))]
+use rustcoalescence_scenarios::spatially_explicit::map::{
+    SpatiallyExplicitTurnoverMapArguments, SpatiallyExplicitTurnoverMapScenario,
+};
+#[cfg(feature = "spatially-explicit-temporal-scenario")]
+use rustcoalescence_scenarios::spatially_explicit::temporal::SpatiallyExplicitTemporalArguments;
+#[cfg(feature = "spatially-explicit-uniform-turnover-scenario")]
+use rustcoalescence_scenarios::spatially_explicit::uniform::{
+    SpatiallyExplicitUniformTurnoverArguments, SpatiallyExplicitUniformTurnoverScenario,
+};
+#[cfg(feature = "spatially-implicit-scenario")]
+use rustcoalescence_scenarios::spatially_implicit::SpatiallyImplicitScenario;
+#[cfg(feature = "spatially-implicit-islands-scenario")]
+use rustcoalescence_scenarios::spatially_implicit_islands::SpatiallyImplicitIslandsScenario;
+#[cfg(feature = "wrapping-noise-scenario")]
+use rustcoalescence_scenarios::wrapping_noise::WrappingNoiseScenario;
+use rustcoalescence_scenarios::Scenario;
+
+use crate::{
+    config::{
+        algorithm::Algorithm as AlgorithmArgs,
+        checkpoint::Checkpoint,
+        maths::Maths,
+        partitioning::Partitioning,
+        rng::{AnyRng, RngGenerator},
+        sample::Sample as SampleArgs,
+        scenario::Scenario as ScenarioArgs,
+    },
+    simulate::SimulationOutcome,
+};
+
+use super::{super::super::BufferingSimulateArgsBuilder, rng, schedule::ScenarioSchedule};
+
+macro_rules! match_scenario_algorithm {
+    (
+        (
+            $algorithm:expr, $scenario:expr, $generator:ident
+            => $algscen:ident : $algscenty:ident, $rngty:ident
+        ) {
+            $($(#[$algmeta:meta])* $algpat:pat => $algname:literal [
+                $defgen:ident => $defgenty:ty $(, $(#[$genmeta:meta])* $gen:ident => $genty:ty)*
+            ] $algcode:block),*
+            <=>
+            $($(#[$scenmeta:meta])* $scenpat:pat => $scencode:block => $scenty:ty),*
+        }
+    ) => {
+        match_scenario_algorithm! {
+            impl ($algorithm, $scenario, $generator => $algscen : $algscenty, $rngty) {
+                $($(#[$algmeta])* $algpat => $algname [
+                    $defgen => $defgenty $(, $(#[$genmeta])* $gen => $genty)*
+                ] $algcode),*
+            } {
+                $($(#[$scenmeta])* $scenpat => $scencode => $scenty),*
+            }
+        }
+    };
+    (
+        impl (
+            $algorithm:expr, $scenario:expr, $generator:ident
+            => $algscen:ident : $algscenty:ident, $rngty:ident
+        ) {
+            $($(#[$algmeta:meta])* $algpat:pat => $algname:literal [
+                $defgen:ident => $defgenty:ty $(, $(#[$genmeta:meta])* $gen:ident => $genty:ty)*
+            ] $algcode:block),*
+        } $scenarios:tt
+    ) => {
+        match $algorithm {
+            $($(#[$algmeta])* $algpat => {
+                let $generator = $generator.unwrap_or(RngGenerator::$defgen);
+
+                match $generator {
+                    RngGenerator::$defgen => {
+                        type $rngty<M> = $defgenty;
+
+                        match_scenario_algorithm! {
+                            impl ($scenario => $algscen : $algscenty) $scenarios $algcode
+                        }
+                    },
+                    $($(#[$genmeta])* RngGenerator::$gen => {
+                        type $rngty<M> = $genty;
+
+                        match_scenario_algorithm! {
+                            impl ($scenario => $algscen : $algscenty) $scenarios $algcode
+                        }
+                    },)*
+                    #[allow(unreachable_patterns)]
+                    generator => Err(anyhow::anyhow!(
+                        "The {} algorithm does not support the {:?} rng generator in this build \
+                         of rustcoalescence.",
+                        $algname, generator,
+                    )),
+                }
+            }),*
+        }
+    };
+    (
+        impl ($scenario:expr => $algscen:ident : $algscenty:ident) {
+            $($(#[$scenmeta:meta])* $scenpat:pat => $scencode:block => $scenty:ty),*
+        } $algcode:block
+    ) => {
+        match $scenario {
+            $($(#[$scenmeta])* $scenpat => {
+                type $algscenty = $scenty;
+                let $algscen = $scencode;
+                $algcode
+            }),*
+        }
+    };
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(super) fn dispatch<R: Reporter, P: ReporterContext<Reporter = R>>(
+    partitioning: Partitioning,
+    event_log: Option<EventLogConfig>,
+    reporter_context: P,
+
+    speciation_probability_per_generation: PositiveUnitF64,
+    protracted_speciation: ProtractedSpeciation,
+    sample: SampleArgs,
+    scenario: ScenarioArgs,
+    algorithm: AlgorithmArgs,
+    maths: Maths,
+    pause_before: Option<NonNegativeF64>,
+    checkpoint: Option<Checkpoint>,
+
+    rng: AnyRng,
+    normalised_args: &mut BufferingSimulateArgsBuilder,
+) -> anyhow::Result<SimulationOutcome> {
+    match maths {
+        Maths::Intrinsics => dispatch_with_maths::<IntrinsicsMaths, R, P>(
+            partitioning,
+            event_log,
+            reporter_context,
+            speciation_probability_per_generation,
+            protracted_speciation,
+            sample,
+            scenario,
+            algorithm,
+            pause_before,
+            checkpoint,
+            rng,
+            normalised_args,
+        ),
+        Maths::Reproducible => {
+            #[cfg(feature = "cuda-algorithm")]
+            if matches!(algorithm, AlgorithmArgs::Cuda(_)) {
+                anyhow::bail!("The CUDA algorithm does not yet support reproducible maths.");
+            }
+
+            dispatch_with_maths::<ReproducibleMaths, R, P>(
+                partitioning,
+                event_log,
+                reporter_context,
+                speciation_probability_per_generation,
+                protracted_speciation,
+                sample,
+                scenario,
+                algorithm,
+                pause_before,
+                checkpoint,
+                rng,
+                normalised_args,
+            )
+        },
+    }
+}
+
+/// Selection of the [`MathsCore`] with which an algorithm is dispatched
+trait MathsSelection {
+    type MathsCore<A: AlgorithmDefaults>: MathsCore;
+}
+
+/// The algorithm's default [`MathsCore`], which uses intrinsics
+enum IntrinsicsMaths {}
+
+impl MathsSelection for IntrinsicsMaths {
+    type MathsCore<A: AlgorithmDefaults> = A::MathsCore;
+}
+
+/// The [`ReproducibleMathsCore`] for all algorithms
+enum ReproducibleMaths {}
+
+impl MathsSelection for ReproducibleMaths {
+    type MathsCore<A: AlgorithmDefaults> = ReproducibleMathsCore;
+}
+
+#[allow(clippy::too_many_arguments, clippy::too_many_lines)]
+fn dispatch_with_maths<S: MathsSelection, R: Reporter, P: ReporterContext<Reporter = R>>(
+    partitioning: Partitioning,
+    event_log: Option<EventLogConfig>,
+    reporter_context: P,
+
+    speciation_probability_per_generation: PositiveUnitF64,
+    protracted_speciation: ProtractedSpeciation,
+    sample: SampleArgs,
+    scenario: ScenarioArgs,
+    algorithm: AlgorithmArgs,
+    pause_before: Option<NonNegativeF64>,
+    checkpoint: Option<Checkpoint>,
+
+    rng: AnyRng,
+    normalised_args: &mut BufferingSimulateArgsBuilder,
+) -> anyhow::Result<SimulationOutcome> {
+    let rng_generator = rng.generator();
+
+    match_scenario_algorithm!(
+        (algorithm, scenario, rng_generator => scenario: ScenarioTy, RngTy)
+    {
+        #[cfg(feature = "gillespie-algorithms")]
+        AlgorithmArgs::Gillespie(algorithm_args) => "Gillespie" [
+            Pcg => Pcg<M>,
+            #[cfg(feature = "xoshiro-rng")]
+            Xoshiro256PlusPlus => Xoshiro256PlusPlus<M>,
+            #[cfg(feature = "chacha-rng")]
+            ChaCha8 => ChaCha8<M>
+        ] {
+            rng::dispatch::<
+                S::MathsCore<GillespieAlgorithm>, RngTy<_>,
+                GillespieAlgorithm, ScenarioTy, R, P,
+            >(
+                partitioning, event_log, reporter_context,
+                sample, rng_generator, algorithm_args,
+                scenario.with_protracted_speciation(protracted_speciation),
+                pause_before, checkpoint, rng, normalised_args,
+            )
+        },
+        #[cfg(feature = "gillespie-algorithms")]
+        AlgorithmArgs::EventSkipping(algorithm_args) => "EventSkipping" [
+            Pcg => Pcg<M>,
+            #[cfg(feature = "xoshiro-rng")]
+            Xoshiro256PlusPlus => Xoshiro256PlusPlus<M>,
+            #[cfg(feature = "chacha-rng")]
+            ChaCha8 => ChaCha8<M>
+        ] {
+            rng::dispatch::<
+                S::MathsCore<EventSkippingAlgorithm>, RngTy<_>,
+                EventSkippingAlgorithm, ScenarioTy, R, P,
+            >(
+                partitioning, event_log, reporter_context,
+                sample, rng_generator, algorithm_args,
+                scenario.with_protracted_speciation(protracted_speciation),
+                pause_before, checkpoint, rng, normalised_args,
+            )
+        },
+        #[cfg(feature = "independent-algorithm")]
+        AlgorithmArgs::Independent(algorithm_args) => "Independent" [
+            WyHash => WyHash<M>,
+            #[cfg(feature = "seahash-rng")]
+            SeaHash => SeaHash<M>,
+            #[cfg(feature = "chacha-rng")]
+            ChaCha8 => ChaCha8<M>
+        ] {
+            rng::dispatch::<
+                S::MathsCore<IndependentAlgorithm>, RngTy<_>,
+                IndependentAlgorithm, ScenarioTy, R, P,
+            >(
+                partitioning, event_log, reporter_context,
+                sample, rng_generator, algorithm_args,
+                scenario.with_protracted_speciation(protracted_speciation),
+                pause_before, checkpoint, rng, normalised_args,
+            )
+        },
+        #[cfg(feature = "cuda-algorithm")]
+        AlgorithmArgs::Cuda(algorithm_args) => "CUDA" [
+            WyHash => <CudaAlgorithm as AlgorithmDefaults>::Rng<M>
+        ] {
+            // The CUDA algorithm always uses its NVPTX maths intrinsics
+            rng::dispatch::<
+                <CudaAlgorithm as AlgorithmDefaults>::MathsCore, RngTy<_>,
+                CudaAlgorithm, ScenarioTy, R, P,
+            >(
+                partitioning, event_log, reporter_context,
+                sample, rng_generator, algorithm_args,
+                scenario.with_protracted_speciation(protracted_speciation),
+                pause_before, checkpoint, rng, normalised_args,
+            )
+        }
+        <=>
+        #[cfg(feature = "spatially-explicit-uniform-turnover-scenario")]
+        ScenarioArgs::SpatiallyExplicitUniformTurnover(
+            scenario_args @ SpatiallyExplicitUniformTurnoverArguments {
+                speciation_map: None, ..
+            },
+        ) => {
+            ScenarioSchedule::fixed(
+                SpatiallyExplicitUniformTurnoverScenario::<UniformSpeciationProbability>::new(
+                    scenario_args,
+                    speciation_probability_per_generation,
+                )?,
+            )
+        } => SpatiallyExplicitUniformTurnoverScenario<UniformSpeciationProbability>,
+        #[cfg(feature = "spatially-explicit-uniform-turnover-scenario")]
+        ScenarioArgs::SpatiallyExplicitUniformTurnover(scenario_args) => {
+            ScenarioSchedule::fixed(
+                SpatiallyExplicitUniformTurnoverScenario::<InMemorySpeciationProbability>::new(
+                    scenario_args,
+                    speciation_probability_per_generation,
+                )?,
+            )
+        } => SpatiallyExplicitUniformTurnoverScenario<InMemorySpeciationProbability>,
+        #[cfg(feature = "spatially-explicit-turnover-map-scenario")]
+        ScenarioArgs::SpatiallyExplicitTurnoverMap(
+            scenario_args @ SpatiallyExplicitTurnoverMapArguments {
+                speciation_map: None, ..
+            },
+        ) => {
+            ScenarioSchedule::fixed(
+                SpatiallyExplicitTurnoverMapScenario::<UniformSpeciationProbability>::new(
+                    scenario_args,
+                    speciation_probability_per_generation,
+                )?,
+            )
+        } => SpatiallyExplicitTurnoverMapScenario<UniformSpeciationProbability>,
+        #[cfg(feature = "spatially-explicit-turnover-map-scenario")]
+        ScenarioArgs::SpatiallyExplicitTurnoverMap(scenario_args) => {
+            ScenarioSchedule::fixed(
+                SpatiallyExplicitTurnoverMapScenario::<InMemorySpeciationProbability>::new(
+                    scenario_args,
+                    speciation_probability_per_generation,
+                )?,
+            )
+        } => SpatiallyExplicitTurnoverMapScenario<InMemorySpeciationProbability>,
+        #[cfg(feature = "spatially-explicit-temporal-scenario")]
+        ScenarioArgs::SpatiallyExplicitTemporal(
+            scenario_args @ SpatiallyExplicitTemporalArguments {
+                initial: SpatiallyExplicitTurnoverMapArguments {
+                    speciation_map: None, ..
+                },
+                ..
+            },
+            fixup_strategy,
+        ) => {
+            ScenarioSchedule::<
+                _, _, SpatiallyExplicitTurnoverMapScenario<UniformSpeciationProbability>,
+            >::try_new(
+                scenario_args.initial,
+                scenario_args
+                    .changes
+                    .into_iter()
+                    .map(|change| (change.time, change.args)),
+                fixup_strategy,
+                speciation_probability_per_generation,
+            )?
+        } => SpatiallyExplicitTurnoverMapScenario<UniformSpeciationProbability>,
+        #[cfg(feature = "spatially-explicit-temporal-scenario")]
+        ScenarioArgs::SpatiallyExplicitTemporal(scenario_args, fixup_strategy) => {
+            ScenarioSchedule::<
+                _, _, SpatiallyExplicitTurnoverMapScenario<InMemorySpeciationProbability>,
+            >::try_new(
+                scenario_args.initial,
+                scenario_args
+                    .changes
+                    .into_iter()
+                    .map(|change| (change.time, change.args)),
+                fixup_strategy,
+                speciation_probability_per_generation,
+            )?
+        } => SpatiallyExplicitTurnoverMapScenario<InMemorySpeciationProbability>,
+        #[cfg(feature = "spatially-explicit-fine-coarse-scenario")]
+        ScenarioArgs::SpatiallyExplicitFineCoarse(scenario_args) => {
+            ScenarioSchedule::fixed(
+                SpatiallyExplicitFineCoarseScenario::new(
+                    scenario_args,
+                    speciation_probability_per_generation,
+                )?,
+            )
+        } => SpatiallyExplicitFineCoarseScenario,
+        #[cfg(feature = "spatially-explicit-kernel-scenario")]
+        ScenarioArgs::SpatiallyExplicitKernel(scenario_args) => {
+            ScenarioSchedule::fixed(
+                SpatiallyExplicitKernelScenario::new(
+                    scenario_args,
+                    speciation_probability_per_generation,
+                )?,
+            )
+        } => SpatiallyExplicitKernelScenario,
+        #[cfg(feature = "non-spatial-scenario")]
+        ScenarioArgs::NonSpatial(scenario_args) => {
+            ScenarioSchedule::fixed(
+                NonSpatialScenario::new(
+                    scenario_args,
+                    speciation_probability_per_generation,
+                )
+                .into_ok(),
+            )
+        } => NonSpatialScenario,
+        #[cfg(feature = "almost-infinite-normal-dispersal-scenario")]
+        ScenarioArgs::AlmostInfiniteNormalDispersal(scenario_args) => {
+            ScenarioSchedule::fixed(
+                AlmostInfiniteNormalDispersalScenario::new(
+                    scenario_args,
+                    speciation_probability_per_generation,
+                )
+                .into_ok(),
+            )
+        } => AlmostInfiniteNormalDispersalScenario,
+        #[cfg(feature = "almost-infinite-clark2dt-dispersal-scenario")]
+        ScenarioArgs::AlmostInfiniteClark2DtDispersal(scenario_args) => {
+            ScenarioSchedule::fixed(
+                AlmostInfiniteClark2DtDispersalScenario::new(
+                    scenario_args,
+                    speciation_probability_per_generation,
+                )
+                .into_ok(),
+            )
+        } => AlmostInfiniteClark2DtDispersalScenario,
+        #[cfg(feature = "spatially-implicit-scenario")]
+        ScenarioArgs::SpatiallyImplicit(scenario_args) => {
+            ScenarioSchedule::fixed(
+                SpatiallyImplicitScenario::new(
+                    scenario_args,
+                    speciation_probability_per_generation,
+                )
+                .into_ok(),
+            )
+        } => SpatiallyImplicitScenario,
+        #[cfg(feature = "spatially-implicit-islands-scenario")]
+        ScenarioArgs::SpatiallyImplicitIslands(scenario_args) => {
+            ScenarioSchedule::fixed(
+                SpatiallyImplicitIslandsScenario::new(
+                    scenario_args,
+                    speciation_probability_per_generation,
+                )?,
+            )
+        } => SpatiallyImplicitIslandsScenario,
+        #[cfg(feature = "wrapping-noise-scenario")]
+        ScenarioArgs::WrappingNoise(scenario_args) => {
+            ScenarioSchedule::fixed(
+                WrappingNoiseScenario::new(
+                    scenario_args,
+                    speciation_probability_per_generation,
+                )
+                .into_ok(),
+            )
+        } => WrappingNoiseScenario
+    })
+}
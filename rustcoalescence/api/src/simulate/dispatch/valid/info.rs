@@ -12,17 +12,23 @@ use necsim_core::{
 use necsim_core_bond::NonNegativeF64;
 use necsim_partitioning_core::reporter::{FinalisableReporter, ReporterContext};
 
-use rustcoalescence_scenarios::{Scenario, ScenarioCogs};
+use rustcoalescence_scenarios::Scenario;
 
-use crate::args::{
+use crate::{
     config::{
+        checkpoint::{Checkpoint, CheckpointInterval},
         partitioning::Partitioning,
+        rng::RngGenerator,
         sample::{Sample, SampleMode, SampleModeRestart},
     },
     utils::parse::try_print,
 };
 
-use super::{super::super::BufferingSimulateArgsBuilder, partitioning};
+use super::{
+    super::super::{checkpoint::Checkpointer, BufferingSimulateArgsBuilder},
+    partitioning,
+    schedule::ScenarioSchedule,
+};
 
 #[allow(dead_code)]
 #[allow(clippy::too_many_arguments, clippy::too_many_lines)]
@@ -40,9 +46,11 @@ pub(super) fn dispatch<
 
     sample: Sample,
     rng: G,
-    scenario: ScenarioCogs<M, G, O>,
+    rng_generator: RngGenerator,
+    scenario: ScenarioSchedule<M, G, O>,
     algorithm_args: A::Arguments,
     pause_before: Option<NonNegativeF64>,
+    checkpoint: Option<Checkpoint>,
 
     normalised_args: &BufferingSimulateArgsBuilder,
 ) -> anyhow::Result<SimulationOutcome<M, G>>
@@ -76,6 +84,32 @@ where
     }
     info!("{}", resume_pause);
 
+    let checkpointer = match checkpoint {
+        None => None,
+        Some(checkpoint) => {
+            match checkpoint.every {
+                CheckpointInterval::Generations(generations) => info!(
+                    "The simulation will write a checkpoint to {:?} every {} generation(s).",
+                    checkpoint.directory, generations
+                ),
+                CheckpointInterval::Minutes(minutes) => info!(
+                    "The simulation will write a checkpoint to {:?} about every {} minute(s).",
+                    checkpoint.directory, minutes
+                ),
+            }
+
+            Some(
+                Checkpointer::try_new(
+                    &checkpoint,
+                    normalised_args,
+                    rng_generator,
+                    event_log.as_ref(),
+                )
+                .context("Failed to initialise the simulation checkpoints.")?,
+            )
+        },
+    };
+
     let logical_partition_size =
         partitioning.get_logical_partition_size::<M, G, O, R, A>(&algorithm_args);
     if logical_partition_size.get() <= 1 {
@@ -133,6 +167,7 @@ where
         scenario,
         algorithm_args,
         pause_before,
+        checkpointer,
     )?;
 
     if log::log_enabled!(log::Level::Info) {
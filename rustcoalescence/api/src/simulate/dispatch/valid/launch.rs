@@ -0,0 +1,324 @@
+use anyhow::Context;
+
+use rustcoalescence_algorithms::{result::SimulationOutcome, Algorithm};
+
+use necsim_core::{
+    cogs::{MathsCore, RngCore},
+    lineage::Lineage,
+    reporter::Reporter,
+};
+use necsim_core_bond::{ClosedUnitF64, NonNegativeF64, PositiveF64};
+use necsim_impls_no_std::cogs::origin_sampler::pre_sampler::OriginPreSampler;
+use necsim_partitioning_core::LocalPartition;
+
+use rustcoalescence_scenarios::{Scenario, ScenarioCogs};
+
+use crate::config::sample::{Sample, SampleMode, SampleModeRestart, SampleOrigin};
+
+use super::{super::super::checkpoint::Checkpointer, schedule::ScenarioSchedule};
+
+#[allow(
+    clippy::too_many_lines,
+    clippy::too_many_arguments,
+    clippy::needless_pass_by_value
+)]
+pub(super) fn simulate<
+    'p,
+    M: MathsCore,
+    G: RngCore<M>,
+    A: Algorithm<'p, M, G, O, R, P>,
+    O: Scenario<M, G>,
+    R: Reporter,
+    P: LocalPartition<'p, R>,
+>(
+    local_partition: &mut P,
+
+    sample: Sample,
+    rng: G,
+    scenario: ScenarioSchedule<M, G, O>,
+    algorithm_args: A::Arguments,
+    pause_before: Option<NonNegativeF64>,
+    mut checkpointer: Option<Checkpointer>,
+) -> anyhow::Result<SimulationOutcome<M, G>> {
+    let lineages = match sample.origin {
+        SampleOrigin::Habitat => None,
+        SampleOrigin::List(lineages) => Some(lineages),
+        SampleOrigin::Bincode(loader) => Some(loader.into_lineages()),
+    };
+
+    let start_time = match (&sample.mode, &lineages) {
+        (SampleMode::Genesis, _) | (SampleMode::Resume, None) => NonNegativeF64::zero(),
+        (SampleMode::Resume, Some(lineages)) => match sample.start {
+            Some(start) => start,
+            // Lineages may have been paused at very different ages, so only
+            //  the configured start time can select the scenario snapshot
+            None if !scenario.changes.is_empty() => anyhow::bail!(
+                "Resuming a scenario with habitat snapshots requires the sample `start` time."
+            ),
+            None => lineages
+                .iter()
+                .map(|lineage| lineage.last_event_time)
+                .max()
+                .unwrap_or_else(NonNegativeF64::zero),
+        },
+        (SampleMode::FixUp(_), _) => pause_before.unwrap_or_else(NonNegativeF64::zero),
+        (SampleMode::Restart(SampleModeRestart { after }), _) => *after,
+    };
+
+    let ScenarioSchedule {
+        scenario,
+        changes,
+        fixup_strategy,
+    } = scenario.skip_until(start_time);
+    let mut changes = changes.into_iter().peekable();
+
+    // Resuming from a checkpoint requires the current scenario
+    let mut current_scenario = checkpointer.as_ref().map(|_| scenario.clone());
+
+    let mut stop = next_stop(
+        start_time,
+        pause_before,
+        changes.peek(),
+        checkpointer.as_mut(),
+    );
+
+    let mut outcome = simulate_snapshot::<M, G, A, O, R, P>(
+        local_partition,
+        sample.mode,
+        sample.percentage,
+        lineages,
+        rng,
+        scenario,
+        algorithm_args.clone(),
+        stop,
+    )?;
+
+    loop {
+        // Only continue if the simulation paused for a scenario change or a
+        //  checkpoint
+        let (steps, lineages, rng, paused_at) = match (outcome, stop) {
+            (
+                SimulationOutcome::Paused {
+                    steps,
+                    lineages,
+                    rng,
+                    ..
+                },
+                Some(stop),
+            ) if pause_before.map_or(true, |pause_before| stop < pause_before) => {
+                (steps, lineages, rng, stop)
+            },
+            (SimulationOutcome::Done { time, steps }, _) => {
+                if let Some(checkpointer) = checkpointer {
+                    checkpointer.finish();
+                }
+
+                return Ok(SimulationOutcome::Done { time, steps });
+            },
+            (outcome, _) => return Ok(outcome),
+        };
+
+        let (steps, lineages, rng, restart_at, scenario) = match changes.peek() {
+            Some((change_at, _)) if NonNegativeF64::from(*change_at) == paused_at => {
+                let Some((change_at, scenario)) = changes.next() else {
+                    unreachable!("the next scenario change was just peeked")
+                };
+
+                info!("The scenario will change at time {}.", change_at);
+
+                match A::fixup_for_restart(
+                    algorithm_args.clone(),
+                    rng,
+                    scenario.clone(),
+                    OriginPreSampler::all(),
+                    lineages.into_iter(),
+                    change_at,
+                    fixup_strategy.clone(),
+                    local_partition,
+                )
+                .context("Failed to fix-up the simulation after the scenario changed.")?
+                {
+                    SimulationOutcome::Paused {
+                        steps: fixup_steps,
+                        lineages,
+                        rng,
+                        ..
+                    } => {
+                        if current_scenario.is_some() {
+                            current_scenario = Some(scenario.clone());
+                        }
+
+                        (
+                            steps + fixup_steps,
+                            lineages,
+                            rng,
+                            Some(change_at.into()),
+                            scenario,
+                        )
+                    },
+                    SimulationOutcome::Done {
+                        time,
+                        steps: fixup_steps,
+                    } => {
+                        if let Some(checkpointer) = checkpointer {
+                            checkpointer.finish();
+                        }
+
+                        return Ok(SimulationOutcome::Done {
+                            time,
+                            steps: steps + fixup_steps,
+                        });
+                    },
+                }
+            },
+            _ => match (checkpointer.as_mut(), current_scenario.clone()) {
+                (Some(checkpointer), Some(scenario)) => {
+                    let lineages = checkpointer.reached(local_partition, lineages, &rng)?;
+
+                    (steps, lineages, rng, None, scenario)
+                },
+                _ => unreachable!("the simulation only pauses early for a checkpoint"),
+            },
+        };
+
+        stop = next_stop(
+            paused_at,
+            pause_before,
+            changes.peek(),
+            checkpointer.as_mut(),
+        );
+
+        outcome = match A::resume_and_simulate(
+            algorithm_args.clone(),
+            rng,
+            scenario,
+            OriginPreSampler::all(),
+            lineages.into_iter(),
+            restart_at,
+            stop,
+            local_partition,
+        )
+        .context("Failed to continue the simulation after it paused.")?
+        {
+            SimulationOutcome::Done {
+                time,
+                steps: resume_steps,
+            } => SimulationOutcome::Done {
+                time,
+                steps: steps + resume_steps,
+            },
+            SimulationOutcome::Paused {
+                time,
+                steps: resume_steps,
+                lineages,
+                rng,
+                marker,
+            } => SimulationOutcome::Paused {
+                time,
+                steps: steps + resume_steps,
+                lineages,
+                rng,
+                marker,
+            },
+        };
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn simulate_snapshot<
+    'p,
+    M: MathsCore,
+    G: RngCore<M>,
+    A: Algorithm<'p, M, G, O, R, P>,
+    O: Scenario<M, G>,
+    R: Reporter,
+    P: LocalPartition<'p, R>,
+>(
+    local_partition: &mut P,
+
+    sample_mode: SampleMode,
+    sample_percentage: ClosedUnitF64,
+    lineages: Option<Vec<Lineage>>,
+    rng: G,
+    scenario: ScenarioCogs<M, G, O>,
+    algorithm_args: A::Arguments,
+    pause_before: Option<NonNegativeF64>,
+) -> anyhow::Result<SimulationOutcome<M, G>> {
+    let Some(lineages) = lineages else {
+        return A::initialise_and_simulate(
+            algorithm_args,
+            rng,
+            scenario,
+            OriginPreSampler::all().percentage(sample_percentage),
+            pause_before,
+            local_partition,
+        )
+        .context("Failed to perform the fresh simulation.");
+    };
+
+    match sample_mode {
+        SampleMode::Genesis => A::initialise_and_simulate(
+            algorithm_args,
+            rng,
+            scenario,
+            OriginPreSampler::all().percentage(sample_percentage),
+            pause_before,
+            local_partition,
+        )
+        .context("Failed to perform the fresh simulation."),
+        SampleMode::Resume => A::resume_and_simulate(
+            algorithm_args,
+            rng,
+            scenario,
+            OriginPreSampler::all().percentage(sample_percentage),
+            lineages.into_iter(),
+            None,
+            pause_before,
+            local_partition,
+        )
+        .context("Failed to perform the resuming simulation."),
+        SampleMode::FixUp(strategy) => A::fixup_for_restart(
+            algorithm_args,
+            rng,
+            scenario,
+            OriginPreSampler::all().percentage(sample_percentage),
+            lineages.into_iter(),
+            PositiveF64::new(pause_before.unwrap().get()).unwrap(),
+            strategy,
+            local_partition,
+        )
+        .context("Failed to fix-up the restarting simulation."),
+        SampleMode::Restart(SampleModeRestart { after }) => A::resume_and_simulate(
+            algorithm_args,
+            rng,
+            scenario,
+            OriginPreSampler::all().percentage(sample_percentage),
+            lineages.into_iter(),
+            Some(after),
+            pause_before,
+            local_partition,
+        )
+        .context("Failed to perform the restarting simulation."),
+    }
+}
+
+/// Returns the time before which the simulation must next pause, i.e. the
+///  earliest of the final pause, the next scenario change, and the next
+///  checkpoint
+fn next_stop<M: MathsCore, G: RngCore<M>, O: Scenario<M, G>>(
+    time: NonNegativeF64,
+    pause_before: Option<NonNegativeF64>,
+    next_change: Option<&(PositiveF64, ScenarioCogs<M, G, O>)>,
+    checkpointer: Option<&mut Checkpointer>,
+) -> Option<NonNegativeF64> {
+    let next_checkpoint = checkpointer.map(|checkpointer| checkpointer.next_stop(time));
+
+    [
+        pause_before,
+        next_change.map(|(change_at, _)| (*change_at).into()),
+        next_checkpoint,
+    ]
+    .into_iter()
+    .flatten()
+    .min()
+}
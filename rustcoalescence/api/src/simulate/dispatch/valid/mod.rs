@@ -0,0 +1,97 @@
+use necsim_core::reporter::Reporter;
+use necsim_core_bond::{NonNegativeF64, OpenClosedUnitF64 as PositiveUnitF64};
+use necsim_impls_no_std::cogs::event_sampler::protracted::ProtractedSpeciation;
+use necsim_impls_std::event_log::recorder::EventLogConfig;
+use necsim_partitioning_core::reporter::ReporterContext;
+use necsim_plugins_core::import::AnyReporterPluginVec;
+
+use crate::{
+    config::{
+        algorithm::Algorithm, checkpoint::Checkpoint, maths::Maths, partitioning::Partitioning,
+        rng::AnyRng, sample::Sample, scenario::Scenario,
+    },
+    simulate::SimulationOutcome,
+};
+
+use super::super::BufferingSimulateArgsBuilder;
+
+mod algorithm_scenario;
+mod info;
+mod launch;
+mod partitioning;
+mod reporter;
+mod rng;
+mod schedule;
+
+#[allow(clippy::too_many_arguments)]
+pub(in super::super) fn dispatch(
+    partitioning: Partitioning,
+    event_log: Option<EventLogConfig>,
+    reporters: AnyReporterPluginVec,
+
+    speciation_probability_per_generation: PositiveUnitF64,
+    protracted_speciation: ProtractedSpeciation,
+    sample: Sample,
+    scenario: Scenario,
+    algorithm: Algorithm,
+    maths: Maths,
+    pause_before: Option<NonNegativeF64>,
+    checkpoint: Option<Checkpoint>,
+
+    rng: AnyRng,
+    normalised_args: &mut BufferingSimulateArgsBuilder,
+) -> anyhow::Result<SimulationOutcome> {
+    reporter::dispatch(
+        partitioning,
+        event_log,
+        reporters,
+        speciation_probability_per_generation,
+        protracted_speciation,
+        sample,
+        scenario,
+        algorithm,
+        maths,
+        pause_before,
+        checkpoint,
+        rng,
+        normalised_args,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(in super::super) fn dispatch_with_reporter_context<
+    R: Reporter,
+    P: ReporterContext<Reporter = R>,
+>(
+    partitioning: Partitioning,
+    event_log: Option<EventLogConfig>,
+    reporter_context: P,
+
+    speciation_probability_per_generation: PositiveUnitF64,
+    protracted_speciation: ProtractedSpeciation,
+    sample: Sample,
+    scenario: Scenario,
+    algorithm: Algorithm,
+    maths: Maths,
+    pause_before: Option<NonNegativeF64>,
+    checkpoint: Option<Checkpoint>,
+
+    rng: AnyRng,
+    normalised_args: &mut BufferingSimulateArgsBuilder,
+) -> anyhow::Result<SimulationOutcome> {
+    algorithm_scenario::dispatch(
+        partitioning,
+        event_log,
+        reporter_context,
+        speciation_probability_per_generation,
+        protracted_speciation,
+        sample,
+        scenario,
+        algorithm,
+        maths,
+        pause_before,
+        checkpoint,
+        rng,
+        normalised_args,
+    )
+}
@@ -10,14 +10,14 @@ use necsim_partitioning_monolithic::MonolithicLocalPartition;
 #[cfg(feature = "mpi-partitioning")]
 use necsim_partitioning_mpi::MpiLocalPartition;
 use rustcoalescence_algorithms::{result::SimulationOutcome, Algorithm, AlgorithmDispatch};
-use rustcoalescence_scenarios::{Scenario, ScenarioCogs};
+use rustcoalescence_scenarios::Scenario;
 
 use crate::{
-    args::config::{partitioning::Partitioning, sample::Sample},
+    config::{partitioning::Partitioning, sample::Sample},
     reporter::FinalisablePartitioningReporter,
 };
 
-use super::launch;
+use super::{super::super::checkpoint::Checkpointer, launch, schedule::ScenarioSchedule};
 
 #[allow(clippy::too_many_arguments)]
 pub(super) fn dispatch<
@@ -34,14 +34,22 @@ pub(super) fn dispatch<
 
     sample: Sample,
     rng: G,
-    scenario: ScenarioCogs<M, G, O>,
+    scenario: ScenarioSchedule<M, G, O>,
     algorithm_args: A::Arguments,
     pause_before: Option<NonNegativeF64>,
+    checkpointer: Option<Checkpointer>,
 ) -> anyhow::Result<(SimulationOutcome<M, G>, FinalisablePartitioningReporter<R>)>
 where
     Result<SimulationOutcome<M, G>, A::Error>: anyhow::Context<SimulationOutcome<M, G>, A::Error>,
 {
-    let args = (sample, rng, scenario, algorithm_args, pause_before);
+    let args: SimulateArgs<M, G, O, A::Arguments> = (
+        sample,
+        rng,
+        scenario,
+        algorithm_args,
+        pause_before,
+        checkpointer,
+    );
 
     // Initialise the local partition and the simulation
     match partitioning {
@@ -50,26 +58,12 @@ where
                 reporter_context,
                 event_log,
                 args,
-                |partition, (sample, rng, scenario, algorithm_args, pause_before)| match partition {
+                |partition, args| match partition {
                     MonolithicLocalPartition::Live(partition) => {
-                        wrap::<M, G, A::Algorithm<'_, _>, O, R, _>(
-                            &mut **partition,
-                            sample,
-                            rng,
-                            scenario,
-                            algorithm_args,
-                            pause_before,
-                        )
+                        wrap::<M, G, A::Algorithm<'_, _>, O, R, _>(&mut **partition, args)
                     },
                     MonolithicLocalPartition::Recorded(partition) => {
-                        wrap::<M, G, A::Algorithm<'_, _>, O, R, _>(
-                            &mut **partition,
-                            sample,
-                            rng,
-                            scenario,
-                            algorithm_args,
-                            pause_before,
-                        )
+                        wrap::<M, G, A::Algorithm<'_, _>, O, R, _>(&mut **partition, args)
                     },
                 },
                 fold,
@@ -86,26 +80,12 @@ where
                 reporter_context,
                 event_log,
                 args,
-                |partition, (sample, rng, scenario, algorithm_args, pause_before)| match partition {
+                |partition, args| match partition {
                     MpiLocalPartition::Root(partition) => {
-                        wrap::<M, G, A::Algorithm<'_, _>, O, R, _>(
-                            &mut **partition,
-                            sample,
-                            rng,
-                            scenario,
-                            algorithm_args,
-                            pause_before,
-                        )
+                        wrap::<M, G, A::Algorithm<'_, _>, O, R, _>(&mut **partition, args)
                     },
                     MpiLocalPartition::Parallel(partition) => {
-                        wrap::<M, G, A::Algorithm<'_, _>, O, R, _>(
-                            &mut **partition,
-                            sample,
-                            rng,
-                            scenario,
-                            algorithm_args,
-                            pause_before,
-                        )
+                        wrap::<M, G, A::Algorithm<'_, _>, O, R, _>(&mut **partition, args)
                     },
                 },
                 fold,
@@ -117,16 +97,7 @@ where
                 reporter_context,
                 event_log,
                 args,
-                |partition, (sample, rng, scenario, algorithm_args, pause_before)| {
-                    wrap::<M, G, A::Algorithm<'_, _>, O, R, _>(
-                        partition,
-                        sample,
-                        rng,
-                        scenario,
-                        algorithm_args,
-                        pause_before,
-                    )
-                },
+                |partition, args| wrap::<M, G, A::Algorithm<'_, _>, O, R, _>(partition, args),
                 fold,
             )
             .map(|(result, reporter)| (result, FinalisablePartitioningReporter::Threads(reporter))),
@@ -134,6 +105,15 @@ where
     .and_then(|(result, reporter)| Ok((result.map_err(anyhow::Error::msg)?, reporter)))
 }
 
+type SimulateArgs<M, G, O, A> = (
+    Sample,
+    G,
+    ScenarioSchedule<M, G, O>,
+    A,
+    Option<NonNegativeF64>,
+    Option<Checkpointer>,
+);
+
 fn wrap<
     'p,
     M: MathsCore,
@@ -144,12 +124,12 @@ fn wrap<
     P: LocalPartition<'p, R>,
 >(
     local_partition: &mut P,
-
-    sample: Sample,
-    rng: G,
-    scenario: ScenarioCogs<M, G, O>,
-    algorithm_args: A::Arguments,
-    pause_before: Option<NonNegativeF64>,
+    (sample, rng, scenario, algorithm_args, pause_before, checkpointer): SimulateArgs<
+        M,
+        G,
+        O,
+        A::Arguments,
+    >,
 ) -> Result<SimulationOutcome<M, G>, String>
 where
     Result<SimulationOutcome<M, G>, A::Error>: anyhow::Context<SimulationOutcome<M, G>, A::Error>,
@@ -161,6 +141,7 @@ where
         scenario,
         algorithm_args,
         pause_before,
+        checkpointer,
     )
     .map_err(|err| format!("{err:?}"))
 }
@@ -1,13 +1,15 @@
 use necsim_core_bond::{NonNegativeF64, OpenClosedUnitF64 as PositiveUnitF64};
+use necsim_impls_no_std::cogs::event_sampler::protracted::ProtractedSpeciation;
 use necsim_impls_std::event_log::recorder::EventLogConfig;
 use necsim_plugins_core::{import::AnyReporterPluginVec, match_any_reporter_plugin_vec};
 
 use crate::{
-    args::config::{
-        algorithm::Algorithm, partitioning::Partitioning, sample::Sample, scenario::Scenario,
+    config::{
+        algorithm::Algorithm, checkpoint::Checkpoint, maths::Maths, partitioning::Partitioning,
+        rng::AnyRng, sample::Sample, scenario::Scenario,
     },
-    cli::simulate::SimulationOutcome,
     reporter::DynamicReporterContext,
+    simulate::SimulationOutcome,
 };
 
 use super::{super::super::BufferingSimulateArgsBuilder, algorithm_scenario};
@@ -19,19 +21,22 @@ pub(super) fn dispatch(
     reporters: AnyReporterPluginVec,
 
     speciation_probability_per_generation: PositiveUnitF64,
+    protracted_speciation: ProtractedSpeciation,
     sample: Sample,
     scenario: Scenario,
     algorithm: Algorithm,
+    maths: Maths,
     pause_before: Option<NonNegativeF64>,
+    checkpoint: Option<Checkpoint>,
 
-    ron_args: &str,
+    rng: AnyRng,
     normalised_args: &mut BufferingSimulateArgsBuilder,
 ) -> anyhow::Result<SimulationOutcome> {
     match_any_reporter_plugin_vec!(reporters => |reporter| {
         algorithm_scenario::dispatch(
             partitioning, event_log, DynamicReporterContext::new(reporter),
-            speciation_probability_per_generation, sample, scenario,
-            algorithm, pause_before, ron_args, normalised_args,
+            speciation_probability_per_generation, protracted_speciation, sample, scenario,
+            algorithm, maths, pause_before, checkpoint, rng, normalised_args,
         )
     })
 }
@@ -12,20 +12,19 @@ use necsim_core::{
 };
 use necsim_core_bond::NonNegativeF64;
 
-use rustcoalescence_scenarios::{Scenario, ScenarioCogs};
+use rustcoalescence_scenarios::Scenario;
 
-use crate::{
-    args::config::{
-        partitioning::Partitioning,
-        rng::{Base32RngState, Rng as RngArgs},
-        sample::Sample,
-    },
-    cli::simulate::parse,
+use crate::config::{
+    checkpoint::Checkpoint,
+    partitioning::Partitioning,
+    rng::{AnyRng, Base32RngState, Rng as RngArgs, RngGenerator, RngWithGenerator},
+    sample::Sample,
 };
 
 use super::{
     super::super::{BufferingSimulateArgsBuilder, SimulationOutcome},
     info,
+    schedule::ScenarioSchedule,
 };
 
 #[allow(clippy::too_many_arguments)]
@@ -42,21 +41,24 @@ pub(super) fn dispatch<
     reporter_context: P,
 
     sample: Sample,
+    rng_generator: RngGenerator,
     algorithm_args: A::Arguments,
-    scenario: ScenarioCogs<M, G, O>,
+    scenario: ScenarioSchedule<M, G, O>,
     pause_before: Option<NonNegativeF64>,
+    checkpoint: Option<Checkpoint>,
 
-    ron_args: &str,
+    rng: AnyRng,
     normalised_args: &mut BufferingSimulateArgsBuilder,
 ) -> anyhow::Result<SimulationOutcome>
 where
     Result<AlgorithmOutcome<M, G>, A::Error>: anyhow::Context<AlgorithmOutcome<M, G>, A::Error>,
 {
-    let rng: G = match parse::rng::parse_and_normalise(
-        ron_args,
-        normalised_args,
-        partitioning.get_logical_partition_size::<M, G, O, R, A>(&algorithm_args),
-    )? {
+    let rng: RngArgs<M, G> =
+        rng.into_rng(partitioning.get_logical_partition_size::<M, G, O, R, A>(&algorithm_args))?;
+
+    normalised_args.rng(&RngWithGenerator::new(rng_generator, &rng));
+
+    let rng: G = match rng {
         RngArgs::Seed(seed) => SeedableRng::seed_from_u64(seed),
         RngArgs::Sponge(bytes) => {
             let mut seed = G::Seed::default();
@@ -76,9 +78,11 @@ where
         reporter_context,
         sample,
         rng,
+        rng_generator,
         scenario,
         algorithm_args,
         pause_before,
+        checkpoint,
         normalised_args,
     )?;
 
@@ -91,7 +95,10 @@ where
             rng: paused_rng,
             ..
         } => {
-            normalised_args.rng(&RngArgs::State(Base32RngState::from(paused_rng)));
+            normalised_args.rng(&RngWithGenerator::new(
+                rng_generator,
+                RngArgs::State(Base32RngState::from(paused_rng)),
+            ));
 
             Ok(SimulationOutcome::Paused {
                 time,
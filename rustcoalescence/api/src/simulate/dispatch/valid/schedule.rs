@@ -0,0 +1,176 @@
+use necsim_core::cogs::{MathsCore, RngCore};
+use necsim_core_bond::{NonNegativeF64, OpenClosedUnitF64 as PositiveUnitF64, PositiveF64};
+use necsim_impls_no_std::cogs::event_sampler::protracted::ProtractedSpeciation;
+
+use rustcoalescence_algorithms::strategy::RestartFixUpStrategy;
+use rustcoalescence_scenarios::{Scenario, ScenarioCogs};
+
+/// Sequence of scenario snapshots, each of which replaces the previous one
+///  once the simulation time crosses its start time
+pub(super) struct ScenarioSchedule<M: MathsCore, G: RngCore<M>, O: Scenario<M, G>> {
+    pub scenario: ScenarioCogs<M, G, O>,
+    pub changes: Vec<(PositiveF64, ScenarioCogs<M, G, O>)>,
+    pub fixup_strategy: RestartFixUpStrategy,
+}
+
+impl<M: MathsCore, G: RngCore<M>, O: Scenario<M, G>> Clone for ScenarioSchedule<M, G, O> {
+    fn clone(&self) -> Self {
+        Self {
+            scenario: self.scenario.clone(),
+            changes: self.changes.clone(),
+            fixup_strategy: self.fixup_strategy.clone(),
+        }
+    }
+}
+
+impl<M: MathsCore, G: RngCore<M>, O: Scenario<M, G>> ScenarioSchedule<M, G, O> {
+    #[must_use]
+    pub fn fixed(scenario: ScenarioCogs<M, G, O>) -> Self {
+        Self {
+            scenario,
+            changes: Vec::new(),
+            fixup_strategy: RestartFixUpStrategy::default(),
+        }
+    }
+
+    /// # Errors
+    ///
+    /// Returns a `O::Error` if creating any of the scenario snapshots failed
+    #[cfg_attr(
+        not(feature = "spatially-explicit-temporal-scenario"),
+        allow(dead_code)
+    )]
+    pub fn try_new<I: IntoIterator<Item = (PositiveF64, O::Arguments)>>(
+        initial: O::Arguments,
+        changes: I,
+        fixup_strategy: RestartFixUpStrategy,
+        speciation_probability_per_generation: PositiveUnitF64,
+    ) -> Result<Self, O::Error> {
+        Ok(Self {
+            scenario: O::new(initial, speciation_probability_per_generation)?,
+            changes: changes
+                .into_iter()
+                .map(|(time, args)| {
+                    O::new(args, speciation_probability_per_generation)
+                        .map(|scenario| (time, scenario))
+                })
+                .collect::<Result<_, _>>()?,
+            fixup_strategy,
+        })
+    }
+
+    /// Applies the same protracted speciation window to all scenario snapshots
+    #[must_use]
+    pub fn with_protracted_speciation(
+        mut self,
+        protracted_speciation: ProtractedSpeciation,
+    ) -> Self {
+        self.scenario.protracted_speciation = protracted_speciation;
+
+        for (_, scenario) in &mut self.changes {
+            scenario.protracted_speciation = protracted_speciation;
+        }
+
+        self
+    }
+
+    /// Skips all scenario snapshots that have been replaced by a later one
+    ///  at or before `time`
+    #[must_use]
+    pub fn skip_until(mut self, time: NonNegativeF64) -> Self {
+        let skip = self
+            .changes
+            .iter()
+            .take_while(|(change, _)| *change <= time)
+            .count();
+
+        if let Some((_, scenario)) = self.changes.drain(..skip).last() {
+            self.scenario = scenario;
+        }
+
+        self
+    }
+}
+
+#[cfg(all(test, feature = "non-spatial-scenario"))]
+mod tests {
+    use std::num::NonZeroU32;
+
+    use necsim_core::cogs::Habitat;
+    use necsim_core_bond::{
+        NonNegativeF64, OffByOneU32, OpenClosedUnitF64 as PositiveUnitF64, PositiveF64,
+    };
+    use necsim_impls_no_std::cogs::{maths::intrinsics::IntrinsicsMathsCore, rng::wyhash::WyHash};
+
+    use rustcoalescence_algorithms::strategy::RestartFixUpStrategy;
+    use rustcoalescence_scenarios::non_spatial::{NonSpatialArguments, NonSpatialScenario};
+
+    use super::ScenarioSchedule;
+
+    type Schedule =
+        ScenarioSchedule<IntrinsicsMathsCore, WyHash<IntrinsicsMathsCore>, NonSpatialScenario>;
+
+    fn args(width: u64) -> NonSpatialArguments {
+        NonSpatialArguments {
+            area: (
+                OffByOneU32::new(width).unwrap(),
+                OffByOneU32::new(1).unwrap(),
+            ),
+            deme: NonZeroU32::new(2).unwrap(),
+        }
+    }
+
+    fn schedule() -> Schedule {
+        Schedule::try_new(
+            args(1),
+            [
+                (PositiveF64::new(10.0).unwrap(), args(2)),
+                (PositiveF64::new(20.0).unwrap(), args(3)),
+            ],
+            RestartFixUpStrategy::default(),
+            PositiveUnitF64::new(0.1).unwrap(),
+        )
+        .unwrap()
+    }
+
+    fn current_width(schedule: &Schedule) -> u64 {
+        schedule.scenario.habitat.get_extent().width().get()
+    }
+
+    fn remaining_changes(schedule: &Schedule) -> Vec<f64> {
+        schedule
+            .changes
+            .iter()
+            .map(|(change_at, _)| change_at.get())
+            .collect()
+    }
+
+    #[test]
+    fn skip_until_genesis() {
+        let schedule = schedule().skip_until(NonNegativeF64::zero());
+
+        assert_eq!(current_width(&schedule), 1);
+        assert_eq!(remaining_changes(&schedule), [10.0, 20.0]);
+    }
+
+    #[test]
+    fn skip_until_between_snapshots() {
+        let schedule = schedule().skip_until(NonNegativeF64::new(15.0).unwrap());
+
+        assert_eq!(current_width(&schedule), 2);
+        assert_eq!(remaining_changes(&schedule), [20.0]);
+    }
+
+    #[test]
+    fn skip_until_snapshot_start() {
+        let schedule = schedule().skip_until(NonNegativeF64::new(10.0).unwrap());
+
+        assert_eq!(current_width(&schedule), 2);
+        assert_eq!(remaining_changes(&schedule), [20.0]);
+
+        let schedule = schedule.skip_until(NonNegativeF64::new(20.0).unwrap());
+
+        assert_eq!(current_width(&schedule), 3);
+        assert!(remaining_changes(&schedule).is_empty());
+    }
+}
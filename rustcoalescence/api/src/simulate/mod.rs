@@ -0,0 +1,298 @@
+use derive_builder::Builder;
+use serde::Serialize;
+
+use necsim_core::{lineage::Lineage, reporter::Reporter};
+use necsim_core_bond::{NonNegativeF64, OpenClosedUnitF64 as PositiveUnitF64};
+use necsim_impls_no_std::cogs::event_sampler::protracted::ProtractedSpeciation;
+use necsim_impls_std::event_log::recorder::EventLogConfig;
+
+use crate::{
+    config::{
+        algorithm::Algorithm, checkpoint::Checkpoint, maths::Maths, partitioning::Partitioning,
+        pause::Pause, rng::AnyRng, sample::Sample, scenario::Scenario,
+    },
+    reporter::InProcessReporterContext,
+    utils::ser::BufferingSerializeResult,
+};
+
+mod builder;
+mod checkpoint;
+mod dispatch;
+mod parse;
+mod pause;
+
+pub use builder::SimulationBuilder;
+
+use dispatch::{dispatch, dispatch_with_reporter_context};
+
+/// Outcome of a completed or paused simulation
+#[allow(clippy::module_name_repetitions)]
+pub enum SimulationOutcome {
+    Done {
+        time: NonNegativeF64,
+        steps: u64,
+    },
+    Paused {
+        time: NonNegativeF64,
+        steps: u64,
+        lineages: Vec<Lineage>,
+    },
+}
+
+/// Performs the simulation described by the `ron_args` config, which reports
+///  all events to the dynamically loaded reporter plugins listed in its
+///  `reporters` field.
+///
+/// If the simulation pauses, the config to resume it is written to the file
+///  specified in its `pause` field. If the config has a `checkpoint` field,
+///  the simulation resumes from the latest checkpoint in its directory.
+///
+/// # Errors
+///
+/// Returns an error iff parsing the simulation config, loading the reporter
+///  plugins, initialising the simulation, or performing the simulation failed.
+#[allow(clippy::module_name_repetitions)]
+pub fn simulate_with_plugins(ron_args: &str) -> anyhow::Result<SimulationOutcome> {
+    let ron_args = &*checkpoint::resume_from_latest(ron_args)?;
+
+    let (args, mut normalised_args) = ParsedSimulateArgs::parse(ron_args)?.normalise()?;
+
+    let reporters = parse::reporters::parse_and_normalise(ron_args, &mut normalised_args)?;
+
+    // Ensure the dynamic reporter plugin libraries are not deallocated prematurely
+    reporters.with_lifetime(|reporters| {
+        let pause = args.pause;
+
+        let result = dispatch(
+            args.partitioning,
+            args.event_log,
+            reporters,
+            args.speciation_probability_per_generation,
+            args.protracted_speciation,
+            args.sample,
+            args.scenario,
+            args.algorithm,
+            args.maths,
+            pause.as_ref().map(|pause| pause.before),
+            args.checkpoint,
+            args.rng,
+            &mut normalised_args,
+        )?;
+
+        finish_simulation(result, pause, normalised_args)
+    })
+}
+
+/// Performs the simulation described by the `ron_args` config in-process.
+///
+/// All events are reported to the in-process `reporter`, which replaces the
+///  dynamically loaded reporter plugins and must be the only reporter, i.e.
+///  the `reporters` list of the config must be empty.
+///
+/// If the simulation pauses, the config to resume it is written to the file
+///  specified in its `pause` field. If the config has a `checkpoint` field,
+///  the simulation resumes from the latest checkpoint in its directory.
+///
+/// # Errors
+///
+/// Returns an error iff parsing the simulation config, initialising the
+///  simulation, or performing the simulation failed.
+#[allow(clippy::module_name_repetitions)]
+pub fn simulate_with_reporter<R: Reporter>(
+    ron_args: &str,
+    reporter: R,
+) -> anyhow::Result<SimulationOutcome> {
+    let ron_args = &*checkpoint::resume_from_latest(ron_args)?;
+
+    let (args, mut normalised_args) = ParsedSimulateArgs::parse(ron_args)?.normalise()?;
+
+    parse::reporters::parse_and_normalise_in_process(ron_args, &mut normalised_args)?;
+
+    simulate_normalised_with_reporter(args, reporter, normalised_args)
+}
+
+fn simulate_normalised_with_reporter<R: Reporter>(
+    args: ParsedSimulateArgs,
+    reporter: R,
+    mut normalised_args: BufferingSimulateArgsBuilder,
+) -> anyhow::Result<SimulationOutcome> {
+    let pause = args.pause;
+
+    let result = dispatch_with_reporter_context(
+        args.partitioning,
+        args.event_log,
+        InProcessReporterContext::new(reporter),
+        args.speciation_probability_per_generation,
+        args.protracted_speciation,
+        args.sample,
+        args.scenario,
+        args.algorithm,
+        args.maths,
+        pause.as_ref().map(|pause| pause.before),
+        args.checkpoint,
+        args.rng,
+        &mut normalised_args,
+    )?;
+
+    finish_simulation(result, pause, normalised_args)
+}
+
+fn finish_simulation(
+    result: SimulationOutcome,
+    pause: Option<Pause>,
+    normalised_args: BufferingSimulateArgsBuilder,
+) -> anyhow::Result<SimulationOutcome> {
+    log_outcome(&result);
+
+    match (pause, result) {
+        (
+            Some(pause),
+            SimulationOutcome::Paused {
+                time,
+                steps,
+                lineages,
+            },
+        ) => {
+            pause::write_resume_config(normalised_args, pause, lineages.clone())?;
+
+            Ok(SimulationOutcome::Paused {
+                time,
+                steps,
+                lineages,
+            })
+        },
+        (_, result) => Ok(result),
+    }
+}
+
+fn log_outcome(result: &SimulationOutcome) {
+    match result {
+        SimulationOutcome::Done { time, steps } => info!(
+            "The simulation finished at time {} after {} steps.\n",
+            time.get(),
+            steps
+        ),
+        SimulationOutcome::Paused { time, steps, .. } => info!(
+            "The simulation paused at time {} after {} steps.\n",
+            time.get(),
+            steps
+        ),
+    }
+}
+
+struct ParsedSimulateArgs {
+    partitioning: Partitioning,
+    event_log: Option<EventLogConfig>,
+    speciation_probability_per_generation: PositiveUnitF64,
+    protracted_speciation: ProtractedSpeciation,
+    sample: Sample,
+    scenario: Scenario,
+    algorithm: Algorithm,
+    maths: Maths,
+    pause: Option<Pause>,
+    checkpoint: Option<Checkpoint>,
+    rng: AnyRng,
+}
+
+impl ParsedSimulateArgs {
+    fn parse(ron_args: &str) -> anyhow::Result<Self> {
+        parse::fields::parse_and_normalise(ron_args)?;
+
+        let partitioning = parse::partitioning::parse(ron_args)?;
+        let pause = parse::pause::parse(ron_args, &partitioning)?;
+        let checkpoint = parse::checkpoint::parse(ron_args)?;
+        let sample = parse::sample::parse(ron_args, &pause)?;
+
+        let (speciation_probability_per_generation, protracted_speciation) =
+            parse::speciation::parse(ron_args)?;
+
+        let rng = parse::rng::parse(ron_args)?;
+        let scenario = parse::scenario::parse(ron_args)?;
+        let algorithm = parse::algorithm::parse(ron_args, &partitioning)?;
+        let maths = parse::maths::parse(ron_args)?;
+
+        let event_log =
+            parse::event_log::parse(ron_args, &partitioning, &sample, &pause, &checkpoint)?;
+
+        Ok(Self {
+            partitioning,
+            event_log,
+            speciation_probability_per_generation,
+            protracted_speciation,
+            sample,
+            scenario,
+            algorithm,
+            maths,
+            pause,
+            checkpoint,
+            rng,
+        })
+    }
+
+    /// Checks that the typed arguments are consistent with each other and
+    ///  records them in the normalised config, which does not yet contain the
+    ///  rng and the reporters
+    fn normalise(self) -> anyhow::Result<(Self, BufferingSimulateArgsBuilder)> {
+        let mut normalised_args = BufferingSimulateArgs::builder();
+
+        parse::partitioning::normalise(&self.partitioning, &mut normalised_args)?;
+        parse::pause::normalise(&self.pause, &mut normalised_args, &self.partitioning)?;
+        parse::checkpoint::normalise(
+            &self.checkpoint,
+            &mut normalised_args,
+            &self.partitioning,
+            &self.algorithm,
+            &self.pause,
+        )?;
+        parse::sample::normalise(&self.sample, &mut normalised_args, &self.pause)?;
+        parse::speciation::normalise(
+            self.speciation_probability_per_generation,
+            self.protracted_speciation,
+            &mut normalised_args,
+        );
+        parse::scenario::normalise(&self.scenario, &mut normalised_args, &self.partitioning)?;
+        parse::algorithm::normalise(
+            &self.algorithm,
+            &mut normalised_args,
+            &self.partitioning,
+            &self.rng,
+        )?;
+        parse::maths::normalise(self.maths, &mut normalised_args);
+
+        let event_log = parse::event_log::normalise(
+            self.event_log,
+            &mut normalised_args,
+            &self.partitioning,
+            &self.sample,
+            &self.pause,
+            &self.checkpoint,
+        )?;
+
+        Ok((Self { event_log, ..self }, normalised_args))
+    }
+}
+
+#[derive(Serialize, Builder)]
+#[builder(setter(into))]
+#[serde(rename = "Simulate")]
+struct BufferingSimulateArgs {
+    speciation: BufferingSerializeResult,
+    protracted: BufferingSerializeResult,
+    sample: BufferingSerializeResult,
+    pause: BufferingSerializeResult,
+    checkpoint: BufferingSerializeResult,
+    rng: BufferingSerializeResult,
+    scenario: BufferingSerializeResult,
+    algorithm: BufferingSerializeResult,
+    maths: BufferingSerializeResult,
+    partitioning: BufferingSerializeResult,
+    log: BufferingSerializeResult,
+    reporters: BufferingSerializeResult,
+}
+
+impl BufferingSimulateArgs {
+    #[must_use]
+    pub fn builder() -> BufferingSimulateArgsBuilder {
+        BufferingSimulateArgsBuilder::default()
+    }
+}
@@ -0,0 +1,124 @@
+use necsim_partitioning_core::partition::PartitionSize;
+
+use crate::{
+    config::{algorithm::Algorithm, partitioning::Partitioning, rng::AnyRng},
+    utils::parse::try_parse_state,
+};
+
+use super::super::BufferingSimulateArgsBuilder;
+
+pub(in super::super) fn parse(
+    ron_args: &str,
+    partitioning: &Partitioning,
+) -> anyhow::Result<Algorithm> {
+    let SimulateArgsAlgorithmOnly { algorithm } =
+        try_parse_state("simulate", ron_args, &mut partitioning.get_size())?;
+
+    Ok(algorithm)
+}
+
+pub(in super::super) fn normalise(
+    algorithm: &Algorithm,
+    normalised_args: &mut BufferingSimulateArgsBuilder,
+    partitioning: &Partitioning,
+    rng: &AnyRng,
+) -> anyhow::Result<()> {
+    algorithm.check(partitioning.get_size())?;
+
+    if let Some(generator) = rng.generator() {
+        anyhow::ensure!(
+            algorithm.supports_rng_generator(generator),
+            "The algorithm does not support the {generator:?} rng generator in this build of \
+             rustcoalescence, try using its default {:?} generator instead.",
+            algorithm.default_rng_generator(),
+        );
+    }
+
+    normalised_args.algorithm(algorithm);
+
+    Ok(())
+}
+
+#[derive(DeserializeState)]
+#[serde(deserialize_state = "PartitionSize")]
+#[serde(rename = "Simulate")]
+struct SimulateArgsAlgorithmOnly {
+    #[serde(deserialize_state)]
+    algorithm: Algorithm,
+}
+
+#[cfg(all(
+    test,
+    any(feature = "gillespie-algorithms", feature = "independent-algorithm")
+))]
+mod tests {
+    use super::super::{partitioning, rng};
+    use crate::simulate::BufferingSimulateArgs;
+
+    fn normalise(algorithm: &str, rng: &str) -> anyhow::Result<()> {
+        let ron_args = format!("(algorithm: {algorithm}, rng: {rng}(Seed(42)))");
+
+        let partitioning = partitioning::parse(&ron_args)?;
+        let algorithm = super::parse(&ron_args, &partitioning)?;
+        let rng = rng::parse(&ron_args)?;
+
+        super::normalise(
+            &algorithm,
+            &mut BufferingSimulateArgs::builder(),
+            &partitioning,
+            &rng,
+        )
+    }
+
+    #[test]
+    #[cfg(feature = "gillespie-algorithms")]
+    fn test_splittable_generators() {
+        for algorithm in ["Gillespie()", "EventSkipping()"] {
+            assert!(normalise(algorithm, "Pcg").is_ok());
+            assert_eq!(
+                normalise(algorithm, "Xoshiro256PlusPlus").is_ok(),
+                cfg!(feature = "xoshiro-rng")
+            );
+            assert_eq!(
+                normalise(algorithm, "ChaCha8").is_ok(),
+                cfg!(feature = "chacha-rng")
+            );
+
+            assert!(normalise(algorithm, "WyHash").is_err());
+            assert!(normalise(algorithm, "SeaHash").is_err());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "independent-algorithm")]
+    fn test_primeable_generators() {
+        assert!(normalise("Independent()", "WyHash").is_ok());
+        assert_eq!(
+            normalise("Independent()", "SeaHash").is_ok(),
+            cfg!(feature = "seahash-rng")
+        );
+        assert_eq!(
+            normalise("Independent()", "ChaCha8").is_ok(),
+            cfg!(feature = "chacha-rng")
+        );
+
+        assert!(normalise("Independent()", "Pcg").is_err());
+        assert!(normalise("Independent()", "Xoshiro256PlusPlus").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "gillespie-algorithms")]
+    fn test_rejection_suggests_the_default_generator() {
+        let Err(error) = normalise("Gillespie()", "WyHash") else {
+            panic!("the WyHash generator is not splittable");
+        };
+
+        assert_eq!(
+            error.to_string(),
+            concat!(
+                "The algorithm does not support the WyHash rng generator in this build of ",
+                "rustcoalescence, try using its default Pcg generator instead."
+            )
+        );
+    }
+}
@@ -0,0 +1,52 @@
+use serde::Deserialize;
+
+use crate::{
+    config::{
+        algorithm::Algorithm, checkpoint::Checkpoint, partitioning::Partitioning, pause::Pause,
+    },
+    utils::parse::try_parse,
+};
+
+use super::super::{checkpoint::config_hash, BufferingSimulateArgsBuilder};
+
+pub(in super::super) fn parse(ron_args: &str) -> anyhow::Result<Option<Checkpoint>> {
+    let SimulateArgsCheckpointOnly { checkpoint } = try_parse("simulate", ron_args)?;
+
+    // A fresh simulation's checkpoints belong to the config that it started with
+    Ok(checkpoint.map(|checkpoint| Checkpoint {
+        config_hash: Some(
+            checkpoint
+                .config_hash
+                .unwrap_or_else(|| config_hash(ron_args)),
+        ),
+        ..checkpoint
+    }))
+}
+
+pub(in super::super) fn normalise(
+    checkpoint: &Option<Checkpoint>,
+    normalised_args: &mut BufferingSimulateArgsBuilder,
+    partitioning: &Partitioning,
+    algorithm: &Algorithm,
+    pause: &Option<Pause>,
+) -> anyhow::Result<()> {
+    if let Some(checkpoint) = checkpoint {
+        checkpoint.check(partitioning.get_size(), algorithm)?;
+    }
+
+    anyhow::ensure!(
+        checkpoint.is_none() || pause.is_none(),
+        "Checkpointing a simulation cannot yet be combined with pausing it."
+    );
+
+    normalised_args.checkpoint(checkpoint);
+
+    Ok(())
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "Simulate")]
+struct SimulateArgsCheckpointOnly {
+    #[serde(default)]
+    checkpoint: Option<Checkpoint>,
+}
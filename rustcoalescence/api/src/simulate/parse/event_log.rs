@@ -0,0 +1,292 @@
+use std::{
+    fs,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Deserializer};
+use serde_state::DeserializeState;
+
+use necsim_impls_std::event_log::recorder::EventLogConfig;
+
+use crate::{
+    config::{
+        checkpoint::{Checkpoint, EventLogBoundary},
+        partitioning::Partitioning,
+        pause::Pause,
+        sample::{Sample, SampleMode},
+    },
+    utils::parse::try_parse_state,
+};
+
+use super::super::BufferingSimulateArgsBuilder;
+
+pub(in super::super) fn parse(
+    ron_args: &str,
+    partitioning: &Partitioning,
+    sample: &Sample,
+    pause: &Option<Pause>,
+    checkpoint: &Option<Checkpoint>,
+) -> anyhow::Result<Option<EventLogConfig>> {
+    let mut event_log_check = event_log_check(partitioning, sample, pause, checkpoint);
+
+    let SimulateArgsEventLogOnly { event_log } =
+        try_parse_state("simulate", ron_args, &mut event_log_check)?;
+
+    Ok(event_log)
+}
+
+pub(in super::super) fn normalise(
+    event_log: Option<EventLogConfig>,
+    normalised_args: &mut BufferingSimulateArgsBuilder,
+    partitioning: &Partitioning,
+    sample: &Sample,
+    pause: &Option<Pause>,
+    checkpoint: &Option<Checkpoint>,
+) -> anyhow::Result<Option<EventLogConfig>> {
+    let (missing_check, present_check) = event_log_check(partitioning, sample, pause, checkpoint);
+
+    if event_log.is_none() {
+        missing_check?;
+    } else {
+        present_check?;
+    }
+
+    normalised_args.log(&event_log);
+
+    let event_log = match event_log {
+        Some(event_log)
+            if event_log.directory() == Path::new("I-solemnly-swear-that-I-am-up-to-no-good") =>
+        {
+            None
+        },
+        event_log => event_log,
+    };
+
+    if let (Some(checkpoint), SampleMode::Resume) = (checkpoint, &sample.mode) {
+        move_aside_segments_after_checkpoint(&checkpoint.event_log)?;
+
+        if let Some(event_log) = &event_log {
+            if event_log.directory().starts_with(&checkpoint.directory) {
+                move_aside_interrupted_event_log(event_log.directory())?;
+            }
+        }
+    }
+
+    Ok(event_log)
+}
+
+/// Returns the checks for a missing and a present event log
+fn event_log_check(
+    partitioning: &Partitioning,
+    sample: &Sample,
+    pause: &Option<Pause>,
+    checkpoint: &Option<Checkpoint>,
+) -> (anyhow::Result<()>, anyhow::Result<()>) {
+    let mut event_log_check = partitioning.get_event_log_check();
+
+    if event_log_check.0.is_ok()
+        && (pause.is_some() || checkpoint.is_some() || !matches!(sample.mode, SampleMode::Genesis))
+    {
+        event_log_check.0 = Err(anyhow::anyhow!(
+            "Pausing, checkpointing, or resuming a simulation requires an event log"
+        ));
+    }
+
+    event_log_check
+}
+
+/// Moves the event log segments that were written after the checkpoint, i.e.
+///  after the `boundaries` of all partitions' event logs, out of the way.
+///  Since the resumed simulation is deterministic, these segments only
+///  contain duplicates of events that will be reported again.
+fn move_aside_segments_after_checkpoint(boundaries: &[EventLogBoundary]) -> anyhow::Result<()> {
+    let mut interrupted_logs: Vec<(&Path, PathBuf)> = Vec::new();
+
+    for boundary in boundaries {
+        let (directory, interrupted_child) = match boundary.partition {
+            Some(rank) => (
+                boundary.directory.join(rank.to_string()),
+                Some(rank.to_string()),
+            ),
+            None => (boundary.directory.clone(), None),
+        };
+
+        if !directory.exists() {
+            continue;
+        }
+
+        let mut segments = Vec::new();
+
+        for entry in fs::read_dir(&directory)
+            .map_err(|err| anyhow::anyhow!("Failed to read the event log {directory:?}: {err}"))?
+        {
+            let file_name = entry?.file_name();
+
+            if file_name
+                .to_str()
+                .and_then(|segment| segment.parse::<usize>().ok())
+                .map_or(false, |segment| segment >= boundary.segments)
+            {
+                segments.push(file_name);
+            }
+        }
+
+        if segments.is_empty() {
+            continue;
+        }
+
+        // All partitions of the same event log are moved to the same place
+        let interrupted = if let Some((_, interrupted)) = interrupted_logs
+            .iter()
+            .find(|(log, _)| *log == boundary.directory)
+        {
+            interrupted.clone()
+        } else {
+            let interrupted = next_interrupted_path(&boundary.directory);
+            interrupted_logs.push((&boundary.directory, interrupted.clone()));
+            interrupted
+        };
+        let interrupted = match interrupted_child {
+            Some(child) => interrupted.join(child),
+            None => interrupted,
+        };
+
+        fs::create_dir_all(&interrupted).map_err(|err| {
+            anyhow::anyhow!("Failed to create the interrupted event log {interrupted:?}: {err}")
+        })?;
+
+        for segment in segments {
+            match fs::rename(directory.join(&segment), interrupted.join(&segment)) {
+                // Another process may have moved the segment already
+                Err(err) if err.kind() != ErrorKind::NotFound => anyhow::bail!(
+                    "Failed to move the event log segment {:?} to {:?}: {}",
+                    directory.join(&segment),
+                    interrupted,
+                    err
+                ),
+                _ => (),
+            }
+        }
+
+        warn!(
+            "The event log segments in {:?} that were written after the checkpoint were moved to \
+             {:?}.",
+            directory, interrupted
+        );
+    }
+
+    Ok(())
+}
+
+/// Moves the event log of an earlier attempt to resume from the same
+///  checkpoint out of the way. Since the resumed simulation is deterministic,
+///  the interrupted log only contains duplicates of events that will be
+///  reported again.
+fn move_aside_interrupted_event_log(directory: &Path) -> anyhow::Result<()> {
+    if !directory.exists() {
+        return Ok(());
+    }
+
+    let interrupted = next_interrupted_path(directory);
+
+    match fs::rename(directory, &interrupted) {
+        // Another process may have moved the event log already
+        Err(err) if err.kind() != ErrorKind::NotFound => anyhow::bail!(
+            "Failed to move the interrupted event log {directory:?} to {interrupted:?}: {err}"
+        ),
+        _ => (),
+    }
+
+    warn!(
+        "The event log {:?} of an interrupted resumption from the same checkpoint was moved to \
+         {:?}.",
+        directory, interrupted
+    );
+
+    Ok(())
+}
+
+/// Returns the first path `{directory}.interrupted-{attempt}` that does not
+///  exist yet
+fn next_interrupted_path(directory: &Path) -> PathBuf {
+    let mut attempt = 1_usize;
+
+    loop {
+        let mut interrupted = directory.as_os_str().to_owned();
+        interrupted.push(format!(".interrupted-{attempt}"));
+
+        let interrupted = PathBuf::from(interrupted);
+
+        if !interrupted.exists() {
+            break interrupted;
+        }
+
+        attempt += 1;
+    }
+}
+
+struct SimulateArgsEventLogOnly {
+    event_log: Option<EventLogConfig>,
+}
+
+impl<'de> DeserializeState<'de, (anyhow::Result<()>, anyhow::Result<()>)>
+    for SimulateArgsEventLogOnly
+{
+    fn deserialize_state<D>(
+        event_log_check: &mut (anyhow::Result<()>, anyhow::Result<()>),
+        deserializer: D,
+    ) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let raw = SimulateArgsEventLogOnlyRaw::deserialize_state(event_log_check, deserializer)?;
+
+        if raw.event_log.is_none() {
+            event_log_check
+                .0
+                .as_ref()
+                .map_err(serde::de::Error::custom)?;
+        } else {
+            event_log_check
+                .1
+                .as_ref()
+                .map_err(serde::de::Error::custom)?;
+        }
+
+        Ok(SimulateArgsEventLogOnly {
+            event_log: raw.event_log,
+        })
+    }
+}
+
+#[derive(DeserializeState)]
+#[serde(deserialize_state = "(anyhow::Result<()>, anyhow::Result<()>)")]
+#[serde(rename = "Simulate")]
+struct SimulateArgsEventLogOnlyRaw {
+    #[serde(alias = "log")]
+    #[serde(default)]
+    #[serde(deserialize_state_with = "deserialize_state_event_log")]
+    event_log: Option<EventLogConfig>,
+}
+
+fn deserialize_state_event_log<'de, D: Deserializer<'de>>(
+    event_log_check: &mut (anyhow::Result<()>, anyhow::Result<()>),
+    deserializer: D,
+) -> Result<Option<EventLogConfig>, D::Error> {
+    let maybe_event_log = <Option<EventLogConfig>>::deserialize(deserializer)?;
+
+    if maybe_event_log.is_none() {
+        event_log_check
+            .0
+            .as_ref()
+            .map_err(serde::de::Error::custom)?;
+    } else {
+        event_log_check
+            .1
+            .as_ref()
+            .map_err(serde::de::Error::custom)?;
+    }
+
+    Ok(maybe_event_log)
+}
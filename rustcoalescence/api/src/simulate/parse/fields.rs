@@ -1,6 +1,6 @@
 use serde::{de::IgnoredAny, Deserialize};
 
-use crate::args::utils::parse::try_parse;
+use crate::utils::parse::try_parse;
 
 pub fn parse_and_normalise(ron_args: &str) -> anyhow::Result<()> {
     // Check for the overall config stucture
@@ -19,12 +19,18 @@ struct SimulateArgsFields {
     #[serde(alias = "speciation_probability_per_generation")]
     speciation: IgnoredAny,
 
+    #[serde(default)]
+    protracted: Option<IgnoredAny>,
+
     #[serde(default)]
     sample: IgnoredAny,
 
     #[serde(default)]
     pause: Option<IgnoredAny>,
 
+    #[serde(default)]
+    checkpoint: Option<IgnoredAny>,
+
     #[serde(alias = "randomness")]
     #[serde(default)]
     rng: IgnoredAny,
@@ -33,6 +39,9 @@ struct SimulateArgsFields {
 
     algorithm: IgnoredAny,
 
+    #[serde(default)]
+    maths: IgnoredAny,
+
     #[serde(default)]
     partitioning: IgnoredAny,
 
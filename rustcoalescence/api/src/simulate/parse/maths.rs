@@ -0,0 +1,25 @@
+use serde::Deserialize;
+
+use crate::{config::maths::Maths, utils::parse::try_parse};
+
+use super::super::BufferingSimulateArgsBuilder;
+
+pub(in super::super) fn parse(ron_args: &str) -> anyhow::Result<Maths> {
+    let SimulateArgsMathsOnly { maths } = try_parse("simulate", ron_args)?;
+
+    Ok(maths)
+}
+
+pub(in super::super) fn normalise(
+    maths: Maths,
+    normalised_args: &mut BufferingSimulateArgsBuilder,
+) {
+    normalised_args.maths(&maths);
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "Simulate")]
+struct SimulateArgsMathsOnly {
+    #[serde(default)]
+    maths: Maths,
+}
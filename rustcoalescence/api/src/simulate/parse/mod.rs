@@ -1,6 +1,8 @@
 pub mod algorithm;
+pub mod checkpoint;
 pub mod event_log;
 pub mod fields;
+pub mod maths;
 pub mod partitioning;
 pub mod pause;
 pub mod reporters;
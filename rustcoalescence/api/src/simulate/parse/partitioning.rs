@@ -1,19 +1,29 @@
 use serde::Deserialize;
 
-use crate::args::{config::partitioning::Partitioning, utils::parse::try_parse};
+use crate::{config::partitioning::Partitioning, utils::parse::try_parse};
 
 use super::super::BufferingSimulateArgsBuilder;
 
-pub(in super::super) fn parse_and_normalise(
-    ron_args: &str,
-    normalised_args: &mut BufferingSimulateArgsBuilder,
-) -> anyhow::Result<Partitioning> {
+pub(in super::super) fn parse(ron_args: &str) -> anyhow::Result<Partitioning> {
     let SimulateArgsPartitioningOnly { partitioning } = try_parse("simulate", ron_args)?;
 
-    normalised_args.partitioning(&partitioning);
+    Ok(partitioning)
+}
+
+#[cfg_attr(not(feature = "mpi-partitioning"), allow(clippy::unnecessary_wraps))]
+pub(in super::super) fn normalise(
+    partitioning: &Partitioning,
+    normalised_args: &mut BufferingSimulateArgsBuilder,
+) -> anyhow::Result<()> {
+    normalised_args.partitioning(partitioning);
 
     #[cfg(feature = "mpi-partitioning")]
-    if !matches!(&partitioning, Partitioning::Mpi(_)) {
+    if let Partitioning::Mpi(partitioning) = partitioning {
+        // Only log to stdout/stderr in the MPI root partition
+        if !partitioning.peek_is_root() {
+            log::set_max_level(log::LevelFilter::Off);
+        }
+    } else {
         match necsim_partitioning_mpi::MpiPartitioning::initialise() {
             Ok(_) | Err(necsim_partitioning_mpi::MpiPartitioningError::AlreadyInitialised) => {
                 anyhow::bail!("MPI should not be used together with a non-MPI partitioning")
@@ -22,7 +32,7 @@ pub(in super::super) fn parse_and_normalise(
         }
     }
 
-    Ok(partitioning)
+    Ok(())
 }
 
 #[derive(Deserialize)]
@@ -1,25 +1,36 @@
 use necsim_partitioning_core::partition::PartitionSize;
 
-use crate::args::{
+use crate::{
     config::{partitioning::Partitioning, pause::Pause},
     utils::parse::try_parse_state,
 };
 
 use super::super::BufferingSimulateArgsBuilder;
 
-pub(in super::super) fn parse_and_normalise(
+pub(in super::super) fn parse(
     ron_args: &str,
-    normalised_args: &mut BufferingSimulateArgsBuilder,
     partitioning: &Partitioning,
 ) -> anyhow::Result<Option<Pause>> {
     let SimulateArgsPauseOnly { pause } =
         try_parse_state("simulate", ron_args, &mut partitioning.get_size())?;
 
-    normalised_args.pause(&pause);
-
     Ok(pause)
 }
 
+pub(in super::super) fn normalise(
+    pause: &Option<Pause>,
+    normalised_args: &mut BufferingSimulateArgsBuilder,
+    partitioning: &Partitioning,
+) -> anyhow::Result<()> {
+    if let Some(pause) = pause {
+        pause.check(partitioning.get_size())?;
+    }
+
+    normalised_args.pause(pause);
+
+    Ok(())
+}
+
 #[derive(DeserializeState)]
 #[serde(deserialize_state = "PartitionSize")]
 #[serde(rename = "Simulate")]
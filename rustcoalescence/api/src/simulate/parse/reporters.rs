@@ -0,0 +1,47 @@
+use serde::{de::IgnoredAny, Deserialize};
+
+use necsim_plugins_core::import::AnyReporterPluginVec;
+
+use crate::utils::parse::try_parse;
+
+use super::super::BufferingSimulateArgsBuilder;
+
+pub(in super::super) fn parse_and_normalise(
+    ron_args: &str,
+    normalised_args: &mut BufferingSimulateArgsBuilder,
+) -> anyhow::Result<AnyReporterPluginVec> {
+    let SimulateArgsReportersOnly { reporters } = try_parse("simulate", ron_args)?;
+
+    normalised_args.reporters(&reporters);
+
+    Ok(reporters)
+}
+
+pub(in super::super) fn parse_and_normalise_in_process(
+    ron_args: &str,
+    normalised_args: &mut BufferingSimulateArgsBuilder,
+) -> anyhow::Result<()> {
+    let SimulateArgsInProcessReportersOnly { reporters } = try_parse("simulate", ron_args)?;
+
+    if !reporters.is_empty() {
+        anyhow::bail!(
+            "simulate.reporters: reporter plugins cannot be combined with an in-process reporter"
+        );
+    }
+
+    normalised_args.reporters(&Vec::<()>::new());
+
+    Ok(())
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "Simulate")]
+struct SimulateArgsReportersOnly {
+    reporters: AnyReporterPluginVec,
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "Simulate")]
+struct SimulateArgsInProcessReportersOnly {
+    reporters: Vec<IgnoredAny>,
+}
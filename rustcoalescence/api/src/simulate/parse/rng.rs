@@ -0,0 +1,19 @@
+use serde::Deserialize;
+
+use crate::{config::rng::AnyRng, utils::parse::try_parse};
+
+/// The rng is only normalised once it has been resolved for the generator
+///  which the simulation is dispatched with
+pub(in super::super) fn parse(ron_args: &str) -> anyhow::Result<AnyRng> {
+    let SimulateArgsRngOnly { rng } = try_parse("simulate", ron_args)?;
+
+    Ok(rng)
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "Simulate")]
+struct SimulateArgsRngOnly {
+    #[serde(alias = "randomness")]
+    #[serde(default)]
+    rng: AnyRng,
+}
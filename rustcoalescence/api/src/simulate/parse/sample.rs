@@ -1,22 +1,31 @@
-use crate::args::{
+use crate::{
     config::{pause::Pause, sample::Sample},
     utils::parse::try_parse_state,
 };
 
 use super::super::BufferingSimulateArgsBuilder;
 
-pub(in super::super) fn parse_and_normalise<'a>(
+pub(in super::super) fn parse<'a>(
     ron_args: &'a str,
-    normalised_args: &mut BufferingSimulateArgsBuilder,
     mut pause: &'a Option<Pause>,
 ) -> anyhow::Result<Sample> {
     let SimulateArgsSampleOnly { sample } = try_parse_state("simulate", ron_args, &mut pause)?;
 
-    normalised_args.sample(&sample);
-
     Ok(sample)
 }
 
+pub(in super::super) fn normalise(
+    sample: &Sample,
+    normalised_args: &mut BufferingSimulateArgsBuilder,
+    pause: &Option<Pause>,
+) -> anyhow::Result<()> {
+    sample.check(pause)?;
+
+    normalised_args.sample(sample);
+
+    Ok(())
+}
+
 #[derive(DeserializeState)]
 #[serde(deserialize_state = "&'de Option<Pause>")]
 #[serde(rename = "Simulate")]
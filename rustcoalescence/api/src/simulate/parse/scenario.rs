@@ -0,0 +1,70 @@
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::{
+    config::{partitioning::Partitioning, scenario::Scenario},
+    utils::parse::try_parse,
+};
+
+use super::super::BufferingSimulateArgsBuilder;
+
+pub(in super::super) fn parse(ron_args: &str) -> anyhow::Result<Scenario> {
+    let SimulateArgsScenarioOnly { scenario } = try_parse("simulate", ron_args)?;
+
+    Ok(scenario)
+}
+
+#[allow(unused_variables)]
+#[cfg_attr(
+    not(feature = "spatially-explicit-temporal-scenario"),
+    allow(clippy::unnecessary_wraps)
+)]
+pub(in super::super) fn normalise(
+    scenario: &Scenario,
+    normalised_args: &mut BufferingSimulateArgsBuilder,
+    partitioning: &Partitioning,
+) -> anyhow::Result<()> {
+    #[cfg(feature = "spatially-explicit-temporal-scenario")]
+    if matches!(scenario, Scenario::SpatiallyExplicitTemporal(..))
+        && !partitioning.get_size().is_monolithic()
+    {
+        anyhow::bail!(
+            "The `SpatiallyExplicitTemporal` scenario does not yet support parallel simulations."
+        );
+    }
+
+    if let Some(speciation_path) = speciation_map_path(scenario) {
+        warn!(
+            "The speciation map {:?} overrides the top-level `speciation` probability, which is \
+             ignored.",
+            speciation_path
+        );
+    }
+
+    normalised_args.scenario(scenario);
+
+    Ok(())
+}
+
+/// Returns the path to the speciation map of the `scenario`, which replaces
+///  the top-level uniform speciation probability
+#[allow(unused_variables, clippy::unnecessary_wraps)]
+fn speciation_map_path(scenario: &Scenario) -> Option<&Path> {
+    match scenario {
+        #[cfg(feature = "spatially-explicit-uniform-turnover-scenario")]
+        Scenario::SpatiallyExplicitUniformTurnover(args) => args.speciation_path.as_deref(),
+        #[cfg(feature = "spatially-explicit-turnover-map-scenario")]
+        Scenario::SpatiallyExplicitTurnoverMap(args) => args.speciation_path.as_deref(),
+        #[cfg(feature = "spatially-explicit-temporal-scenario")]
+        Scenario::SpatiallyExplicitTemporal(args, _) => args.initial.speciation_path.as_deref(),
+        #[allow(unreachable_patterns)]
+        _ => None,
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "Simulate")]
+struct SimulateArgsScenarioOnly {
+    scenario: Scenario,
+}
@@ -0,0 +1,49 @@
+use serde::Deserialize;
+
+use necsim_core_bond::OpenClosedUnitF64 as PositiveUnitF64;
+use necsim_impls_no_std::cogs::event_sampler::protracted::ProtractedSpeciation;
+
+use crate::{config::protracted::Protracted, utils::parse::try_parse};
+
+use super::super::BufferingSimulateArgsBuilder;
+
+pub(in super::super) fn parse(
+    ron_args: &str,
+) -> anyhow::Result<(PositiveUnitF64, ProtractedSpeciation)> {
+    let SimulateArgsSpeciationOnly {
+        speciation_probability_per_generation,
+        protracted,
+    } = try_parse("simulate", ron_args)?;
+
+    let protracted_speciation = match &protracted {
+        None => ProtractedSpeciation::instantaneous(),
+        Some(Protracted { min, max }) => ProtractedSpeciation::new(*min, *max)
+            .map_err(|err| anyhow::anyhow!("Invalid protracted speciation: {}", err))?,
+    };
+
+    Ok((speciation_probability_per_generation, protracted_speciation))
+}
+
+pub(in super::super) fn normalise(
+    speciation_probability_per_generation: PositiveUnitF64,
+    protracted_speciation: ProtractedSpeciation,
+    normalised_args: &mut BufferingSimulateArgsBuilder,
+) {
+    let protracted = (!protracted_speciation.is_instantaneous()).then(|| Protracted {
+        min: protracted_speciation.min(),
+        max: protracted_speciation.max(),
+    });
+
+    normalised_args.speciation(&speciation_probability_per_generation);
+    normalised_args.protracted(&protracted);
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "Simulate")]
+struct SimulateArgsSpeciationOnly {
+    #[serde(alias = "speciation")]
+    speciation_probability_per_generation: PositiveUnitF64,
+
+    #[serde(default)]
+    protracted: Option<Protracted>,
+}
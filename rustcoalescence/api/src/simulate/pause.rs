@@ -7,7 +7,7 @@ use necsim_impls_std::lineage_file::loader::LineageFileLoader;
 
 use rustcoalescence_algorithms::strategy::RestartFixUpStrategy;
 
-use crate::args::{
+use crate::{
     config::{
         pause::{FuturePause, Pause, PauseMode, SampleDestiny},
         sample::{Sample, SampleMode, SampleModeRestart, SampleOrigin},
@@ -47,6 +47,10 @@ pub(super) fn write_resume_config(
                     after: pause.before,
                 }),
             },
+            start: match pause.mode {
+                PauseMode::Resume => Some(pause.before),
+                PauseMode::FixUp | PauseMode::Restart => None,
+            },
         })
         .pause(&match pause.mode {
             PauseMode::Resume | PauseMode::Restart => None,
@@ -3,11 +3,17 @@ use ron::{extensions::Extensions, ser::PrettyConfig, Options};
 use serde::{Deserialize, Serialize};
 use serde_state::DeserializeState;
 
+/// # Errors
+///
+/// Returns an error iff the `ron_args` cannot be parsed.
 #[allow(clippy::module_name_repetitions)]
 pub fn try_parse<'de, D: Deserialize<'de>>(subcommand: &str, ron_args: &'de str) -> Result<D> {
     try_parse_inner(subcommand, ron_args, |de| D::deserialize(de))
 }
 
+/// # Errors
+///
+/// Returns an error iff the `ron_args` cannot be parsed.
 pub fn try_parse_state<'de, D: DeserializeState<'de, Seed>, Seed: ?Sized>(
     subcommand: &str,
     ron_args: &'de str,
@@ -16,6 +22,9 @@ pub fn try_parse_state<'de, D: DeserializeState<'de, Seed>, Seed: ?Sized>(
     try_parse_inner(subcommand, ron_args, |de| D::deserialize_state(seed, de))
 }
 
+/// # Errors
+///
+/// Returns an error iff the `value` cannot be serialised.
 pub fn try_print<S: Serialize>(value: &S) -> Result<String> {
     ron_options()
         .to_string_pretty(value, PrettyConfig::default().struct_names(true))
@@ -0,0 +1,263 @@
+//! Integration tests which check that simulations with protracted speciation
+//!  respect the speciation window, that all algorithms apply the same window,
+//!  and that the default instantaneous speciation reproduces the events of
+//!  point-mutation speciation.
+
+use std::{
+    fmt::{self, Write},
+    sync::{Arc, Mutex},
+};
+
+use tiny_keccak::{Hasher, Keccak};
+
+use necsim_core::{event::SpeciationEvent, impl_finalise, impl_report, reporter::Reporter};
+
+use rustcoalescence_api::simulate::simulate_with_reporter;
+
+#[derive(Default)]
+struct ReportedEvents {
+    speciations: Vec<SpeciationEvent>,
+    out_dispersal_times: Vec<f64>,
+    checksum: Option<String>,
+}
+
+/// Reporter which records the times of all speciation and out-dispersal
+///  events and hashes all events in the order in which they are reported
+struct EventTimesReporter {
+    hasher: Keccak,
+    events: Arc<Mutex<ReportedEvents>>,
+}
+
+impl fmt::Debug for EventTimesReporter {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct(stringify!(EventTimesReporter))
+            .finish_non_exhaustive()
+    }
+}
+
+impl Reporter for EventTimesReporter {
+    impl_report!(speciation(&mut self, speciation: Used) {
+        self.hasher.update(b"speciation");
+        self.hasher
+            .update(&bincode::serialize(speciation).expect("events can be serialised"));
+
+        if let Ok(mut events) = self.events.lock() {
+            events.speciations.push(speciation.clone());
+        }
+    });
+
+    impl_report!(dispersal(&mut self, dispersal: Used) {
+        self.hasher.update(b"dispersal");
+        self.hasher
+            .update(&bincode::serialize(dispersal).expect("events can be serialised"));
+
+        if dispersal.origin.location() != dispersal.target.location() {
+            if let Ok(mut events) = self.events.lock() {
+                events.out_dispersal_times.push(dispersal.event_time.get());
+            }
+        }
+    });
+
+    impl_report!(progress(&mut self, _progress: Ignored) {});
+
+    impl_finalise!((self) {
+        let mut digest = [0_u8; 32];
+        self.hasher.finalize(&mut digest);
+
+        let checksum = digest.iter().fold(String::new(), |mut checksum, byte| {
+            let _ = write!(checksum, "{byte:02x}");
+            checksum
+        });
+
+        if let Ok(mut events) = self.events.lock() {
+            events.checksum = Some(checksum);
+        }
+    });
+}
+
+fn simulate_events(algorithm: &str, protracted: &str) -> ReportedEvents {
+    simulate_scenario_events(
+        algorithm,
+        protracted,
+        42,
+        "NonSpatial(area: (10, 10), deme: 10)",
+    )
+}
+
+fn simulate_scenario_events(
+    algorithm: &str,
+    protracted: &str,
+    seed: u64,
+    scenario: &str,
+) -> ReportedEvents {
+    let config = format!(
+        "(
+            speciation: 0.01,
+            {protracted}
+            sample: Sample(percentage: 1.0),
+            rng: Seed({seed}),
+            maths: Reproducible,
+            algorithm: {algorithm},
+            scenario: {scenario},
+            reporters: [],
+        )"
+    );
+
+    let events = Arc::new(Mutex::new(ReportedEvents::default()));
+
+    simulate_with_reporter(
+        &config,
+        EventTimesReporter {
+            hasher: Keccak::v256(),
+            events: events.clone(),
+        },
+    )
+    .expect("the simulation succeeds");
+
+    let mut events = events.lock().expect("the reporter did not panic");
+
+    std::mem::take(&mut *events)
+}
+
+fn assert_window_is_respected(algorithm: &str) {
+    let events = simulate_events(algorithm, "protracted: Protracted(min: 10.0, max: 50.0),");
+
+    assert!(!events.speciations.is_empty());
+
+    for time in events
+        .speciations
+        .iter()
+        .map(|event| event.event_time.get())
+    {
+        assert!(
+            time >= 10.0_f64,
+            "{algorithm} speciated at {time} before the minimum age"
+        );
+    }
+
+    for time in events.out_dispersal_times {
+        assert!(
+            time < 50.0_f64,
+            "{algorithm} dispersed out at {time} instead of speciating after the maximum age"
+        );
+    }
+}
+
+/// Mean and standard error of the mean
+fn mean_and_error(samples: &[f64]) -> (f64, f64) {
+    #[allow(clippy::cast_precision_loss)]
+    let n = samples.len() as f64;
+
+    let mean = samples.iter().sum::<f64>() / n;
+    let variance = samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0);
+
+    (mean, (variance / n).sqrt())
+}
+
+/// Species richness per simulation and the ages of all species, i.e. the
+///  times of their speciation events, across many simulations with a
+///  protracted speciation window which forces most lineages to speciate
+fn protracted_species_distributions(algorithm: &str) -> (Vec<f64>, Vec<f64>) {
+    let mut richness = Vec::new();
+    let mut ages = Vec::new();
+
+    // Half of all dispersal events are self-dispersal events, which the
+    //  event-skipping algorithm partially skips over
+    for seed in 1..=50 {
+        let events = simulate_scenario_events(
+            algorithm,
+            "protracted: Protracted(min: 1.0, max: 5.0),",
+            seed,
+            "NonSpatial(area: (2, 1), deme: 50)",
+        );
+
+        // The independent algorithm reports the duplicate speciation events
+        //  of coalesced lineages, which only count as one species
+        let mut species: Vec<SpeciationEvent> = Vec::new();
+
+        for speciation in events.speciations {
+            if !species.contains(&speciation) {
+                species.push(speciation);
+            }
+        }
+
+        #[allow(clippy::cast_precision_loss)]
+        richness.push(species.len() as f64);
+        ages.extend(species.iter().map(|speciation| speciation.event_time.get()));
+    }
+
+    (richness, ages)
+}
+
+#[test]
+fn protracted_distributions_agree_across_algorithms() {
+    let (gillespie_richness, gillespie_ages) = protracted_species_distributions("Gillespie()");
+
+    for algorithm in ["EventSkipping()", "Independent()"] {
+        let (richness, ages) = protracted_species_distributions(algorithm);
+
+        for (statistic, expected, actual) in [
+            ("richness", &gillespie_richness, &richness),
+            ("species age", &gillespie_ages, &ages),
+        ] {
+            let (expected_mean, expected_error) = mean_and_error(expected);
+            let (actual_mean, actual_error) = mean_and_error(actual);
+
+            assert!(
+                (expected_mean - actual_mean).abs() < 4.0 * expected_error.hypot(actual_error),
+                "{algorithm} has a mean {statistic} of {actual_mean} +- {actual_error}, but \
+                 Gillespie() has {expected_mean} +- {expected_error}"
+            );
+        }
+    }
+}
+
+/// Checksums of the events, including their speciation samples, that were
+///  reported before protracted speciation was introduced
+const GILLESPIE_BASELINE: &str = "8ee2d505e9d6b5909a00d5e302da86bd286e8ab3608366b5f541a8661400d38a";
+const EVENT_SKIPPING_BASELINE: &str =
+    "2456fdf0267bab1733ad249003b280ddddbef5888c034710c40f3d7d02cc28e7";
+const INDEPENDENT_BASELINE: &str =
+    "f8fb8fdd705eed30ce0cb43bf6990f59a4e5874a0aa1aed6f10689f9500356a2";
+
+fn assert_instantaneous_reproduces_baseline(algorithm: &str, baseline: &str) {
+    for protracted in ["", "protracted: Protracted(min: 0.0, max: inf),"] {
+        let events = simulate_events(algorithm, protracted);
+
+        assert_eq!(
+            events.checksum.as_deref(),
+            Some(baseline),
+            "{algorithm} with {protracted:?} diverged from point-mutation speciation"
+        );
+    }
+}
+
+#[test]
+fn gillespie_protracted_window() {
+    assert_window_is_respected("Gillespie()");
+}
+
+#[test]
+fn event_skipping_protracted_window() {
+    assert_window_is_respected("EventSkipping()");
+}
+
+#[test]
+fn independent_protracted_window() {
+    assert_window_is_respected("Independent()");
+}
+
+#[test]
+fn gillespie_instantaneous_baseline() {
+    assert_instantaneous_reproduces_baseline("Gillespie()", GILLESPIE_BASELINE);
+}
+
+#[test]
+fn event_skipping_instantaneous_baseline() {
+    assert_instantaneous_reproduces_baseline("EventSkipping()", EVENT_SKIPPING_BASELINE);
+}
+
+#[test]
+fn independent_instantaneous_baseline() {
+    assert_instantaneous_reproduces_baseline("Independent()", INDEPENDENT_BASELINE);
+}
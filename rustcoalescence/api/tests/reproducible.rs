@@ -0,0 +1,272 @@
+//! Regression tests which pin the checksums of all events that simulations
+//!  with `maths: Reproducible` and a fixed seed report, such that published
+//!  results can be re-derived bit-for-bit on any machine.
+//!
+//! The checksums are pinned in `tests/reproducible.checksums`, and a missing
+//!  checksum fails its test. After an intentional change, or to pin a new
+//!  test, run the tests with the `NECSIM_BLESS_CHECKSUMS` environment variable
+//!  set, which writes the blessed checksums into the cargo target's temporary
+//!  directory, and copy them over the pinned checksums.
+
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Write},
+    fs,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use tiny_keccak::{Hasher, Keccak};
+
+use necsim_core::{impl_finalise, impl_report, reporter::Reporter};
+
+use rustcoalescence_api::simulate::simulate_with_reporter;
+
+/// Reporter which hashes all speciation and dispersal events in the order in
+///  which they are reported
+struct ChecksumReporter {
+    hasher: Keccak,
+    checksum: Arc<Mutex<Option<String>>>,
+}
+
+impl fmt::Debug for ChecksumReporter {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct(stringify!(ChecksumReporter))
+            .finish_non_exhaustive()
+    }
+}
+
+impl ChecksumReporter {
+    fn hash_event<E: serde::Serialize>(&mut self, event: &E) {
+        self.hasher
+            .update(&bincode::serialize(event).expect("events can be serialised"));
+    }
+}
+
+impl Reporter for ChecksumReporter {
+    impl_report!(speciation(&mut self, speciation: Used) {
+        self.hasher.update(b"speciation");
+        self.hash_event(speciation);
+    });
+
+    impl_report!(dispersal(&mut self, dispersal: Used) {
+        self.hasher.update(b"dispersal");
+        self.hash_event(dispersal);
+    });
+
+    impl_report!(progress(&mut self, _progress: Ignored) {});
+
+    impl_finalise!((self) {
+        let mut digest = [0_u8; 32];
+        self.hasher.finalize(&mut digest);
+
+        let checksum = digest.iter().fold(String::new(), |mut checksum, byte| {
+            let _ = write!(checksum, "{byte:02x}");
+            checksum
+        });
+
+        if let Ok(mut shared) = self.checksum.lock() {
+            *shared = Some(checksum);
+        }
+    });
+}
+
+fn simulate_checksum(algorithm: &str, scenario: &str) -> String {
+    let config = format!(
+        "(
+            speciation: 0.01,
+            sample: Sample(percentage: 1.0),
+            rng: Seed(42),
+            maths: Reproducible,
+            algorithm: {algorithm},
+            scenario: {scenario},
+            reporters: [],
+        )"
+    );
+
+    let checksum = Arc::new(Mutex::new(None));
+
+    simulate_with_reporter(
+        &config,
+        ChecksumReporter {
+            hasher: Keccak::v256(),
+            checksum: checksum.clone(),
+        },
+    )
+    .expect("the simulation succeeds");
+
+    let checksum = checksum.lock().expect("the reporter did not panic").take();
+
+    checksum.expect("the reporter was finalised")
+}
+
+fn assert_pinned_checksum(name: &str, algorithm: &str, scenario: &str) {
+    let checksum = simulate_checksum(algorithm, scenario);
+
+    if std::env::var_os("NECSIM_BLESS_CHECKSUMS").is_some() {
+        return bless_checksum(name, checksum);
+    }
+
+    let pinned = read_checksums(&pinned_checksums_path());
+
+    let Some(expected) = pinned.get(name) else {
+        panic!(
+            "no checksum is pinned for {name}, bless it with NECSIM_BLESS_CHECKSUMS=1 and copy \
+             {:?} to {:?}",
+            blessed_checksums_path(),
+            pinned_checksums_path(),
+        );
+    };
+
+    assert_eq!(
+        expected,
+        &checksum,
+        "the events of {name} have changed, bless them with NECSIM_BLESS_CHECKSUMS=1 and copy \
+         {:?} to {:?} if this change is intentional",
+        blessed_checksums_path(),
+        pinned_checksums_path(),
+    );
+}
+
+/// Records the `checksum` of the test `name` in the blessed checksums, which
+///  start out as a copy of the pinned checksums
+fn bless_checksum(name: &str, checksum: String) {
+    // The cargo test harness runs tests in parallel
+    static BLESSED: Mutex<Option<BTreeMap<String, String>>> = Mutex::new(None);
+    let mut blessed = BLESSED
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+
+    let blessed = blessed.get_or_insert_with(|| read_checksums(&pinned_checksums_path()));
+    blessed.insert(name.to_owned(), checksum);
+
+    let checksums = blessed
+        .iter()
+        .fold(String::new(), |mut checksums, (name, checksum)| {
+            let _ = writeln!(checksums, "{name} {checksum}");
+            checksums
+        });
+
+    fs::write(blessed_checksums_path(), checksums).expect("the blessed checksums can be written");
+}
+
+fn read_checksums(path: &Path) -> BTreeMap<String, String> {
+    fs::read_to_string(path)
+        .unwrap_or_default()
+        .lines()
+        .filter_map(|line| line.split_once(' '))
+        .map(|(name, checksum)| (name.to_owned(), checksum.to_owned()))
+        .collect()
+}
+
+fn pinned_checksums_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("reproducible.checksums")
+}
+
+fn blessed_checksums_path() -> PathBuf {
+    Path::new(env!("CARGO_TARGET_TMPDIR")).join("reproducible.checksums")
+}
+
+const NON_SPATIAL: &str = "NonSpatial(area: (10, 10), deme: 10)";
+const SPATIALLY_IMPLICIT: &str = "SpatiallyImplicit(
+    local_area: (5, 5), local_deme: 10, meta_area: (20, 20), meta_deme: 10, migration: 0.1,
+)";
+
+fn spatially_explicit() -> String {
+    let maps = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join("..")
+        .join("maps")
+        .join("test");
+
+    format!(
+        "SpatiallyExplicit(habitat: {:?}, dispersal: {:?}, turnover: Uniform(0.5))",
+        maps.join("habitat.tif"),
+        maps.join("dispersal.tif"),
+    )
+}
+
+#[test]
+fn gillespie_non_spatial() {
+    assert_pinned_checksum("gillespie_non_spatial", "Gillespie()", NON_SPATIAL);
+}
+
+#[test]
+fn gillespie_spatially_implicit() {
+    assert_pinned_checksum(
+        "gillespie_spatially_implicit",
+        "Gillespie()",
+        SPATIALLY_IMPLICIT,
+    );
+}
+
+#[test]
+fn gillespie_spatially_explicit() {
+    assert_pinned_checksum(
+        "gillespie_spatially_explicit",
+        "Gillespie()",
+        &spatially_explicit(),
+    );
+}
+
+#[test]
+fn classical_non_spatial() {
+    assert_pinned_checksum("classical_non_spatial", "Classical()", NON_SPATIAL);
+}
+
+#[test]
+fn classical_spatially_explicit() {
+    assert_pinned_checksum(
+        "classical_spatially_explicit",
+        "Classical()",
+        &spatially_explicit(),
+    );
+}
+
+#[test]
+fn event_skipping_non_spatial() {
+    assert_pinned_checksum("event_skipping_non_spatial", "EventSkipping()", NON_SPATIAL);
+}
+
+#[test]
+fn event_skipping_spatially_implicit() {
+    assert_pinned_checksum(
+        "event_skipping_spatially_implicit",
+        "EventSkipping()",
+        SPATIALLY_IMPLICIT,
+    );
+}
+
+#[test]
+fn event_skipping_spatially_explicit() {
+    assert_pinned_checksum(
+        "event_skipping_spatially_explicit",
+        "EventSkipping()",
+        &spatially_explicit(),
+    );
+}
+
+#[test]
+fn independent_non_spatial() {
+    assert_pinned_checksum("independent_non_spatial", "Independent()", NON_SPATIAL);
+}
+
+#[test]
+fn independent_spatially_implicit() {
+    assert_pinned_checksum(
+        "independent_spatially_implicit",
+        "Independent()",
+        SPATIALLY_IMPLICIT,
+    );
+}
+
+#[test]
+fn independent_spatially_explicit() {
+    assert_pinned_checksum(
+        "independent_spatially_explicit",
+        "Independent()",
+        &spatially_explicit(),
+    );
+}
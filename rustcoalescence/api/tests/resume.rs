@@ -0,0 +1,200 @@
+//! Integration tests which check that a checkpointed simulation which is
+//!  interrupted while writing a checkpoint resumes from the latest checkpoint
+//!  and logs the same events as an uninterrupted simulation.
+//!
+//! The simulation is interrupted by blocking the path of the temporary
+//!  config file of one of its checkpoints, such that writing this checkpoint
+//!  fails after the event log was flushed, just like a simulation that is
+//!  killed in the middle of writing a checkpoint.
+
+use std::{
+    fs,
+    num::NonZeroUsize,
+    path::{Path, PathBuf},
+};
+
+use necsim_core::{impl_report, reporter::Reporter};
+use necsim_impls_std::event_log::replay::segment::SortedSegment;
+
+use rustcoalescence_api::simulate::simulate_with_reporter;
+
+/// Reporter which requests that all speciation and dispersal events are
+///  recorded in the event log
+#[derive(Debug)]
+struct EventLogReporter;
+
+impl Reporter for EventLogReporter {
+    impl_report!(speciation(&mut self, _speciation: Used) {});
+
+    impl_report!(dispersal(&mut self, _dispersal: Used) {});
+
+    impl_report!(progress(&mut self, _progress: Ignored) {});
+}
+
+/// Index of the checkpoint while writing which the simulation is interrupted
+const INTERRUPTED_CHECKPOINT: usize = 3;
+
+struct ResumeTest {
+    directory: PathBuf,
+    algorithm: &'static str,
+    partitioning: &'static str,
+}
+
+impl ResumeTest {
+    fn new(name: &str, algorithm: &'static str, partitioning: &'static str) -> Self {
+        let directory = Path::new(env!("CARGO_TARGET_TMPDIR"))
+            .join("resume")
+            .join(name);
+
+        if directory.exists() {
+            fs::remove_dir_all(&directory).expect("the old test directory can be removed");
+        }
+
+        Self {
+            directory,
+            algorithm,
+            partitioning,
+        }
+    }
+
+    fn config(&self, run: &str) -> String {
+        let algorithm = self.algorithm;
+        let partitioning = self.partitioning;
+        let log = self.log_directory(run);
+        let checkpoint = self.checkpoint_directory(run);
+
+        format!(
+            "(
+                speciation: 0.01,
+                sample: Sample(percentage: 1.0),
+                rng: Seed(42),
+                maths: Reproducible,
+                algorithm: {algorithm},
+                scenario: NonSpatial(area: (10, 10), deme: 10),
+                partitioning: {partitioning},
+                log: EventLog(directory: {log:?}, capacity: 100),
+                checkpoint: Checkpoint(directory: {checkpoint:?}, every: Generations(2.0)),
+                reporters: [],
+            )"
+        )
+    }
+
+    fn log_directory(&self, run: &str) -> PathBuf {
+        self.directory.join(run).join("log")
+    }
+
+    fn checkpoint_directory(&self, run: &str) -> PathBuf {
+        self.directory.join(run).join("checkpoint")
+    }
+
+    fn assert_resume_is_uninterrupted(&self) {
+        simulate_with_reporter(&self.config("uninterrupted"), EventLogReporter)
+            .expect("the uninterrupted simulation succeeds");
+
+        let config = self.config("interrupted");
+        let checkpoint = self.checkpoint_directory("interrupted");
+
+        let obstacle = checkpoint.join(format!("checkpoint-{INTERRUPTED_CHECKPOINT}.ron.tmp"));
+        fs::create_dir_all(&obstacle).expect("the checkpoint can be blocked");
+
+        let Err(error) = simulate_with_reporter(&config, EventLogReporter) else {
+            panic!("the simulation is interrupted");
+        };
+        assert!(
+            format!("{error:?}").contains("Failed to write the checkpoint"),
+            "the simulation failed for another reason: {error:?}"
+        );
+        assert!(checkpoint
+            .join(format!("checkpoint-{}.ron", INTERRUPTED_CHECKPOINT - 1))
+            .exists());
+
+        fs::remove_dir(&obstacle).expect("the checkpoint can be unblocked");
+
+        simulate_with_reporter(&config, EventLogReporter).expect("the resumed simulation succeeds");
+
+        // The resumed simulation continues its event log in the checkpoint
+        //  directory and removes all checkpoints once it has finished
+        assert!(checkpoint
+            .join(format!("log-{}", INTERRUPTED_CHECKPOINT - 1))
+            .exists());
+        assert!(!checkpoint
+            .join(format!("checkpoint-{}.ron", INTERRUPTED_CHECKPOINT - 1))
+            .exists());
+
+        let uninterrupted = logged_events(&[&self.log_directory("uninterrupted")]);
+        let resumed = logged_events(&[&self.log_directory("interrupted"), &checkpoint]);
+
+        assert!(!uninterrupted.is_empty());
+        assert!(
+            uninterrupted == resumed,
+            "the resumed simulation logged {} instead of {} distinct events",
+            resumed.len(),
+            uninterrupted.len(),
+        );
+    }
+}
+
+/// Returns the sorted distinct events in all event log segments inside the
+///  `directories`, except for the segments that were moved aside as they were
+///  written after the latest checkpoint
+fn logged_events(directories: &[&Path]) -> Vec<Vec<u8>> {
+    fn collect_events(directory: &Path, events: &mut Vec<Vec<u8>>) {
+        for entry in fs::read_dir(directory).expect("the event log can be read") {
+            let path = entry.expect("the event log can be read").path();
+            let name = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .unwrap_or("");
+
+            if name.contains(".interrupted-") {
+                continue;
+            }
+
+            if path.is_dir() {
+                collect_events(&path, events);
+            } else if name.parse::<usize>().is_ok() {
+                let segment = SortedSegment::try_new(&path, NonZeroUsize::MIN)
+                    .expect("the event log segment can be read");
+
+                events
+                    .extend(segment.map(|event| {
+                        bincode::serialize(&event).expect("events can be serialised")
+                    }));
+            }
+        }
+    }
+
+    let mut events = Vec::new();
+
+    for directory in directories {
+        collect_events(directory, &mut events);
+    }
+
+    // The independent algorithm may report some events more than once
+    events.sort_unstable();
+    events.dedup();
+
+    events
+}
+
+#[test]
+fn gillespie_monolithic_resume() {
+    ResumeTest::new("gillespie_monolithic", "Gillespie()", "Monolithic()")
+        .assert_resume_is_uninterrupted();
+}
+
+#[test]
+fn independent_monolithic_resume() {
+    ResumeTest::new("independent_monolithic", "Independent()", "Monolithic()")
+        .assert_resume_is_uninterrupted();
+}
+
+#[test]
+fn independent_individuals_partitioned_resume() {
+    ResumeTest::new(
+        "independent_individuals_partitioned",
+        "Independent(parallelism_mode: Individuals)",
+        "Threads(threads: 2)",
+    )
+    .assert_resume_is_uninterrupted();
+}
@@ -0,0 +1,135 @@
+//! Integration tests which check that a spatially implicit islands scenario
+//!  with just one island reports exactly the same events as the equivalent
+//!  spatially implicit scenario.
+//!
+//! Only the Gillespie algorithm is compared, as the independent algorithm
+//!  primes its random number generator with the habitat's location indices,
+//!  which the spatially implicit habitat shares between the local and meta
+//!  communities, while the islands habitat keeps them apart.
+
+use std::{
+    fmt::{self, Write},
+    sync::{Arc, Mutex},
+};
+
+use tiny_keccak::{Hasher, Keccak};
+
+use necsim_core::{impl_finalise, impl_report, reporter::Reporter};
+
+use rustcoalescence_api::simulate::simulate_with_reporter;
+
+#[derive(Default)]
+struct ReportedEvents {
+    speciations: usize,
+    dispersals: usize,
+    checksum: Option<String>,
+}
+
+/// Reporter which counts all speciation and dispersal events and hashes them
+///  in the order in which they are reported
+struct EventsReporter {
+    hasher: Keccak,
+    events: Arc<Mutex<ReportedEvents>>,
+}
+
+impl fmt::Debug for EventsReporter {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct(stringify!(EventsReporter))
+            .finish_non_exhaustive()
+    }
+}
+
+impl Reporter for EventsReporter {
+    impl_report!(speciation(&mut self, speciation: Used) {
+        self.hasher.update(b"speciation");
+        self.hasher
+            .update(&bincode::serialize(speciation).expect("events can be serialised"));
+
+        if let Ok(mut events) = self.events.lock() {
+            events.speciations += 1;
+        }
+    });
+
+    impl_report!(dispersal(&mut self, dispersal: Used) {
+        self.hasher.update(b"dispersal");
+        self.hasher
+            .update(&bincode::serialize(dispersal).expect("events can be serialised"));
+
+        if let Ok(mut events) = self.events.lock() {
+            events.dispersals += 1;
+        }
+    });
+
+    impl_report!(progress(&mut self, _progress: Ignored) {});
+
+    impl_finalise!((self) {
+        let mut digest = [0_u8; 32];
+        self.hasher.finalize(&mut digest);
+
+        let checksum = digest.iter().fold(String::new(), |mut checksum, byte| {
+            let _ = write!(checksum, "{byte:02x}");
+            checksum
+        });
+
+        if let Ok(mut events) = self.events.lock() {
+            events.checksum = Some(checksum);
+        }
+    });
+}
+
+fn simulate_events(algorithm: &str, scenario: &str) -> ReportedEvents {
+    let config = format!(
+        "(
+            speciation: 0.01,
+            sample: Sample(percentage: 1.0),
+            rng: Seed(42),
+            maths: Reproducible,
+            algorithm: {algorithm},
+            scenario: {scenario},
+            reporters: [],
+        )"
+    );
+
+    let events = Arc::new(Mutex::new(ReportedEvents::default()));
+
+    simulate_with_reporter(
+        &config,
+        EventsReporter {
+            hasher: Keccak::v256(),
+            events: events.clone(),
+        },
+    )
+    .expect("the simulation succeeds");
+
+    let mut events = events.lock().expect("the reporter did not panic");
+
+    std::mem::take(&mut *events)
+}
+
+#[test]
+fn gillespie_one_island() {
+    let spatially_implicit = simulate_events(
+        "Gillespie()",
+        "SpatiallyImplicit(
+            local_area: (5, 5), local_deme: 10, meta_area: (20, 20), meta_deme: 10,
+            migration: 0.1,
+        )",
+    );
+    let one_island = simulate_events(
+        "Gillespie()",
+        "SpatiallyImplicitIslands(
+            islands: [Island(area: (5, 5), deme: 10, migration: 0.1)],
+            meta_area: (20, 20), meta_deme: 10,
+        )",
+    );
+
+    assert_eq!(one_island.speciations, spatially_implicit.speciations);
+    assert_eq!(one_island.dispersals, spatially_implicit.dispersals);
+    assert!(spatially_implicit.speciations > 0);
+
+    assert!(spatially_implicit.checksum.is_some());
+    assert_eq!(
+        one_island.checksum, spatially_implicit.checksum,
+        "Gillespie reported different events for one island"
+    );
+}
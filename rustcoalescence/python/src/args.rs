@@ -0,0 +1,326 @@
+use pyo3::{
+    exceptions::{PyTypeError, PyValueError},
+    prelude::*,
+    types::{PyBool, PyDict, PyFloat, PyList, PyLong, PyString, PyTuple},
+};
+
+use necsim_core_bond::OpenClosedUnitF64 as PositiveUnitF64;
+
+/// Configuration of a simulation, which mirrors the config of the
+///  `rustcoalescence simulate` subcommand
+///
+/// The `speciation` probability, `scenario`, and `algorithm` are required,
+///  while all other fields are optional keyword arguments, e.g.
+///  `SimulateArgs(0.001, Variant("NonSpatial", ...), Variant("Gillespie",
+///  ...), rng=Variant("Seed", 42))`.
+/// Since in-process simulations report to Python instead of to reporter
+///  plugins, there is no `reporters` field.
+#[pyclass(module = "rustcoalescence")]
+#[allow(clippy::module_name_repetitions)]
+pub struct SimulateArgs {
+    speciation: PositiveUnitF64,
+    #[pyo3(get, set)]
+    scenario: Py<Variant>,
+    #[pyo3(get, set)]
+    algorithm: Py<Variant>,
+    #[pyo3(get, set)]
+    sample: Option<PyObject>,
+    #[pyo3(get, set)]
+    rng: Option<PyObject>,
+    #[pyo3(get, set)]
+    maths: Option<PyObject>,
+    #[pyo3(get, set)]
+    protracted: Option<PyObject>,
+    #[pyo3(get, set)]
+    pause: Option<PyObject>,
+    #[pyo3(get, set)]
+    checkpoint: Option<PyObject>,
+    #[pyo3(get, set)]
+    partitioning: Option<PyObject>,
+    #[pyo3(get, set)]
+    log: Option<PyObject>,
+}
+
+#[pymethods]
+impl SimulateArgs {
+    #[new]
+    #[pyo3(signature = (
+        speciation, scenario, algorithm, *, sample = None, rng = None, maths = None,
+        protracted = None, pause = None, checkpoint = None, partitioning = None, log = None,
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        speciation: f64,
+        scenario: Py<Variant>,
+        algorithm: Py<Variant>,
+        sample: Option<PyObject>,
+        rng: Option<PyObject>,
+        maths: Option<PyObject>,
+        protracted: Option<PyObject>,
+        pause: Option<PyObject>,
+        checkpoint: Option<PyObject>,
+        partitioning: Option<PyObject>,
+        log: Option<PyObject>,
+    ) -> PyResult<Self> {
+        Ok(Self {
+            speciation: speciation_probability(speciation)?,
+            scenario,
+            algorithm,
+            sample,
+            rng,
+            maths,
+            protracted,
+            pause,
+            checkpoint,
+            partitioning,
+            log,
+        })
+    }
+
+    /// Speciation probability per generation, which must be in (0.0, 1.0]
+    #[getter]
+    fn speciation(&self) -> f64 {
+        self.speciation.get()
+    }
+
+    #[setter]
+    fn set_speciation(&mut self, speciation: f64) -> PyResult<()> {
+        self.speciation = speciation_probability(speciation)?;
+
+        Ok(())
+    }
+
+    /// Returns the config in the format of the `rustcoalescence simulate`
+    ///  subcommand
+    fn to_ron(&self, py: Python) -> PyResult<String> {
+        self.to_config(py)
+    }
+
+    fn __repr__(&self, py: Python) -> PyResult<String> {
+        let mut fields = vec![
+            format!("{:?}", self.speciation.get()),
+            self.scenario.bind(py).repr()?.to_string(),
+            self.algorithm.bind(py).repr()?.to_string(),
+        ];
+
+        for (name, value) in self.optional_fields() {
+            if let Some(value) = value {
+                fields.push(format!("{name}={}", value.bind(py).repr()?));
+            }
+        }
+
+        Ok(format!("SimulateArgs({})", fields.join(", ")))
+    }
+}
+
+impl SimulateArgs {
+    /// # Errors
+    ///
+    /// Returns a `PyErr` iff any field cannot be converted into a config
+    ///  value.
+    pub fn to_config(&self, py: Python) -> PyResult<String> {
+        let mut fields = vec![
+            format!("speciation: {:?}", self.speciation.get()),
+            format!("scenario: {}", to_ron(self.scenario.bind(py).as_any())?),
+            format!("algorithm: {}", to_ron(self.algorithm.bind(py).as_any())?),
+        ];
+
+        for (name, value) in self.optional_fields() {
+            if let Some(value) = value {
+                fields.push(format!("{name}: {}", to_ron(value.bind(py))?));
+            }
+        }
+
+        fields.push(String::from("reporters: []"));
+
+        Ok(format!("Simulate({})", fields.join(", ")))
+    }
+
+    fn optional_fields(&self) -> [(&'static str, Option<&PyObject>); 8] {
+        [
+            ("sample", self.sample.as_ref()),
+            ("rng", self.rng.as_ref()),
+            ("maths", self.maths.as_ref()),
+            ("protracted", self.protracted.as_ref()),
+            ("pause", self.pause.as_ref()),
+            ("checkpoint", self.checkpoint.as_ref()),
+            ("partitioning", self.partitioning.as_ref()),
+            ("log", self.log.as_ref()),
+        ]
+    }
+}
+
+/// Named config enum variant or struct, e.g. `Variant("Habitat")`,
+///  `Variant("Sponge", "...")`, or `Variant("NonSpatial", area=(100, 100),
+///  deme=10)`
+#[pyclass(module = "rustcoalescence")]
+pub struct Variant {
+    /// Name of the variant or struct
+    #[pyo3(get)]
+    name: String,
+    /// Positional fields of a tuple variant
+    #[pyo3(get)]
+    args: Py<PyTuple>,
+    /// Named fields of a struct variant
+    #[pyo3(get)]
+    kwargs: Py<PyDict>,
+}
+
+#[pymethods]
+impl Variant {
+    #[new]
+    #[pyo3(signature = (name, *args, **kwargs))]
+    fn new(
+        name: &Bound<PyAny>,
+        args: &Bound<PyTuple>,
+        kwargs: Option<&Bound<PyDict>>,
+    ) -> PyResult<Self> {
+        let name = identifier(name)?;
+
+        let kwargs = match kwargs {
+            Some(kwargs) if !args.is_empty() && !kwargs.is_empty() => {
+                return Err(PyTypeError::new_err(
+                    "a Variant takes either positional or keyword arguments, not both",
+                ))
+            },
+            Some(kwargs) => {
+                // Validate the field names early
+                for key in kwargs.keys() {
+                    identifier(&key)?;
+                }
+
+                kwargs.copy()?
+            },
+            None => PyDict::new_bound(args.py()),
+        };
+
+        Ok(Self {
+            name,
+            args: args.clone().unbind(),
+            kwargs: kwargs.unbind(),
+        })
+    }
+
+    fn __repr__(&self, py: Python) -> PyResult<String> {
+        let mut fields = vec![format!("{:?}", self.name)];
+
+        for arg in self.args.bind(py) {
+            fields.push(arg.repr()?.to_string());
+        }
+
+        for (key, value) in self.kwargs.bind(py) {
+            fields.push(format!("{key}={}", value.repr()?));
+        }
+
+        Ok(format!("Variant({})", fields.join(", ")))
+    }
+}
+
+impl Variant {
+    fn to_ron(&self, py: Python) -> PyResult<String> {
+        let args = self.args.bind(py);
+        let kwargs = self.kwargs.bind(py);
+
+        Ok(if !kwargs.is_empty() {
+            format!("{}{}", self.name, dict_to_ron(kwargs)?)
+        } else if !args.is_empty() {
+            format!("{}({})", self.name, sequence_to_ron(args.iter())?)
+        } else {
+            self.name.clone()
+        })
+    }
+}
+
+fn speciation_probability(speciation: f64) -> PyResult<PositiveUnitF64> {
+    PositiveUnitF64::new(speciation).map_err(|err| PyValueError::new_err(err.to_string()))
+}
+
+fn to_ron(value: &Bound<PyAny>) -> PyResult<String> {
+    if value.is_none() {
+        return Ok(String::from("None"));
+    }
+
+    if let Ok(variant) = value.downcast::<Variant>() {
+        return variant.borrow().to_ron(value.py());
+    }
+
+    if let Ok(boolean) = value.downcast::<PyBool>() {
+        return Ok(boolean.is_true().to_string());
+    }
+
+    if value.is_instance_of::<PyLong>() {
+        return Ok(value.extract::<i128>()?.to_string());
+    }
+
+    if value.is_instance_of::<PyFloat>() {
+        return Ok(format!("{:?}", value.extract::<f64>()?));
+    }
+
+    if let Ok(string) = value.downcast::<PyString>() {
+        return string_to_ron(&string.to_cow()?);
+    }
+
+    if let Ok(tuple) = value.downcast::<PyTuple>() {
+        return Ok(if tuple.len() == 1 {
+            format!("({},)", to_ron(&tuple.get_item(0)?)?)
+        } else {
+            format!("({})", sequence_to_ron(tuple.iter())?)
+        });
+    }
+
+    if let Ok(list) = value.downcast::<PyList>() {
+        return Ok(format!("[{}]", sequence_to_ron(list.iter())?));
+    }
+
+    if let Ok(dict) = value.downcast::<PyDict>() {
+        return dict_to_ron(dict);
+    }
+
+    // Support `pathlib.Path` and other path-like objects
+    if value.hasattr("__fspath__")? {
+        return string_to_ron(&value.call_method0("__fspath__")?.extract::<String>()?);
+    }
+
+    Err(PyTypeError::new_err(format!(
+        "cannot convert {} into a simulation config value",
+        value.get_type().name()?
+    )))
+}
+
+fn sequence_to_ron<'py>(values: impl Iterator<Item = Bound<'py, PyAny>>) -> PyResult<String> {
+    values
+        .map(|value| to_ron(&value))
+        .collect::<PyResult<Vec<_>>>()
+        .map(|values| values.join(", "))
+}
+
+fn dict_to_ron(dict: &Bound<PyDict>) -> PyResult<String> {
+    let fields = dict
+        .iter()
+        .map(|(key, value)| Ok(format!("{}: {}", identifier(&key)?, to_ron(&value)?)))
+        .collect::<PyResult<Vec<_>>>()?;
+
+    Ok(format!("({})", fields.join(", ")))
+}
+
+fn string_to_ron(string: &str) -> PyResult<String> {
+    ron::to_string(string).map_err(|err| PyValueError::new_err(err.to_string()))
+}
+
+fn identifier(name: &Bound<PyAny>) -> PyResult<String> {
+    let name: String = name.extract()?;
+
+    let mut chars = name.chars();
+
+    if chars
+        .next()
+        .map_or(false, |c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+    {
+        Ok(name)
+    } else {
+        Err(PyValueError::new_err(format!(
+            "{name:?} is not a valid config identifier"
+        )))
+    }
+}
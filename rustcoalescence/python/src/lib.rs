@@ -0,0 +1,48 @@
+#![deny(clippy::pedantic)]
+
+use pyo3::{exceptions::PyRuntimeError, prelude::*};
+
+mod args;
+mod reporter;
+mod result;
+
+use args::{SimulateArgs, Variant};
+use reporter::InProcessReporter;
+use result::SimulationResult;
+
+/// Performs the simulation configured by `args` in-process
+///
+/// If `events` is set, all speciation and dispersal events are collected.
+/// The per-species abundances of the sampled individuals are always
+///  collected.
+#[pyfunction]
+#[pyo3(signature = (args, *, events = false))]
+fn simulate(py: Python, args: &SimulateArgs, events: bool) -> PyResult<SimulationResult> {
+    let config = args.to_config(py)?;
+
+    let (outcome, collected) = py
+        .allow_threads(|| {
+            let (reporter, collected) = InProcessReporter::new(events);
+
+            rustcoalescence_api::simulate::simulate_with_reporter(&config, reporter)
+                .map(|outcome| (outcome, collected.take()))
+        })
+        .map_err(|err| {
+            PyRuntimeError::new_err(format!(
+                "Failed to initialise or perform the simulation:\n{err:?}"
+            ))
+        })?;
+
+    SimulationResult::new(py, &outcome, collected)
+}
+
+#[pymodule]
+#[pyo3(name = "rustcoalescence")]
+fn rustcoalescence_python(m: &Bound<PyModule>) -> PyResult<()> {
+    m.add_class::<SimulateArgs>()?;
+    m.add_class::<Variant>()?;
+    m.add_class::<SimulationResult>()?;
+    m.add_function(wrap_pyfunction!(simulate, m)?)?;
+
+    Ok(())
+}
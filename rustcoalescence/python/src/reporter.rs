@@ -0,0 +1,200 @@
+use std::{
+    fmt,
+    sync::{Arc, Mutex},
+};
+
+use necsim_core::{
+    event::{DispersalEvent, SpeciationEvent},
+    impl_finalise, impl_report,
+    lineage::LineageInteraction,
+    reporter::Reporter,
+};
+
+use necsim_plugins_species_core::resolver::LocationSpeciesResolver;
+
+/// Reporter that collects the events of an in-process simulation, which are
+///  handed over to Python once the reporter has been finalised
+#[allow(clippy::module_name_repetitions)]
+pub struct InProcessReporter {
+    collected: Collected,
+    shared: SharedCollected,
+
+    resolver: LocationSpeciesResolver,
+}
+
+#[derive(Default)]
+pub struct Collected {
+    pub speciation: Option<SpeciationColumns>,
+    pub dispersal: Option<DispersalColumns>,
+    pub abundances: AbundanceColumns,
+}
+
+#[derive(Default)]
+pub struct SpeciationColumns {
+    pub lineage: Vec<u64>,
+    pub prior_time: Vec<f64>,
+    pub event_time: Vec<f64>,
+    pub x: Vec<u32>,
+    pub y: Vec<u32>,
+    pub index: Vec<u32>,
+}
+
+#[derive(Default)]
+pub struct DispersalColumns {
+    pub lineage: Vec<u64>,
+    pub prior_time: Vec<f64>,
+    pub event_time: Vec<f64>,
+    pub origin_x: Vec<u32>,
+    pub origin_y: Vec<u32>,
+    pub origin_index: Vec<u32>,
+    pub target_x: Vec<u32>,
+    pub target_y: Vec<u32>,
+    pub target_index: Vec<u32>,
+    /// 0 = no coalescence, 1 = maybe coalescence, 2 = coalescence
+    pub interaction: Vec<u8>,
+    /// parent lineage of a coalescence, 0 otherwise
+    pub parent: Vec<u64>,
+}
+
+#[derive(Default)]
+pub struct AbundanceColumns {
+    pub x: Vec<u32>,
+    pub y: Vec<u32>,
+    pub index: Vec<u32>,
+    pub time: Vec<f64>,
+    pub abundance: Vec<u64>,
+}
+
+#[derive(Clone, Default)]
+pub struct SharedCollected(Arc<Mutex<Option<Collected>>>);
+
+impl SharedCollected {
+    /// Takes the collected events, which are only available once the
+    ///  simulation has finalised its reporter
+    #[must_use]
+    pub fn take(&self) -> Collected {
+        self.0
+            .lock()
+            .map(|mut collected| collected.take())
+            .ok()
+            .flatten()
+            .unwrap_or_default()
+    }
+}
+
+impl fmt::Debug for InProcessReporter {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct(stringify!(InProcessReporter))
+            .field("events", &self.collected.speciation.is_some())
+            .finish_non_exhaustive()
+    }
+}
+
+impl InProcessReporter {
+    #[must_use]
+    pub fn new(events: bool) -> (Self, SharedCollected) {
+        let shared = SharedCollected::default();
+
+        let reporter = Self {
+            collected: Collected {
+                speciation: events.then(SpeciationColumns::default),
+                dispersal: events.then(DispersalColumns::default),
+                abundances: AbundanceColumns::default(),
+            },
+            shared: shared.clone(),
+
+            resolver: LocationSpeciesResolver::default(),
+        };
+
+        (reporter, shared)
+    }
+
+    fn store_speciation_event(&mut self, speciation: &SpeciationEvent) {
+        if let Some(columns) = &mut self.collected.speciation {
+            columns
+                .lineage
+                .push(unsafe { speciation.global_lineage_reference.clone().into_inner() });
+            columns.prior_time.push(speciation.prior_time.get());
+            columns.event_time.push(speciation.event_time.get());
+            columns.x.push(speciation.origin.location().x());
+            columns.y.push(speciation.origin.location().y());
+            columns.index.push(speciation.origin.index());
+        }
+    }
+
+    fn store_dispersal_event(&mut self, dispersal: &DispersalEvent) {
+        if let Some(columns) = &mut self.collected.dispersal {
+            columns
+                .lineage
+                .push(unsafe { dispersal.global_lineage_reference.clone().into_inner() });
+            columns.prior_time.push(dispersal.prior_time.get());
+            columns.event_time.push(dispersal.event_time.get());
+            columns.origin_x.push(dispersal.origin.location().x());
+            columns.origin_y.push(dispersal.origin.location().y());
+            columns.origin_index.push(dispersal.origin.index());
+            columns.target_x.push(dispersal.target.location().x());
+            columns.target_y.push(dispersal.target.location().y());
+            columns.target_index.push(dispersal.target.index());
+
+            let (interaction, parent) = match &dispersal.interaction {
+                LineageInteraction::None => (0, 0),
+                LineageInteraction::Maybe => (1, 0),
+                LineageInteraction::Coalescence(parent) => {
+                    (2, unsafe { parent.clone().into_inner() })
+                },
+            };
+
+            columns.interaction.push(interaction);
+            columns.parent.push(parent);
+        }
+    }
+
+    fn compute_abundances(&mut self) {
+        let species = std::mem::take(&mut self.resolver).resolve();
+
+        let mut abundances = vec![0_u64; species.identities.len()];
+
+        for (_location, index) in species.records {
+            abundances[index] += 1;
+        }
+
+        let columns = &mut self.collected.abundances;
+
+        for (identity, abundance) in species.identities.into_iter().zip(abundances) {
+            // Lineages which have not yet speciated have no species origin
+            let Ok((origin, time)) = identity.try_into_speciation() else {
+                continue;
+            };
+
+            columns.x.push(origin.location().x());
+            columns.y.push(origin.location().y());
+            columns.index.push(origin.index());
+            columns.time.push(time.get());
+            columns.abundance.push(abundance);
+        }
+    }
+}
+
+impl Reporter for InProcessReporter {
+    impl_report!(speciation(&mut self, speciation: Used) {
+        self.store_speciation_event(speciation);
+
+        self.resolver.report_speciation(speciation);
+    });
+
+    impl_report!(dispersal(&mut self, dispersal: Used) {
+        self.store_dispersal_event(dispersal);
+
+        self.resolver.report_dispersal(dispersal);
+    });
+
+    impl_report!(progress(&mut self, _progress: Ignored) {});
+
+    impl_finalise!((mut self) {
+        self.compute_abundances();
+
+        if let Ok(mut shared) = self.shared.0.lock() {
+            *shared = Some(std::mem::take(&mut self.collected));
+        }
+    });
+}
@@ -0,0 +1,144 @@
+use numpy::IntoPyArray;
+use pyo3::{prelude::*, types::PyDict};
+
+use rustcoalescence_api::simulate::SimulationOutcome;
+
+use crate::reporter::{AbundanceColumns, Collected, DispersalColumns, SpeciationColumns};
+
+/// Result of an in-process simulation
+///
+/// The collected events and species abundances are returned as dictionaries
+///  of equally long NumPy column arrays.
+#[pyclass(module = "rustcoalescence")]
+#[allow(clippy::module_name_repetitions)]
+pub struct SimulationResult {
+    time: f64,
+    steps: u64,
+    paused: bool,
+    speciation: Option<Py<PyDict>>,
+    dispersal: Option<Py<PyDict>>,
+    abundances: Py<PyDict>,
+}
+
+impl SimulationResult {
+    /// # Errors
+    ///
+    /// Returns a `PyErr` iff converting the collected columns into `NumPy`
+    ///  arrays failed.
+    pub fn new(py: Python, outcome: &SimulationOutcome, collected: Collected) -> PyResult<Self> {
+        let (time, steps, paused) = match outcome {
+            SimulationOutcome::Done { time, steps } => (time.get(), *steps, false),
+            SimulationOutcome::Paused { time, steps, .. } => (time.get(), *steps, true),
+        };
+
+        Ok(Self {
+            time,
+            steps,
+            paused,
+            speciation: collected
+                .speciation
+                .map(|columns| speciation_to_dict(py, columns))
+                .transpose()?,
+            dispersal: collected
+                .dispersal
+                .map(|columns| dispersal_to_dict(py, columns))
+                .transpose()?,
+            abundances: abundances_to_dict(py, collected.abundances)?,
+        })
+    }
+}
+
+#[pymethods]
+impl SimulationResult {
+    /// Simulation time at which the simulation finished or paused
+    #[getter]
+    fn time(&self) -> f64 {
+        self.time
+    }
+
+    /// Number of simulation steps that were performed
+    #[getter]
+    fn steps(&self) -> u64 {
+        self.steps
+    }
+
+    /// Whether the simulation paused before completion
+    #[getter]
+    fn paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Speciation event columns, or `None` if events were not collected
+    #[getter]
+    fn speciation(&self, py: Python) -> Option<Py<PyDict>> {
+        self.speciation.as_ref().map(|dict| dict.clone_ref(py))
+    }
+
+    /// Dispersal event columns, or `None` if events were not collected
+    ///
+    /// The `interaction` column is 0 for no coalescence, 1 for a possible
+    ///  coalescence, and 2 for a coalescence with the `parent` lineage.
+    #[getter]
+    fn dispersal(&self, py: Python) -> Option<Py<PyDict>> {
+        self.dispersal.as_ref().map(|dict| dict.clone_ref(py))
+    }
+
+    /// Per-species abundances of the sampled individuals, where each species
+    ///  is identified by the location and time of its speciation event
+    #[getter]
+    fn abundances(&self, py: Python) -> Py<PyDict> {
+        self.abundances.clone_ref(py)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "SimulationResult(time={}, steps={}, paused={})",
+            self.time,
+            self.steps,
+            if self.paused { "True" } else { "False" }
+        )
+    }
+}
+
+fn speciation_to_dict(py: Python, columns: SpeciationColumns) -> PyResult<Py<PyDict>> {
+    let dict = PyDict::new_bound(py);
+
+    dict.set_item("lineage", columns.lineage.into_pyarray_bound(py))?;
+    dict.set_item("prior_time", columns.prior_time.into_pyarray_bound(py))?;
+    dict.set_item("event_time", columns.event_time.into_pyarray_bound(py))?;
+    dict.set_item("x", columns.x.into_pyarray_bound(py))?;
+    dict.set_item("y", columns.y.into_pyarray_bound(py))?;
+    dict.set_item("index", columns.index.into_pyarray_bound(py))?;
+
+    Ok(dict.unbind())
+}
+
+fn dispersal_to_dict(py: Python, columns: DispersalColumns) -> PyResult<Py<PyDict>> {
+    let dict = PyDict::new_bound(py);
+
+    dict.set_item("lineage", columns.lineage.into_pyarray_bound(py))?;
+    dict.set_item("prior_time", columns.prior_time.into_pyarray_bound(py))?;
+    dict.set_item("event_time", columns.event_time.into_pyarray_bound(py))?;
+    dict.set_item("origin_x", columns.origin_x.into_pyarray_bound(py))?;
+    dict.set_item("origin_y", columns.origin_y.into_pyarray_bound(py))?;
+    dict.set_item("origin_index", columns.origin_index.into_pyarray_bound(py))?;
+    dict.set_item("target_x", columns.target_x.into_pyarray_bound(py))?;
+    dict.set_item("target_y", columns.target_y.into_pyarray_bound(py))?;
+    dict.set_item("target_index", columns.target_index.into_pyarray_bound(py))?;
+    dict.set_item("interaction", columns.interaction.into_pyarray_bound(py))?;
+    dict.set_item("parent", columns.parent.into_pyarray_bound(py))?;
+
+    Ok(dict.unbind())
+}
+
+fn abundances_to_dict(py: Python, columns: AbundanceColumns) -> PyResult<Py<PyDict>> {
+    let dict = PyDict::new_bound(py);
+
+    dict.set_item("x", columns.x.into_pyarray_bound(py))?;
+    dict.set_item("y", columns.y.into_pyarray_bound(py))?;
+    dict.set_item("index", columns.index.into_pyarray_bound(py))?;
+    dict.set_item("time", columns.time.into_pyarray_bound(py))?;
+    dict.set_item("abundance", columns.abundance.into_pyarray_bound(py))?;
+
+    Ok(dict.unbind())
+}
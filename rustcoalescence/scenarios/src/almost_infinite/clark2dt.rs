@@ -9,6 +9,7 @@ use necsim_partitioning_core::partition::Partition;
 use necsim_impls_no_std::{
     cogs::{
         dispersal_sampler::almost_infinite_clark2dt::AlmostInfiniteClark2DtDispersalSampler,
+        event_sampler::protracted::ProtractedSpeciation,
         habitat::almost_infinite::AlmostInfiniteHabitat,
         lineage_store::coherent::globally::singleton_demes::SingletonDemesLineageStore,
         origin_sampler::{
@@ -74,6 +75,7 @@ impl<M: MathsCore, G: RngCore<M>> Scenario<M, G> for AlmostInfiniteClark2DtDispe
             speciation_probability,
             origin_sampler_auxiliary: (args.sample,),
             decomposition_auxiliary: (),
+            protracted_speciation: ProtractedSpeciation::instantaneous(),
             _marker: PhantomData::<(M, G, Self)>,
         })
     }
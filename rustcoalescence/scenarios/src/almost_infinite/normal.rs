@@ -9,6 +9,7 @@ use necsim_partitioning_core::partition::Partition;
 use necsim_impls_no_std::{
     cogs::{
         dispersal_sampler::almost_infinite_normal::AlmostInfiniteNormalDispersalSampler,
+        event_sampler::protracted::ProtractedSpeciation,
         habitat::almost_infinite::AlmostInfiniteHabitat,
         lineage_store::coherent::globally::singleton_demes::SingletonDemesLineageStore,
         origin_sampler::{
@@ -69,6 +70,7 @@ impl<M: MathsCore, G: RngCore<M>> Scenario<M, G> for AlmostInfiniteNormalDispers
             speciation_probability,
             origin_sampler_auxiliary: (args.sample,),
             decomposition_auxiliary: (),
+            protracted_speciation: ProtractedSpeciation::instantaneous(),
             _marker: PhantomData::<(M, G, Self)>,
         })
     }
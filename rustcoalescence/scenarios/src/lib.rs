@@ -15,7 +15,10 @@ use necsim_core_bond::OpenClosedUnitF64 as PositiveUnitF64;
 use necsim_partitioning_core::partition::Partition;
 
 use necsim_impls_no_std::{
-    cogs::origin_sampler::{pre_sampler::OriginPreSampler, TrustedOriginSampler},
+    cogs::{
+        event_sampler::protracted::ProtractedSpeciation,
+        origin_sampler::{pre_sampler::OriginPreSampler, TrustedOriginSampler},
+    },
     decomposition::Decomposition,
 };
 
@@ -28,14 +31,20 @@ pub mod almost_infinite;
 pub mod non_spatial;
 #[cfg(any(
     feature = "spatially-explicit-uniform-turnover",
-    feature = "spatially-explicit-turnover-map"
+    feature = "spatially-explicit-turnover-map",
+    feature = "spatially-explicit-fine-coarse",
+    feature = "spatially-explicit-kernel",
 ))]
 pub mod spatially_explicit;
 #[cfg(feature = "spatially-implicit")]
 pub mod spatially_implicit;
+#[cfg(feature = "spatially-implicit-islands")]
+pub mod spatially_implicit_islands;
 #[cfg(feature = "wrapping-noise")]
 pub mod wrapping_noise;
 
+pub mod tiff;
+
 pub trait ScenarioParameters {
     type Arguments;
     type Error;
@@ -91,6 +100,7 @@ pub struct ScenarioCogs<M: MathsCore, G: RngCore<M>, O: Scenario<M, G>> {
     pub speciation_probability: O::SpeciationProbability,
     pub origin_sampler_auxiliary: O::OriginSamplerAuxiliary,
     pub decomposition_auxiliary: O::DecompositionAuxiliary,
+    pub protracted_speciation: ProtractedSpeciation,
     _marker: PhantomData<(M, G, O)>,
 }
 
@@ -103,6 +113,7 @@ impl<M: MathsCore, G: RngCore<M>, O: Scenario<M, G>> Clone for ScenarioCogs<M, G
             speciation_probability: self.speciation_probability.clone(),
             origin_sampler_auxiliary: self.origin_sampler_auxiliary.clone(),
             decomposition_auxiliary: self.decomposition_auxiliary.clone(),
+            protracted_speciation: self.protracted_speciation,
             _marker: PhantomData::<(M, G, O)>,
         }
     }
@@ -9,6 +9,7 @@ use necsim_partitioning_core::partition::Partition;
 use necsim_impls_no_std::{
     cogs::{
         dispersal_sampler::non_spatial::NonSpatialDispersalSampler,
+        event_sampler::protracted::ProtractedSpeciation,
         habitat::non_spatial::NonSpatialHabitat,
         origin_sampler::{non_spatial::NonSpatialOriginSampler, pre_sampler::OriginPreSampler},
         speciation_probability::uniform::UniformSpeciationProbability,
@@ -64,6 +65,7 @@ impl<M: MathsCore, G: RngCore<M>> Scenario<M, G> for NonSpatialScenario {
             speciation_probability,
             origin_sampler_auxiliary: (),
             decomposition_auxiliary: (),
+            protracted_speciation: ProtractedSpeciation::instantaneous(),
             _marker: PhantomData::<(M, G, Self)>,
         })
     }
@@ -0,0 +1,279 @@
+#![allow(non_local_definitions)] // FIXME: displaydoc
+
+use std::{marker::PhantomData, num::NonZeroU32, path::PathBuf, sync::Arc};
+
+use serde::{Deserialize, Serialize};
+
+use necsim_core::{
+    cogs::{Habitat, LineageStore, MathsCore, RngCore},
+    landscape::Location,
+};
+use necsim_core_bond::{NonNegativeF64, OpenClosedUnitF64 as PositiveUnitF64};
+use necsim_partitioning_core::partition::Partition;
+
+use necsim_impls_no_std::{
+    array2d::Array2D,
+    cogs::{
+        dispersal_sampler::fine_coarse::FineCoarseApproximateNormalDispersalSampler,
+        event_sampler::protracted::ProtractedSpeciation,
+        habitat::fine_coarse::{FineCoarseHabitat, FineCoarseHabitatError},
+        origin_sampler::{fine_coarse::FineCoarseOriginSampler, pre_sampler::OriginPreSampler},
+        speciation_probability::uniform::UniformSpeciationProbability,
+        turnover_rate::uniform::UniformTurnoverRate,
+    },
+    decomposition::equal::EqualDecomposition,
+};
+
+use crate::{Scenario, ScenarioCogs, ScenarioParameters};
+
+use super::maps::{self, MapGrid, MapLoadingMode};
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(thiserror::Error, displaydoc::Display, Debug)]
+pub enum SpatiallyExplicitFineCoarseScenarioError {
+    /// invalid fine and coarse habitat maps: {0}
+    Habitat(FineCoarseHabitatError),
+    /** the sample mask with dimensions {mask:?} [(cols, rows)] does not match
+    the fine map with dimensions {fine:?} [(cols, rows)] */
+    SampleMaskDimensions {
+        mask: (usize, usize),
+        fine: (usize, usize),
+    },
+}
+
+#[allow(clippy::module_name_repetitions, clippy::empty_enum)]
+pub enum SpatiallyExplicitFineCoarseScenario {}
+
+impl ScenarioParameters for SpatiallyExplicitFineCoarseScenario {
+    type Arguments = SpatiallyExplicitFineCoarseArguments;
+    type Error = SpatiallyExplicitFineCoarseScenarioError;
+}
+
+impl<M: MathsCore, G: RngCore<M>> Scenario<M, G> for SpatiallyExplicitFineCoarseScenario {
+    type Decomposition = EqualDecomposition<M, Self::Habitat>;
+    type DecompositionAuxiliary = ();
+    type DispersalSampler = FineCoarseApproximateNormalDispersalSampler<M, G>;
+    type Habitat = FineCoarseHabitat<M>;
+    type LineageStore<L: LineageStore<M, Self::Habitat>> = L;
+    type OriginSampler<'h, I: Iterator<Item = u64>> = FineCoarseOriginSampler<'h, M, I> where G: 'h;
+    type OriginSamplerAuxiliary = (Option<Arc<[bool]>>,);
+    type SpeciationProbability = UniformSpeciationProbability;
+    type TurnoverRate = UniformTurnoverRate;
+
+    fn new(
+        args: Self::Arguments,
+        speciation_probability_per_generation: PositiveUnitF64,
+    ) -> Result<ScenarioCogs<M, G, Self>, Self::Error> {
+        if let Some(sample_mask) = &args.sample_mask {
+            check_sample_mask_dimensions(sample_mask, &args.fine_map)?;
+        }
+
+        let habitat =
+            FineCoarseHabitat::try_new(args.fine_map, args.coarse_map, args.scale, args.offset)
+                .map_err(SpatiallyExplicitFineCoarseScenarioError::Habitat)?;
+        let dispersal_sampler = FineCoarseApproximateNormalDispersalSampler::new(args.sigma);
+        let turnover_rate = UniformTurnoverRate::default();
+        let speciation_probability =
+            UniformSpeciationProbability::new(speciation_probability_per_generation.into());
+
+        let sample_mask = args
+            .sample_mask
+            .map(|sample_mask| Arc::from(sample_mask.into_row_major().into_boxed_slice()));
+
+        Ok(ScenarioCogs {
+            habitat,
+            dispersal_sampler,
+            turnover_rate,
+            speciation_probability,
+            origin_sampler_auxiliary: (sample_mask,),
+            decomposition_auxiliary: (),
+            protracted_speciation: ProtractedSpeciation::instantaneous(),
+            _marker: PhantomData::<(M, G, Self)>,
+        })
+    }
+
+    fn sample_habitat<'h, I: Iterator<Item = u64>>(
+        habitat: &'h Self::Habitat,
+        pre_sampler: OriginPreSampler<M, I>,
+        (sample_mask,): Self::OriginSamplerAuxiliary,
+    ) -> Self::OriginSampler<'h, I>
+    where
+        G: 'h,
+    {
+        FineCoarseOriginSampler::new(pre_sampler, habitat, sample_mask)
+    }
+
+    fn decompose(
+        habitat: &Self::Habitat,
+        subdomain: Partition,
+        _auxiliary: Self::DecompositionAuxiliary,
+    ) -> Self::Decomposition {
+        match EqualDecomposition::weight(habitat, subdomain) {
+            Ok(decomposition) => decomposition,
+            Err(decomposition) => {
+                warn!(
+                    "Spatially explicit fine and coarse habitat of size {}x{} could not be \
+                     partitioned into {} partition(s).",
+                    habitat.get_extent().width(),
+                    habitat.get_extent().height(),
+                    subdomain.size().get(),
+                );
+
+                decomposition
+            },
+        }
+    }
+}
+
+fn check_sample_mask_dimensions(
+    sample_mask: &Array2D<bool>,
+    fine_map: &Array2D<u32>,
+) -> Result<(), SpatiallyExplicitFineCoarseScenarioError> {
+    let mask = (sample_mask.num_columns(), sample_mask.num_rows());
+    let fine = (fine_map.num_columns(), fine_map.num_rows());
+
+    if mask == fine {
+        Ok(())
+    } else {
+        Err(SpatiallyExplicitFineCoarseScenarioError::SampleMaskDimensions { mask, fine })
+    }
+}
+
+#[derive(Debug)]
+#[allow(clippy::module_name_repetitions)]
+pub struct SpatiallyExplicitFineCoarseArguments {
+    pub fine_path: PathBuf,
+    pub fine_map: Array2D<u32>,
+    pub coarse_path: PathBuf,
+    pub coarse_map: Array2D<u32>,
+    pub scale: NonZeroU32,
+    pub offset: Location,
+    pub sample_mask_path: Option<PathBuf>,
+    pub sample_mask: Option<Array2D<bool>>,
+    pub sigma: NonNegativeF64,
+    pub loading_mode: MapLoadingMode,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[allow(clippy::module_name_repetitions)]
+#[serde(deny_unknown_fields)]
+#[serde(rename = "SpatiallyExplicitFineCoarse")]
+pub struct SpatiallyExplicitFineCoarseConfig {
+    #[serde(rename = "fine", alias = "fine_map")]
+    fine_map: PathBuf,
+
+    #[serde(rename = "coarse", alias = "coarse_map")]
+    coarse_map: PathBuf,
+
+    scale: NonZeroU32,
+
+    offset: Location,
+
+    #[serde(default)]
+    sample_mask: Option<PathBuf>,
+
+    sigma: NonNegativeF64,
+
+    #[serde(default)]
+    #[serde(rename = "mode", alias = "loading_mode")]
+    loading_mode: MapLoadingMode,
+}
+
+impl SpatiallyExplicitFineCoarseConfig {
+    #[allow(clippy::missing_errors_doc)]
+    pub fn try_load(self) -> Result<SpatiallyExplicitFineCoarseArguments, String> {
+        info!("Starting to load the fine map {:?} ...", self.fine_map);
+
+        let mut fine_grid = MapGrid::default();
+
+        let fine_map = maps::load_habitat_map_without_dispersal(
+            &self.fine_map,
+            "fine habitat",
+            self.loading_mode,
+            &mut fine_grid,
+        )
+        .map_err(|err| format!("{err:?}"))?;
+
+        info!(
+            "Successfully loaded the fine map {:?} with dimensions {}x{} [cols x rows].",
+            &self.fine_map,
+            fine_map.num_columns(),
+            fine_map.num_rows()
+        );
+
+        let sample_mask = match &self.sample_mask {
+            Some(sample_mask_path) => {
+                info!(
+                    "Starting to load the sample mask {:?} ...",
+                    sample_mask_path
+                );
+
+                let sample_mask =
+                    maps::load_sample_mask(sample_mask_path, self.loading_mode, &mut fine_grid)
+                        .map_err(|err| format!("{err:?}"))?;
+
+                check_sample_mask_dimensions(&sample_mask, &fine_map)
+                    .map_err(|err| format!("{err}"))?;
+
+                info!(
+                    "Successfully loaded the sample mask {:?} with dimensions {}x{} [cols x rows].",
+                    sample_mask_path,
+                    sample_mask.num_columns(),
+                    sample_mask.num_rows()
+                );
+
+                Some(sample_mask)
+            },
+            None => None,
+        };
+
+        info!("Starting to load the coarse map {:?} ...", self.coarse_map);
+
+        let mut coarse_grid = MapGrid::default();
+
+        let coarse_map = maps::load_habitat_map_without_dispersal(
+            &self.coarse_map,
+            "coarse habitat",
+            self.loading_mode,
+            &mut coarse_grid,
+        )
+        .map_err(|err| format!("{err:?}"))?;
+
+        info!(
+            "Successfully loaded the coarse map {:?} with dimensions {}x{} [cols x rows].",
+            &self.coarse_map,
+            coarse_map.num_columns(),
+            coarse_map.num_rows()
+        );
+
+        fine_grid
+            .check_nested_in(&coarse_grid, self.scale, &self.offset)
+            .map_err(|err| format!("{err:?}"))?;
+
+        Ok(SpatiallyExplicitFineCoarseArguments {
+            fine_path: self.fine_map,
+            fine_map,
+            coarse_path: self.coarse_map,
+            coarse_map,
+            scale: self.scale,
+            offset: self.offset,
+            sample_mask_path: self.sample_mask,
+            sample_mask,
+            sigma: self.sigma,
+            loading_mode: self.loading_mode,
+        })
+    }
+
+    #[must_use]
+    pub fn from_args(args: &SpatiallyExplicitFineCoarseArguments) -> Self {
+        Self {
+            fine_map: args.fine_path.clone(),
+            coarse_map: args.coarse_path.clone(),
+            scale: args.scale,
+            offset: args.offset.clone(),
+            sample_mask: args.sample_mask_path.clone(),
+            sigma: args.sigma,
+            loading_mode: args.loading_mode,
+        }
+    }
+}
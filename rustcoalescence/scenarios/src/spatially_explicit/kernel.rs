@@ -0,0 +1,237 @@
+#![allow(non_local_definitions)] // FIXME: displaydoc
+
+use std::{marker::PhantomData, path::PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use necsim_core::cogs::{Habitat, LineageStore, MathsCore, RngCore};
+use necsim_core_bond::{NonNegativeF64, OpenClosedUnitF64 as PositiveUnitF64, PositiveF64};
+use necsim_partitioning_core::partition::Partition;
+
+use necsim_impls_no_std::{
+    array2d::Array2D,
+    cogs::{
+        dispersal_sampler::kernel::{
+            DispersalBoundary, DispersalKernel, InMemoryKernelDispersalSampler,
+        },
+        event_sampler::protracted::ProtractedSpeciation,
+        habitat::in_memory::InMemoryHabitat,
+        origin_sampler::{in_memory::InMemoryOriginSampler, pre_sampler::OriginPreSampler},
+        speciation_probability::uniform::UniformSpeciationProbability,
+        turnover_rate::uniform::UniformTurnoverRate,
+    },
+    decomposition::equal::EqualDecomposition,
+};
+
+use crate::{Scenario, ScenarioCogs, ScenarioParameters};
+
+use super::maps::{self, MapGrid, MapLoadingMode};
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(thiserror::Error, displaydoc::Display, Debug)]
+pub enum SpatiallyExplicitKernelScenarioError {
+    /// invalid habitat map: no habitable locations
+    EmptyHabitatMap,
+}
+
+#[allow(clippy::module_name_repetitions, clippy::empty_enum)]
+pub enum SpatiallyExplicitKernelScenario {}
+
+impl ScenarioParameters for SpatiallyExplicitKernelScenario {
+    type Arguments = SpatiallyExplicitKernelArguments;
+    type Error = SpatiallyExplicitKernelScenarioError;
+}
+
+impl<M: MathsCore, G: RngCore<M>> Scenario<M, G> for SpatiallyExplicitKernelScenario {
+    type Decomposition = EqualDecomposition<M, Self::Habitat>;
+    type DecompositionAuxiliary = ();
+    type DispersalSampler = InMemoryKernelDispersalSampler<M, G>;
+    type Habitat = InMemoryHabitat<M>;
+    type LineageStore<L: LineageStore<M, Self::Habitat>> = L;
+    type OriginSampler<'h, I: Iterator<Item = u64>> = InMemoryOriginSampler<'h, M, I> where G: 'h;
+    type OriginSamplerAuxiliary = ();
+    type SpeciationProbability = UniformSpeciationProbability;
+    type TurnoverRate = UniformTurnoverRate;
+
+    fn new(
+        args: Self::Arguments,
+        speciation_probability_per_generation: PositiveUnitF64,
+    ) -> Result<ScenarioCogs<M, G, Self>, Self::Error> {
+        let habitat = InMemoryHabitat::try_new(args.habitat_map)
+            .ok_or(SpatiallyExplicitKernelScenarioError::EmptyHabitatMap)?;
+        let dispersal_sampler =
+            InMemoryKernelDispersalSampler::new(args.kernel.into(), args.boundary.into(), &habitat);
+        let turnover_rate = UniformTurnoverRate::default();
+        let speciation_probability =
+            UniformSpeciationProbability::new(speciation_probability_per_generation.into());
+
+        Ok(ScenarioCogs {
+            habitat,
+            dispersal_sampler,
+            turnover_rate,
+            speciation_probability,
+            origin_sampler_auxiliary: (),
+            decomposition_auxiliary: (),
+            protracted_speciation: ProtractedSpeciation::instantaneous(),
+            _marker: PhantomData::<(M, G, Self)>,
+        })
+    }
+
+    fn sample_habitat<'h, I: Iterator<Item = u64>>(
+        habitat: &'h Self::Habitat,
+        pre_sampler: OriginPreSampler<M, I>,
+        _auxiliary: Self::OriginSamplerAuxiliary,
+    ) -> Self::OriginSampler<'h, I>
+    where
+        G: 'h,
+    {
+        InMemoryOriginSampler::new(pre_sampler, habitat)
+    }
+
+    fn decompose(
+        habitat: &Self::Habitat,
+        subdomain: Partition,
+        _auxiliary: Self::DecompositionAuxiliary,
+    ) -> Self::Decomposition {
+        match EqualDecomposition::weight(habitat, subdomain) {
+            Ok(decomposition) => decomposition,
+            Err(decomposition) => {
+                warn!(
+                    "Spatially explicit habitat of size {}x{} could not be partitioned into {} \
+                     partition(s).",
+                    habitat.get_extent().width(),
+                    habitat.get_extent().height(),
+                    subdomain.size().get(),
+                );
+
+                decomposition
+            },
+        }
+    }
+}
+
+#[derive(Debug)]
+#[allow(clippy::module_name_repetitions)]
+pub struct SpatiallyExplicitKernelArguments {
+    pub habitat_path: PathBuf,
+    pub habitat_map: Array2D<u32>,
+    pub kernel: Kernel,
+    pub boundary: Boundary,
+    pub loading_mode: MapLoadingMode,
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub enum Kernel {
+    #[serde(alias = "Gaussian")]
+    Normal { sigma: NonNegativeF64 },
+    Clark2Dt {
+        #[serde(alias = "u")]
+        shape_u: PositiveF64,
+        #[serde(default = "PositiveF64::one")]
+        #[serde(alias = "p")]
+        tail_p: PositiveF64,
+    },
+    FatTailed {
+        scale: PositiveF64,
+        shape: PositiveF64,
+    },
+}
+
+impl From<Kernel> for DispersalKernel {
+    fn from(kernel: Kernel) -> Self {
+        match kernel {
+            Kernel::Normal { sigma } => Self::Normal { sigma },
+            Kernel::Clark2Dt { shape_u, tail_p } => Self::Clark2Dt { shape_u, tail_p },
+            Kernel::FatTailed { scale, shape } => Self::FatTailed { scale, shape },
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum Boundary {
+    Reflect,
+    #[serde(alias = "Absorb")]
+    AbsorbResample,
+    Wrap,
+}
+
+impl Default for Boundary {
+    fn default() -> Self {
+        Self::AbsorbResample
+    }
+}
+
+impl From<Boundary> for DispersalBoundary {
+    fn from(boundary: Boundary) -> Self {
+        match boundary {
+            Boundary::Reflect => Self::Reflect,
+            Boundary::AbsorbResample => Self::AbsorbResample,
+            Boundary::Wrap => Self::Wrap,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[allow(clippy::module_name_repetitions)]
+#[serde(deny_unknown_fields)]
+#[serde(rename = "SpatiallyExplicitKernel")]
+pub struct SpatiallyExplicitKernelConfig {
+    #[serde(rename = "habitat", alias = "habitat_map")]
+    habitat_map: PathBuf,
+
+    #[serde(alias = "dispersal")]
+    kernel: Kernel,
+
+    #[serde(default)]
+    boundary: Boundary,
+
+    #[serde(default)]
+    #[serde(rename = "mode", alias = "loading_mode")]
+    loading_mode: MapLoadingMode,
+}
+
+impl SpatiallyExplicitKernelConfig {
+    #[allow(clippy::missing_errors_doc)]
+    pub fn try_load(self) -> Result<SpatiallyExplicitKernelArguments, String> {
+        info!(
+            "Starting to load the habitat map {:?} ...",
+            self.habitat_map
+        );
+
+        let mut grid = MapGrid::default();
+
+        let habitat_map = maps::load_habitat_map_without_dispersal(
+            &self.habitat_map,
+            "habitat",
+            self.loading_mode,
+            &mut grid,
+        )
+        .map_err(|err| format!("{err:?}"))?;
+
+        info!(
+            "Successfully loaded the habitat map {:?} with dimensions {}x{} [cols x rows].",
+            &self.habitat_map,
+            habitat_map.num_columns(),
+            habitat_map.num_rows()
+        );
+
+        Ok(SpatiallyExplicitKernelArguments {
+            habitat_path: self.habitat_map,
+            habitat_map,
+            kernel: self.kernel,
+            boundary: self.boundary,
+            loading_mode: self.loading_mode,
+        })
+    }
+
+    #[must_use]
+    pub fn from_args(args: &SpatiallyExplicitKernelArguments) -> Self {
+        Self {
+            habitat_map: args.habitat_path.clone(),
+            kernel: args.kernel,
+            boundary: args.boundary,
+            loading_mode: args.loading_mode,
+        }
+    }
+}
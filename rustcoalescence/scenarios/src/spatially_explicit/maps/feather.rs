@@ -0,0 +1,134 @@
+use std::{fs::File, io::BufReader, path::Path};
+
+use anyhow::{Context, Result};
+use arrow2::{
+    array::PrimitiveArray,
+    datatypes::{DataType, Field},
+};
+
+use necsim_core_bond::NonNegativeF64;
+use necsim_impls_no_std::cogs::dispersal_sampler::in_memory::sparse::SparseDispersalMap;
+
+/// Loads a sparse dispersal map from the Arrow IPC (Feather v2) file at
+/// `path`, together with the `(width, height)` of the habitat grid it is
+/// defined on.
+///
+/// The file must have the columns
+/// `source_x: u32, source_y: u32, target_x: u32, target_y: u32, weight: f64`,
+/// where each row lists the dispersal weight from the source to the target
+/// location. The dimensions of the habitat grid must be stored in the `width`
+/// and `height` schema metadata. Pairs of locations that are not listed have
+/// zero dispersal.
+#[allow(clippy::module_name_repetitions, clippy::similar_names)]
+pub fn load_sparse_dispersal_map_from_feather(
+    path: &Path,
+) -> Result<(SparseDispersalMap, (usize, usize))> {
+    let file = File::open(path).context("Could not read file.")?;
+    let mut reader = BufReader::new(file);
+
+    let metadata = arrow2::io::ipc::read::read_file_metadata(&mut reader)
+        .context("Could not read Arrow IPC file metadata.")?;
+
+    let expected_fields = vec![
+        Field::new("source_x", DataType::UInt32, false),
+        Field::new("source_y", DataType::UInt32, false),
+        Field::new("target_x", DataType::UInt32, false),
+        Field::new("target_y", DataType::UInt32, false),
+        Field::new("weight", DataType::Float64, false),
+    ];
+
+    anyhow::ensure!(
+        metadata.schema.fields == expected_fields,
+        "The sparse dispersal map schema {:?} does not match the expected schema {:?}.",
+        metadata.schema.fields,
+        expected_fields,
+    );
+
+    let dimension = |key: &str| -> Result<usize> {
+        let value = metadata
+            .schema
+            .metadata
+            .get(key)
+            .with_context(|| format!("The sparse dispersal map has no {key:?} metadata."))?;
+
+        value
+            .parse::<usize>()
+            .ok()
+            .filter(|dimension| *dimension > 0)
+            .with_context(|| {
+                format!("The sparse dispersal map {key:?} metadata {value:?} is not positive.")
+            })
+    };
+
+    let width = dimension("width")?;
+    let height = dimension("height")?;
+
+    let mut entries = Vec::new();
+
+    for chunk in arrow2::io::ipc::read::FileReader::new(reader, metadata, None, None) {
+        let chunk = chunk.context("Could not read Arrow IPC record batch.")?;
+
+        let [source_xs, source_ys, target_xs, target_ys, weights] = chunk.columns() else {
+            anyhow::bail!("The sparse dispersal map has corrupted columns.");
+        };
+
+        let column = |array: &dyn arrow2::array::Array, name: &str| -> Result<Vec<u32>> {
+            array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<u32>>()
+                .map(|array| array.values_iter().copied().collect())
+                .with_context(|| format!("The sparse dispersal map has a corrupted {name} column."))
+        };
+
+        let source_xs = column(source_xs.as_ref(), "source_x")?;
+        let source_ys = column(source_ys.as_ref(), "source_y")?;
+        let target_xs = column(target_xs.as_ref(), "target_x")?;
+        let target_ys = column(target_ys.as_ref(), "target_y")?;
+
+        let weights = weights
+            .as_any()
+            .downcast_ref::<PrimitiveArray<f64>>()
+            .context("The sparse dispersal map has a corrupted weight column.")?;
+
+        for ((((source_x, source_y), target_x), target_y), weight) in source_xs
+            .into_iter()
+            .zip(source_ys)
+            .zip(target_xs)
+            .zip(target_ys)
+            .zip(weights.values_iter().copied())
+        {
+            let index = |x: u32, y: u32| -> Result<usize> {
+                let (x, y) = (x as usize, y as usize);
+
+                anyhow::ensure!(
+                    x < width && y < height,
+                    "The location ({x}, {y}) is outside the {width}x{height} [cols x rows] \
+                     habitat grid of the sparse dispersal map."
+                );
+
+                Ok(y * width + x)
+            };
+
+            let weight = NonNegativeF64::new(weight)
+                .ok()
+                .filter(|weight| weight.get().is_finite())
+                .with_context(|| {
+                    format!(
+                        "The dispersal weight {weight} from ({source_x}, {source_y}) to \
+                         ({target_x}, {target_y}) is not finite and non-negative."
+                    )
+                })?;
+
+            entries.push((
+                index(source_x, source_y)?,
+                index(target_x, target_y)?,
+                weight,
+            ));
+        }
+    }
+
+    let map = SparseDispersalMap::try_from_entries(width * height, entries)
+        .map_err(|err| anyhow::anyhow!("{err}"))?;
+
+    Ok((map, (width, height)))
+}
@@ -1,19 +1,292 @@
-use std::path::Path;
+#[cfg(feature = "spatially-explicit-fine-coarse")]
+use std::num::NonZeroU32;
+use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 
-use necsim_core_bond::NonNegativeF64;
-use necsim_impls_no_std::array2d::Array2D;
+use necsim_core::cogs::{Habitat, MathsCore, RngCore};
+#[cfg(feature = "spatially-explicit-fine-coarse")]
+use necsim_core::landscape::Location;
+use necsim_core_bond::{ClosedUnitF64, NonNegativeF64};
+use necsim_impls_no_std::{
+    array2d::Array2D,
+    cogs::dispersal_sampler::in_memory::{
+        sparse::SparseDispersalMap, InMemoryDispersalSamplerError, InMemorySparseDispersalSampler,
+    },
+};
+
+mod feather;
+
+#[cfg(test)]
+mod tests;
+
+use crate::tiff::{self, GeoTransform};
+
+/// Georeferenced grid of the habitat map that all maps of a scenario must share
+#[derive(Debug, Default)]
+pub struct MapGrid {
+    habitat: Option<MapGridEntry>,
+    /// Maps which were loaded before the habitat map and still need to be
+    ///  checked against it
+    pending: Vec<MapGridEntry>,
+}
+
+#[derive(Debug)]
+struct MapGridEntry {
+    kind: &'static str,
+    path: PathBuf,
+    georeference: Option<GeoTransform>,
+    dimensions: Option<(usize, usize)>,
+}
+
+impl MapGrid {
+    /// Checks that the `kind` map at `path` shares the georeferencing and
+    /// `dimensions` of the habitat map, which is deferred until the habitat
+    /// map has been checked.
+    ///
+    /// Maps without georeferencing or without habitat-grid `dimensions`, e.g.
+    /// dispersal maps, are only checked in the other aspect.
+    fn check(
+        &mut self,
+        kind: &'static str,
+        path: &Path,
+        georeference: Option<GeoTransform>,
+        dimensions: Option<(usize, usize)>,
+    ) -> Result<()> {
+        let map = MapGridEntry {
+            kind,
+            path: path.to_owned(),
+            georeference,
+            dimensions,
+        };
+
+        match &self.habitat {
+            Some(habitat) => map.check_against(habitat),
+            None => {
+                self.pending.push(map);
+                Ok(())
+            },
+        }
+    }
+
+    /// Sets the `kind` habitat map at `path` as the reference grid, and checks
+    /// all previously checked maps against it.
+    fn check_habitat(
+        &mut self,
+        kind: &'static str,
+        path: &Path,
+        georeference: Option<GeoTransform>,
+        dimensions: (usize, usize),
+    ) -> Result<()> {
+        let habitat = MapGridEntry {
+            kind,
+            path: path.to_owned(),
+            georeference,
+            dimensions: Some(dimensions),
+        };
+
+        for map in std::mem::take(&mut self.pending) {
+            map.check_against(&habitat)?;
+        }
+
+        self.habitat = Some(habitat);
+
+        Ok(())
+    }
+
+    /// Checks that this fine grid is nested inside the `coarse` grid, such
+    /// that each coarse cell covers `scale x scale` fine cells and the first
+    /// fine cell is located at `offset` fine cells inside the coarse grid.
+    ///
+    /// Only grids whose habitat maps are both georeferenced are checked.
+    #[cfg(feature = "spatially-explicit-fine-coarse")]
+    pub fn check_nested_in(
+        &self,
+        coarse: &MapGrid,
+        scale: NonZeroU32,
+        offset: &Location,
+    ) -> Result<()> {
+        let (
+            Some(MapGridEntry {
+                kind: fine_kind,
+                path: fine_path,
+                georeference: Some(fine),
+                ..
+            }),
+            Some(MapGridEntry {
+                kind: coarse_kind,
+                path: coarse_path,
+                georeference: Some(coarse),
+                ..
+            }),
+        ) = (&self.habitat, &coarse.habitat)
+        else {
+            return Ok(());
+        };
+
+        let pixel_size = (
+            coarse.pixel_size.0 / f64::from(scale.get()),
+            coarse.pixel_size.1 / f64::from(scale.get()),
+        );
+        let expected = GeoTransform {
+            origin: (
+                coarse.origin.0 + f64::from(offset.x()) * pixel_size.0,
+                // Raster rows go southwards while model coordinates go northwards
+                coarse.origin.1 - f64::from(offset.y()) * pixel_size.1,
+            ),
+            pixel_size,
+        };
+
+        anyhow::ensure!(
+            fine.is_aligned_with(&expected),
+            "The {fine_kind} map {fine_path:?} with origin {:?} and pixel size {:?} is not nested \
+             inside the {coarse_kind} map {coarse_path:?} with scale {scale} and offset ({}, {}), \
+             which requires origin {:?} and pixel size {:?}.",
+            fine.origin,
+            fine.pixel_size,
+            offset.x(),
+            offset.y(),
+            expected.origin,
+            expected.pixel_size,
+        );
+
+        Ok(())
+    }
+}
+
+impl MapGridEntry {
+    fn check_against(&self, habitat: &Self) -> Result<()> {
+        let Self { kind, path, .. } = self;
+        let Self {
+            kind: habitat_kind,
+            path: habitat_path,
+            ..
+        } = habitat;
+
+        if let (Some(georeference), Some(habitat)) = (&self.georeference, &habitat.georeference) {
+            anyhow::ensure!(
+                georeference.is_aligned_with(habitat),
+                "The {kind} map {path:?} with origin {:?} and pixel size {:?} does not share the \
+                 georeferenced grid of the {habitat_kind} map {habitat_path:?} with origin {:?} \
+                 and pixel size {:?}.",
+                georeference.origin,
+                georeference.pixel_size,
+                habitat.origin,
+                habitat.pixel_size,
+            );
+        }
+
+        if let (Some((columns, rows)), Some((habitat_columns, habitat_rows))) =
+            (self.dimensions, habitat.dimensions)
+        {
+            anyhow::ensure!(
+                (columns, rows) == (habitat_columns, habitat_rows),
+                "The {kind} map {path:?} with dimensions {columns}x{rows} [cols x rows] does not \
+                 share the grid of the {habitat_kind} map {habitat_path:?} with dimensions \
+                 {habitat_columns}x{habitat_rows} [cols x rows]."
+            );
+        }
+
+        Ok(())
+    }
+}
+
+/// Dispersal map between all pairs of habitat grid cells, which is either
+/// stored densely or sparsely
+#[derive(Debug)]
+pub enum DispersalMap {
+    /// `(WxH x WxH)` dispersal map loaded from a TIFF file
+    Dense(Array2D<NonNegativeF64>),
+    /// Sparse dispersal map loaded from an Arrow IPC (Feather) file
+    Sparse(SparseDispersalMap),
+}
+
+impl DispersalMap {
+    #[must_use]
+    pub fn num_rows(&self) -> usize {
+        match self {
+            Self::Dense(dispersal) => dispersal.num_rows(),
+            Self::Sparse(dispersal) => dispersal.num_locations(),
+        }
+    }
+
+    #[must_use]
+    pub fn num_columns(&self) -> usize {
+        match self {
+            Self::Dense(dispersal) => dispersal.num_columns(),
+            Self::Sparse(dispersal) => dispersal.num_locations(),
+        }
+    }
+
+    fn has_dispersal_from(&self, row: usize) -> bool {
+        match self {
+            Self::Dense(dispersal) => dispersal
+                .row_iter(row)
+                .map_or(false, |mut it| it.any(|p| *p > 0.0_f64)),
+            Self::Sparse(dispersal) => dispersal.row_iter(row).next().is_some(),
+        }
+    }
 
-mod tiff;
+    /// Creates an in-memory dispersal sampler from this dispersal map, which
+    /// is only densified if it is dense already.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error iff the dispersal map does not fulfil the in-memory
+    /// dispersal contract with the `habitat`.
+    pub fn to_dispersal_sampler<
+        M: MathsCore,
+        H: Habitat<M>,
+        G: RngCore<M>,
+        D: InMemorySparseDispersalSampler<M, H, G>,
+    >(
+        &self,
+        habitat: &H,
+    ) -> Result<D, InMemoryDispersalSamplerError> {
+        match self {
+            Self::Dense(dispersal) => D::new(dispersal, habitat),
+            Self::Sparse(dispersal) => D::new_sparse(dispersal, habitat),
+        }
+    }
+}
 
+/// Loads a dispersal map from the file at `path`, which is read as a sparse
+/// dispersal map if it is an Arrow IPC (Feather) file with a `.feather`,
+/// `.arrow`, or `.ipc` extension, and as a dense TIFF dispersal map otherwise.
 pub fn load_dispersal_map(
     path: &Path,
     loading_mode: MapLoadingMode,
+    grid: &mut MapGrid,
+) -> Result<DispersalMap> {
+    let is_sparse = path.extension().map_or(false, |extension| {
+        ["feather", "arrow", "ipc"]
+            .iter()
+            .any(|sparse| extension.eq_ignore_ascii_case(sparse))
+    });
+
+    if is_sparse {
+        return (|| -> Result<DispersalMap> {
+            let (map, dimensions) = feather::load_sparse_dispersal_map_from_feather(path)?;
+
+            // The sparse dispersal map knows the dimensions of its habitat grid
+            grid.check("dispersal", path, None, Some(dimensions))?;
+
+            Ok(DispersalMap::Sparse(map))
+        })()
+        .with_context(|| format!("Failed to load the sparse dispersal map from {path:?}."));
+    }
+
+    load_dense_dispersal_map(path, loading_mode, grid).map(DispersalMap::Dense)
+}
+
+fn load_dense_dispersal_map(
+    path: &Path,
+    loading_mode: MapLoadingMode,
+    grid: &mut MapGrid,
 ) -> Result<Array2D<NonNegativeF64>> {
     (|| {
-        let map = tiff::load_map_from_tiff::<f64>(
+        let (map, georeference) = tiff::load_map_from_tiff::<f64>(
             path,
             match loading_mode {
                 MapLoadingMode::FixMe | MapLoadingMode::OffByOne => false,
@@ -25,6 +298,9 @@ pub fn load_dispersal_map(
             anyhow::bail!("Dispersal weights are not all non-negative")
         }
 
+        // The dispersal map is indexed by pairs of habitat grid cells
+        grid.check("dispersal", path, georeference, None)?;
+
         Ok(unsafe { std::mem::transmute::<Array2D<f64>, Array2D<NonNegativeF64>>(map) })
     })()
     .with_context(|| format!("Failed to load the dispersal map from {path:?}."))
@@ -33,9 +309,10 @@ pub fn load_dispersal_map(
 pub fn load_turnover_map(
     path: &Path,
     loading_mode: MapLoadingMode,
+    grid: &mut MapGrid,
 ) -> Result<Array2D<NonNegativeF64>> {
     (|| {
-        let map = tiff::load_map_from_tiff::<f64>(
+        let (map, georeference) = tiff::load_map_from_tiff::<f64>(
             path,
             match loading_mode {
                 MapLoadingMode::FixMe | MapLoadingMode::OffByOne => false,
@@ -47,31 +324,89 @@ pub fn load_turnover_map(
             anyhow::bail!("Turnover rates are not all non-negative")
         }
 
+        grid.check(
+            "turnover",
+            path,
+            georeference,
+            Some((map.num_columns(), map.num_rows())),
+        )?;
+
         Ok(unsafe { std::mem::transmute::<Array2D<f64>, Array2D<NonNegativeF64>>(map) })
     })()
     .with_context(|| format!("Failed to load the turnover map from {path:?}."))
 }
 
+pub fn load_speciation_map(
+    path: &Path,
+    habitat: &Array2D<u32>,
+    loading_mode: MapLoadingMode,
+    grid: &mut MapGrid,
+) -> Result<Array2D<ClosedUnitF64>> {
+    (|| {
+        let (map, georeference) = tiff::load_map_from_tiff::<f64>(
+            path,
+            match loading_mode {
+                MapLoadingMode::FixMe | MapLoadingMode::OffByOne => false,
+                MapLoadingMode::Strict => true,
+            },
+        )?;
+
+        grid.check(
+            "speciation",
+            path,
+            georeference,
+            Some((map.num_columns(), map.num_rows())),
+        )?;
+
+        for y in 0..map.num_rows() {
+            for x in 0..map.num_columns() {
+                let speciation = map[(y, x)];
+
+                // Lineages in a habitat region without speciation never finish
+                let (valid, range) = if habitat[(y, x)] > 0 {
+                    (
+                        speciation > 0.0_f64 && speciation <= 1.0_f64,
+                        "(0.0, 1.0] on habitat",
+                    )
+                } else {
+                    ((0.0_f64..=1.0_f64).contains(&speciation), "[0.0, 1.0]")
+                };
+
+                if !valid {
+                    anyhow::bail!(
+                        "Speciation probability {} at ({}, {}) is not in {}",
+                        speciation,
+                        x,
+                        y,
+                        range
+                    );
+                }
+            }
+        }
+
+        Ok(unsafe { std::mem::transmute::<Array2D<f64>, Array2D<ClosedUnitF64>>(map) })
+    })()
+    .with_context(|| format!("Failed to load the speciation map from {path:?}."))
+}
+
 pub fn load_habitat_map(
     path: &Path,
     turnover: Option<&Array2D<NonNegativeF64>>,
-    dispersal: &mut Array2D<NonNegativeF64>,
+    dispersal: &mut DispersalMap,
     loading_mode: MapLoadingMode,
+    grid: &mut MapGrid,
 ) -> Result<Array2D<u32>> {
-    let mut habitat = tiff::load_map_from_tiff::<u32>(
-        path,
-        match loading_mode {
-            MapLoadingMode::FixMe | MapLoadingMode::OffByOne => false,
-            MapLoadingMode::Strict => true,
-        },
-    )
-    .with_context(|| format!("Failed to load the habiat map from {path:?}."))?;
+    let mut habitat = load_habitat_map_without_dispersal(path, "habitat", loading_mode, grid)?;
 
     match loading_mode {
         MapLoadingMode::FixMe => {
             fix_habitat_map(&mut habitat, turnover, dispersal);
             fix_no_turnover_habitat_map(&mut habitat, turnover);
-            fix_dispersal_map(&habitat, dispersal);
+
+            match dispersal {
+                DispersalMap::Dense(dispersal) => fix_dispersal_map(&habitat, dispersal),
+                DispersalMap::Sparse(dispersal) => fix_sparse_dispersal_map(&habitat, dispersal),
+            }
         },
         MapLoadingMode::OffByOne => fix_habitat_map(&mut habitat, turnover, dispersal),
         MapLoadingMode::Strict => (),
@@ -80,6 +415,62 @@ pub fn load_habitat_map(
     Ok(habitat)
 }
 
+/// Loads a `kind` habitat map, which is not accompanied by a dispersal map
+/// and can thus only be fixed for rounding errors.
+pub fn load_habitat_map_without_dispersal(
+    path: &Path,
+    kind: &'static str,
+    loading_mode: MapLoadingMode,
+    grid: &mut MapGrid,
+) -> Result<Array2D<u32>> {
+    (|| -> Result<Array2D<u32>> {
+        let (habitat, georeference) = tiff::load_map_from_tiff::<u32>(
+            path,
+            match loading_mode {
+                MapLoadingMode::FixMe | MapLoadingMode::OffByOne => false,
+                MapLoadingMode::Strict => true,
+            },
+        )?;
+
+        grid.check_habitat(
+            kind,
+            path,
+            georeference,
+            (habitat.num_columns(), habitat.num_rows()),
+        )?;
+
+        Ok(habitat)
+    })()
+    .with_context(|| format!("Failed to load the {kind} map from {path:?}."))
+}
+
+#[cfg(feature = "spatially-explicit-fine-coarse")]
+pub fn load_sample_mask(
+    path: &Path,
+    loading_mode: MapLoadingMode,
+    grid: &mut MapGrid,
+) -> Result<Array2D<bool>> {
+    (|| -> Result<Array2D<bool>> {
+        let (mask, georeference) = tiff::load_map_from_tiff::<bool>(
+            path,
+            match loading_mode {
+                MapLoadingMode::FixMe | MapLoadingMode::OffByOne => false,
+                MapLoadingMode::Strict => true,
+            },
+        )?;
+
+        grid.check(
+            "sample mask",
+            path,
+            georeference,
+            Some((mask.num_columns(), mask.num_rows())),
+        )?;
+
+        Ok(mask)
+    })()
+    .with_context(|| format!("Failed to load the sample mask from {path:?}."))
+}
+
 #[derive(Copy, Clone, Debug, Serialize, Deserialize)]
 pub enum MapLoadingMode {
     FixMe,
@@ -98,7 +489,7 @@ impl Default for MapLoadingMode {
 fn fix_habitat_map(
     habitat: &mut Array2D<u32>,
     turnover: Option<&Array2D<NonNegativeF64>>,
-    dispersal: &Array2D<NonNegativeF64>,
+    dispersal: &DispersalMap,
 ) {
     for y in 0..habitat.num_rows() {
         for x in 0..habitat.num_columns() {
@@ -110,10 +501,7 @@ fn fix_habitat_map(
                 #[allow(clippy::bool_to_int_with_if)]
                 let h_fixed = if turnover.map_or(false, |turnover| turnover[(y, x)] == 0.0_f64) {
                     0
-                } else if dispersal
-                    .row_iter(y * habitat.num_columns() + x)
-                    .map_or(false, |mut it| it.any(|p| *p > 0.0_f64))
-                {
+                } else if dispersal.has_dispersal_from(y * habitat.num_columns() + x) {
                     1
                 } else {
                     0
@@ -213,3 +601,36 @@ fn fix_dispersal_map(habitat: &Array2D<u32>, dispersal: &mut Array2D<NonNegative
         }
     }
 }
+
+// Fix sparse dispersal by removing dispersal to/from non-habitat
+// Fix sparse dispersal by adding self-dispersal when no dispersal exists from
+//  habitat
+fn fix_sparse_dispersal_map(habitat: &Array2D<u32>, dispersal: &mut SparseDispersalMap) {
+    let is_habitat = |index: usize| {
+        habitat
+            .get(index / habitat.num_columns(), index % habitat.num_columns())
+            .map_or(false, |h| *h > 0)
+    };
+
+    let mut entries = dispersal
+        .entries_iter()
+        .filter(|(source, target, _)| is_habitat(*source) && is_habitat(*target))
+        .collect::<Vec<_>>();
+
+    let mut has_dispersal = vec![false; dispersal.num_locations()];
+
+    for (source, _, _) in &entries {
+        has_dispersal[*source] = true;
+    }
+
+    for (location, has_dispersal) in has_dispersal.into_iter().enumerate() {
+        if !has_dispersal && is_habitat(location) {
+            entries.push((location, location, NonNegativeF64::one()));
+        }
+    }
+
+    // The fixed entries are a subset of the valid entries, and any added
+    //  self-dispersal did not exist before
+    *dispersal = SparseDispersalMap::try_from_entries(dispersal.num_locations(), entries)
+        .expect("fixed sparse dispersal entries are valid");
+}
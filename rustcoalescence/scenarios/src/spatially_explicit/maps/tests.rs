@@ -0,0 +1,220 @@
+use std::path::{Path, PathBuf};
+
+use tiff::{
+    encoder::{colortype::Gray32Float, TiffEncoder},
+    tags::Tag,
+};
+
+use super::{
+    load_habitat_map_without_dispersal, load_turnover_map, tiff::GeoTransform, MapGrid,
+    MapLoadingMode,
+};
+
+const ORIGIN: GeoTransform = GeoTransform {
+    origin: (10.0, 20.0),
+    pixel_size: (0.5, 0.5),
+};
+
+const SHIFTED: GeoTransform = GeoTransform {
+    origin: (10.25, 20.0),
+    pixel_size: (0.5, 0.5),
+};
+
+#[test]
+fn test_aligned_maps() {
+    let mut grid = MapGrid::default();
+
+    assert!(grid
+        .check("dispersal", Path::new("dispersal.tif"), None, None)
+        .is_ok());
+    assert!(grid
+        .check(
+            "turnover",
+            Path::new("turnover.tif"),
+            Some(ORIGIN),
+            Some((4, 3))
+        )
+        .is_ok());
+    assert!(grid
+        .check_habitat("habitat", Path::new("habitat.tif"), Some(ORIGIN), (4, 3))
+        .is_ok());
+    assert!(grid
+        .check(
+            "speciation",
+            Path::new("speciation.tif"),
+            Some(ORIGIN),
+            Some((4, 3))
+        )
+        .is_ok());
+}
+
+#[test]
+fn test_mismatched_georeference_before_habitat() {
+    let mut grid = MapGrid::default();
+
+    assert!(grid
+        .check(
+            "turnover",
+            Path::new("turnover.tif"),
+            Some(SHIFTED),
+            Some((4, 3))
+        )
+        .is_ok());
+
+    let error = grid
+        .check_habitat("habitat", Path::new("habitat.tif"), Some(ORIGIN), (4, 3))
+        .unwrap_err()
+        .to_string();
+
+    assert!(error.contains("turnover map \"turnover.tif\""), "{error}");
+    assert!(error.contains("habitat map \"habitat.tif\""), "{error}");
+}
+
+#[test]
+fn test_mismatched_georeference_after_habitat() {
+    let mut grid = MapGrid::default();
+
+    assert!(grid
+        .check_habitat("habitat", Path::new("habitat.tif"), Some(ORIGIN), (4, 3))
+        .is_ok());
+
+    let error = grid
+        .check(
+            "speciation",
+            Path::new("speciation.tif"),
+            Some(SHIFTED),
+            Some((4, 3)),
+        )
+        .unwrap_err()
+        .to_string();
+
+    assert!(
+        error.contains("speciation map \"speciation.tif\""),
+        "{error}"
+    );
+    assert!(error.contains("habitat map \"habitat.tif\""), "{error}");
+}
+
+#[test]
+fn test_habitat_is_the_reference() {
+    let mut grid = MapGrid::default();
+
+    // Both maps agree with each other, but not with the habitat map
+    assert!(grid
+        .check("dispersal", Path::new("dispersal.tif"), Some(SHIFTED), None)
+        .is_ok());
+    assert!(grid
+        .check(
+            "turnover",
+            Path::new("turnover.tif"),
+            Some(SHIFTED),
+            Some((4, 3))
+        )
+        .is_ok());
+
+    let error = grid
+        .check_habitat("habitat", Path::new("habitat.tif"), Some(ORIGIN), (4, 3))
+        .unwrap_err()
+        .to_string();
+
+    assert!(error.contains("dispersal map \"dispersal.tif\""), "{error}");
+    assert!(error.contains("habitat map \"habitat.tif\""), "{error}");
+}
+
+#[test]
+fn test_mismatched_dimensions() {
+    let mut grid = MapGrid::default();
+
+    assert!(grid
+        .check_habitat("habitat", Path::new("habitat.tif"), None, (4, 3))
+        .is_ok());
+
+    let error = grid
+        .check(
+            "turnover",
+            Path::new("turnover.tif"),
+            Some(SHIFTED),
+            Some((3, 4)),
+        )
+        .unwrap_err()
+        .to_string();
+
+    assert!(error.contains("3x4"), "{error}");
+    assert!(error.contains("habitat map \"habitat.tif\""), "{error}");
+}
+
+fn write_map_with_no_data(name: &str, data: &[f32]) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("{}-{name}.tif", std::process::id()));
+
+    let mut encoder = TiffEncoder::new(std::fs::File::create(&path).unwrap()).unwrap();
+    #[allow(clippy::cast_possible_truncation)]
+    let mut image = encoder
+        .new_image::<Gray32Float>(data.len() as u32, 1)
+        .unwrap();
+    image.encoder().write_tag(Tag::GdalNodata, "-9999").unwrap();
+    image.write_data(data).unwrap();
+
+    path
+}
+
+#[test]
+fn test_no_data_is_replaced_in_all_loading_modes() {
+    let path = write_map_with_no_data("no-data-turnover", &[0.5_f32, -9999.0_f32]);
+
+    for mode in [MapLoadingMode::OffByOne, MapLoadingMode::Strict] {
+        let turnover = load_turnover_map(&path, mode, &mut MapGrid::default()).unwrap();
+        assert_eq!(
+            turnover
+                .elements_row_major_iter()
+                .map(|turnover| turnover.get())
+                .collect::<Vec<_>>(),
+            [0.5_f64, 0.0_f64]
+        );
+    }
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_strict_loading_honours_no_data_as_zero_habitat() {
+    let path = write_map_with_no_data("no-data-strict-habitat", &[3.0_f32, -9999.0_f32, 1.0_f32]);
+
+    let habitat = load_habitat_map_without_dispersal(
+        &path,
+        "habitat",
+        MapLoadingMode::Strict,
+        &mut MapGrid::default(),
+    )
+    .unwrap();
+    assert_eq!(
+        habitat
+            .elements_row_major_iter()
+            .copied()
+            .collect::<Vec<_>>(),
+        [3_u32, 0_u32, 1_u32]
+    );
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn test_strict_loading_still_rejects_inexact_samples() {
+    let path = write_map_with_no_data("no-data-inexact-habitat", &[2.5_f32, -9999.0_f32]);
+
+    assert!(load_habitat_map_without_dispersal(
+        &path,
+        "habitat",
+        MapLoadingMode::Strict,
+        &mut MapGrid::default()
+    )
+    .is_err());
+    assert!(load_habitat_map_without_dispersal(
+        &path,
+        "habitat",
+        MapLoadingMode::OffByOne,
+        &mut MapGrid::default()
+    )
+    .is_ok());
+
+    std::fs::remove_file(path).unwrap();
+}
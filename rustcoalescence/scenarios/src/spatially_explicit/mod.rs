@@ -1,4 +1,23 @@
+#[cfg(feature = "spatially-explicit-fine-coarse")]
+pub mod fine_coarse;
+#[cfg(feature = "spatially-explicit-kernel")]
+pub mod kernel;
+// The dispersal, turnover, and speciation maps are only loaded by the
+//  turnover scenarios, the other scenarios only load habitat maps
+#[cfg_attr(
+    not(any(
+        feature = "spatially-explicit-uniform-turnover",
+        feature = "spatially-explicit-turnover-map",
+    )),
+    allow(dead_code)
+)]
 mod maps;
+#[cfg(feature = "spatially-explicit-temporal")]
+pub mod temporal;
+#[cfg(any(
+    feature = "spatially-explicit-uniform-turnover",
+    feature = "spatially-explicit-turnover-map",
+))]
 mod turnover;
 
 #[cfg(any(
@@ -6,7 +25,9 @@ mod turnover;
     feature = "spatially-explicit-turnover-map",
 ))]
 #[allow(clippy::module_name_repetitions)]
-pub use turnover::SpatiallyExplicitArguments;
+pub use turnover::{
+    SpatiallyExplicitArguments, SpatiallyExplicitSpeciationProbability, SpeciationMapError,
+};
 
 #[cfg(feature = "spatially-explicit-turnover-map")]
 pub use turnover::map;
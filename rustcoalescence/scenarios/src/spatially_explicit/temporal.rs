@@ -0,0 +1,176 @@
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use necsim_core_bond::{NonNegativeF64, PositiveF64};
+
+use super::{
+    map::SpatiallyExplicitTurnoverMapArguments, maps::MapLoadingMode, turnover::Speciation,
+};
+
+#[derive(Debug)]
+#[allow(clippy::module_name_repetitions)]
+pub struct SpatiallyExplicitTemporalArguments {
+    pub initial: SpatiallyExplicitTurnoverMapArguments,
+    pub changes: Vec<SpatiallyExplicitTemporalChange>,
+}
+
+#[derive(Debug)]
+#[allow(clippy::module_name_repetitions)]
+pub struct SpatiallyExplicitTemporalChange {
+    pub time: PositiveF64,
+    pub args: SpatiallyExplicitTurnoverMapArguments,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+#[allow(clippy::module_name_repetitions)]
+pub struct SpatiallyExplicitTemporalSnapshots {
+    snapshots: Vec<SpatiallyExplicitTemporalSnapshot>,
+}
+
+impl SpatiallyExplicitTemporalSnapshots {
+    #[allow(clippy::missing_errors_doc)]
+    pub fn try_load(self) -> Result<SpatiallyExplicitTemporalArguments, String> {
+        let mut snapshots = self.snapshots.into_iter();
+
+        let initial = match snapshots.next() {
+            Some(snapshot) if snapshot.time == NonNegativeF64::zero() => snapshot,
+            Some(snapshot) => {
+                return Err(format!(
+                    "the first habitat snapshot must start at time 0.0, not {}",
+                    snapshot.time
+                ))
+            },
+            None => return Err(String::from("at least one habitat snapshot is required")),
+        };
+
+        info!("Starting to load the habitat snapshot at time 0.0 ...");
+
+        let initial = SpatiallyExplicitTurnoverMapArguments::try_load(
+            initial.habitat_map,
+            initial.dispersal_map,
+            initial.turnover_map,
+            initial.speciation.into_path(),
+            initial.loading_mode,
+        )?;
+
+        let mut changes: Vec<SpatiallyExplicitTemporalChange> = Vec::new();
+
+        for snapshot in snapshots {
+            let previous_time = changes
+                .last()
+                .map_or(NonNegativeF64::zero(), |change| change.time.into());
+
+            if snapshot.time <= previous_time {
+                return Err(format!(
+                    "habitat snapshots must be strictly ordered by time, but {} follows {}",
+                    snapshot.time, previous_time
+                ));
+            }
+
+            let time = PositiveF64::new(snapshot.time.get()).map_err(|err| format!("{err}"))?;
+
+            info!("Starting to load the habitat snapshot at time {} ...", time);
+
+            let args = SpatiallyExplicitTurnoverMapArguments::try_load(
+                snapshot.habitat_map,
+                snapshot.dispersal_map,
+                snapshot.turnover_map,
+                snapshot.speciation.into_path(),
+                snapshot.loading_mode,
+            )?;
+
+            if args.habitat_map.num_columns() != initial.habitat_map.num_columns()
+                || args.habitat_map.num_rows() != initial.habitat_map.num_rows()
+            {
+                return Err(format!(
+                    "the habitat snapshot {:?} at time {} has dimensions {}x{} [cols x rows], but \
+                     the initial habitat snapshot {:?} has dimensions {}x{} [cols x rows]",
+                    args.habitat_path,
+                    time,
+                    args.habitat_map.num_columns(),
+                    args.habitat_map.num_rows(),
+                    initial.habitat_path,
+                    initial.habitat_map.num_columns(),
+                    initial.habitat_map.num_rows(),
+                ));
+            }
+
+            // All snapshots must share the same kind of speciation probability
+            if args.speciation_map.is_some() != initial.speciation_map.is_some() {
+                return Err(format!(
+                    "the habitat snapshot {:?} at time {} must {} a speciation map, like the \
+                     initial habitat snapshot {:?}",
+                    args.habitat_path,
+                    time,
+                    if initial.speciation_map.is_some() {
+                        "have"
+                    } else {
+                        "not have"
+                    },
+                    initial.habitat_path,
+                ));
+            }
+
+            changes.push(SpatiallyExplicitTemporalChange { time, args });
+        }
+
+        info!(
+            "Successfully loaded {} habitat snapshot(s).",
+            changes.len() + 1
+        );
+
+        Ok(SpatiallyExplicitTemporalArguments { initial, changes })
+    }
+
+    #[must_use]
+    pub fn from_args(args: &SpatiallyExplicitTemporalArguments) -> Self {
+        Self {
+            snapshots: std::iter::once(SpatiallyExplicitTemporalSnapshot::from_args(
+                NonNegativeF64::zero(),
+                &args.initial,
+            ))
+            .chain(args.changes.iter().map(|change| {
+                SpatiallyExplicitTemporalSnapshot::from_args(change.time.into(), &change.args)
+            }))
+            .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+#[serde(rename = "Snapshot")]
+struct SpatiallyExplicitTemporalSnapshot {
+    time: NonNegativeF64,
+
+    #[serde(rename = "habitat", alias = "habitat_map")]
+    habitat_map: PathBuf,
+
+    #[serde(rename = "dispersal", alias = "dispersal_map")]
+    dispersal_map: PathBuf,
+
+    #[serde(rename = "turnover", alias = "turnover_map")]
+    turnover_map: PathBuf,
+
+    #[serde(default)]
+    speciation: Speciation,
+
+    #[serde(default)]
+    #[serde(rename = "mode", alias = "loading_mode")]
+    loading_mode: MapLoadingMode,
+}
+
+impl SpatiallyExplicitTemporalSnapshot {
+    fn from_args(time: NonNegativeF64, args: &SpatiallyExplicitTurnoverMapArguments) -> Self {
+        Self {
+            time,
+            habitat_map: args.habitat_path.clone(),
+            dispersal_map: args.dispersal_path.clone(),
+            turnover_map: args.turnover_path.clone(),
+            speciation: Speciation::from_path(args.speciation_path.as_ref()),
+            loading_mode: args.loading_mode,
+        }
+    }
+}
@@ -5,16 +5,14 @@ use std::{convert::TryFrom, marker::PhantomData, path::PathBuf};
 use serde::{Deserialize, Serialize, Serializer};
 
 use necsim_core::cogs::{Habitat, LineageStore, MathsCore, RngCore};
-use necsim_core_bond::{NonNegativeF64, OpenClosedUnitF64 as PositiveUnitF64};
+use necsim_core_bond::{ClosedUnitF64, NonNegativeF64, OpenClosedUnitF64 as PositiveUnitF64};
 use necsim_partitioning_core::partition::Partition;
 
 use necsim_impls_no_std::{
     array2d::Array2D,
     cogs::{
-        dispersal_sampler::in_memory::{
-            packed_separable_alias::InMemoryPackedSeparableAliasDispersalSampler,
-            InMemoryDispersalSampler,
-        },
+        dispersal_sampler::in_memory::packed_separable_alias::InMemoryPackedSeparableAliasDispersalSampler,
+        event_sampler::protracted::ProtractedSpeciation,
         habitat::in_memory::InMemoryHabitat,
         origin_sampler::{in_memory::InMemoryOriginSampler, pre_sampler::OriginPreSampler},
         speciation_probability::uniform::UniformSpeciationProbability,
@@ -27,7 +25,10 @@ use necsim_impls_std::cogs::dispersal_sampler::in_memory::InMemoryDispersalSampl
 
 use crate::{Scenario, ScenarioCogs, ScenarioParameters};
 
-use super::super::maps::{self, MapLoadingMode};
+use super::{
+    super::maps::{self, DispersalMap, MapGrid, MapLoadingMode},
+    SpatiallyExplicitSpeciationProbability, Speciation, SpeciationMapError,
+};
 
 #[allow(clippy::module_name_repetitions, clippy::enum_variant_names)]
 #[derive(thiserror::Error, displaydoc::Display, Debug)]
@@ -36,19 +37,30 @@ pub enum SpatiallyExplicitTurnoverMapScenarioError {
     EmptyHabitatMap,
     /// invalid dispersal map: {0}
     DispersalMap(InMemoryDispersalSamplerError),
+    /// invalid speciation map: {0}
+    SpeciationMap(SpeciationMapError),
     /// invalid turnover map: {0}
     TurnoverMap(InMemoryTurnoverRateError),
 }
 
-#[allow(clippy::module_name_repetitions, clippy::empty_enum)]
-pub enum SpatiallyExplicitTurnoverMapScenario {}
+/// Scenario with a turnover map, where `S` is either a
+///  [`UniformSpeciationProbability`] or an
+///  [`InMemorySpeciationProbability`](necsim_impls_no_std::cogs::speciation_probability::in_memory::InMemorySpeciationProbability)
+///  map
+#[allow(clippy::module_name_repetitions)]
+pub struct SpatiallyExplicitTurnoverMapScenario<S = UniformSpeciationProbability>(
+    PhantomData<S>,
+    !,
+);
 
-impl ScenarioParameters for SpatiallyExplicitTurnoverMapScenario {
+impl<S> ScenarioParameters for SpatiallyExplicitTurnoverMapScenario<S> {
     type Arguments = SpatiallyExplicitTurnoverMapArguments;
     type Error = SpatiallyExplicitTurnoverMapScenarioError;
 }
 
-impl<M: MathsCore, G: RngCore<M>> Scenario<M, G> for SpatiallyExplicitTurnoverMapScenario {
+impl<M: MathsCore, G: RngCore<M>, S: SpatiallyExplicitSpeciationProbability<M>> Scenario<M, G>
+    for SpatiallyExplicitTurnoverMapScenario<S>
+{
     type Decomposition = EqualDecomposition<M, Self::Habitat>;
     type DecompositionAuxiliary = ();
     type DispersalSampler = InMemoryPackedSeparableAliasDispersalSampler<M, Self::Habitat, G>;
@@ -56,7 +68,7 @@ impl<M: MathsCore, G: RngCore<M>> Scenario<M, G> for SpatiallyExplicitTurnoverMa
     type LineageStore<L: LineageStore<M, Self::Habitat>> = L;
     type OriginSampler<'h, I: Iterator<Item = u64>> = InMemoryOriginSampler<'h, M, I> where G: 'h;
     type OriginSamplerAuxiliary = ();
-    type SpeciationProbability = UniformSpeciationProbability;
+    type SpeciationProbability = S;
     type TurnoverRate = InMemoryTurnoverRate;
 
     fn new(
@@ -67,13 +79,16 @@ impl<M: MathsCore, G: RngCore<M>> Scenario<M, G> for SpatiallyExplicitTurnoverMa
             .ok_or(SpatiallyExplicitTurnoverMapScenarioError::EmptyHabitatMap)?;
         let turnover_rate = InMemoryTurnoverRate::new(args.turnover_map, &habitat)
             .map_err(SpatiallyExplicitTurnoverMapScenarioError::TurnoverMap)?;
-        let speciation_probability =
-            UniformSpeciationProbability::new(speciation_probability_per_generation.into());
-        let dispersal_sampler =
-            InMemoryPackedSeparableAliasDispersalSampler::new(&args.dispersal_map, &habitat)
-                .map_err(|err| {
-                    SpatiallyExplicitTurnoverMapScenarioError::DispersalMap(err.into())
-                })?;
+        let speciation_probability = S::try_new(
+            args.speciation_map,
+            speciation_probability_per_generation,
+            &habitat,
+        )
+        .map_err(SpatiallyExplicitTurnoverMapScenarioError::SpeciationMap)?;
+        let dispersal_sampler = args
+            .dispersal_map
+            .to_dispersal_sampler(&habitat)
+            .map_err(|err| SpatiallyExplicitTurnoverMapScenarioError::DispersalMap(err.into()))?;
 
         Ok(ScenarioCogs {
             habitat,
@@ -82,6 +97,7 @@ impl<M: MathsCore, G: RngCore<M>> Scenario<M, G> for SpatiallyExplicitTurnoverMa
             speciation_probability,
             origin_sampler_auxiliary: (),
             decomposition_auxiliary: (),
+            protracted_speciation: ProtractedSpeciation::instantaneous(),
             _marker: PhantomData::<(M, G, Self)>,
         })
     }
@@ -126,9 +142,11 @@ pub struct SpatiallyExplicitTurnoverMapArguments {
     pub habitat_path: PathBuf,
     pub habitat_map: Array2D<u32>,
     pub dispersal_path: PathBuf,
-    pub dispersal_map: Array2D<NonNegativeF64>,
+    pub dispersal_map: DispersalMap,
     pub turnover_path: PathBuf,
     pub turnover_map: Array2D<NonNegativeF64>,
+    pub speciation_path: Option<PathBuf>,
+    pub speciation_map: Option<Array2D<ClosedUnitF64>>,
     pub loading_mode: MapLoadingMode,
 }
 
@@ -138,6 +156,7 @@ impl SpatiallyExplicitTurnoverMapArguments {
         habitat_path: PathBuf,
         dispersal_path: PathBuf,
         turnover_path: PathBuf,
+        speciation_path: Option<PathBuf>,
         loading_mode: MapLoadingMode,
     ) -> Result<Self, String> {
         info!(
@@ -145,7 +164,9 @@ impl SpatiallyExplicitTurnoverMapArguments {
             dispersal_path
         );
 
-        let mut dispersal_map = maps::load_dispersal_map(&dispersal_path, loading_mode)
+        let mut grid = MapGrid::default();
+
+        let mut dispersal_map = maps::load_dispersal_map(&dispersal_path, loading_mode, &mut grid)
             .map_err(|err| format!("{err:?}"))?;
 
         info!(
@@ -157,7 +178,7 @@ impl SpatiallyExplicitTurnoverMapArguments {
 
         info!("Starting to load the turnover map {:?} ...", &turnover_path);
 
-        let turnover_map = maps::load_turnover_map(&turnover_path, loading_mode)
+        let turnover_map = maps::load_turnover_map(&turnover_path, loading_mode, &mut grid)
             .map_err(|err| format!("{err:?}"))?;
 
         info!(
@@ -174,6 +195,7 @@ impl SpatiallyExplicitTurnoverMapArguments {
             Some(&turnover_map),
             &mut dispersal_map,
             loading_mode,
+            &mut grid,
         )
         .map_err(|err| format!("{err:?}"))?;
 
@@ -184,6 +206,34 @@ impl SpatiallyExplicitTurnoverMapArguments {
             habitat_map.num_rows()
         );
 
+        let speciation_map = match &speciation_path {
+            Some(speciation_path) => {
+                info!(
+                    "Starting to load the speciation map {:?} ...",
+                    speciation_path
+                );
+
+                let speciation_map = maps::load_speciation_map(
+                    speciation_path,
+                    &habitat_map,
+                    loading_mode,
+                    &mut grid,
+                )
+                .map_err(|err| format!("{err:?}"))?;
+
+                info!(
+                    "Successfully loaded the speciation map {:?} with dimensions {}x{} [cols x \
+                     rows].",
+                    speciation_path,
+                    speciation_map.num_columns(),
+                    speciation_map.num_rows()
+                );
+
+                Some(speciation_map)
+            },
+            None => None,
+        };
+
         Ok(SpatiallyExplicitTurnoverMapArguments {
             habitat_path,
             habitat_map,
@@ -191,6 +241,8 @@ impl SpatiallyExplicitTurnoverMapArguments {
             dispersal_map,
             turnover_path,
             turnover_map,
+            speciation_path,
+            speciation_map,
             loading_mode,
         })
     }
@@ -202,6 +254,7 @@ impl Serialize for SpatiallyExplicitTurnoverMapArguments {
             habitat_map: self.habitat_path.clone(),
             dispersal_map: self.dispersal_path.clone(),
             turnover_map: self.turnover_path.clone(),
+            speciation: Speciation::from_path(self.speciation_path.as_ref()),
             loading_mode: self.loading_mode,
         }
         .serialize(serializer)
@@ -216,6 +269,7 @@ impl TryFrom<SpatiallyExplicitTurnoverMapArgumentsRaw> for SpatiallyExplicitTurn
             raw.habitat_map,
             raw.dispersal_map,
             raw.turnover_map,
+            raw.speciation.into_path(),
             raw.loading_mode,
         )
     }
@@ -235,6 +289,9 @@ struct SpatiallyExplicitTurnoverMapArgumentsRaw {
     #[serde(rename = "turnover", alias = "turnover_map")]
     turnover_map: PathBuf,
 
+    #[serde(default)]
+    speciation: Speciation,
+
     #[serde(default)]
     #[serde(rename = "mode", alias = "loading_mode")]
     loading_mode: MapLoadingMode,
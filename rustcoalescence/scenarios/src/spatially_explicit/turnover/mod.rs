@@ -1,13 +1,33 @@
+#![allow(non_local_definitions)] // FIXME: displaydoc
+
 use std::path::PathBuf;
 
 use either::Either;
 use serde::{Deserialize, Serialize};
 
+use necsim_core::cogs::{MathsCore, SpeciationProbability};
+use necsim_core_bond::{ClosedUnitF64, OpenClosedUnitF64 as PositiveUnitF64};
+
+use necsim_impls_no_std::{
+    array2d::Array2D,
+    cogs::{
+        habitat::in_memory::InMemoryHabitat,
+        speciation_probability::{
+            in_memory::{InMemorySpeciationProbability, InMemorySpeciationProbabilityError},
+            uniform::UniformSpeciationProbability,
+        },
+    },
+};
+
 use super::maps::MapLoadingMode;
 
 pub mod map;
 pub mod uniform;
 
+#[cfg(test)]
+#[cfg(feature = "spatially-explicit-uniform-turnover")]
+mod tests;
+
 #[derive(Debug, Serialize, Deserialize)]
 #[allow(clippy::module_name_repetitions)]
 #[serde(deny_unknown_fields)]
@@ -22,6 +42,9 @@ pub struct SpatiallyExplicitArguments {
     #[cfg_attr(feature = "spatially-explicit-uniform-turnover", serde(default))]
     turnover: Turnover,
 
+    #[serde(default)]
+    speciation: Speciation,
+
     #[serde(default)]
     #[serde(rename = "mode", alias = "loading_mode")]
     loading_mode: MapLoadingMode,
@@ -48,11 +71,13 @@ impl SpatiallyExplicitArguments {
                 habitat_map,
                 dispersal_map,
                 turnover: Turnover::UniformRate(turnover_rate),
+                speciation,
                 loading_mode,
             } => uniform::SpatiallyExplicitUniformTurnoverArguments::try_load(
                 habitat_map,
                 dispersal_map,
                 turnover_rate,
+                speciation.into_path(),
                 loading_mode,
             )
             .map(Either::Left),
@@ -61,11 +86,13 @@ impl SpatiallyExplicitArguments {
                 habitat_map,
                 dispersal_map,
                 turnover: Turnover::Map(turnover_map),
+                speciation,
                 loading_mode,
             } => map::SpatiallyExplicitTurnoverMapArguments::try_load(
                 habitat_map,
                 dispersal_map,
                 turnover_map,
+                speciation.into_path(),
                 loading_mode,
             )
             .map(Either::Right),
@@ -79,6 +106,7 @@ impl SpatiallyExplicitArguments {
             habitat_map: args.habitat_path.clone(),
             dispersal_map: args.dispersal_path.clone(),
             turnover: Turnover::UniformRate(args.turnover_rate),
+            speciation: Speciation::from_path(args.speciation_path.as_ref()),
             loading_mode: args.loading_mode,
         }
     }
@@ -90,6 +118,7 @@ impl SpatiallyExplicitArguments {
             habitat_map: args.habitat_path.clone(),
             dispersal_map: args.dispersal_path.clone(),
             turnover: Turnover::Map(args.turnover_path.clone()),
+            speciation: Speciation::from_path(args.speciation_path.as_ref()),
             loading_mode: args.loading_mode,
         }
     }
@@ -111,3 +140,86 @@ impl Default for Turnover {
         Self::UniformRate(necsim_core_bond::PositiveF64::new(0.5_f64).unwrap())
     }
 }
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub(super) enum Speciation {
+    Uniform,
+    Map(PathBuf),
+}
+
+impl Default for Speciation {
+    fn default() -> Self {
+        Self::Uniform
+    }
+}
+
+impl Speciation {
+    #[must_use]
+    pub(super) fn into_path(self) -> Option<PathBuf> {
+        match self {
+            Self::Uniform => None,
+            Self::Map(path) => Some(path),
+        }
+    }
+
+    #[must_use]
+    pub(super) fn from_path(path: Option<&PathBuf>) -> Self {
+        path.map_or(Self::Uniform, |path| Self::Map(path.clone()))
+    }
+}
+
+/// Speciation probability of the spatially explicit scenarios, which is
+///  either uniform or given by a speciation map
+pub trait SpatiallyExplicitSpeciationProbability<M: MathsCore>:
+    'static + Send + Clone + SpeciationProbability<M, InMemoryHabitat<M>>
+{
+    /// # Errors
+    ///
+    /// Returns a `SpeciationMapError` iff the `speciation_map` is missing,
+    ///  unexpected, or inconsistent with the `habitat`.
+    fn try_new(
+        speciation_map: Option<Array2D<ClosedUnitF64>>,
+        speciation_probability_per_generation: PositiveUnitF64,
+        habitat: &InMemoryHabitat<M>,
+    ) -> Result<Self, SpeciationMapError>;
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(thiserror::Error, displaydoc::Display, Debug)]
+pub enum SpeciationMapError {
+    /// {0}
+    Inconsistent(InMemorySpeciationProbabilityError),
+    /// a uniform speciation probability cannot use a speciation map
+    Unexpected,
+    /// the speciation map is missing
+    Missing,
+}
+
+impl<M: MathsCore> SpatiallyExplicitSpeciationProbability<M> for UniformSpeciationProbability {
+    fn try_new(
+        speciation_map: Option<Array2D<ClosedUnitF64>>,
+        speciation_probability_per_generation: PositiveUnitF64,
+        _habitat: &InMemoryHabitat<M>,
+    ) -> Result<Self, SpeciationMapError> {
+        match speciation_map {
+            Some(_) => Err(SpeciationMapError::Unexpected),
+            None => Ok(Self::new(speciation_probability_per_generation.into())),
+        }
+    }
+}
+
+impl<M: MathsCore> SpatiallyExplicitSpeciationProbability<M> for InMemorySpeciationProbability {
+    fn try_new(
+        speciation_map: Option<Array2D<ClosedUnitF64>>,
+        _speciation_probability_per_generation: PositiveUnitF64,
+        habitat: &InMemoryHabitat<M>,
+    ) -> Result<Self, SpeciationMapError> {
+        match speciation_map {
+            Some(speciation_map) => {
+                Self::new(speciation_map, habitat).map_err(SpeciationMapError::Inconsistent)
+            },
+            None => Err(SpeciationMapError::Missing),
+        }
+    }
+}
@@ -0,0 +1,157 @@
+use std::path::{Path, PathBuf};
+
+use tiff::encoder::{
+    colortype::{Gray32, Gray64Float},
+    TiffEncoder,
+};
+
+use necsim_core::{
+    cogs::{Habitat, SpeciationProbability},
+    landscape::Location,
+};
+use necsim_core_bond::{OpenClosedUnitF64 as PositiveUnitF64, PositiveF64};
+use necsim_impls_no_std::cogs::{
+    maths::intrinsics::IntrinsicsMathsCore, rng::wyhash::WyHash,
+    speciation_probability::in_memory::InMemorySpeciationProbability,
+};
+
+use crate::Scenario;
+
+use super::{
+    super::maps::MapLoadingMode,
+    uniform::{
+        SpatiallyExplicitUniformTurnoverArguments, SpatiallyExplicitUniformTurnoverScenario,
+    },
+};
+
+struct Maps {
+    habitat: PathBuf,
+    dispersal: PathBuf,
+    speciation: PathBuf,
+}
+
+impl Maps {
+    /// Writes a 2x2 habitat map with one non-habitat cell, a dispersal map
+    ///  with only self-dispersal, and the `speciation` map
+    fn write(name: &str, speciation: &[f64; 4]) -> Self {
+        let habitat = temp_path(name, "habitat");
+        let dispersal = temp_path(name, "dispersal");
+        let speciation_path = temp_path(name, "speciation");
+
+        let mut encoder = TiffEncoder::new(std::fs::File::create(&habitat).unwrap()).unwrap();
+        encoder
+            .write_image::<Gray32>(2, 2, &[1_u32, 0, 2, 1])
+            .unwrap();
+
+        let mut encoder = TiffEncoder::new(std::fs::File::create(&dispersal).unwrap()).unwrap();
+        #[rustfmt::skip]
+        encoder
+            .write_image::<Gray64Float>(4, 4, &[
+                1.0_f64, 0.0, 0.0, 0.0,
+                0.0, 0.0, 0.0, 0.0,
+                0.0, 0.0, 1.0, 0.0,
+                0.0, 0.0, 0.0, 1.0,
+            ])
+            .unwrap();
+
+        let mut encoder =
+            TiffEncoder::new(std::fs::File::create(&speciation_path).unwrap()).unwrap();
+        encoder
+            .write_image::<Gray64Float>(2, 2, speciation)
+            .unwrap();
+
+        Self {
+            habitat,
+            dispersal,
+            speciation: speciation_path,
+        }
+    }
+
+    fn try_load(&self) -> Result<SpatiallyExplicitUniformTurnoverArguments, String> {
+        SpatiallyExplicitUniformTurnoverArguments::try_load(
+            self.habitat.clone(),
+            self.dispersal.clone(),
+            PositiveF64::new(0.5_f64).unwrap(),
+            Some(self.speciation.clone()),
+            MapLoadingMode::Strict,
+        )
+    }
+}
+
+impl Drop for Maps {
+    fn drop(&mut self) {
+        for path in [&self.habitat, &self.dispersal, &self.speciation] {
+            let _ = std::fs::remove_file(path);
+        }
+    }
+}
+
+fn temp_path(name: &str, kind: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("{}-{name}-{kind}.tif", std::process::id()))
+}
+
+fn assert_speciation_map_error(name: &str, speciation: &[f64; 4], expected: &str) {
+    let error = Maps::write(name, speciation).try_load().unwrap_err();
+
+    assert!(error.contains(expected), "{error}");
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn test_speciation_map_per_location() {
+    let maps = Maps::write("speciation-map", &[0.25, 0.0, 0.5, 1.0]);
+    let args = maps.try_load().unwrap();
+
+    assert_eq!(
+        args.speciation_path.as_deref(),
+        Some(Path::new(&maps.speciation))
+    );
+
+    // The top-level speciation probability is ignored when a map is given
+    let cogs =
+        <SpatiallyExplicitUniformTurnoverScenario<InMemorySpeciationProbability> as Scenario<
+            IntrinsicsMathsCore,
+            WyHash<IntrinsicsMathsCore>,
+        >>::new(args, PositiveUnitF64::new(0.125_f64).unwrap())
+        .unwrap();
+
+    // The zero speciation probability is only valid outside the habitat
+    assert_eq!(
+        cogs.habitat.get_habitat_at_location(&Location::new(1, 0)),
+        0
+    );
+
+    for (location, speciation) in [
+        (Location::new(0, 0), 0.25_f64),
+        (Location::new(0, 1), 0.5_f64),
+        (Location::new(1, 1), 1.0_f64),
+    ] {
+        assert_eq!(
+            cogs.speciation_probability
+                .get_speciation_probability_at_location(&location, &cogs.habitat)
+                .get(),
+            speciation,
+            "at {location:?}"
+        );
+    }
+}
+
+#[test]
+fn test_speciation_map_out_of_range() {
+    assert_speciation_map_error(
+        "speciation-map-above-one",
+        &[0.25, 0.0, 1.5, 1.0],
+        "Speciation probability 1.5 at (0, 1) is not in (0.0, 1.0] on habitat",
+    );
+    assert_speciation_map_error(
+        "speciation-map-negative",
+        &[0.25, -0.5, 0.5, 1.0],
+        "Speciation probability -0.5 at (1, 0) is not in [0.0, 1.0]",
+    );
+    // Lineages on habitat without speciation would never finish
+    assert_speciation_map_error(
+        "speciation-map-zero-on-habitat",
+        &[0.25, 0.0, 0.0, 1.0],
+        "Speciation probability 0 at (0, 1) is not in (0.0, 1.0] on habitat",
+    );
+}
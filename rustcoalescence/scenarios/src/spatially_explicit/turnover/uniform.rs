@@ -5,16 +5,14 @@ use std::{convert::TryFrom, marker::PhantomData, path::PathBuf};
 use serde::{Deserialize, Serialize, Serializer};
 
 use necsim_core::cogs::{Habitat, LineageStore, MathsCore, RngCore};
-use necsim_core_bond::{NonNegativeF64, OpenClosedUnitF64 as PositiveUnitF64, PositiveF64};
+use necsim_core_bond::{ClosedUnitF64, OpenClosedUnitF64 as PositiveUnitF64, PositiveF64};
 use necsim_partitioning_core::partition::Partition;
 
 use necsim_impls_no_std::{
     array2d::Array2D,
     cogs::{
-        dispersal_sampler::in_memory::{
-            packed_separable_alias::InMemoryPackedSeparableAliasDispersalSampler,
-            InMemoryDispersalSampler,
-        },
+        dispersal_sampler::in_memory::packed_separable_alias::InMemoryPackedSeparableAliasDispersalSampler,
+        event_sampler::protracted::ProtractedSpeciation,
         habitat::in_memory::InMemoryHabitat,
         origin_sampler::{in_memory::InMemoryOriginSampler, pre_sampler::OriginPreSampler},
         speciation_probability::uniform::UniformSpeciationProbability,
@@ -27,7 +25,10 @@ use necsim_impls_std::cogs::dispersal_sampler::in_memory::InMemoryDispersalSampl
 
 use crate::{Scenario, ScenarioCogs, ScenarioParameters};
 
-use super::super::maps::{self, MapLoadingMode};
+use super::{
+    super::maps::{self, DispersalMap, MapGrid, MapLoadingMode},
+    SpatiallyExplicitSpeciationProbability, Speciation, SpeciationMapError,
+};
 
 #[allow(clippy::module_name_repetitions, clippy::enum_variant_names)]
 #[derive(thiserror::Error, displaydoc::Display, Debug)]
@@ -36,17 +37,26 @@ pub enum SpatiallyExplicitUniformTurnoverScenarioError {
     EmptyHabitatMap,
     /// invalid dispersal map: {0}
     DispersalMap(InMemoryDispersalSamplerError),
+    /// invalid speciation map: {0}
+    SpeciationMap(SpeciationMapError),
 }
 
-#[allow(clippy::module_name_repetitions, clippy::empty_enum)]
-pub enum SpatiallyExplicitUniformTurnoverScenario {}
+/// Scenario with a uniform turnover rate, see
+///  [`SpatiallyExplicitSpeciationProbability`] for the choices of `S`
+#[allow(clippy::module_name_repetitions)]
+pub struct SpatiallyExplicitUniformTurnoverScenario<S = UniformSpeciationProbability>(
+    PhantomData<S>,
+    !,
+);
 
-impl ScenarioParameters for SpatiallyExplicitUniformTurnoverScenario {
+impl<S> ScenarioParameters for SpatiallyExplicitUniformTurnoverScenario<S> {
     type Arguments = SpatiallyExplicitUniformTurnoverArguments;
     type Error = SpatiallyExplicitUniformTurnoverScenarioError;
 }
 
-impl<M: MathsCore, G: RngCore<M>> Scenario<M, G> for SpatiallyExplicitUniformTurnoverScenario {
+impl<M: MathsCore, G: RngCore<M>, S: SpatiallyExplicitSpeciationProbability<M>> Scenario<M, G>
+    for SpatiallyExplicitUniformTurnoverScenario<S>
+{
     type Decomposition = EqualDecomposition<M, Self::Habitat>;
     type DecompositionAuxiliary = ();
     type DispersalSampler = InMemoryPackedSeparableAliasDispersalSampler<M, Self::Habitat, G>;
@@ -54,7 +64,7 @@ impl<M: MathsCore, G: RngCore<M>> Scenario<M, G> for SpatiallyExplicitUniformTur
     type LineageStore<L: LineageStore<M, Self::Habitat>> = L;
     type OriginSampler<'h, I: Iterator<Item = u64>> = InMemoryOriginSampler<'h, M, I> where G: 'h;
     type OriginSamplerAuxiliary = ();
-    type SpeciationProbability = UniformSpeciationProbability;
+    type SpeciationProbability = S;
     type TurnoverRate = UniformTurnoverRate;
 
     fn new(
@@ -64,10 +74,15 @@ impl<M: MathsCore, G: RngCore<M>> Scenario<M, G> for SpatiallyExplicitUniformTur
         let habitat = InMemoryHabitat::try_new(args.habitat_map)
             .ok_or(SpatiallyExplicitUniformTurnoverScenarioError::EmptyHabitatMap)?;
         let turnover_rate = UniformTurnoverRate::new(args.turnover_rate);
-        let speciation_probability =
-            UniformSpeciationProbability::new(speciation_probability_per_generation.into());
+        let speciation_probability = S::try_new(
+            args.speciation_map,
+            speciation_probability_per_generation,
+            &habitat,
+        )
+        .map_err(SpatiallyExplicitUniformTurnoverScenarioError::SpeciationMap)?;
         let dispersal_sampler =
-            InMemoryPackedSeparableAliasDispersalSampler::new(&args.dispersal_map, &habitat)
+            args.dispersal_map
+                .to_dispersal_sampler(&habitat)
                 .map_err(|err| {
                     SpatiallyExplicitUniformTurnoverScenarioError::DispersalMap(err.into())
                 })?;
@@ -79,6 +94,7 @@ impl<M: MathsCore, G: RngCore<M>> Scenario<M, G> for SpatiallyExplicitUniformTur
             speciation_probability,
             origin_sampler_auxiliary: (),
             decomposition_auxiliary: (),
+            protracted_speciation: ProtractedSpeciation::instantaneous(),
             _marker: PhantomData::<(M, G, Self)>,
         })
     }
@@ -123,8 +139,10 @@ pub struct SpatiallyExplicitUniformTurnoverArguments {
     pub habitat_path: PathBuf,
     pub habitat_map: Array2D<u32>,
     pub dispersal_path: PathBuf,
-    pub dispersal_map: Array2D<NonNegativeF64>,
+    pub dispersal_map: DispersalMap,
     pub turnover_rate: PositiveF64,
+    pub speciation_path: Option<PathBuf>,
+    pub speciation_map: Option<Array2D<ClosedUnitF64>>,
     pub loading_mode: MapLoadingMode,
 }
 
@@ -134,6 +152,7 @@ impl SpatiallyExplicitUniformTurnoverArguments {
         habitat_path: PathBuf,
         dispersal_path: PathBuf,
         turnover_rate: PositiveF64,
+        speciation_path: Option<PathBuf>,
         loading_mode: MapLoadingMode,
     ) -> Result<Self, String> {
         info!(
@@ -141,7 +160,9 @@ impl SpatiallyExplicitUniformTurnoverArguments {
             dispersal_path
         );
 
-        let mut dispersal_map = maps::load_dispersal_map(&dispersal_path, loading_mode)
+        let mut grid = MapGrid::default();
+
+        let mut dispersal_map = maps::load_dispersal_map(&dispersal_path, loading_mode, &mut grid)
             .map_err(|err| format!("{err:?}"))?;
 
         info!(
@@ -153,9 +174,14 @@ impl SpatiallyExplicitUniformTurnoverArguments {
 
         info!("Starting to load the habitat map {:?} ...", habitat_path);
 
-        let habitat_map =
-            maps::load_habitat_map(&habitat_path, None, &mut dispersal_map, loading_mode)
-                .map_err(|err| format!("{err:?}"))?;
+        let habitat_map = maps::load_habitat_map(
+            &habitat_path,
+            None,
+            &mut dispersal_map,
+            loading_mode,
+            &mut grid,
+        )
+        .map_err(|err| format!("{err:?}"))?;
 
         info!(
             "Successfully loaded the habitat map {:?} with dimensions {}x{} [cols x rows].",
@@ -164,12 +190,42 @@ impl SpatiallyExplicitUniformTurnoverArguments {
             habitat_map.num_rows()
         );
 
+        let speciation_map = match &speciation_path {
+            Some(speciation_path) => {
+                info!(
+                    "Starting to load the speciation map {:?} ...",
+                    speciation_path
+                );
+
+                let speciation_map = maps::load_speciation_map(
+                    speciation_path,
+                    &habitat_map,
+                    loading_mode,
+                    &mut grid,
+                )
+                .map_err(|err| format!("{err:?}"))?;
+
+                info!(
+                    "Successfully loaded the speciation map {:?} with dimensions {}x{} [cols x \
+                     rows].",
+                    speciation_path,
+                    speciation_map.num_columns(),
+                    speciation_map.num_rows()
+                );
+
+                Some(speciation_map)
+            },
+            None => None,
+        };
+
         Ok(SpatiallyExplicitUniformTurnoverArguments {
             habitat_path,
             habitat_map,
             dispersal_path,
             dispersal_map,
             turnover_rate,
+            speciation_path,
+            speciation_map,
             loading_mode,
         })
     }
@@ -181,6 +237,7 @@ impl Serialize for SpatiallyExplicitUniformTurnoverArguments {
             habitat_map: self.habitat_path.clone(),
             dispersal_map: self.dispersal_path.clone(),
             turnover_rate: self.turnover_rate,
+            speciation: Speciation::from_path(self.speciation_path.as_ref()),
             loading_mode: self.loading_mode,
         }
         .serialize(serializer)
@@ -197,6 +254,7 @@ impl TryFrom<SpatiallyExplicitUniformTurnoverArgumentsRaw>
             raw.habitat_map,
             raw.dispersal_map,
             raw.turnover_rate,
+            raw.speciation.into_path(),
             raw.loading_mode,
         )
     }
@@ -217,6 +275,9 @@ struct SpatiallyExplicitUniformTurnoverArgumentsRaw {
     #[serde(default = "default_turnover_rate")]
     turnover_rate: PositiveF64,
 
+    #[serde(default)]
+    speciation: Speciation,
+
     #[serde(default)]
     #[serde(rename = "mode", alias = "loading_mode")]
     loading_mode: MapLoadingMode,
@@ -9,6 +9,7 @@ use necsim_partitioning_core::partition::Partition;
 use necsim_impls_no_std::{
     cogs::{
         dispersal_sampler::spatially_implicit::SpatiallyImplicitDispersalSampler,
+        event_sampler::protracted::ProtractedSpeciation,
         habitat::spatially_implicit::SpatiallyImplicitHabitat,
         origin_sampler::{
             pre_sampler::OriginPreSampler, spatially_implicit::SpatiallyImplicitOriginSampler,
@@ -77,6 +78,7 @@ impl<M: MathsCore, G: RngCore<M>> Scenario<M, G> for SpatiallyImplicitScenario {
             speciation_probability,
             origin_sampler_auxiliary: (),
             decomposition_auxiliary: (),
+            protracted_speciation: ProtractedSpeciation::instantaneous(),
             _marker: PhantomData::<(M, G, Self)>,
         })
     }
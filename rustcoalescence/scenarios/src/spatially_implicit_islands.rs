@@ -0,0 +1,145 @@
+#![allow(non_local_definitions)] // FIXME: displaydoc
+
+use std::{marker::PhantomData, num::NonZeroU32};
+
+use serde::{Deserialize, Serialize};
+
+use necsim_core::cogs::{LineageStore, MathsCore, RngCore};
+use necsim_core_bond::{ClosedUnitF64, OffByOneU32, OpenClosedUnitF64 as PositiveUnitF64};
+use necsim_partitioning_core::partition::Partition;
+
+use necsim_impls_no_std::{
+    cogs::{
+        dispersal_sampler::spatially_implicit_islands::{
+            SpatiallyImplicitIslandsDispersalSampler, SpatiallyImplicitIslandsDispersalSamplerError,
+        },
+        event_sampler::protracted::ProtractedSpeciation,
+        habitat::spatially_implicit_islands::{
+            SpatiallyImplicitIslandsHabitat, SpatiallyImplicitIslandsHabitatError,
+        },
+        origin_sampler::{
+            pre_sampler::OriginPreSampler,
+            spatially_implicit_islands::SpatiallyImplicitIslandsOriginSampler,
+        },
+        speciation_probability::spatially_implicit_islands::SpatiallyImplicitIslandsSpeciationProbability,
+        turnover_rate::uniform::UniformTurnoverRate,
+    },
+    decomposition::modulo::ModuloDecomposition,
+};
+
+use crate::{Scenario, ScenarioCogs, ScenarioParameters};
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(thiserror::Error, displaydoc::Display, Debug)]
+pub enum SpatiallyImplicitIslandsScenarioError {
+    /// invalid islands: {0}
+    Habitat(SpatiallyImplicitIslandsHabitatError),
+    /// invalid island migration: {0}
+    Migration(SpatiallyImplicitIslandsDispersalSamplerError),
+}
+
+#[allow(clippy::module_name_repetitions, clippy::empty_enum)]
+pub enum SpatiallyImplicitIslandsScenario {}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[allow(clippy::module_name_repetitions)]
+#[serde(deny_unknown_fields)]
+#[serde(rename = "SpatiallyImplicitIslands")]
+pub struct SpatiallyImplicitIslandsArguments {
+    pub islands: Vec<IslandArguments>,
+    pub meta_area: (OffByOneU32, OffByOneU32),
+    pub meta_deme: NonZeroU32,
+
+    /// Optional square matrix of the per-generation probabilities with which
+    ///  a lineage on the island of the row migrates to the island of the
+    ///  column
+    #[serde(default)]
+    pub island_migration: Option<Vec<Vec<ClosedUnitF64>>>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+#[serde(rename = "Island")]
+pub struct IslandArguments {
+    pub area: (OffByOneU32, OffByOneU32),
+    pub deme: NonZeroU32,
+
+    #[serde(alias = "migration")]
+    pub migration_probability_per_generation: PositiveUnitF64,
+}
+
+impl ScenarioParameters for SpatiallyImplicitIslandsScenario {
+    type Arguments = SpatiallyImplicitIslandsArguments;
+    type Error = SpatiallyImplicitIslandsScenarioError;
+}
+
+impl<M: MathsCore, G: RngCore<M>> Scenario<M, G> for SpatiallyImplicitIslandsScenario {
+    type Decomposition = ModuloDecomposition;
+    type DecompositionAuxiliary = ();
+    type DispersalSampler = SpatiallyImplicitIslandsDispersalSampler<M, G>;
+    type Habitat = SpatiallyImplicitIslandsHabitat<M>;
+    type LineageStore<L: LineageStore<M, Self::Habitat>> = L;
+    type OriginSampler<'h, I: Iterator<Item = u64>> = SpatiallyImplicitIslandsOriginSampler<'h, M, I> where G: 'h;
+    type OriginSamplerAuxiliary = ();
+    type SpeciationProbability = SpatiallyImplicitIslandsSpeciationProbability;
+    type TurnoverRate = UniformTurnoverRate;
+
+    fn new(
+        args: Self::Arguments,
+        speciation_probability_per_generation: PositiveUnitF64,
+    ) -> Result<ScenarioCogs<M, G, Self>, Self::Error> {
+        let islands = args
+            .islands
+            .iter()
+            .map(|island| (island.area, island.deme))
+            .collect::<Vec<_>>();
+        let meta_migration = args
+            .islands
+            .iter()
+            .map(|island| island.migration_probability_per_generation)
+            .collect::<Vec<_>>();
+
+        let habitat =
+            SpatiallyImplicitIslandsHabitat::try_new(&islands, args.meta_area, args.meta_deme)
+                .map_err(SpatiallyImplicitIslandsScenarioError::Habitat)?;
+        let dispersal_sampler = SpatiallyImplicitIslandsDispersalSampler::try_new(
+            &meta_migration,
+            args.island_migration.as_deref(),
+        )
+        .map_err(SpatiallyImplicitIslandsScenarioError::Migration)?;
+        let turnover_rate = UniformTurnoverRate::default();
+        let speciation_probability = SpatiallyImplicitIslandsSpeciationProbability::new(
+            speciation_probability_per_generation,
+        );
+
+        Ok(ScenarioCogs {
+            habitat,
+            dispersal_sampler,
+            turnover_rate,
+            speciation_probability,
+            origin_sampler_auxiliary: (),
+            decomposition_auxiliary: (),
+            protracted_speciation: ProtractedSpeciation::instantaneous(),
+            _marker: PhantomData::<(M, G, Self)>,
+        })
+    }
+
+    fn sample_habitat<'h, I: Iterator<Item = u64>>(
+        habitat: &'h Self::Habitat,
+        pre_sampler: OriginPreSampler<M, I>,
+        _auxiliary: Self::OriginSamplerAuxiliary,
+    ) -> Self::OriginSampler<'h, I>
+    where
+        G: 'h,
+    {
+        SpatiallyImplicitIslandsOriginSampler::new(pre_sampler, habitat)
+    }
+
+    fn decompose(
+        _habitat: &Self::Habitat,
+        subdomain: Partition,
+        _auxiliary: Self::DecompositionAuxiliary,
+    ) -> Self::Decomposition {
+        ModuloDecomposition::new(subdomain)
+    }
+}
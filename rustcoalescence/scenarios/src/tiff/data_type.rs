@@ -0,0 +1,154 @@
+use tiff::decoder::DecodingResult;
+
+#[allow(clippy::module_name_repetitions)]
+pub trait TiffDataType: Clone + std::fmt::Debug + Default + PartialEq {
+    /// Converts a finite TIFF sample, which has been widened to `f64`, into
+    /// `Self`, or returns `None` iff the sample cannot be represented.
+    ///
+    /// If `strict` is `true`, the conversion must be exact.
+    fn try_from_sample(sample: f64, strict: bool) -> Option<Self>;
+}
+
+impl TiffDataType for u32 {
+    fn try_from_sample(sample: f64, strict: bool) -> Option<Self> {
+        // Floating point habitat maps may contain rounding errors
+        let sample = if strict { sample } else { sample.round() };
+
+        if sample.fract() != 0.0_f64 || !(0.0_f64..=f64::from(u32::MAX)).contains(&sample) {
+            return None;
+        }
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let sample = sample as u32;
+
+        Some(sample)
+    }
+}
+
+impl TiffDataType for bool {
+    #[allow(clippy::float_cmp)]
+    fn try_from_sample(sample: f64, strict: bool) -> Option<Self> {
+        if strict && sample != 0.0_f64 && sample != 1.0_f64 {
+            return None;
+        }
+
+        Some(sample != 0.0_f64)
+    }
+}
+
+impl TiffDataType for f64 {
+    fn try_from_sample(sample: f64, _strict: bool) -> Option<Self> {
+        Some(sample)
+    }
+}
+
+/// Widens all samples to `f64`, where samples that are equal to the
+/// `no_data` value are returned as `None`.
+///
+/// The `no_data` value is parsed with the sample type of the image, such that
+/// it is compared at the precision at which it was written.
+pub fn decoding_result_to_samples(
+    result: DecodingResult,
+    no_data: Option<&str>,
+) -> anyhow::Result<(Vec<Option<f64>>, &'static str)> {
+    match result {
+        DecodingResult::U8(data) => widen_samples(data, no_data),
+        DecodingResult::U16(data) => widen_samples(data, no_data),
+        DecodingResult::U32(data) => widen_samples(data, no_data),
+        DecodingResult::U64(data) => widen_samples(data, no_data),
+        DecodingResult::I8(data) => widen_samples(data, no_data),
+        DecodingResult::I16(data) => widen_samples(data, no_data),
+        DecodingResult::I32(data) => widen_samples(data, no_data),
+        DecodingResult::I64(data) => widen_samples(data, no_data),
+        DecodingResult::F32(data) => widen_samples(data, no_data),
+        DecodingResult::F64(data) => widen_samples(data, no_data),
+    }
+}
+
+fn widen_samples<S: TiffSample>(
+    data: Vec<S>,
+    no_data: Option<&str>,
+) -> anyhow::Result<(Vec<Option<f64>>, &'static str)> {
+    let no_data = match no_data {
+        Some(no_data) => Some(S::parse_no_data(no_data).ok_or_else(|| {
+            anyhow::anyhow!(
+                "Could not interpret GDAL nodata value {:?} as {}.",
+                no_data,
+                std::any::type_name::<S>()
+            )
+        })?),
+        None => None,
+    };
+
+    let samples = data
+        .into_iter()
+        .map(|sample| match no_data {
+            Some(no_data) if sample.is_no_data(no_data) => None,
+            _ => Some(sample.widen()),
+        })
+        .collect();
+
+    Ok((samples, std::any::type_name::<S>()))
+}
+
+trait TiffSample: Copy {
+    fn parse_no_data(no_data: &str) -> Option<Self>;
+
+    fn is_no_data(self, no_data: Self) -> bool;
+
+    fn widen(self) -> f64;
+}
+
+macro_rules! impl_integer_tiff_sample {
+    ($($ty:ty),*) => {
+        $(impl TiffSample for $ty {
+            fn parse_no_data(no_data: &str) -> Option<Self> {
+                // GDAL may write integer nodata values in floating point notation
+                no_data.parse().ok().or_else(|| {
+                    let no_data: f64 = no_data.parse().ok()?;
+
+                    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                    let value = no_data as Self;
+
+                    #[allow(clippy::cast_precision_loss, clippy::cast_lossless, clippy::float_cmp)]
+                    let is_exact = value as f64 == no_data;
+
+                    is_exact.then_some(value)
+                })
+            }
+
+            fn is_no_data(self, no_data: Self) -> bool {
+                self == no_data
+            }
+
+            #[allow(clippy::cast_precision_loss, clippy::cast_lossless)]
+            fn widen(self) -> f64 {
+                self as f64
+            }
+        })*
+    };
+}
+
+impl_integer_tiff_sample! { u8, u16, u32, u64, i8, i16, i32, i64 }
+
+macro_rules! impl_float_tiff_sample {
+    ($($ty:ty),*) => {
+        $(impl TiffSample for $ty {
+            fn parse_no_data(no_data: &str) -> Option<Self> {
+                no_data.parse().ok()
+            }
+
+            #[allow(clippy::float_cmp)]
+            fn is_no_data(self, no_data: Self) -> bool {
+                self == no_data || (self.is_nan() && no_data.is_nan())
+            }
+
+            #[allow(clippy::cast_lossless)]
+            fn widen(self) -> f64 {
+                self as f64
+            }
+        })*
+    };
+}
+
+impl_float_tiff_sample! { f32, f64 }
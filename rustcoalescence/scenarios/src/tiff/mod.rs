@@ -0,0 +1,206 @@
+use std::{fs::File, path::Path};
+
+use anyhow::{Context, Result};
+use tiff::{
+    decoder::{Decoder, Limits},
+    tags::Tag,
+};
+
+use necsim_impls_no_std::array2d::Array2D;
+
+mod data_type;
+
+#[allow(clippy::module_name_repetitions)]
+pub trait TiffDataType: data_type::TiffDataType {}
+impl<T: data_type::TiffDataType> TiffDataType for T {}
+
+/// Georeferencing of a north-up `GeoTIFF` raster, read from its `ModelTiepoint`
+/// and `ModelPixelScale` tags
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GeoTransform {
+    /// Model-space coordinates of the top-left corner of the raster
+    pub origin: (f64, f64),
+    /// Model-space size of a single pixel
+    pub pixel_size: (f64, f64),
+}
+
+impl GeoTransform {
+    /// Checks whether both rasters are aligned on the same grid, up to a
+    /// small fraction of a pixel.
+    #[must_use]
+    pub fn is_aligned_with(&self, other: &Self) -> bool {
+        let tolerance_x = self.pixel_size.0.abs() * 1e-6_f64;
+        let tolerance_y = self.pixel_size.1.abs() * 1e-6_f64;
+
+        (self.origin.0 - other.origin.0).abs() <= tolerance_x
+            && (self.origin.1 - other.origin.1).abs() <= tolerance_y
+            && (self.pixel_size.0 - other.pixel_size.0).abs() <= tolerance_x
+            && (self.pixel_size.1 - other.pixel_size.1).abs() <= tolerance_y
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+/// Loads a 2D map from TIFF file at `path` and converts it to the data type
+/// `D`, together with its optional `GeoTIFF` georeferencing.
+///
+/// This function assumes that normal, non-sparse TIFF files are read.
+///
+/// Any numeric sample type is accepted and converted to `D`:
+/// - values equal to the GDAL no data value are replaced with `D::default()`,
+///   e.g. with zero habitat
+/// - all other values must be finite
+/// - integer maps require non-negative in-range values, which, unless
+///   `strict_load` is `true`, are rounded to the nearest integer
+///
+/// Furthermore, only the first image is read and any subsequent ones are
+/// currently ignored.
+///
+/// # Errors
+///
+/// Fails if the file cannot be read, is not a single-channel grayscale TIFF
+/// image, or contains samples which cannot be converted to `D`.
+pub fn load_map_from_tiff<D: TiffDataType>(
+    path: &Path,
+    strict_load: bool,
+) -> Result<(Array2D<D>, Option<GeoTransform>)> {
+    let file = File::open(path).context("Could not read file.")?;
+
+    // Set no limit to the map file size, users can deal with OOM on setup
+    let mut decoder_limits = Limits::default();
+    decoder_limits.decoding_buffer_size = usize::MAX;
+
+    let mut decoder = Decoder::new(file)
+        .context("Could not decode TIFF file.")?
+        .with_limits(decoder_limits);
+
+    let colortype = decoder
+        .colortype()
+        .context("Could not read image colour type.")?;
+
+    anyhow::ensure!(
+        matches!(colortype, tiff::ColorType::Gray(_)),
+        format!("Image data format {colortype:?} is not a single-channel grayscale format.")
+    );
+
+    if let Some(val) = decoder
+        .find_tag_unsigned(Tag::SamplesPerPixel)
+        .context("Could not read SamplesPerPixel tag.")?
+    {
+        let samples_per_pixel: u8 = val;
+
+        anyhow::ensure!(
+            samples_per_pixel == 1_u8,
+            format!("Image must only have one sample per pixel but has {samples_per_pixel}.")
+        );
+    }
+
+    let (width, height) = decoder
+        .dimensions()
+        .context("Could not read image dimensions.")?;
+
+    let georeference = read_geo_transform(&mut decoder)?;
+
+    // The GDAL no data value is always honoured, strict loading only applies
+    //  to the conversion of all other samples
+    let no_data = decoder
+        .get_tag_ascii_string(Tag::GdalNodata)
+        .ok()
+        .map(|no_data| {
+            no_data
+                .trim_matches(|c: char| c.is_whitespace() || c == '\0')
+                .to_owned()
+        });
+
+    let any_image = decoder
+        .read_image()
+        .context("Could not decode the image.")?;
+
+    let (samples, sample_type) =
+        data_type::decoding_result_to_samples(any_image, no_data.as_deref())?;
+
+    if let Some(no_data) = &no_data {
+        info!("Used GDAL no data value {:?} ...", no_data);
+    }
+
+    let image_data = samples
+        .into_iter()
+        .enumerate()
+        .map(|(i, sample)| match sample {
+            None => Ok(D::default()),
+            Some(sample) if sample.is_finite() => D::try_from_sample(sample, strict_load)
+                .ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "Sample {} of type {} at ({}, {}) cannot be converted to {}.",
+                        sample,
+                        sample_type,
+                        i % (width as usize),
+                        i / (width as usize),
+                        std::any::type_name::<D>()
+                    )
+                }),
+            Some(sample) => Err(anyhow::anyhow!(
+                "Sample {} of type {} at ({}, {}) is not finite.",
+                sample,
+                sample_type,
+                i % (width as usize),
+                i / (width as usize),
+            )),
+        })
+        .collect::<Result<Vec<D>>>()?;
+
+    let map =
+        Array2D::from_row_major(&image_data, height as usize, width as usize).map_err(|_| {
+            anyhow::anyhow!(
+                "Image contains {} pixels instead of {width} x {height}.",
+                image_data.len()
+            )
+        })?;
+
+    Ok((map, georeference))
+}
+
+fn read_geo_transform<R: std::io::Read + std::io::Seek>(
+    decoder: &mut Decoder<R>,
+) -> Result<Option<GeoTransform>> {
+    let pixel_scale = decoder
+        .find_tag(Tag::ModelPixelScaleTag)
+        .context("Could not read ModelPixelScale tag.")?
+        .map(tiff::decoder::ifd::Value::into_f64_vec)
+        .transpose()
+        .context("Could not read ModelPixelScale tag.")?;
+    let tiepoint = decoder
+        .find_tag(Tag::ModelTiepointTag)
+        .context("Could not read ModelTiepoint tag.")?
+        .map(tiff::decoder::ifd::Value::into_f64_vec)
+        .transpose()
+        .context("Could not read ModelTiepoint tag.")?;
+
+    let (pixel_scale, tiepoint) = match (pixel_scale, tiepoint) {
+        (None, None) => return Ok(None),
+        (Some(pixel_scale), Some(tiepoint)) => (pixel_scale, tiepoint),
+        (Some(_), None) => anyhow::bail!("GeoTIFF has a ModelPixelScale but no ModelTiepoint."),
+        (None, Some(_)) => anyhow::bail!("GeoTIFF has a ModelTiepoint but no ModelPixelScale."),
+    };
+
+    let [scale_x, scale_y, ..] = pixel_scale[..] else {
+        anyhow::bail!("GeoTIFF ModelPixelScale {pixel_scale:?} must have at least two values.")
+    };
+    // Only the first tiepoint (I, J, K, X, Y, Z) is used for north-up rasters
+    let [raster_i, raster_j, _, model_x, model_y, ..] = tiepoint[..] else {
+        anyhow::bail!("GeoTIFF ModelTiepoint {tiepoint:?} must have at least six values.")
+    };
+
+    anyhow::ensure!(
+        scale_x.is_finite() && scale_y.is_finite() && scale_x > 0.0_f64 && scale_y > 0.0_f64,
+        "GeoTIFF ModelPixelScale ({scale_x}, {scale_y}) must be positive and finite."
+    );
+
+    Ok(Some(GeoTransform {
+        origin: (
+            model_x - raster_i * scale_x,
+            // Raster rows go southwards while model coordinates go northwards
+            model_y + raster_j * scale_y,
+        ),
+        pixel_size: (scale_x, scale_y),
+    }))
+}
@@ -12,6 +12,7 @@ use necsim_partitioning_core::partition::Partition;
 use necsim_impls_no_std::{
     cogs::{
         dispersal_sampler::wrapping_noise::WrappingNoiseApproximateNormalDispersalSampler,
+        event_sampler::protracted::ProtractedSpeciation,
         habitat::wrapping_noise::WrappingNoiseHabitat,
         lineage_store::coherent::globally::singleton_demes::SingletonDemesLineageStore,
         origin_sampler::{
@@ -93,6 +94,7 @@ impl<M: MathsCore, G: RngCore<M>> Scenario<M, G> for WrappingNoiseScenario {
             speciation_probability,
             origin_sampler_auxiliary: (sample,),
             decomposition_auxiliary: (),
+            protracted_speciation: ProtractedSpeciation::instantaneous(),
             _marker: PhantomData::<(M, G, Self)>,
         })
     }
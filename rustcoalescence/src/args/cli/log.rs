@@ -0,0 +1,151 @@
+use std::{convert::TryFrom, path::PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use necsim_core::{event::TypedEvent, lineage::GlobalLineageReference};
+use necsim_core_bond::{NonNegativeF64, PositiveF64};
+
+use necsim_impls_std::event_log::{recorder::EventLogConfig, replay::EventLogReplay};
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename = "Log")]
+#[serde(deny_unknown_fields)]
+#[allow(clippy::module_name_repetitions)]
+pub struct LogArgs {
+    #[serde(alias = "event_log")]
+    pub log: EventLogReplay,
+    pub command: LogCommand,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+#[allow(clippy::module_name_repetitions)]
+pub enum LogCommand {
+    /// Prints the header summaries of all event log segments
+    Summary,
+    /// Checks that every segment is sorted and consistent with its header,
+    ///  and that the segments do not overlap in time
+    Validate {
+        #[serde(default = "default_allow_overlap")]
+        allow_overlap: bool,
+    },
+    /// Rewrites the (filtered) events into a new event log with fewer,
+    ///  larger, and non-overlapping segments
+    Merge {
+        output: EventLogConfig,
+        #[serde(default)]
+        filter: EventFilter,
+    },
+    /// Converts the (filtered) events into a single dataframe
+    Convert {
+        output: PathBuf,
+        #[serde(default)]
+        format: LogFormat,
+        #[serde(default)]
+        filter: EventFilter,
+    },
+}
+
+fn default_allow_overlap() -> bool {
+    true
+}
+
+#[derive(Copy, Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+#[allow(clippy::module_name_repetitions)]
+pub enum LogFormat {
+    #[default]
+    Csv,
+    /// Arrow IPC (Feather v2) file
+    #[serde(alias = "Feather")]
+    Arrow,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(try_from = "EventFilterRaw")]
+#[serde(rename = "EventFilter")]
+pub struct EventFilter {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    from: Option<NonNegativeF64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    until: Option<PositiveF64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    lineages: Option<Vec<GlobalLineageReference>>,
+}
+
+impl TryFrom<EventFilterRaw> for EventFilter {
+    type Error = String;
+
+    fn try_from(raw: EventFilterRaw) -> Result<Self, Self::Error> {
+        if let (Some(from), Some(until)) = (raw.from, raw.until) {
+            if from >= until {
+                return Err(format!(
+                    "the time window must not be empty, but from={from} >= until={until}"
+                ));
+            }
+        }
+
+        let lineages = raw.lineages.map(|mut lineages| {
+            lineages.sort_unstable();
+            lineages.dedup();
+            lineages
+        });
+
+        Ok(Self {
+            from: raw.from,
+            until: raw.until,
+            lineages,
+        })
+    }
+}
+
+impl EventFilter {
+    /// Checks if an event falls into the `[from, until)` time window and
+    ///  belongs to one of the selected lineages
+    #[must_use]
+    pub fn matches(&self, event: &TypedEvent) -> bool {
+        let (lineage, event_time) = match event {
+            TypedEvent::Speciation(speciation) => {
+                (&speciation.global_lineage_reference, speciation.event_time)
+            },
+            TypedEvent::Dispersal(dispersal) => {
+                (&dispersal.global_lineage_reference, dispersal.event_time)
+            },
+        };
+
+        if matches!(self.from, Some(from) if event_time < from) {
+            return false;
+        }
+
+        if !self.is_before_end(event_time) {
+            return false;
+        }
+
+        match &self.lineages {
+            Some(lineages) => lineages.binary_search(lineage).is_ok(),
+            None => true,
+        }
+    }
+
+    /// Checks if the `event_time` lies before the end of the time window,
+    ///  after which no more events of a sorted event log can match
+    #[must_use]
+    pub fn is_before_end(&self, event_time: PositiveF64) -> bool {
+        match self.until {
+            Some(until) => event_time < until,
+            None => true,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "EventFilter")]
+#[serde(deny_unknown_fields)]
+struct EventFilterRaw {
+    #[serde(default)]
+    from: Option<NonNegativeF64>,
+    #[serde(default)]
+    until: Option<PositiveF64>,
+    #[serde(default)]
+    lineages: Option<Vec<GlobalLineageReference>>,
+}
@@ -1,11 +1,13 @@
 use clap::Parser;
 
+pub mod log;
 pub mod replay;
 
 #[derive(Debug, Parser)]
 pub enum RustcoalescenceArgs {
     Simulate(CommandArgs),
     Replay(CommandArgs),
+    Log(CommandArgs),
 }
 
 #[derive(Debug, Parser)]
@@ -15,6 +17,7 @@ pub struct CommandArgs {
 }
 
 impl CommandArgs {
+    #[must_use]
     pub fn into_config_string(self) -> String {
         let config = self.args.join(" ");
         std::mem::drop(self);
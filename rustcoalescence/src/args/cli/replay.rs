@@ -1,9 +1,17 @@
-use serde::{Deserialize, Deserializer, Serialize};
+use std::{convert::TryFrom, fmt, num::NonZeroUsize, path::PathBuf};
+
+use anyhow::Context;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use necsim_core::landscape::{LandscapeExtent, Location};
+use necsim_core_bond::{NonNegativeF64, PositiveF64};
 
 use necsim_impls_std::event_log::replay::EventLogReplay;
 
 use necsim_plugins_core::import::{AnyReporterPluginVec, ReporterPluginLibrary};
 
+use rustcoalescence_scenarios::tiff;
+
 #[derive(Serialize, Debug)]
 #[serde(rename = "Replay")]
 #[allow(clippy::module_name_repetitions)]
@@ -11,6 +19,9 @@ pub struct ReplayArgs {
     #[serde(rename = "log", alias = "event_log")]
     pub event_log: EventLogReplay,
     pub mode: ReplayMode,
+    pub parallelism: ReplayParallelism,
+    pub time: Option<ReplayTimeWindow>,
+    pub region: Option<ReplayRegion>,
     pub reporters: AnyReporterPluginVec,
 }
 
@@ -28,6 +39,149 @@ impl Default for ReplayMode {
     }
 }
 
+#[derive(Copy, Clone, Debug, Default, Serialize, Deserialize)]
+#[allow(clippy::module_name_repetitions)]
+#[serde(deny_unknown_fields)]
+pub enum ReplayParallelism {
+    /// All reporters analyse all events in their global order on one thread
+    #[default]
+    Sequential,
+    /// Mergeable reporters analyse time shards of the event log on a pool
+    ///  of `threads`, while all other reporters still analyse all events in
+    ///  their global order afterwards
+    Parallel {
+        threads: NonZeroUsize,
+        #[serde(default)]
+        shards: Option<NonZeroUsize>,
+    },
+}
+
+/// Closed time window `[min, max]` outside of which events are not replayed
+#[derive(Copy, Clone, Debug, Serialize, Deserialize)]
+#[allow(clippy::module_name_repetitions)]
+#[serde(try_from = "(NonNegativeF64, PositiveF64)")]
+#[serde(into = "(NonNegativeF64, PositiveF64)")]
+pub struct ReplayTimeWindow {
+    min: NonNegativeF64,
+    max: PositiveF64,
+}
+
+impl TryFrom<(NonNegativeF64, PositiveF64)> for ReplayTimeWindow {
+    type Error = String;
+
+    fn try_from((min, max): (NonNegativeF64, PositiveF64)) -> Result<Self, Self::Error> {
+        if min.get() > max.get() {
+            return Err(format!(
+                "The replay time window ({min}, {max}) must not end before it starts."
+            ));
+        }
+
+        Ok(Self { min, max })
+    }
+}
+
+impl From<ReplayTimeWindow> for (NonNegativeF64, PositiveF64) {
+    fn from(window: ReplayTimeWindow) -> Self {
+        (window.min, window.max)
+    }
+}
+
+impl ReplayTimeWindow {
+    #[must_use]
+    pub fn contains(&self, time: PositiveF64) -> bool {
+        time.get() >= self.min.get() && time <= self.max
+    }
+
+    #[must_use]
+    pub fn overlaps(&self, min_time: PositiveF64, max_time: PositiveF64) -> bool {
+        max_time.get() >= self.min.get() && min_time <= self.max
+    }
+
+    #[must_use]
+    pub fn is_after_end(&self, time: PositiveF64) -> bool {
+        time > self.max
+    }
+}
+
+/// Subregion of the landscape, such that only events whose origin lies
+///  inside it are replayed
+#[derive(Debug, Serialize, Deserialize)]
+#[allow(clippy::module_name_repetitions)]
+#[serde(deny_unknown_fields)]
+pub enum ReplayRegion {
+    Rectangle(LandscapeExtent),
+    Circle { centre: Location, radius: u32 },
+    Mask(ReplayRegionMask),
+}
+
+impl ReplayRegion {
+    #[must_use]
+    pub fn contains(&self, location: &Location) -> bool {
+        match self {
+            Self::Rectangle(extent) => extent.contains(location),
+            Self::Circle { centre, radius } => {
+                location.wrapping_distance_squared(centre)
+                    <= u64::from(*radius) * u64::from(*radius)
+            },
+            Self::Mask(mask) => mask.contains(location),
+        }
+    }
+}
+
+/// Single-channel TIFF mask, in which all non-zero pixels belong to the
+///  region, and whose pixel `(x, y)` corresponds to the location `(x, y)`.
+///  Pixels with the GDAL no data value do not belong to the region.
+#[derive(Deserialize)]
+#[allow(clippy::module_name_repetitions)]
+#[serde(try_from = "PathBuf")]
+pub struct ReplayRegionMask {
+    path: PathBuf,
+    width: usize,
+    height: usize,
+    mask: Vec<bool>,
+}
+
+impl fmt::Debug for ReplayRegionMask {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct(stringify!(ReplayRegionMask))
+            .field("path", &self.path)
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Serialize for ReplayRegionMask {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.path.serialize(serializer)
+    }
+}
+
+impl TryFrom<PathBuf> for ReplayRegionMask {
+    type Error = anyhow::Error;
+
+    fn try_from(path: PathBuf) -> Result<Self, Self::Error> {
+        let (mask, _georeference) = tiff::load_map_from_tiff::<bool>(&path, false)
+            .with_context(|| format!("Failed to load the region mask from {path:?}."))?;
+
+        Ok(Self {
+            path,
+            width: mask.num_columns(),
+            height: mask.num_rows(),
+            mask: mask.into_row_major(),
+        })
+    }
+}
+
+impl ReplayRegionMask {
+    #[must_use]
+    pub fn contains(&self, location: &Location) -> bool {
+        let (x, y) = (location.x() as usize, location.y() as usize);
+
+        x < self.width && y < self.height && self.mask[y * self.width + x]
+    }
+}
+
 impl<'de> Deserialize<'de> for ReplayArgs {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -37,6 +191,9 @@ impl<'de> Deserialize<'de> for ReplayArgs {
 
         let event_log = raw.event_log;
         let mode = raw.mode;
+        let parallelism = raw.parallelism;
+        let time = raw.time;
+        let region = raw.region;
         let reporters = raw.reporters.into_iter().flatten().collect();
 
         let (report_speciation, report_dispersal) = match &reporters {
@@ -79,6 +236,9 @@ impl<'de> Deserialize<'de> for ReplayArgs {
             (Ok(()), _) => Ok(Self {
                 event_log,
                 mode,
+                parallelism,
+                time,
+                region,
                 reporters,
             }),
             (Err(error), ReplayMode::WarnOnly) => {
@@ -87,6 +247,9 @@ impl<'de> Deserialize<'de> for ReplayArgs {
                 Ok(Self {
                     event_log,
                     mode,
+                    parallelism,
+                    time,
+                    region,
                     reporters,
                 })
             },
@@ -104,5 +267,11 @@ struct ReplayArgsRaw {
     event_log: EventLogReplay,
     #[serde(default)]
     mode: ReplayMode,
+    #[serde(default)]
+    parallelism: ReplayParallelism,
+    #[serde(default)]
+    time: Option<ReplayTimeWindow>,
+    #[serde(default)]
+    region: Option<ReplayRegion>,
     reporters: Vec<ReporterPluginLibrary>,
 }
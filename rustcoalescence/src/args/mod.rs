@@ -1,3 +1 @@
 pub mod cli;
-pub mod config;
-pub mod utils;
@@ -1,2 +0,0 @@
-pub mod parse;
-pub mod ser;
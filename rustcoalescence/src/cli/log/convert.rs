@@ -0,0 +1,258 @@
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+};
+
+use anyhow::{Context, Result};
+use arrow2::{
+    array::{Array, PrimitiveArray, Utf8Array},
+    chunk::Chunk,
+    datatypes::{DataType, Field, Schema},
+    io::ipc,
+};
+
+use necsim_core::{event::TypedEvent, lineage::LineageInteraction};
+use necsim_impls_std::event_log::replay::EventLogReplay;
+use necsim_plugins_csv_core::columns::{CsvColumn, CsvEvent};
+
+use crate::args::cli::log::{EventFilter, LogFormat};
+
+/// Columns of the converted CSV event log, which match the Arrow schema
+const CSV_COLUMNS: [CsvColumn; 12] = [
+    CsvColumn::Reference,
+    CsvColumn::Time,
+    CsvColumn::PriorTime,
+    CsvColumn::X,
+    CsvColumn::Y,
+    CsvColumn::Index,
+    CsvColumn::TargetX,
+    CsvColumn::TargetY,
+    CsvColumn::TargetIndex,
+    CsvColumn::Interaction,
+    CsvColumn::Parent,
+    CsvColumn::Type,
+];
+
+/// Number of events that are written per Arrow record batch
+const ARROW_CHUNK_SIZE: usize = 1 << 16;
+
+#[allow(clippy::module_name_repetitions)]
+pub fn convert_events(
+    log: EventLogReplay,
+    output: &Path,
+    format: LogFormat,
+    filter: &EventFilter,
+) -> Result<()> {
+    let length = log.length();
+
+    info!("Converting {length} event(s) into {output:?} ...");
+
+    let file = File::options()
+        .write(true)
+        .create_new(true)
+        .open(output)
+        .with_context(|| format!("Failed to newly create the output file {output:?}."))?;
+    let writer = BufWriter::new(file);
+
+    let events = log
+        .take_while(|event| filter.is_before_end(event.event_time()))
+        .map(TypedEvent::from)
+        .filter(|event| filter.matches(event));
+
+    let converted = match format {
+        LogFormat::Csv => write_csv(writer, events)?,
+        LogFormat::Arrow => write_arrow(writer, events)?,
+    };
+
+    info!("Converted {converted} out of {length} event(s) into {output:?}.");
+
+    Ok(())
+}
+
+fn write_csv<W: Write>(mut writer: W, events: impl Iterator<Item = TypedEvent>) -> Result<usize> {
+    for (i, column) in CSV_COLUMNS.iter().enumerate() {
+        if i > 0 {
+            writer.write_all(b",")?;
+        }
+
+        writer.write_all(column.header().as_bytes())?;
+    }
+    writeln!(writer)?;
+
+    let mut converted = 0_usize;
+    let mut row = String::new();
+
+    for event in events {
+        let event = match &event {
+            TypedEvent::Speciation(speciation) => CsvEvent::Speciation(speciation),
+            TypedEvent::Dispersal(dispersal) => CsvEvent::Dispersal(dispersal),
+        };
+
+        row.clear();
+
+        for (i, column) in CSV_COLUMNS.iter().enumerate() {
+            if i > 0 {
+                row.push(',');
+            }
+
+            event.write_column(&mut row, *column)?;
+        }
+
+        row.push('\n');
+
+        writer.write_all(row.as_bytes())?;
+
+        converted += 1;
+    }
+
+    writer.flush()?;
+
+    Ok(converted)
+}
+
+fn write_arrow<W: Write>(writer: W, events: impl Iterator<Item = TypedEvent>) -> Result<usize> {
+    let schema = Schema::from(vec![
+        Field::new("reference", DataType::UInt64, false),
+        Field::new("time", DataType::Float64, false),
+        Field::new("prior_time", DataType::Float64, false),
+        Field::new("x", DataType::UInt32, false),
+        Field::new("y", DataType::UInt32, false),
+        Field::new("index", DataType::UInt32, false),
+        Field::new("target_x", DataType::UInt32, true),
+        Field::new("target_y", DataType::UInt32, true),
+        Field::new("target_index", DataType::UInt32, true),
+        Field::new("interaction", DataType::Utf8, true),
+        Field::new("parent", DataType::UInt64, true),
+        Field::new("type", DataType::Utf8, false),
+    ]);
+
+    let mut writer = ipc::write::FileWriter::new(
+        writer,
+        schema,
+        None,
+        ipc::write::WriteOptions { compression: None },
+    );
+    writer.start()?;
+
+    let mut converted = 0_usize;
+    let mut columns = ArrowColumns::default();
+
+    for event in events {
+        columns.push(&event);
+        converted += 1;
+
+        if columns.len() >= ARROW_CHUNK_SIZE {
+            writer.write(&std::mem::take(&mut columns).into_chunk()?, None)?;
+        }
+    }
+
+    if !columns.is_empty() || converted == 0 {
+        writer.write(&columns.into_chunk()?, None)?;
+    }
+
+    writer.finish()?;
+
+    Ok(converted)
+}
+
+#[derive(Default)]
+struct ArrowColumns {
+    reference: Vec<u64>,
+    time: Vec<f64>,
+    prior_time: Vec<f64>,
+    x: Vec<u32>,
+    y: Vec<u32>,
+    index: Vec<u32>,
+    target_x: Vec<Option<u32>>,
+    target_y: Vec<Option<u32>>,
+    target_index: Vec<Option<u32>>,
+    interaction: Vec<Option<&'static str>>,
+    parent: Vec<Option<u64>>,
+    r#type: Vec<&'static str>,
+}
+
+impl ArrowColumns {
+    fn len(&self) -> usize {
+        self.reference.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.reference.is_empty()
+    }
+
+    fn push(&mut self, event: &TypedEvent) {
+        let (reference, event_time, prior_time, origin) = match event {
+            TypedEvent::Speciation(speciation) => (
+                &speciation.global_lineage_reference,
+                speciation.event_time,
+                speciation.prior_time,
+                &speciation.origin,
+            ),
+            TypedEvent::Dispersal(dispersal) => (
+                &dispersal.global_lineage_reference,
+                dispersal.event_time,
+                dispersal.prior_time,
+                &dispersal.origin,
+            ),
+        };
+
+        self.reference
+            .push(unsafe { reference.clone().into_inner() });
+        self.time.push(event_time.get());
+        self.prior_time.push(prior_time.get());
+        self.x.push(origin.location().x());
+        self.y.push(origin.location().y());
+        self.index.push(origin.index());
+
+        match event {
+            TypedEvent::Speciation(_) => {
+                self.target_x.push(None);
+                self.target_y.push(None);
+                self.target_index.push(None);
+                self.interaction.push(None);
+                self.parent.push(None);
+                self.r#type.push("s");
+            },
+            TypedEvent::Dispersal(dispersal) => {
+                self.target_x.push(Some(dispersal.target.location().x()));
+                self.target_y.push(Some(dispersal.target.location().y()));
+                self.target_index.push(Some(dispersal.target.index()));
+                self.interaction
+                    .push(Some(interaction_name(&dispersal.interaction)));
+                self.parent.push(
+                    dispersal
+                        .interaction
+                        .parent()
+                        .map(|parent| unsafe { parent.into_inner() }),
+                );
+                self.r#type.push("d");
+            },
+        }
+    }
+
+    fn into_chunk(self) -> arrow2::error::Result<Chunk<Box<dyn Array>>> {
+        Chunk::try_new(vec![
+            PrimitiveArray::from_vec(self.reference).boxed(),
+            PrimitiveArray::from_vec(self.time).boxed(),
+            PrimitiveArray::from_vec(self.prior_time).boxed(),
+            PrimitiveArray::from_vec(self.x).boxed(),
+            PrimitiveArray::from_vec(self.y).boxed(),
+            PrimitiveArray::from_vec(self.index).boxed(),
+            PrimitiveArray::from(self.target_x).boxed(),
+            PrimitiveArray::from(self.target_y).boxed(),
+            PrimitiveArray::from(self.target_index).boxed(),
+            Utf8Array::<i32>::from(self.interaction).boxed(),
+            PrimitiveArray::from(self.parent).boxed(),
+            Utf8Array::<i32>::from_slice(self.r#type).boxed(),
+        ])
+    }
+}
+
+fn interaction_name(interaction: &LineageInteraction) -> &'static str {
+    match interaction {
+        LineageInteraction::None => "none",
+        LineageInteraction::Maybe => "maybe",
+        LineageInteraction::Coalescence(_) => "coalescence",
+    }
+}
@@ -0,0 +1,55 @@
+use anyhow::Result;
+
+use necsim_core::event::TypedEvent;
+use necsim_impls_std::event_log::{recorder::EventLogConfig, replay::EventLogReplay};
+
+use crate::args::cli::log::EventFilter;
+
+/// Replays the sorted `log` into a new event log, whose segments are thus
+///  sorted and non-overlapping
+#[allow(clippy::module_name_repetitions)]
+pub fn merge_segments(
+    log: EventLogReplay,
+    output: EventLogConfig,
+    filter: &EventFilter,
+) -> Result<()> {
+    let length = log.length();
+
+    let mut recorder = output.create()?;
+    recorder.set_event_filter(log.with_speciation(), log.with_dispersal());
+
+    info!(
+        "Merging {length} event(s) into the event log at {:?} ...",
+        recorder.directory()
+    );
+
+    let mut merged = 0_usize;
+
+    for event in log {
+        if !filter.is_before_end(event.event_time()) {
+            break;
+        }
+
+        let event = event.into();
+
+        if !filter.matches(&event) {
+            continue;
+        }
+
+        match event {
+            TypedEvent::Speciation(event) => recorder.record_speciation(&event),
+            TypedEvent::Dispersal(event) => recorder.record_dispersal(&event),
+        }
+
+        merged += 1;
+    }
+
+    let directory = recorder.directory().to_owned();
+
+    // Dropping the recorder writes out its final segment
+    std::mem::drop(recorder);
+
+    info!("Merged {merged} out of {length} event(s) into the event log at {directory:?}.");
+
+    Ok(())
+}
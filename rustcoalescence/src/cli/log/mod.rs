@@ -0,0 +1,54 @@
+use anyhow::{Context, Result};
+use log::LevelFilter;
+
+use rustcoalescence_api::utils::parse::{try_parse, try_print};
+
+use crate::args::cli::{
+    log::{LogArgs, LogCommand},
+    CommandArgs,
+};
+
+mod convert;
+mod merge;
+mod summary;
+mod validate;
+
+/// # Errors
+///
+/// Returns an error iff parsing the log config, reading the event log, or
+///  writing the merged or converted events failed, or if validating the event
+///  log found any inconsistencies.
+#[allow(clippy::module_name_repetitions)]
+pub fn log_with_logger(log_args: CommandArgs) -> Result<()> {
+    log::set_max_level(LevelFilter::Info);
+
+    let log_args: LogArgs = try_parse("log", &log_args.into_config_string())?;
+
+    let config_str = try_print(&log_args).context("Failed to normalise the event log config.")?;
+
+    println!("\n{:=^80}\n", " Log Configuration ");
+    println!("{}", config_str.trim_start_matches("Log"));
+    println!("\n{:=^80}\n", " Log Configuration ");
+
+    match log_args.command {
+        LogCommand::Summary => {
+            summary::print_summary(&log_args.log);
+
+            Ok(())
+        },
+        LogCommand::Validate { allow_overlap } => {
+            validate::validate_segments(&log_args.log, allow_overlap)
+        },
+        LogCommand::Merge { output, filter } => {
+            merge::merge_segments(log_args.log, output, &filter)
+        },
+        LogCommand::Convert {
+            output,
+            format,
+            filter,
+        } => convert::convert_events(log_args.log, &output, format, &filter),
+    }
+}
+
+#[cfg(test)]
+mod tests;
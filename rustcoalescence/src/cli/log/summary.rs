@@ -0,0 +1,58 @@
+use necsim_impls_std::event_log::replay::{segment::SortedSegment, EventLogReplay};
+
+#[allow(clippy::module_name_repetitions)]
+pub fn print_summary(log: &EventLogReplay) {
+    let mut segments = log
+        .segment_groups()
+        .flatten()
+        .collect::<Vec<&SortedSegment>>();
+    segments.sort_unstable_by_key(|segment| segment.path());
+
+    println!("{:=^80}\n", " Event Log Segments ");
+
+    for segment in &segments {
+        let header = segment.header();
+
+        println!(
+            "{:?}: {} event(s) in [{}, {}]{}{}, {:?} compression",
+            segment.path(),
+            header.length(),
+            header.min_time().get(),
+            header.max_time().get(),
+            if header.with_speciation() {
+                ", speciation"
+            } else {
+                ""
+            },
+            if header.with_dispersal() {
+                ", dispersal"
+            } else {
+                ""
+            },
+            header.compression(),
+        );
+    }
+
+    println!("\n{:=^80}\n", " Event Log Summary ");
+
+    let min_time = segments
+        .iter()
+        .map(|segment| segment.header().min_time().get())
+        .fold(f64::INFINITY, f64::min);
+    let max_time = segments
+        .iter()
+        .map(|segment| segment.header().max_time().get())
+        .fold(f64::NEG_INFINITY, f64::max);
+
+    println!("Segments:        {}", segments.len());
+    println!("Events:          {}", log.length());
+    println!("Time range:      [{min_time}, {max_time}]");
+    println!("Speciation:      {}", log.with_speciation());
+    println!("Dispersal:       {}", log.with_dispersal());
+    println!(
+        "Replay groups:   {} (of non-overlapping segments)",
+        log.segment_groups().count()
+    );
+
+    println!("\n{:=^80}\n", " Event Log Summary ");
+}
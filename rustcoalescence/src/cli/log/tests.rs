@@ -0,0 +1,320 @@
+use std::{
+    fs::{self, File},
+    num::NonZeroUsize,
+    path::{Path, PathBuf},
+};
+
+use anyhow::Result;
+use arrow2::io::ipc;
+use clap::Parser;
+
+use necsim_core::{
+    event::{DispersalEvent, PackedEvent, SpeciationEvent, TypedEvent},
+    landscape::{IndexedLocation, Location},
+    lineage::{GlobalLineageReference, LineageInteraction},
+};
+use necsim_core_bond::{ClosedUnitF64, NonNegativeF64, PositiveF64};
+use necsim_impls_std::event_log::{
+    recorder::EventLogRecorder, replay::EventLogReplay, EventLogCompression,
+};
+
+use rustcoalescence_api::utils::parse::try_parse;
+
+use crate::args::cli::RustcoalescenceArgs;
+
+use super::log_with_logger;
+
+struct TempDir(PathBuf);
+
+impl TempDir {
+    fn new(name: &str) -> Self {
+        let directory =
+            std::env::temp_dir().join(format!("rustcoalescence-log-{}-{name}", std::process::id()));
+
+        std::mem::drop(fs::remove_dir_all(&directory));
+        fs::create_dir_all(&directory).unwrap();
+
+        Self(directory)
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        std::mem::drop(fs::remove_dir_all(&self.0));
+    }
+}
+
+/// Runs `rustcoalescence log <config>`
+fn log(config: &str) -> Result<()> {
+    let RustcoalescenceArgs::Log(log_args) =
+        RustcoalescenceArgs::try_parse_from(["rustcoalescence", "log", config])?
+    else {
+        anyhow::bail!("not a log command");
+    };
+
+    log_with_logger(log_args)
+}
+
+fn lineage(inner: u64) -> GlobalLineageReference {
+    unsafe { GlobalLineageReference::from_inner(inner) }
+}
+
+fn dispersal(reference: u64, event_time: f64, interaction: LineageInteraction) -> DispersalEvent {
+    DispersalEvent {
+        global_lineage_reference: lineage(reference),
+        prior_time: NonNegativeF64::new(event_time - 1.0).unwrap(),
+        event_time: PositiveF64::new(event_time).unwrap(),
+        origin: IndexedLocation::new(Location::new(0, 0), 0),
+        target: IndexedLocation::new(Location::new(1, 0), 0),
+        interaction,
+        speciation_sample: ClosedUnitF64::one(),
+    }
+}
+
+fn speciation(reference: u64, event_time: f64) -> SpeciationEvent {
+    SpeciationEvent {
+        global_lineage_reference: lineage(reference),
+        prior_time: NonNegativeF64::new(event_time - 1.0).unwrap(),
+        event_time: PositiveF64::new(event_time).unwrap(),
+        origin: IndexedLocation::new(Location::new(1, 0), 0),
+        speciation_sample: ClosedUnitF64::new(0.125).unwrap(),
+    }
+}
+
+/// Records eight events, at times 1 to 8, of the lineages 1 to 3 into an
+///  event log with segments of at most three events, which are written in
+///  the order of the event `times`
+fn write_event_log(directory: &Path, times: [usize; 8]) -> String {
+    let events = [
+        PackedEvent::from(dispersal(1, 1.0, LineageInteraction::None)),
+        PackedEvent::from(dispersal(2, 2.0, LineageInteraction::Maybe)),
+        PackedEvent::from(dispersal(3, 3.0, LineageInteraction::None)),
+        PackedEvent::from(dispersal(
+            1,
+            4.0,
+            LineageInteraction::Coalescence(lineage(2)),
+        )),
+        PackedEvent::from(dispersal(3, 5.0, LineageInteraction::Maybe)),
+        PackedEvent::from(speciation(2, 6.0)),
+        PackedEvent::from(dispersal(3, 7.0, LineageInteraction::None)),
+        PackedEvent::from(speciation(3, 8.0)),
+    ];
+
+    let mut recorder = EventLogRecorder::try_new(
+        directory.join("log"),
+        NonZeroUsize::new(3).unwrap(),
+        EventLogCompression::None,
+    )
+    .unwrap();
+    recorder.set_event_filter(true, true);
+
+    for time in times {
+        match events[time - 1].clone().into() {
+            TypedEvent::Speciation(event) => {
+                recorder.record_speciation(&event);
+            },
+            TypedEvent::Dispersal(event) => recorder.record_dispersal(&event),
+        }
+    }
+
+    std::mem::drop(recorder);
+
+    format!(
+        "EventLog(segments: [{:?}])",
+        directory.join("log").join("*")
+    )
+}
+
+fn event_times(log: &str) -> Vec<f64> {
+    try_parse::<EventLogReplay>("log", log)
+        .unwrap()
+        .map(|event| event.event_time().get())
+        .collect()
+}
+
+#[test]
+fn test_summary() {
+    let directory = TempDir::new("summary");
+    let event_log = write_event_log(&directory.0, [1, 2, 3, 4, 5, 6, 7, 8]);
+
+    log(&format!("(log: {event_log}, command: Summary)")).unwrap();
+
+    assert!(log("(log: EventLog(segments: []), command: Summary)").is_err());
+}
+
+#[test]
+fn test_validate() {
+    let directory = TempDir::new("validate");
+    let event_log = write_event_log(&directory.0, [1, 2, 3, 4, 5, 6, 7, 8]);
+
+    log(&format!(
+        "(log: {event_log}, command: Validate(allow_overlap: false))"
+    ))
+    .unwrap();
+}
+
+#[test]
+fn test_validate_overlapping_segments() {
+    let directory = TempDir::new("overlap");
+    let event_log = write_event_log(&directory.0, [1, 5, 2, 6, 3, 7, 4, 8]);
+
+    log(&format!("(log: {event_log}, command: Validate())")).unwrap();
+    assert!(log(&format!(
+        "(log: {event_log}, command: Validate(allow_overlap: false))"
+    ))
+    .is_err());
+}
+
+#[test]
+fn test_validate_rejects_corrupted_segment() {
+    let directory = TempDir::new("corrupted");
+    let event_log = write_event_log(&directory.0, [1, 2, 3, 4, 5, 6, 7, 8]);
+
+    log(&format!("(log: {event_log}, command: Validate())")).unwrap();
+
+    // Truncate the last event of the second segment, whose header is intact
+    let segment = directory.0.join("log").join("1");
+    let length = fs::metadata(&segment).unwrap().len();
+    File::options()
+        .write(true)
+        .open(&segment)
+        .unwrap()
+        .set_len(length - 1)
+        .unwrap();
+
+    assert!(log(&format!("(log: {event_log}, command: Validate())")).is_err());
+}
+
+#[test]
+fn test_merge() {
+    let directory = TempDir::new("merge");
+    let event_log = write_event_log(&directory.0, [1, 5, 2, 6, 3, 7, 4, 8]);
+
+    log(&format!(
+        "(log: {event_log}, command: Merge(output: EventLog(directory: {:?}, capacity: 4, \
+         compression: Zstd)))",
+        directory.0.join("merged"),
+    ))
+    .unwrap();
+
+    let merged = format!(
+        "EventLog(segments: [{:?}])",
+        directory.0.join("merged").join("*")
+    );
+
+    assert_eq!(
+        event_times(&merged),
+        [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]
+    );
+    assert_eq!(fs::read_dir(directory.0.join("merged")).unwrap().count(), 2);
+
+    // The merged segments no longer overlap
+    log(&format!(
+        "(log: {merged}, command: Validate(allow_overlap: false))"
+    ))
+    .unwrap();
+}
+
+#[test]
+fn test_merge_filter() {
+    let directory = TempDir::new("filter");
+    let event_log = write_event_log(&directory.0, [1, 2, 3, 4, 5, 6, 7, 8]);
+
+    log(&format!(
+        "(log: {event_log}, command: Merge(output: EventLog(directory: {:?}), filter: (from: 2.0, \
+         until: 7.0, lineages: [3, 2])))",
+        directory.0.join("filtered"),
+    ))
+    .unwrap();
+
+    assert_eq!(
+        event_times(&format!(
+            "EventLog(segments: [{:?}])",
+            directory.0.join("filtered").join("*")
+        )),
+        [2.0, 3.0, 5.0, 6.0]
+    );
+
+    assert!(log(&format!(
+        "(log: {event_log}, command: Merge(output: EventLog(directory: {:?}), filter: (from: 7.0, \
+         until: 7.0)))",
+        directory.0.join("empty"),
+    ))
+    .is_err());
+}
+
+#[test]
+fn test_convert_csv() {
+    let directory = TempDir::new("csv");
+    let event_log = write_event_log(&directory.0, [1, 2, 3, 4, 5, 6, 7, 8]);
+    let output = directory.0.join("events.csv");
+
+    log(&format!(
+        "(log: {event_log}, command: Convert(output: {output:?}, filter: (lineages: [2])))"
+    ))
+    .unwrap();
+
+    assert_eq!(
+        fs::read_to_string(&output).unwrap(),
+        concat!(
+            "reference,time,prior_time,x,y,index,target_x,target_y,target_index,interaction,",
+            "parent,type\n",
+            "2,2,1,0,0,0,1,0,0,maybe,,d\n",
+            "2,6,5,1,0,0,,,,,,s\n",
+        )
+    );
+
+    // The converted output must not exist yet
+    assert!(log(&format!(
+        "(log: {event_log}, command: Convert(output: {output:?}))"
+    ))
+    .is_err());
+}
+
+#[test]
+fn test_convert_arrow() {
+    let directory = TempDir::new("arrow");
+    let event_log = write_event_log(&directory.0, [1, 5, 2, 6, 3, 7, 4, 8]);
+    let output = directory.0.join("events.arrow");
+
+    log(&format!(
+        "(log: {event_log}, command: Convert(output: {output:?}, format: Arrow, filter: (until: \
+         8.0)))"
+    ))
+    .unwrap();
+
+    let mut reader = File::open(&output).unwrap();
+    let metadata = ipc::read::read_file_metadata(&mut reader).unwrap();
+
+    assert_eq!(
+        metadata
+            .schema
+            .fields
+            .iter()
+            .map(|field| field.name.as_str())
+            .collect::<Vec<_>>(),
+        [
+            "reference",
+            "time",
+            "prior_time",
+            "x",
+            "y",
+            "index",
+            "target_x",
+            "target_y",
+            "target_index",
+            "interaction",
+            "parent",
+            "type"
+        ]
+    );
+
+    let chunks = ipc::read::FileReader::new(reader, metadata, None, None)
+        .collect::<arrow2::error::Result<Vec<_>>>()
+        .unwrap();
+
+    assert_eq!(
+        chunks.iter().map(arrow2::chunk::Chunk::len).sum::<usize>(),
+        7
+    );
+}
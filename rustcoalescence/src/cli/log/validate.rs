@@ -0,0 +1,140 @@
+use anyhow::Result;
+
+use necsim_core::event::{PackedEvent, TypedEvent};
+use necsim_impls_std::event_log::replay::{segment::SortedSegment, EventLogReplay};
+
+#[allow(clippy::module_name_repetitions)]
+pub fn validate_segments(log: &EventLogReplay, allow_overlap: bool) -> Result<()> {
+    let mut segments = log
+        .segment_groups()
+        .flatten()
+        .collect::<Vec<&SortedSegment>>();
+    segments.sort_unstable_by_key(|segment| segment.path());
+
+    info!("Validating {} event log segment(s) ...", segments.len());
+
+    let mut invalid = 0_usize;
+
+    for segment in &segments {
+        if let Err(err) = validate_segment(segment) {
+            error!("The segment {:?} is invalid: {err}", segment.path());
+
+            invalid += 1;
+        }
+    }
+
+    // Sweep over the segments in order of their start times to find overlaps
+    segments.sort_unstable_by(|a, b| {
+        a.header()
+            .min_time()
+            .get()
+            .total_cmp(&b.header().min_time().get())
+    });
+
+    let mut overlapping = 0_usize;
+    let mut latest: Option<&SortedSegment> = None;
+
+    for segment in &segments {
+        if let Some(latest) = latest {
+            if segment.header().min_time() <= latest.header().max_time() {
+                overlapping += 1;
+
+                if allow_overlap {
+                    debug!(
+                        "The segment {:?} overlaps with the segment {:?}.",
+                        segment.path(),
+                        latest.path()
+                    );
+                } else {
+                    error!(
+                        "The segment {:?} overlaps with the segment {:?}.",
+                        segment.path(),
+                        latest.path()
+                    );
+                }
+            }
+        }
+
+        let extends_latest = match latest {
+            Some(latest) => segment.header().max_time() > latest.header().max_time(),
+            None => true,
+        };
+
+        if extends_latest {
+            latest = Some(segment);
+        }
+    }
+
+    if overlapping > 0 && allow_overlap {
+        warn!(
+            "{overlapping} segment(s) overlap in time with an earlier segment, which requires {} \
+             segment group(s) to be merged during replay. Consider merging the event log.",
+            log.segment_groups().count()
+        );
+    }
+
+    anyhow::ensure!(
+        invalid == 0,
+        "{invalid} out of {} event log segment(s) are invalid.",
+        segments.len()
+    );
+    anyhow::ensure!(
+        allow_overlap || overlapping == 0,
+        "{overlapping} out of {} event log segment(s) overlap in time.",
+        segments.len()
+    );
+
+    info!("The event log is valid.");
+
+    Ok(())
+}
+
+/// Reads the entire `segment` to check that its events are sorted and that
+///  its header correctly summarises them
+fn validate_segment(segment: &SortedSegment) -> Result<()> {
+    let header = segment.header();
+
+    let mut length = 0_usize;
+    let mut previous: Option<PackedEvent> = None;
+
+    for event in SortedSegment::try_new(segment.path(), segment.capacity())? {
+        anyhow::ensure!(
+            event.event_time() >= header.min_time() && event.event_time() <= header.max_time(),
+            "event #{length} at time {} lies outside the segment's time range [{}, {}]",
+            event.event_time(),
+            header.min_time(),
+            header.max_time()
+        );
+
+        if let Some(previous) = &previous {
+            anyhow::ensure!(
+                previous <= &event,
+                "event #{length} at time {} is out of order after an event at time {}",
+                event.event_time(),
+                previous.event_time()
+            );
+        }
+
+        match event.clone().into() {
+            TypedEvent::Speciation(_) => anyhow::ensure!(
+                header.with_speciation(),
+                "event #{length} is a speciation event, but the header excludes speciation"
+            ),
+            TypedEvent::Dispersal(_) => anyhow::ensure!(
+                header.with_dispersal(),
+                "event #{length} is a dispersal event, but the header excludes dispersal"
+            ),
+        }
+
+        length += 1;
+        previous = Some(event);
+    }
+
+    anyhow::ensure!(
+        length == header.length(),
+        "the segment contains {length} event(s), but its header records {}",
+        header.length()
+    );
+
+    Ok(())
+}
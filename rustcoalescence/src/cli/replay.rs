@@ -1,15 +1,30 @@
+use std::{num::NonZeroUsize, sync::Mutex};
+
 use anyhow::{Context, Result};
 use log::LevelFilter;
 
-use necsim_core::{event::TypedEvent, reporter::Reporter};
+use necsim_core::{
+    event::{PackedEvent, TypedEvent},
+    reporter::{boolean::Boolean, Reporter},
+};
+use necsim_impls_std::event_log::replay::{shard::EventLogShard, EventLogReplay};
 
-use necsim_plugins_core::match_any_reporter_plugin_vec;
+use necsim_plugins_core::{
+    import::{ReporterPluginShardVec, ReporterPluginVec},
+    match_any_reporter_plugin_vec,
+};
 
-use crate::args::{
-    cli::{replay::ReplayArgs, CommandArgs},
-    utils::parse::{try_parse, try_print},
+use rustcoalescence_api::utils::parse::{try_parse, try_print};
+
+use crate::args::cli::{
+    replay::{ReplayArgs, ReplayParallelism, ReplayRegion, ReplayTimeWindow},
+    CommandArgs,
 };
 
+/// # Errors
+///
+/// Returns an error iff parsing the replay config or replaying the events
+///  failed.
 #[allow(clippy::module_name_repetitions)]
 pub fn replay_with_logger(replay_args: CommandArgs) -> Result<()> {
     log::set_max_level(LevelFilter::Info);
@@ -23,43 +38,272 @@ pub fn replay_with_logger(replay_args: CommandArgs) -> Result<()> {
     println!("{}", config_str.trim_start_matches("Replay"));
     println!("\n{:=^80}\n", " Replay Configuration ");
 
+    let mut event_log = replay_args.event_log;
+
+    if let Some(time) = replay_args.time {
+        let length = event_log.length();
+
+        event_log.retain_segments(|header| time.overlaps(header.min_time(), header.max_time()));
+
+        info!(
+            "Skipping {} event(s) in segments outside of the replay time window ...",
+            length - event_log.length()
+        );
+    }
+
+    let filter = ReplayFilter {
+        time: replay_args.time,
+        region: replay_args.region.as_ref(),
+    };
+
     info!("Starting event replay ...");
 
     match_any_reporter_plugin_vec!(replay_args.reporters => |mut reporter| {
-        reporter.initialise().map_err(anyhow::Error::msg)?;
+        match replay_args.parallelism {
+            ReplayParallelism::Sequential => {
+                reporter.initialise().map_err(anyhow::Error::msg)?;
 
-        let mut remaining = replay_args.event_log.length() as u64;
+                replay_sequential(event_log, &mut reporter, filter);
 
-        reporter.report_progress(&remaining.into());
+                finalise_with_summary(|| reporter.finalise());
+            },
+            ReplayParallelism::Parallel { threads, shards } => {
+                let (mut sequential, mut mergeable) = reporter.split_mergeable();
 
-        for event in replay_args.event_log {
-            remaining -= 1;
-            reporter.report_progress(&remaining.into());
+                sequential.initialise().map_err(anyhow::Error::msg)?;
+                mergeable.initialise().map_err(anyhow::Error::msg)?;
 
-            match event.into() {
-                TypedEvent::Speciation(event) => {
-                    reporter.report_speciation(&event.into());
-                },
-                TypedEvent::Dispersal(event) => {
-                    reporter.report_dispersal(&event.into());
-                },
+                if mergeable.is_empty() {
+                    warn!(
+                        "None of the reporters are mergeable, so all events are replayed \
+                         sequentially."
+                    );
+                } else {
+                    replay_parallel(
+                        &event_log,
+                        &mut mergeable,
+                        filter,
+                        threads,
+                        shards.unwrap_or(threads),
+                    )?;
+                }
+
+                if !sequential.is_empty() {
+                    replay_sequential(event_log, &mut sequential, filter);
+                }
+
+                finalise_with_summary(|| {
+                    mergeable.finalise();
+                    sequential.finalise();
+                });
+            },
+        }
+    });
+
+    info!("The event replay has completed.");
+
+    Ok(())
+}
+
+/// Time window and region which select the replayed events
+#[derive(Clone, Copy)]
+struct ReplayFilter<'r> {
+    time: Option<ReplayTimeWindow>,
+    region: Option<&'r ReplayRegion>,
+}
+
+impl ReplayFilter<'_> {
+    fn is_after_end(&self, event: &PackedEvent) -> bool {
+        match self.time {
+            Some(time) => time.is_after_end(event.event_time()),
+            None => false,
+        }
+    }
+
+    fn select(&self, event: PackedEvent) -> Option<TypedEvent> {
+        if let Some(time) = self.time {
+            if !time.contains(event.event_time()) {
+                return None;
             }
         }
 
-        if log::log_enabled!(log::Level::Info) {
-            println!("\n");
-            println!("{:=^80}", " Reporter Summary ");
-            println!();
+        let event = TypedEvent::from(event);
+
+        if let Some(region) = self.region {
+            let origin = match &event {
+                TypedEvent::Speciation(speciation) => &speciation.origin,
+                TypedEvent::Dispersal(dispersal) => &dispersal.origin,
+            };
+
+            if !region.contains(origin.location()) {
+                return None;
+            }
         }
-        reporter.finalise();
-        if log::log_enabled!(log::Level::Info) {
-            println!();
-            println!("{:=^80}", " Reporter Summary ");
-            println!();
+
+        Some(event)
+    }
+}
+
+/// Replays all selected events of the `event_log` in their global order on
+///  the current thread
+fn replay_sequential<R: Reporter>(
+    event_log: EventLogReplay,
+    reporter: &mut R,
+    filter: ReplayFilter,
+) {
+    let mut remaining = event_log.length() as u64;
+
+    reporter.report_progress(&remaining.into());
+
+    for event in event_log {
+        if filter.is_after_end(&event) {
+            break;
         }
-    });
 
-    info!("The event replay has completed.");
+        remaining = remaining.saturating_sub(1);
+        reporter.report_progress(&remaining.into());
+
+        match filter.select(event) {
+            Some(TypedEvent::Speciation(event)) => {
+                reporter.report_speciation(&event.into());
+            },
+            Some(TypedEvent::Dispersal(event)) => {
+                reporter.report_dispersal(&event.into());
+            },
+            None => (),
+        }
+    }
+
+    if remaining > 0 {
+        reporter.report_progress(&0_u64.into());
+    }
+}
+
+/// Replays time shards of the `event_log` into forks of the mergeable
+///  `reporter` on a pool of `threads`, and then merges the forks back into
+///  the `reporter` in order of time
+fn replay_parallel<ReportSpeciation: Boolean, ReportDispersal: Boolean, ReportProgress: Boolean>(
+    event_log: &EventLogReplay,
+    reporter: &mut ReporterPluginVec<ReportSpeciation, ReportDispersal, ReportProgress>,
+    filter: ReplayFilter,
+    threads: NonZeroUsize,
+    shards: NonZeroUsize,
+) -> Result<()>
+where
+    ReporterPluginShardVec<ReportSpeciation, ReportDispersal>: Send,
+{
+    let shards = event_log.time_shards(shards);
+
+    info!(
+        "Replaying {} time shard(s) of the event log on {threads} thread(s) ...",
+        shards.len()
+    );
+
+    let forks = shards
+        .into_iter()
+        .enumerate()
+        .map(|(index, shard)| {
+            reporter
+                .fork_shard()
+                .map(|fork| (index, shard, fork))
+                .context("Failed to fork the mergeable reporters.")
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let workers = threads.get().min(forks.len());
+    let queue = Mutex::new(forks.into_iter());
+
+    let mut replayed = std::thread::scope(|scope| {
+        let workers = (0..workers)
+            .map(|_| scope.spawn(|| replay_shards_worker(&queue, filter)))
+            .collect::<Vec<_>>();
+
+        workers
+            .into_iter()
+            .map(|worker| {
+                worker
+                    .join()
+                    .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+            })
+            .collect::<Result<Vec<_>>>()
+    })?
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>();
+
+    replayed.sort_unstable_by_key(|(index, _)| *index);
+
+    for (_, fork) in replayed {
+        reporter.merge_shard(fork);
+    }
 
     Ok(())
 }
+
+type ShardQueue<ReportSpeciation, ReportDispersal> = Mutex<
+    std::vec::IntoIter<(
+        usize,
+        EventLogShard,
+        ReporterPluginShardVec<ReportSpeciation, ReportDispersal>,
+    )>,
+>;
+
+/// Replays time shards from the `queue` into their forked reporters until
+///  the `queue` is exhausted
+fn replay_shards_worker<ReportSpeciation: Boolean, ReportDispersal: Boolean>(
+    queue: &ShardQueue<ReportSpeciation, ReportDispersal>,
+    filter: ReplayFilter,
+) -> Result<
+    Vec<(
+        usize,
+        ReporterPluginShardVec<ReportSpeciation, ReportDispersal>,
+    )>,
+> {
+    let mut replayed = Vec::new();
+
+    loop {
+        let next = queue.lock().ok().and_then(|mut queue| queue.next());
+
+        let Some((index, shard, mut fork)) = next else {
+            return Ok(replayed);
+        };
+
+        for event in shard
+            .replay()
+            .with_context(|| format!("Failed to replay the time shard #{index}."))?
+        {
+            if filter.is_after_end(&event) {
+                break;
+            }
+
+            match filter.select(event) {
+                Some(TypedEvent::Speciation(event)) => {
+                    fork.report_speciation(&event.into());
+                },
+                Some(TypedEvent::Dispersal(event)) => {
+                    fork.report_dispersal(&event.into());
+                },
+                None => (),
+            }
+        }
+
+        replayed.push((index, fork));
+    }
+}
+
+fn finalise_with_summary<F: FnOnce()>(finalise: F) {
+    if log::log_enabled!(log::Level::Info) {
+        println!("\n");
+        println!("{:=^80}", " Reporter Summary ");
+        println!();
+    }
+    finalise();
+    if log::log_enabled!(log::Level::Info) {
+        println!();
+        println!("{:=^80}", " Reporter Summary ");
+        println!();
+    }
+}
+
+#[cfg(test)]
+mod tests;
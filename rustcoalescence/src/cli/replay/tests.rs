@@ -0,0 +1,415 @@
+use std::{
+    convert::TryFrom,
+    fs,
+    num::NonZeroUsize,
+    path::{Path, PathBuf},
+};
+
+use necsim_core::{
+    event::{DispersalEvent, PackedEvent, SpeciationEvent, TypedEvent},
+    landscape::{IndexedLocation, LandscapeExtent, Location},
+    lineage::{GlobalLineageReference, LineageInteraction},
+    reporter::Reporter,
+};
+use necsim_core_bond::{ClosedUnitF64, NonNegativeF64, OffByOneU32, PositiveF64};
+use necsim_impls_std::event_log::{recorder::EventLogRecorder, EventLogCompression};
+use necsim_plugins_core::match_any_reporter_plugin_vec;
+
+use rustcoalescence_api::utils::parse::try_parse;
+
+use crate::args::cli::replay::{ReplayArgs, ReplayRegion, ReplayTimeWindow};
+
+use super::{replay_parallel, replay_sequential, ReplayFilter};
+
+struct TempDir(PathBuf);
+
+impl TempDir {
+    fn new(name: &str) -> Self {
+        let directory = std::env::temp_dir().join(format!(
+            "rustcoalescence-replay-{}-{name}",
+            std::process::id()
+        ));
+
+        std::mem::drop(fs::remove_dir_all(&directory));
+        fs::create_dir_all(&directory).unwrap();
+
+        Self(directory)
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        std::mem::drop(fs::remove_dir_all(&self.0));
+    }
+}
+
+/// Path to the dynamic library of the common reporter plugins, which is built
+///  next to this test binary
+fn common_plugins_library() -> PathBuf {
+    std::env::current_exe().unwrap().with_file_name(format!(
+        "{}necsim_plugins_common{}",
+        std::env::consts::DLL_PREFIX,
+        std::env::consts::DLL_SUFFIX
+    ))
+}
+
+fn lineage(inner: u64) -> GlobalLineageReference {
+    unsafe { GlobalLineageReference::from_inner(inner) }
+}
+
+/// Records a pseudo-random event log of `events` events, which contains
+///  speciation, dispersal, and coalescence events, some of which are
+///  duplicated, in segments of at most 64 events
+fn write_event_log(directory: &Path, events: u64) -> String {
+    let mut recorder = EventLogRecorder::try_new(
+        directory.join("log"),
+        NonZeroUsize::new(64).unwrap(),
+        EventLogCompression::Zstd,
+    )
+    .unwrap();
+    recorder.set_event_filter(true, true);
+
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let mut next_random = move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+
+    for i in 0..events {
+        #[allow(clippy::cast_precision_loss)]
+        let event_time = PositiveF64::new(1.0 + (i as f64) * 0.125).unwrap();
+        let prior_time = NonNegativeF64::new(event_time.get() - 1.0).unwrap();
+
+        let random = next_random();
+        #[allow(clippy::cast_possible_truncation)]
+        let origin = IndexedLocation::new(
+            Location::new((random % 4) as u32, ((random >> 8) % 4) as u32),
+            ((random >> 16) % 2) as u32,
+        );
+        let reference = lineage(1 + (random >> 24) % 50);
+
+        if (random >> 32) % 10 == 0 {
+            let speciation = SpeciationEvent {
+                global_lineage_reference: reference,
+                prior_time,
+                event_time,
+                origin,
+                speciation_sample: ClosedUnitF64::zero(),
+            };
+
+            recorder.record_speciation(&speciation);
+
+            // Duplicate speciation event of a lineage which coalesced earlier
+            if (random >> 40) % 3 == 0 {
+                recorder.record_speciation(&SpeciationEvent {
+                    global_lineage_reference: lineage(100 + i),
+                    prior_time: NonNegativeF64::zero(),
+                    ..speciation
+                });
+            }
+        } else {
+            #[allow(clippy::cast_possible_truncation)]
+            let target = if (random >> 36) % 2 == 0 {
+                origin.clone()
+            } else {
+                IndexedLocation::new(
+                    Location::new(((random >> 44) % 4) as u32, ((random >> 52) % 4) as u32),
+                    0,
+                )
+            };
+
+            let interaction = match (random >> 40) % 3 {
+                0 => LineageInteraction::None,
+                1 => LineageInteraction::Maybe,
+                _ => LineageInteraction::Coalescence(lineage(1 + (random >> 48) % 50)),
+            };
+
+            let dispersal = DispersalEvent {
+                global_lineage_reference: reference,
+                prior_time,
+                event_time,
+                origin,
+                target,
+                interaction,
+                speciation_sample: ClosedUnitF64::one(),
+            };
+
+            recorder.record_dispersal(&dispersal);
+
+            // Duplicate dispersal event, which is detected late
+            if (random >> 58) % 4 == 0 {
+                recorder.record_dispersal(&DispersalEvent {
+                    global_lineage_reference: lineage(100 + i),
+                    prior_time: NonNegativeF64::zero(),
+                    ..dispersal
+                });
+            }
+        }
+    }
+
+    std::mem::drop(recorder);
+
+    format!(
+        "EventLog(segments: [{:?}])",
+        directory.join("log").join("*")
+    )
+}
+
+/// Replays the event log with the `Biodiversity` and `Counter` reporters,
+///  optionally in parallel with `(threads, shards)`, and returns the debug
+///  representation of the reporters' results before they are finalised
+fn replay(event_log: &str, options: &str, parallel: Option<(usize, usize)>) -> String {
+    let args: ReplayArgs = try_parse(
+        "replay",
+        &format!(
+            "(log: {event_log}, {options} reporters: [Plugin(library: {:?}, reporters: \
+             [Biodiversity(), Counter()])])",
+            common_plugins_library(),
+        ),
+    )
+    .unwrap();
+
+    let filter = ReplayFilter {
+        time: args.time,
+        region: args.region.as_ref(),
+    };
+
+    match_any_reporter_plugin_vec!(args.reporters => |mut reporter| {
+        reporter.initialise().unwrap();
+
+        match parallel {
+            None => replay_sequential(args.event_log, &mut reporter, filter),
+            Some((threads, shards)) => replay_parallel(
+                &args.event_log,
+                &mut reporter,
+                filter,
+                NonZeroUsize::new(threads).unwrap(),
+                NonZeroUsize::new(shards).unwrap(),
+            )
+            .unwrap(),
+        }
+
+        let results = format!("{reporter:?}");
+
+        reporter.finalise();
+
+        results
+    })
+}
+
+#[test]
+fn test_parallel_replay_matches_sequential_replay() {
+    let directory = TempDir::new("parallel");
+    let event_log = write_event_log(&directory.0, 2000);
+
+    let sequential = replay(&event_log, "", None);
+
+    assert!(sequential.contains("BiodiversityReporter { biodiversity: "));
+    assert!(sequential.contains("EventCounterReporter { speciation: "));
+    assert!(!sequential.contains("biodiversity: 0"));
+    assert!(!sequential.contains("late_coalescence: 0"));
+
+    for (threads, shards) in [(1, 1), (1, 5), (2, 2), (3, 7), (4, 64)] {
+        assert_eq!(
+            replay(&event_log, "", Some((threads, shards))),
+            sequential,
+            "{threads} thread(s) with {shards} shard(s)"
+        );
+    }
+
+    let options =
+        "time: (42.0, 142.0), region: Rectangle(origin: (x: 1, y: 0), width: 2, height: 3),";
+
+    let sequential = replay(&event_log, options, None);
+
+    for (threads, shards) in [(2, 3), (3, 16)] {
+        assert_eq!(
+            replay(&event_log, options, Some((threads, shards))),
+            sequential,
+            "{threads} thread(s) with {shards} shard(s) in a time window and region"
+        );
+    }
+}
+
+fn dispersal_at(event_time: f64, origin: (u32, u32), target: (u32, u32)) -> PackedEvent {
+    PackedEvent::from(DispersalEvent {
+        global_lineage_reference: lineage(1),
+        prior_time: NonNegativeF64::zero(),
+        event_time: PositiveF64::new(event_time).unwrap(),
+        origin: IndexedLocation::new(Location::new(origin.0, origin.1), 0),
+        target: IndexedLocation::new(Location::new(target.0, target.1), 0),
+        interaction: LineageInteraction::None,
+        speciation_sample: ClosedUnitF64::one(),
+    })
+}
+
+fn speciation_at(event_time: f64, origin: (u32, u32)) -> PackedEvent {
+    PackedEvent::from(SpeciationEvent {
+        global_lineage_reference: lineage(1),
+        prior_time: NonNegativeF64::zero(),
+        event_time: PositiveF64::new(event_time).unwrap(),
+        origin: IndexedLocation::new(Location::new(origin.0, origin.1), 0),
+        speciation_sample: ClosedUnitF64::zero(),
+    })
+}
+
+fn time_window(min: f64, max: f64) -> ReplayTimeWindow {
+    ReplayTimeWindow::try_from((
+        NonNegativeF64::new(min).unwrap(),
+        PositiveF64::new(max).unwrap(),
+    ))
+    .unwrap()
+}
+
+fn selects(filter: ReplayFilter, event: &PackedEvent) -> bool {
+    filter.select(event.clone()).is_some()
+}
+
+#[test]
+fn test_time_window_is_closed() {
+    let filter = ReplayFilter {
+        time: Some(time_window(2.0, 4.0)),
+        region: None,
+    };
+
+    for (event_time, selected, after_end) in [
+        (1.875, false, false),
+        (2.0, true, false),
+        (3.0, true, false),
+        (4.0, true, false),
+        (4.125, false, true),
+    ] {
+        for event in [
+            dispersal_at(event_time, (0, 0), (1, 0)),
+            speciation_at(event_time, (0, 0)),
+        ] {
+            assert_eq!(selects(filter, &event), selected, "{event_time}");
+            assert_eq!(filter.is_after_end(&event), after_end, "{event_time}");
+        }
+    }
+
+    // A window of a single point in time only selects events at that time
+    let filter = ReplayFilter {
+        time: Some(time_window(2.0, 2.0)),
+        region: None,
+    };
+
+    assert!(selects(filter, &dispersal_at(2.0, (0, 0), (1, 0))));
+    assert!(!selects(filter, &dispersal_at(2.125, (0, 0), (1, 0))));
+    assert!(filter.is_after_end(&dispersal_at(2.125, (0, 0), (1, 0))));
+
+    assert!(ReplayTimeWindow::try_from((
+        NonNegativeF64::new(4.0).unwrap(),
+        PositiveF64::new(2.0).unwrap(),
+    ))
+    .is_err());
+}
+
+#[test]
+fn test_rectangle_region_includes_its_edges() {
+    let region = ReplayRegion::Rectangle(LandscapeExtent::new(
+        Location::new(1, 2),
+        OffByOneU32::new(2).unwrap(),
+        OffByOneU32::new(3).unwrap(),
+    ));
+    let filter = ReplayFilter {
+        time: None,
+        region: Some(&region),
+    };
+
+    for (origin, selected) in [
+        ((1, 2), true),
+        ((2, 2), true),
+        ((1, 4), true),
+        ((2, 4), true),
+        ((0, 2), false),
+        ((3, 2), false),
+        ((1, 1), false),
+        ((1, 5), false),
+    ] {
+        assert_eq!(
+            selects(filter, &dispersal_at(1.0, origin, (0, 0))),
+            selected,
+            "{origin:?}"
+        );
+        assert_eq!(
+            selects(filter, &speciation_at(1.0, origin)),
+            selected,
+            "{origin:?}"
+        );
+    }
+
+    // Only the origin, not the dispersal target, decides the selection
+    assert!(!selects(filter, &dispersal_at(1.0, (0, 0), (1, 2))));
+    assert!(selects(filter, &dispersal_at(1.0, (1, 2), (0, 0))));
+
+    // The region does not affect the time window
+    assert!(!filter.is_after_end(&dispersal_at(1e10, (0, 0), (0, 0))));
+}
+
+#[test]
+fn test_circle_region_includes_its_boundary() {
+    let region = ReplayRegion::Circle {
+        centre: Location::new(5, 5),
+        radius: 2,
+    };
+    let filter = ReplayFilter {
+        time: None,
+        region: Some(&region),
+    };
+
+    for (origin, selected) in [
+        ((5, 5), true),
+        ((7, 5), true),
+        ((5, 3), true),
+        ((6, 6), true),
+        ((8, 5), false),
+        ((7, 6), false),
+        ((3, 4), false),
+    ] {
+        assert_eq!(
+            selects(filter, &dispersal_at(1.0, origin, (5, 5))),
+            selected,
+            "{origin:?}"
+        );
+    }
+
+    // The circle wraps around the edges of the landscape
+    let region = ReplayRegion::Circle {
+        centre: Location::new(0, 0),
+        radius: 1,
+    };
+    let filter = ReplayFilter {
+        time: None,
+        region: Some(&region),
+    };
+
+    assert!(selects(filter, &speciation_at(1.0, (u32::MAX, 0))));
+    assert!(selects(filter, &speciation_at(1.0, (0, u32::MAX))));
+    assert!(!selects(filter, &speciation_at(1.0, (u32::MAX, u32::MAX))));
+}
+
+#[test]
+fn test_time_window_and_region_are_combined() {
+    let region = ReplayRegion::Circle {
+        centre: Location::new(0, 0),
+        radius: 0,
+    };
+    let filter = ReplayFilter {
+        time: Some(time_window(0.0, 1.0)),
+        region: Some(&region),
+    };
+
+    assert!(selects(filter, &dispersal_at(1.0, (0, 0), (1, 1))));
+    assert!(!selects(filter, &dispersal_at(1.0, (1, 0), (0, 0))));
+    assert!(!selects(filter, &dispersal_at(1.125, (0, 0), (0, 0))));
+
+    match filter.select(speciation_at(0.5, (0, 0))) {
+        Some(TypedEvent::Speciation(speciation)) => {
+            assert_eq!(speciation.event_time, PositiveF64::new(0.5).unwrap());
+        },
+        _ => panic!("expected the speciation event to be selected"),
+    }
+}
@@ -0,0 +1,16 @@
+use log::LevelFilter;
+
+use rustcoalescence_api::simulate::simulate_with_plugins;
+
+use crate::args::cli::CommandArgs;
+
+/// # Errors
+///
+/// Returns an error iff parsing the simulation config, initialising the
+///  simulation, or performing the simulation failed.
+#[allow(clippy::module_name_repetitions)]
+pub fn simulate_with_logger(simulate_args: CommandArgs) -> anyhow::Result<()> {
+    log::set_max_level(LevelFilter::Info);
+
+    simulate_with_plugins(&simulate_args.into_config_string()).map(|_outcome| ())
+}
@@ -1,10 +1,4 @@
 #![deny(clippy::pedantic)]
-#![feature(unwrap_infallible)]
-#![feature(split_array)]
-#![feature(result_flattening)]
-
-#[macro_use]
-extern crate serde_derive_state;
 
 #[macro_use]
 extern crate log;
@@ -15,7 +9,6 @@ use clap::Parser;
 mod args;
 mod cli;
 mod minimal_logger;
-mod reporter;
 
 use args::cli::RustcoalescenceArgs;
 use minimal_logger::MinimalLogger;
@@ -38,5 +31,8 @@ fn main() -> Result<()> {
         RustcoalescenceArgs::Replay(replay_args) => {
             cli::replay::replay_with_logger(replay_args).context("Failed to replay the simulation.")
         },
+        RustcoalescenceArgs::Log(log_args) => {
+            cli::log::log_with_logger(log_args).context("Failed to inspect the event log.")
+        },
     }
 }
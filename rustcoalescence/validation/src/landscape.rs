@@ -0,0 +1,89 @@
+//! Small spatially explicit landscapes
+
+use std::{
+    fs::{self, File},
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result};
+use tiff::encoder::{colortype, TiffEncoder};
+
+/// Habitat and dispersal maps of a small spatially explicit landscape with
+///  heterogeneous habitat, in which individuals disperse to their own and all
+///  neighbouring cells
+pub struct Landscape {
+    habitat: PathBuf,
+    dispersal: PathBuf,
+}
+
+impl Landscape {
+    /// Writes the maps of a `width` x `height` landscape into the `directory`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error iff writing either map failed.
+    pub fn try_write(directory: &Path, width: u32, height: u32) -> Result<Self> {
+        fs::create_dir_all(directory)
+            .with_context(|| format!("Failed to create the directory {directory:?}."))?;
+
+        let habitat_path = directory.join(format!("habitat-{width}x{height}.tif"));
+        let dispersal_path = directory.join(format!("dispersal-{width}x{height}.tif"));
+
+        let mut habitat = Vec::with_capacity((width * height) as usize);
+
+        for y in 0..height {
+            for x in 0..width {
+                habitat.push(1 + (x * y + x + 2 * y) % 4);
+            }
+        }
+
+        let mut dispersal = Vec::with_capacity((width * height * width * height) as usize);
+
+        // The ith row stores the dispersal from the cell (i % width, i / width)
+        for source in 0..(width * height) {
+            let (source_x, source_y) = (source % width, source / width);
+
+            for target in 0..(width * height) {
+                let (target_x, target_y) = (target % width, target / width);
+
+                dispersal.push(if source == target {
+                    2.0_f64
+                } else if source_x.abs_diff(target_x) <= 1 && source_y.abs_diff(target_y) <= 1 {
+                    1.0_f64
+                } else {
+                    0.0_f64
+                });
+            }
+        }
+
+        TiffEncoder::new(File::create(&habitat_path)?)
+            .and_then(|mut encoder| {
+                encoder.write_image::<colortype::Gray32>(width, height, &habitat)
+            })
+            .with_context(|| format!("Failed to write the habitat map {habitat_path:?}."))?;
+
+        TiffEncoder::new(File::create(&dispersal_path)?)
+            .and_then(|mut encoder| {
+                encoder.write_image::<colortype::Gray64Float>(
+                    width * height,
+                    width * height,
+                    &dispersal,
+                )
+            })
+            .with_context(|| format!("Failed to write the dispersal map {dispersal_path:?}."))?;
+
+        Ok(Self {
+            habitat: habitat_path,
+            dispersal: dispersal_path,
+        })
+    }
+
+    /// Returns the `SpatiallyExplicit` scenario config of this landscape
+    #[must_use]
+    pub fn scenario(&self) -> String {
+        format!(
+            "SpatiallyExplicit(habitat: {:?}, dispersal: {:?}, mode: Strict)",
+            self.habitat, self.dispersal
+        )
+    }
+}
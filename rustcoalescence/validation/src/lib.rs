@@ -0,0 +1,21 @@
+//! Statistical validation of the rustcoalescence algorithms against
+//!  analytical results of neutral theory
+//!
+//! The integration tests of this crate simulate every algorithm on the same
+//!  scenario for many seeds and compare the resulting species richness and
+//!  abundances against
+//! - Ewens' sampling formula for the `NonSpatial` scenario,
+//! - Etienne's sampling formula for the `SpatiallyImplicit` scenario,
+//! - each other for the `SpatiallyExplicit` scenario, which has no analytical
+//!   solution.
+//!
+//! All tests use fixed seeds and are thus deterministic. Since they run many
+//!  simulations, they should be run in release mode:
+//! `cargo test -p rustcoalescence-validation --release`.
+
+#![deny(clippy::pedantic)]
+
+pub mod landscape;
+pub mod neutral;
+pub mod species;
+pub mod statistics;
@@ -0,0 +1,105 @@
+//! Analytical species richness distributions of the neutral model
+//!
+//! In necsim, a lineage which does not speciate disperses to any of the `J`
+//!  individuals of its community, including its own position, and coalesces
+//!  with the lineage that is already there. Thus, the fundamental biodiversity
+//!  number is `θ = ν J / (1 - ν)` instead of `ν (J - 1) / (1 - ν)`, and the
+//!  immigration number is `I = m J / (1 - m)` instead of
+//!  `m (J - 1) / (1 - m)`.
+
+/// Computes the fundamental biodiversity number `θ` of a community of
+///  `individuals` with the per-generation `speciation` probability
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn fundamental_biodiversity_number(individuals: u64, speciation: f64) -> f64 {
+    speciation * (individuals as f64) / (1.0_f64 - speciation)
+}
+
+/// Computes the immigration number `I` of a local community of `individuals`
+///  with the per-generation `migration` probability
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn immigration_number(individuals: u64, migration: f64) -> f64 {
+    migration * (individuals as f64) / (1.0_f64 - migration)
+}
+
+/// Computes the distribution of the number of species in a sample of
+///  `sample` individuals from a community with the fundamental biodiversity
+///  number `theta`, as given by Ewens' sampling formula.
+///
+/// The `k`th entry of the returned vector is the probability of observing
+///  exactly `k` species.
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn ewens_species_richness(theta: f64, sample: u64) -> Vec<f64> {
+    // Going back in time, the next event among `i+1` lineages is a speciation
+    //  with probability `θ / (θ + i)`, and a coalescence otherwise
+    poisson_binomial((0..sample).map(|i| theta / (theta + (i as f64))))
+}
+
+/// Computes the distribution of the number of species in a sample of
+///  `sample` individuals from a local community with the immigration number
+///  `immigration`, which is coupled to a metacommunity with the fundamental
+///  biodiversity number `theta`, as given by Etienne's sampling formula.
+///
+/// The `k`th entry of the returned vector is the probability of observing
+///  exactly `k` species.
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn etienne_species_richness(theta: f64, immigration: f64, sample: u64) -> Vec<f64> {
+    // The sample descends from `A` immigrating ancestors, which are
+    //  distributed like the species in Ewens' sampling formula with `I`
+    let ancestors = poisson_binomial((0..sample).map(|i| immigration / (immigration + (i as f64))));
+
+    let mut richness = vec![0.0_f64; ancestors.len()];
+
+    // The immigrating ancestors are a sample from the metacommunity
+    for (num_ancestors, p_ancestors) in (0_u64..).zip(ancestors) {
+        for (species, p_species) in ewens_species_richness(theta, num_ancestors)
+            .into_iter()
+            .enumerate()
+        {
+            richness[species] += p_ancestors * p_species;
+        }
+    }
+
+    richness
+}
+
+/// Computes the mean of the discrete probability mass function `pmf`
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn mean(pmf: &[f64]) -> f64 {
+    pmf.iter().enumerate().map(|(k, p)| (k as f64) * p).sum()
+}
+
+/// Computes the variance of the discrete probability mass function `pmf`
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn variance(pmf: &[f64]) -> f64 {
+    let mean = mean(pmf);
+
+    pmf.iter()
+        .enumerate()
+        .map(|(k, p)| ((k as f64) - mean).powi(2) * p)
+        .sum()
+}
+
+/// Computes the distribution of the number of successes in independent
+///  Bernoulli trials with the success `probabilities`
+fn poisson_binomial<I: IntoIterator<Item = f64>>(probabilities: I) -> Vec<f64> {
+    let mut pmf = vec![1.0_f64];
+
+    for p in probabilities {
+        let mut next = vec![0.0_f64; pmf.len() + 1];
+
+        for (k, p_k) in pmf.into_iter().enumerate() {
+            next[k] += p_k * (1.0_f64 - p);
+            next[k + 1] += p_k * p;
+        }
+
+        pmf = next;
+    }
+
+    pmf
+}
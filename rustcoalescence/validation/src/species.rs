@@ -0,0 +1,169 @@
+//! Species abundances of in-process simulations
+
+use std::{
+    collections::hash_map::Entry,
+    sync::{Arc, Mutex},
+};
+
+use fnv::{FnvHashMap, FnvHashSet};
+
+use necsim_core::{
+    impl_finalise, impl_report,
+    landscape::IndexedLocation,
+    lineage::{GlobalLineageReference, LineageInteraction},
+    reporter::Reporter,
+};
+use necsim_core_bond::{NonNegativeF64, PositiveF64};
+
+use rustcoalescence_api::simulate::simulate_with_reporter;
+
+/// Simulates the `scenario` with the `algorithm` and the `seed`, and returns
+///  the abundances of all species in the sample in descending order.
+///
+/// The simulation samples all individuals and uses reproducible maths, such
+///  that its result does not depend on the machine.
+///
+/// # Errors
+///
+/// Returns an error iff the simulation failed.
+pub fn simulate_species_abundances(
+    speciation: f64,
+    algorithm: &str,
+    scenario: &str,
+    seed: u64,
+) -> anyhow::Result<Vec<usize>> {
+    let config = format!(
+        "(
+            speciation: {speciation},
+            sample: Sample(percentage: 1.0),
+            rng: Seed({seed}),
+            maths: Reproducible,
+            algorithm: {algorithm},
+            scenario: {scenario},
+            reporters: [],
+        )"
+    );
+
+    let abundances = Arc::new(Mutex::new(None));
+
+    simulate_with_reporter(
+        &config,
+        SpeciesAbundanceReporter {
+            individuals: FnvHashSet::default(),
+            parents: FnvHashMap::default(),
+            events: FnvHashMap::default(),
+            abundances: abundances.clone(),
+        },
+    )?;
+
+    let abundances = abundances
+        .lock()
+        .map_err(|_| anyhow::anyhow!("the species abundance reporter panicked"))?
+        .take();
+
+    abundances.ok_or_else(|| anyhow::anyhow!("the species abundance reporter was not finalised"))
+}
+
+/// Identity of an event, which the Independent algorithm reports once for
+///  every lineage that has coalesced before it
+type EventKey = (IndexedLocation, u64, Option<IndexedLocation>);
+
+/// Reporter which resolves the species of all sampled individuals
+#[derive(Debug)]
+struct SpeciesAbundanceReporter {
+    individuals: FnvHashSet<GlobalLineageReference>,
+    parents: FnvHashMap<GlobalLineageReference, GlobalLineageReference>,
+    events: FnvHashMap<EventKey, GlobalLineageReference>,
+    abundances: Arc<Mutex<Option<Vec<usize>>>>,
+}
+
+impl SpeciesAbundanceReporter {
+    fn report_lineage_event(
+        &mut self,
+        reference: &GlobalLineageReference,
+        prior_time: NonNegativeF64,
+        event_time: PositiveF64,
+        origin: &IndexedLocation,
+        target: Option<&IndexedLocation>,
+    ) {
+        if prior_time == 0.0_f64 {
+            self.individuals.insert(reference.clone());
+        }
+
+        let key = (origin.clone(), event_time.get().to_bits(), target.cloned());
+
+        match self.events.entry(key) {
+            Entry::Occupied(entry) => {
+                let reporter = entry.get().clone();
+                self.coalesce(reference, &reporter);
+            },
+            Entry::Vacant(entry) => {
+                entry.insert(reference.clone());
+            },
+        }
+    }
+
+    fn coalesce(&mut self, child: &GlobalLineageReference, parent: &GlobalLineageReference) {
+        let child = self.ancestor(child);
+        let parent = self.ancestor(parent);
+
+        if child != parent {
+            self.parents.insert(child, parent);
+        }
+    }
+
+    fn ancestor(&self, reference: &GlobalLineageReference) -> GlobalLineageReference {
+        let mut ancestor = reference;
+
+        while let Some(parent) = self.parents.get(ancestor) {
+            ancestor = parent;
+        }
+
+        ancestor.clone()
+    }
+}
+
+impl Reporter for SpeciesAbundanceReporter {
+    impl_report!(speciation(&mut self, speciation: Used) {
+        self.report_lineage_event(
+            &speciation.global_lineage_reference,
+            speciation.prior_time,
+            speciation.event_time,
+            &speciation.origin,
+            None,
+        );
+    });
+
+    impl_report!(dispersal(&mut self, dispersal: Used) {
+        self.report_lineage_event(
+            &dispersal.global_lineage_reference,
+            dispersal.prior_time,
+            dispersal.event_time,
+            &dispersal.origin,
+            Some(&dispersal.target),
+        );
+
+        if let LineageInteraction::Coalescence(parent) = &dispersal.interaction {
+            self.coalesce(&dispersal.global_lineage_reference, parent);
+        }
+    });
+
+    impl_report!(progress(&mut self, _progress: Ignored) {});
+
+    impl_finalise!((self) {
+        // Every lineage eventually speciates, so the most ancestral lineage
+        //  of every individual identifies its species
+        let mut abundances = FnvHashMap::default();
+
+        for individual in &self.individuals {
+            *abundances.entry(self.ancestor(individual)).or_insert(0_usize) += 1;
+        }
+
+        let mut abundances = abundances.into_values().collect::<Vec<_>>();
+        abundances.sort_unstable_by(|a, b| b.cmp(a));
+
+        if let Ok(mut shared) = self.abundances.lock() {
+            *shared = Some(abundances);
+        }
+    });
+}
@@ -0,0 +1,126 @@
+//! Statistical tests of simulated samples
+//!
+//! All tests are performed at the [`SIGNIFICANCE`] level. Since the
+//!  validation tests use fixed seeds, a test either always or never fails.
+
+use crate::neutral;
+
+/// Significance level of all statistical tests
+pub const SIGNIFICANCE: f64 = 0.001;
+
+/// Maximum deviation of a sample mean from the expected mean, in standard
+///  errors
+pub const MAX_STANDARD_ERRORS: f64 = 4.0;
+
+/// Asserts that the integer `samples` follow the discrete distribution with
+///  the probability mass function `pmf`, using a test of the mean and a
+///  one-sample Kolmogorov-Smirnov test.
+///
+/// # Panics
+///
+/// Panics if either test rejects that the `samples` follow the `pmf`.
+#[allow(clippy::cast_precision_loss)]
+pub fn assert_follows_distribution(name: &str, samples: &[usize], pmf: &[f64]) {
+    let expected_mean = neutral::mean(pmf);
+    let standard_error = (neutral::variance(pmf) / (samples.len() as f64)).sqrt();
+
+    let mean = samples.iter().sum::<usize>() as f64 / (samples.len() as f64);
+
+    assert!(
+        (mean - expected_mean).abs() <= MAX_STANDARD_ERRORS * standard_error,
+        "{name}: the mean {mean} deviates from the expected {expected_mean} by more than \
+         {MAX_STANDARD_ERRORS} standard errors of {standard_error}"
+    );
+
+    let statistic = ks_statistic(samples, pmf);
+    let critical = ks_critical_value(samples.len(), None);
+
+    assert!(
+        statistic <= critical,
+        "{name}: the Kolmogorov-Smirnov statistic {statistic} exceeds the critical value \
+         {critical}"
+    );
+}
+
+/// Asserts that the integer samples `a` and `b` follow the same distribution,
+///  using a two-sample Kolmogorov-Smirnov test.
+///
+/// # Panics
+///
+/// Panics if the test rejects that `a` and `b` follow the same distribution.
+pub fn assert_same_distribution(name: &str, a: &[usize], b: &[usize]) {
+    let statistic = ks_two_sample_statistic(a, b);
+    let critical = ks_critical_value(a.len(), Some(b.len()));
+
+    assert!(
+        statistic <= critical,
+        "{name}: the two-sample Kolmogorov-Smirnov statistic {statistic} exceeds the critical \
+         value {critical}"
+    );
+}
+
+/// Computes the Kolmogorov-Smirnov statistic `sup |F_n(k) - F(k)|` of the
+///  integer `samples` against the discrete distribution with the probability
+///  mass function `pmf`.
+///
+/// Since both distribution functions are step functions, the supremum is
+///  attained at an integer `k`. For discrete distributions, the test is
+///  conservative.
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn ks_statistic(samples: &[usize], pmf: &[f64]) -> f64 {
+    let support = pmf.len().max(samples.iter().max().map_or(0, |max| max + 1));
+
+    let mut statistic = 0.0_f64;
+
+    let mut cdf = 0.0_f64;
+    let mut empirical = 0_usize;
+
+    for k in 0..support {
+        cdf += pmf.get(k).copied().unwrap_or(0.0_f64);
+        empirical += samples.iter().filter(|sample| **sample == k).count();
+
+        statistic = statistic.max(((empirical as f64) / (samples.len() as f64) - cdf).abs());
+    }
+
+    statistic
+}
+
+/// Computes the two-sample Kolmogorov-Smirnov statistic
+///  `sup |F_a(k) - F_b(k)|` of the integer samples `a` and `b`
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn ks_two_sample_statistic(a: &[usize], b: &[usize]) -> f64 {
+    let mut a = a.to_vec();
+    a.sort_unstable();
+
+    let mut b = b.to_vec();
+    b.sort_unstable();
+
+    let mut statistic = 0.0_f64;
+
+    for k in a.iter().chain(b.iter()) {
+        let cdf_a = a.partition_point(|sample| sample <= k) as f64 / (a.len() as f64);
+        let cdf_b = b.partition_point(|sample| sample <= k) as f64 / (b.len() as f64);
+
+        statistic = statistic.max((cdf_a - cdf_b).abs());
+    }
+
+    statistic
+}
+
+/// Computes the asymptotic critical value of the (two-sample)
+///  Kolmogorov-Smirnov test with `n` (and `m`) samples at the
+///  [`SIGNIFICANCE`] level
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn ks_critical_value(n: usize, m: Option<usize>) -> f64 {
+    let coefficient = (-(SIGNIFICANCE / 2.0_f64).ln() / 2.0_f64).sqrt();
+
+    let effective_size = match m {
+        None => n as f64,
+        Some(m) => (n as f64) * (m as f64) / ((n + m) as f64),
+    };
+
+    coefficient / effective_size.sqrt()
+}
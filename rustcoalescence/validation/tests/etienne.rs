@@ -0,0 +1,60 @@
+//! Validates the species richness of the `SpatiallyImplicit` scenario
+//!  against Etienne's sampling formula
+//!
+//! Etienne's formula assumes that all immigrants are sampled from the
+//!  metacommunity at the same time, whereas the simulated immigrants arrive
+//!  over time. The metacommunity is therefore chosen to be large and to
+//!  change slowly, such that the resulting bias is negligible.
+
+use rustcoalescence_validation::{neutral, species::simulate_species_abundances, statistics};
+
+const SEEDS: u64 = 200;
+
+const SPECIATION: f64 = 0.005;
+const LOCAL_INDIVIDUALS: u64 = 25;
+const META_INDIVIDUALS: u64 = 2500;
+const MIGRATION: f64 = 0.1;
+const SPATIALLY_IMPLICIT: &str = "SpatiallyImplicit(
+    local_area: (5, 5), local_deme: 1, meta_area: (50, 50), meta_deme: 1, migration: 0.1,
+)";
+
+fn assert_etienne_species_richness(algorithm: &str) {
+    let expected = neutral::etienne_species_richness(
+        neutral::fundamental_biodiversity_number(META_INDIVIDUALS, SPECIATION),
+        neutral::immigration_number(LOCAL_INDIVIDUALS, MIGRATION),
+        LOCAL_INDIVIDUALS,
+    );
+
+    let richness = (0..SEEDS)
+        .map(|seed| {
+            let abundances =
+                simulate_species_abundances(SPECIATION, algorithm, SPATIALLY_IMPLICIT, seed)
+                    .expect("the simulation succeeds");
+
+            assert_eq!(
+                abundances.iter().sum::<usize>() as u64,
+                LOCAL_INDIVIDUALS,
+                "the species of all individuals are resolved"
+            );
+
+            abundances.len()
+        })
+        .collect::<Vec<_>>();
+
+    statistics::assert_follows_distribution(algorithm, &richness, &expected);
+}
+
+#[test]
+fn gillespie() {
+    assert_etienne_species_richness("Gillespie()");
+}
+
+#[test]
+fn event_skipping() {
+    assert_etienne_species_richness("EventSkipping()");
+}
+
+#[test]
+fn independent() {
+    assert_etienne_species_richness("Independent()");
+}
@@ -0,0 +1,49 @@
+//! Validates the species richness of the `NonSpatial` scenario against
+//!  Ewens' sampling formula
+
+use rustcoalescence_validation::{neutral, species::simulate_species_abundances, statistics};
+
+const SEEDS: u64 = 200;
+
+const SPECIATION: f64 = 0.1;
+const INDIVIDUALS: u64 = 100;
+const NON_SPATIAL: &str = "NonSpatial(area: (5, 5), deme: 4)";
+
+fn assert_ewens_species_richness(algorithm: &str) {
+    let expected = neutral::ewens_species_richness(
+        neutral::fundamental_biodiversity_number(INDIVIDUALS, SPECIATION),
+        INDIVIDUALS,
+    );
+
+    let richness = (0..SEEDS)
+        .map(|seed| {
+            let abundances = simulate_species_abundances(SPECIATION, algorithm, NON_SPATIAL, seed)
+                .expect("the simulation succeeds");
+
+            assert_eq!(
+                abundances.iter().sum::<usize>() as u64,
+                INDIVIDUALS,
+                "the species of all individuals are resolved"
+            );
+
+            abundances.len()
+        })
+        .collect::<Vec<_>>();
+
+    statistics::assert_follows_distribution(algorithm, &richness, &expected);
+}
+
+#[test]
+fn gillespie() {
+    assert_ewens_species_richness("Gillespie()");
+}
+
+#[test]
+fn event_skipping() {
+    assert_ewens_species_richness("EventSkipping()");
+}
+
+#[test]
+fn independent() {
+    assert_ewens_species_richness("Independent()");
+}
@@ -0,0 +1,69 @@
+//! Validates that all algorithms agree on the species richness and the
+//!  species abundance distribution of the `SpatiallyExplicit` scenario, for
+//!  which no analytical solution exists
+//!
+//! The Gillespie algorithm, which can also be selected as `Classical`, serves
+//!  as the reference.
+
+use std::path::Path;
+
+use rustcoalescence_validation::{
+    landscape::Landscape, species::simulate_species_abundances, statistics,
+};
+
+const SEEDS: u64 = 200;
+
+const SPECIATION: f64 = 0.02;
+
+/// Returns the species richness of every simulation and the pooled species
+///  abundance distribution of all simulations
+fn simulate(
+    algorithm: &str,
+    scenario: &str,
+    seeds: std::ops::Range<u64>,
+) -> (Vec<usize>, Vec<usize>) {
+    let mut richness = Vec::new();
+    let mut abundances = Vec::new();
+
+    for seed in seeds {
+        let species = simulate_species_abundances(SPECIATION, algorithm, scenario, seed)
+            .expect("the simulation succeeds");
+
+        richness.push(species.len());
+        abundances.extend(species);
+    }
+
+    (richness, abundances)
+}
+
+fn assert_agrees_with_gillespie(name: &str, algorithm: &str) {
+    let landscape = Landscape::try_write(&Path::new(env!("CARGO_TARGET_TMPDIR")).join(name), 5, 5)
+        .expect("the landscape can be written");
+    let scenario = landscape.scenario();
+
+    // The reference and the algorithm use disjoint seeds to ensure that their
+    //  samples are independent
+    let (reference_richness, reference_abundances) = simulate("Gillespie()", &scenario, 0..SEEDS);
+    let (richness, abundances) = simulate(algorithm, &scenario, SEEDS..(SEEDS * 2));
+
+    statistics::assert_same_distribution(
+        &format!("{algorithm} species richness"),
+        &richness,
+        &reference_richness,
+    );
+    statistics::assert_same_distribution(
+        &format!("{algorithm} species abundances"),
+        &abundances,
+        &reference_abundances,
+    );
+}
+
+#[test]
+fn event_skipping() {
+    assert_agrees_with_gillespie("event_skipping", "EventSkipping()");
+}
+
+#[test]
+fn independent() {
+    assert_agrees_with_gillespie("independent", "Independent()");
+}